@@ -0,0 +1,418 @@
+//! Read Diffie-Hellman parameters such as those generated by `openssl
+//! dhparam` and identify the group they describe.
+//!
+//! Primes are compared against those of the named groups of RFC 7919,
+//! RFC 3526 and RFC 2409 so that the well-known groups are recognised
+//! whichever file they appear in.
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+use openssl::bn::{BigNum, BigNumContext, BigNumRef};
+use openssl::dh::Dh;
+use openssl::error::ErrorStack;
+use wardstone_core::primitive::ffc::*;
+
+use crate::key::Error;
+
+/// Represents Diffie-Hellman domain parameters read from a file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Parameters {
+  /// The named group the parameters describe or a custom group.
+  pub group: Ffc,
+  /// The flaws found in the parameters, if any.
+  pub flaws: Vec<Flaw>,
+}
+
+/// Represents a flaw in Diffie-Hellman parameters that makes them
+/// unsafe to use whatever their size.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Flaw {
+  /// The modulus p is not prime.
+  CompositeModulus,
+  /// The order q of the subgroup is not a prime divisor of p - 1.
+  InvalidSubgroup,
+  /// The generator g is not in the range 1 < g < p - 1.
+  InvalidGenerator,
+  /// The order of the subgroup is not given and (p - 1) / 2 is not
+  /// prime so the generator may well generate a small subgroup.
+  UnsafePrime,
+}
+
+impl Flaw {
+  /// Describes what the parameters need to satisfy to do away with the
+  /// flaw.
+  pub fn requirement(&self) -> &'static str {
+    match self {
+      Self::CompositeModulus => "prime p",
+      Self::InvalidSubgroup => "prime q dividing p - 1",
+      Self::InvalidGenerator => "1 < g < p - 1",
+      Self::UnsafePrime => "safe prime p",
+    }
+  }
+}
+
+impl Display for Flaw {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::CompositeModulus => write!(f, "composite p"),
+      Self::InvalidSubgroup => write!(f, "q that is not a prime divisor of p - 1"),
+      Self::InvalidGenerator => write!(f, "g out of range"),
+      Self::UnsafePrime => write!(f, "p that is not a safe prime"),
+    }
+  }
+}
+
+/// Reads Diffie-Hellman parameters from a file in the PKCS#3 or X9.42
+/// format, either PEM or DER encoded, and checks that they are sound.
+pub fn from_file(path: &Path) -> Result<Parameters, Error> {
+  let data = fs::read(path)?;
+  let dh = Dh::params_from_pem(&data)
+    .or_else(|_| Dh::params_from_der(&data))
+    .map_err(|_| Error::ParseParameters)?;
+  let parameters = check(dh.prime_p(), dh.prime_q(), dh.generator())?;
+  Ok(parameters)
+}
+
+/// Identifies the group with the prime modulus `p` and the subgroup of
+/// order `q`, if given.
+///
+/// Groups other than the named ones are custom groups where N is the
+/// size of `q` or, if it is not given, the size of the private exponent
+/// that matches the security of the modulus.
+pub fn group(p: &BigNumRef, q: Option<&BigNumRef>) -> Result<Ffc, ErrorStack> {
+  if let Some(group) = named_group(p)? {
+    return Ok(group);
+  }
+  let l = p.num_bits() as u16;
+  let n = match q {
+    Some(q) => q.num_bits() as u16,
+    None => exponent_size(l),
+  };
+  Ok(Ffc::new(ID_DH, l, n))
+}
+
+/// Identifies the group the parameters describe and checks them for
+/// the flaws that would undermine it.
+fn check(p: &BigNumRef, q: Option<&BigNumRef>, g: &BigNumRef) -> Result<Parameters, ErrorStack> {
+  let mut ctx = BigNumContext::new()?;
+  let mut flaws = Vec::new();
+  let mut p_minus_one = p.to_owned()?;
+  p_minus_one.sub_word(1)?;
+  if g.num_bits() < 2 || g >= &*p_minus_one {
+    flaws.push(Flaw::InvalidGenerator);
+  }
+  // The primes of the named groups are known to be safe which spares
+  // the primality tests that take a while for large moduli.
+  if let Some(group) = named_group(p)? {
+    return Ok(Parameters { group, flaws });
+  }
+  if !p.is_prime_fasttest(0, &mut ctx, true)? {
+    flaws.push(Flaw::CompositeModulus);
+    return Ok(Parameters {
+      group: group(p, q)?,
+      flaws,
+    });
+  }
+  match q {
+    Some(q) => {
+      let mut remainder = BigNum::new()?;
+      remainder.checked_rem(&p_minus_one, q, &mut ctx)?;
+      if remainder.num_bits() != 0 || !q.is_prime_fasttest(0, &mut ctx, true)? {
+        flaws.push(Flaw::InvalidSubgroup);
+      }
+    },
+    None => {
+      let mut half = BigNum::new()?;
+      half.rshift1(&p_minus_one)?;
+      if !half.is_prime_fasttest(0, &mut ctx, true)? {
+        flaws.push(Flaw::UnsafePrime);
+      }
+    },
+  }
+  Ok(Parameters {
+    group: group(p, q)?,
+    flaws,
+  })
+}
+
+/// Finds the named group with the prime modulus `p` if there is one.
+fn named_group(p: &BigNumRef) -> Result<Option<Ffc>, ErrorStack> {
+  // The value X of the groups of RFC 7919 is the smallest that makes
+  // the prime safe (see Appendix A of the RFC).
+  let candidates = match p.num_bits() {
+    768 => vec![(BigNum::get_rfc2409_prime_768()?, MODP_768)],
+    1024 => vec![(BigNum::get_rfc2409_prime_1024()?, MODP_1024)],
+    1536 => vec![(BigNum::get_rfc3526_prime_1536()?, MODP_1536)],
+    2048 => vec![
+      (ffdhe(2048, 560316)?, FFDHE2048),
+      (BigNum::get_rfc3526_prime_2048()?, MODP_2048),
+    ],
+    3072 => vec![
+      (ffdhe(3072, 2625351)?, FFDHE3072),
+      (BigNum::get_rfc3526_prime_3072()?, MODP_3072),
+    ],
+    4096 => vec![
+      (ffdhe(4096, 5736041)?, FFDHE4096),
+      (BigNum::get_rfc3526_prime_4096()?, MODP_4096),
+    ],
+    6144 => vec![
+      (ffdhe(6144, 15705020)?, FFDHE6144),
+      (BigNum::get_rfc3526_prime_6144()?, MODP_6144),
+    ],
+    8192 => vec![
+      (ffdhe(8192, 10965728)?, FFDHE8192),
+      (BigNum::get_rfc3526_prime_8192()?, MODP_8192),
+    ],
+    _ => Vec::new(),
+  };
+  let group = candidates
+    .into_iter()
+    .find(|(prime, _)| prime.as_ref() == p)
+    .map(|(_, group)| group);
+  Ok(group)
+}
+
+/// Derives the prime of the group of RFC 7919 with `b` bits which is
+/// p = 2^b - 2^{b-64} + {[2^{b-130} e] + x} * 2^64 - 1.
+fn ffdhe(b: i32, x: u32) -> Result<BigNum, ErrorStack> {
+  let mut p = BigNum::new()?;
+  p.set_bit(b)?;
+  let mut low = BigNum::new()?;
+  low.set_bit(b - 64)?;
+  let mut high = BigNum::new()?;
+  high.checked_sub(&p, &low)?;
+  let mut middle = floor_e(b - 130)?;
+  middle.add_word(x)?;
+  let mut shifted = BigNum::new()?;
+  shifted.lshift(&middle, 64)?;
+  p.checked_add(&high, &shifted)?;
+  p.sub_word(1)?;
+  Ok(p)
+}
+
+/// Computes [2^m e] as the sum of 2^m / k! for every k.
+///
+/// Each term is computed with guard bits that absorb the error of
+/// truncating the terms before the sum is scaled back down.
+fn floor_e(m: i32) -> Result<BigNum, ErrorStack> {
+  const GUARD_BITS: i32 = 64;
+  let mut term = BigNum::new()?;
+  term.set_bit(m + GUARD_BITS)?;
+  let mut sum = BigNum::new()?;
+  let mut k = 1;
+  while term.num_bits() > 0 {
+    let mut next = BigNum::new()?;
+    next.checked_add(&sum, &term)?;
+    sum = next;
+    term.div_word(k)?;
+    k += 1;
+  }
+  let mut e = BigNum::new()?;
+  e.rshift(&sum, GUARD_BITS)?;
+  Ok(e)
+}
+
+/// The size of the private exponent that offers the same security as
+/// a modulus of `l` bits according to SP 800-57 Part 1 Rev. 5 (p. 54).
+fn exponent_size(l: u16) -> u16 {
+  match l {
+    ..=1023 => 128,
+    1024..=2047 => 160,
+    2048..=3071 => 224,
+    3072..=7679 => 256,
+    7680..=15359 => 384,
+    15360.. => 512,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  // The primes of the groups as they appear in Appendix A of RFC 7919.
+  const FFDHE2048_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695",
+    "A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A",
+    "D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A",
+    "BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4",
+    "AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005",
+    "C58EF1837D1683B2C6F34A26C1B2EFFA886B423861285C97FFFFFFFFFFFFFFFF",
+  );
+
+  const FFDHE3072_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695",
+    "A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A",
+    "D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A",
+    "BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4",
+    "AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005",
+    "C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B",
+    "BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF",
+    "5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E",
+    "0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B66C62E37FFFFFFFFFFFFFFFF",
+  );
+
+  const FFDHE4096_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695",
+    "A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A",
+    "D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A",
+    "BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4",
+    "AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005",
+    "C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B",
+    "BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF",
+    "5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E",
+    "0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB",
+    "7930E9E4E58857B6AC7D5F42D69F6D187763CF1D5503400487F55BA57E31CC7A",
+    "7135C886EFB4318AED6A1E012D9E6832A907600A918130C46DC778F971AD0038",
+    "092999A333CB8B7A1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF",
+    "8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E655F6AFFFFFFFFFFFFFFFF",
+  );
+
+  const FFDHE6144_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695",
+    "A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A",
+    "D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A",
+    "BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4",
+    "AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005",
+    "C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B",
+    "BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF",
+    "5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E",
+    "0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB",
+    "7930E9E4E58857B6AC7D5F42D69F6D187763CF1D5503400487F55BA57E31CC7A",
+    "7135C886EFB4318AED6A1E012D9E6832A907600A918130C46DC778F971AD0038",
+    "092999A333CB8B7A1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF",
+    "8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E0DD9020BFD64B645036C7A",
+    "4E677D2C38532A3A23BA4442CAF53EA63BB454329B7624C8917BDD64B1C0FD4C",
+    "B38E8C334C701C3ACDAD0657FCCFEC719B1F5C3E4E46041F388147FB4CFDB477",
+    "A52471F7A9A96910B855322EDB6340D8A00EF092350511E30ABEC1FFF9E3A26E",
+    "7FB29F8C183023C3587E38DA0077D9B4763E4E4B94B2BBC194C6651E77CAF992",
+    "EEAAC0232A281BF6B3A739C1226116820AE8DB5847A67CBEF9C9091B462D538C",
+    "D72B03746AE77F5E62292C311562A846505DC82DB854338AE49F5235C95B9117",
+    "8CCF2DD5CACEF403EC9D1810C6272B045B3B71F9DC6B80D63FDD4A8E9ADB1E69",
+    "62A69526D43161C1A41D570D7938DAD4A40E329CD0E40E65FFFFFFFFFFFFFFFF",
+  );
+
+  const FFDHE8192_P: &str = concat!(
+    "FFFFFFFFFFFFFFFFADF85458A2BB4A9AAFDC5620273D3CF1D8B9C583CE2D3695",
+    "A9E13641146433FBCC939DCE249B3EF97D2FE363630C75D8F681B202AEC4617A",
+    "D3DF1ED5D5FD65612433F51F5F066ED0856365553DED1AF3B557135E7F57C935",
+    "984F0C70E0E68B77E2A689DAF3EFE8721DF158A136ADE73530ACCA4F483A797A",
+    "BC0AB182B324FB61D108A94BB2C8E3FBB96ADAB760D7F4681D4F42A3DE394DF4",
+    "AE56EDE76372BB190B07A7C8EE0A6D709E02FCE1CDF7E2ECC03404CD28342F61",
+    "9172FE9CE98583FF8E4F1232EEF28183C3FE3B1B4C6FAD733BB5FCBC2EC22005",
+    "C58EF1837D1683B2C6F34A26C1B2EFFA886B4238611FCFDCDE355B3B6519035B",
+    "BC34F4DEF99C023861B46FC9D6E6C9077AD91D2691F7F7EE598CB0FAC186D91C",
+    "AEFE130985139270B4130C93BC437944F4FD4452E2D74DD364F2E21E71F54BFF",
+    "5CAE82AB9C9DF69EE86D2BC522363A0DABC521979B0DEADA1DBF9A42D5C4484E",
+    "0ABCD06BFA53DDEF3C1B20EE3FD59D7C25E41D2B669E1EF16E6F52C3164DF4FB",
+    "7930E9E4E58857B6AC7D5F42D69F6D187763CF1D5503400487F55BA57E31CC7A",
+    "7135C886EFB4318AED6A1E012D9E6832A907600A918130C46DC778F971AD0038",
+    "092999A333CB8B7A1A1DB93D7140003C2A4ECEA9F98D0ACC0A8291CDCEC97DCF",
+    "8EC9B55A7F88A46B4DB5A851F44182E1C68A007E5E0DD9020BFD64B645036C7A",
+    "4E677D2C38532A3A23BA4442CAF53EA63BB454329B7624C8917BDD64B1C0FD4C",
+    "B38E8C334C701C3ACDAD0657FCCFEC719B1F5C3E4E46041F388147FB4CFDB477",
+    "A52471F7A9A96910B855322EDB6340D8A00EF092350511E30ABEC1FFF9E3A26E",
+    "7FB29F8C183023C3587E38DA0077D9B4763E4E4B94B2BBC194C6651E77CAF992",
+    "EEAAC0232A281BF6B3A739C1226116820AE8DB5847A67CBEF9C9091B462D538C",
+    "D72B03746AE77F5E62292C311562A846505DC82DB854338AE49F5235C95B9117",
+    "8CCF2DD5CACEF403EC9D1810C6272B045B3B71F9DC6B80D63FDD4A8E9ADB1E69",
+    "62A69526D43161C1A41D570D7938DAD4A40E329CCFF46AAA36AD004CF600C838",
+    "1E425A31D951AE64FDB23FCEC9509D43687FEB69EDD1CC5E0B8CC3BDF64B10EF",
+    "86B63142A3AB8829555B2F747C932665CB2C0F1CC01BD70229388839D2AF05E4",
+    "54504AC78B7582822846C0BA35C35F5C59160CC046FD8251541FC68C9C86B022",
+    "BB7099876A460E7451A8A93109703FEE1C217E6C3826E52C51AA691E0E423CFC",
+    "99E9E31650C1217B624816CDAD9A95F9D5B8019488D9C0A0A1FE3075A577E231",
+    "83F81D4A3F2FA4571EFC8CE0BA8A4FE8B6855DFE72B0A66EDED2FBABFBE58A30",
+    "FAFABE1C5D71A87E2F741EF8C1FE86FEA6BBFDE530677F0D97D11D49F7A8443D",
+    "0822E506A9F4614E011E2A94838FF88CD68C8BB7C5C6424CFFFFFFFFFFFFFFFF",
+  );
+
+  fn hex(s: &str) -> BigNum {
+    BigNum::from_hex_str(s).unwrap()
+  }
+
+  fn dec(s: &str) -> BigNum {
+    BigNum::from_dec_str(s).unwrap()
+  }
+
+  #[test]
+  fn ffdhe_primes_match_rfc_7919() {
+    assert_eq!(ffdhe(2048, 560316).unwrap(), hex(FFDHE2048_P));
+    assert_eq!(ffdhe(3072, 2625351).unwrap(), hex(FFDHE3072_P));
+    assert_eq!(ffdhe(4096, 5736041).unwrap(), hex(FFDHE4096_P));
+    assert_eq!(ffdhe(6144, 15705020).unwrap(), hex(FFDHE6144_P));
+    assert_eq!(ffdhe(8192, 10965728).unwrap(), hex(FFDHE8192_P));
+  }
+
+  #[test]
+  fn floor_e_of_small_powers() {
+    // e = 2.71828... so [2^m e] for m = 0, 4 and 10 is 2, 43 and 2783.
+    assert_eq!(floor_e(0).unwrap(), dec("2"));
+    assert_eq!(floor_e(4).unwrap(), dec("43"));
+    assert_eq!(floor_e(10).unwrap(), dec("2783"));
+  }
+
+  #[test]
+  fn named_groups() {
+    let two = dec("2");
+    let parameters = check(&hex(FFDHE2048_P), None, &two).unwrap();
+    assert_eq!(parameters.group, FFDHE2048);
+    assert!(parameters.flaws.is_empty());
+    let modp = BigNum::get_rfc3526_prime_2048().unwrap();
+    assert_eq!(group(&modp, None).unwrap(), MODP_2048);
+  }
+
+  #[test]
+  fn custom_safe_prime() {
+    let parameters = check(&dec("23"), None, &dec("5")).unwrap();
+    assert_eq!(parameters.group, Ffc::new(ID_DH, 5, 128));
+    assert!(parameters.flaws.is_empty());
+  }
+
+  #[test]
+  fn composite_modulus() {
+    // The product of the primes 1000000007 and 1000000009.
+    let p = dec("1000000016000000063");
+    let parameters = check(&p, None, &dec("2")).unwrap();
+    assert_eq!(parameters.flaws, [Flaw::CompositeModulus]);
+  }
+
+  #[test]
+  fn unsafe_prime() {
+    // The Mersenne prime 2^127 - 1 where (p - 1) / 2 = 2^126 - 1 is
+    // divisible by 3.
+    let p = dec("170141183460469231731687303715884105727");
+    let parameters = check(&p, None, &dec("2")).unwrap();
+    assert_eq!(parameters.flaws, [Flaw::UnsafePrime]);
+  }
+
+  #[test]
+  fn subgroup() {
+    let p = dec("23");
+    let parameters = check(&p, Some(&dec("11")), &dec("2")).unwrap();
+    assert!(parameters.flaws.is_empty());
+    assert_eq!(parameters.group, Ffc::new(ID_DH, 5, 4));
+    let parameters = check(&p, Some(&dec("7")), &dec("2")).unwrap();
+    assert_eq!(parameters.flaws, [Flaw::InvalidSubgroup]);
+  }
+
+  #[test]
+  fn generator_out_of_range() {
+    let p = dec("23");
+    for g in ["1", "22", "23"] {
+      let parameters = check(&p, None, &dec(g)).unwrap();
+      assert_eq!(parameters.flaws, [Flaw::InvalidGenerator], "g = {}", g);
+    }
+  }
+}
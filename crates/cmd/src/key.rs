@@ -4,12 +4,22 @@ use std::{fmt, io};
 
 use openssh_keys::errors::OpenSSHKeyError;
 use openssl::error::ErrorStack;
+use serde::Serialize;
+use wardstone_core::context::Usage;
 use wardstone_core::primitive::asymmetric::Asymmetric;
 use wardstone_core::primitive::hash::Hash;
+use wardstone_core::primitive::kdf::Kdf;
+use wardstone_core::primitive::phf::Phf;
+use wardstone_core::primitive::symmetric::Symmetric;
+use wardstone_core::primitive::{Primitive, Security};
 use x509_parser::nom::Err as NomError;
 use x509_parser::prelude::{PEMError, X509Error};
+use x509_parser::time::ASN1Time;
+
+use crate::key::ssh::SshCertificate;
 
 pub mod certificate;
+pub mod private;
 pub mod ssh;
 
 /// Represents a cryptographic key.
@@ -18,34 +28,187 @@ pub trait Key {
   where
     Self: Sized;
   fn hash_function(&self) -> Option<Hash>;
-  fn signature_algorithm(&self) -> Asymmetric;
+  /// The algorithm of the key unless it is encrypted and cannot be
+  /// identified without its passphrase.
+  fn signature_algorithm(&self) -> Option<Asymmetric>;
+  /// The algorithm the key was signed with by its issuer, if any, and
+  /// if it can be determined.
+  fn issuer_signature(&self) -> Option<Asymmetric> {
+    None
+  }
+  /// The purpose the key is put to if it can be determined.
+  fn usage(&self) -> Usage;
+  /// The parameters of the issuer signature if it uses RSASSA-PSS.
+  fn pss_parameters(&self) -> Option<PssParameters> {
+    None
+  }
+  /// The encryption protecting the key at rest, if any.
+  fn protection(&self) -> Option<&Protection> {
+    None
+  }
+  /// The period during which the key is valid if it has one.
+  fn validity(&self) -> Option<Validity> {
+    None
+  }
+  /// The restrictions placed on the key if it is an OpenSSH
+  /// certificate.
+  fn ssh_certificate(&self) -> Option<&SshCertificate> {
+    None
+  }
+}
+
+/// Represents the parameters of an RSASSA-PSS signature (see RFC 4055)
+/// where the trailer field is always 1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PssParameters {
+  /// The hash function applied to the message.
+  pub hash: Hash,
+  /// The hash function of the MGF1 mask generation function.
+  pub mask_hash: Hash,
+  /// The length of the salt in bytes.
+  pub salt_length: u32,
+}
+
+/// Represents the period during which a certificate is valid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Validity {
+  pub not_before: ASN1Time,
+  pub not_after: ASN1Time,
+}
+
+impl Validity {
+  /// Returns the year the certificate expires which is also the last
+  /// year its primitives need to protect anything.
+  pub fn expiry_year(&self) -> u16 {
+    let year = self.not_after.to_datetime().year();
+    u16::try_from(year).unwrap_or_default()
+  }
+
+  /// Whether the certificate has expired at the given time.
+  pub fn is_expired(&self, at: ASN1Time) -> bool {
+    at > self.not_after
+  }
+
+  /// Whether the certificate is not valid yet at the given time.
+  pub fn is_premature(&self, at: ASN1Time) -> bool {
+    at < self.not_before
+  }
+}
+
+/// Represents the function deriving the key that encrypts a private
+/// key from its passphrase.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeyDerivation {
+  /// A hash function applied to the passphrase as done by PBES1 and the
+  /// traditional PEM format.
+  Hash(Hash),
+  /// PBKDF2 along with its iteration count.
+  Kdf(Kdf),
+  /// A password hashing function such as scrypt along with its cost
+  /// parameters.
+  Phf(Phf),
+}
+
+impl Primitive for KeyDerivation {
+  fn security(&self) -> Security {
+    match self {
+      KeyDerivation::Hash(hash) => hash.security(),
+      KeyDerivation::Kdf(kdf) => kdf.security(),
+      KeyDerivation::Phf(phf) => phf.security(),
+    }
+  }
+}
+
+impl fmt::Display for KeyDerivation {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      KeyDerivation::Hash(hash) => hash.fmt(f),
+      KeyDerivation::Kdf(kdf) => kdf.fmt(f),
+      KeyDerivation::Phf(phf) => phf.fmt(f),
+    }
+  }
+}
+
+impl From<Hash> for KeyDerivation {
+  fn from(hash: Hash) -> Self {
+    Self::Hash(hash)
+  }
+}
+
+impl From<Kdf> for KeyDerivation {
+  fn from(kdf: Kdf) -> Self {
+    Self::Kdf(kdf)
+  }
+}
+
+impl From<Phf> for KeyDerivation {
+  fn from(phf: Phf) -> Self {
+    Self::Phf(phf)
+  }
+}
+
+impl Serialize for KeyDerivation {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.serialize_str(&self.to_string())
+  }
+}
+
+/// Represents the encryption protecting a private key at rest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Protection {
+  /// A description of the scheme such as `pbes2 with pbkdf2`.
+  pub scheme: String,
+  /// The function deriving the encryption key from the passphrase, if
+  /// it can be identified.
+  pub kdf: Option<KeyDerivation>,
+  /// The cipher used to encrypt the key, if it can be identified.
+  pub cipher: Option<Symmetric>,
 }
 
 /// Represents an error that could arise as a result of reading a key or
 /// parsing its contents.
 #[derive(Debug)]
 pub enum Error {
+  Config(String),
+  Connect(String),
+  Decrypt,
   Io(io::Error),
   ParsePEM(NomError<PEMError>),
+  ParseParameters,
+  ParsePrivateKey,
   ParseSsh(OpenSSHKeyError),
   ParseX509(ErrorStack),
   ParseX509Certificate(NomError<X509Error>),
+  Pattern(String),
+  Policy(String),
+  TooLarge(u64),
   Unrecognised(String),
 }
 
 impl fmt::Display for Error {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
+      Error::Config(reason) => write!(f, "Invalid configuration: {}", reason),
+      Error::Connect(reason) => write!(f, "Cannot connect: {}", reason),
+      Error::Decrypt => write!(f, "Cannot decrypt private key. Check the passphrase."),
       Error::Io(err) => match err.kind() {
         io::ErrorKind::NotFound => write!(f, "Key not found."),
         io::ErrorKind::PermissionDenied => write!(f, "Permission denied."),
         _ => write!(f, "Unexpected error. Please file an issue."),
       },
       Error::ParsePEM(_) => write!(f, "Cannot parse PEM file."),
+      Error::ParseParameters => write!(f, "Cannot parse Diffie-Hellman parameters."),
+      Error::ParsePrivateKey => write!(f, "Cannot parse private key."),
       Error::ParseSsh(_) => write!(f, "Cannot parse SSH public key."),
       Error::ParseX509Certificate(_) | Error::ParseX509(_) => {
         write!(f, "Cannot parse X.509 certificate.")
       },
+      Error::Pattern(reason) => write!(f, "Invalid pattern: {}", reason),
+      Error::Policy(reason) => write!(f, "Invalid policy: {}", reason),
+      Error::TooLarge(limit) => write!(f, "Skipped: larger than {} MiB.", limit >> 20),
       Error::Unrecognised(oid) => write!(f, "Unrecognised key: {}. Please file an issue.", oid),
     }
   }
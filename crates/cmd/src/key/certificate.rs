@@ -6,17 +6,27 @@ use std::io::Read;
 use std::path::Path;
 
 use once_cell::sync::Lazy;
+use openssl::pkcs7::Pkcs7;
 use openssl::x509::X509;
+use wardstone_core::context::Usage;
 use wardstone_core::primitive::asymmetric::Asymmetric;
 use wardstone_core::primitive::ecc::*;
+use wardstone_core::primitive::ffc::*;
 use wardstone_core::primitive::hash::*;
 use wardstone_core::primitive::ifc::*;
-use x509_parser::pem;
-use x509_parser::prelude::{FromDer, TbsCertificate, X509Certificate};
+use wardstone_core::primitive::pqs::*;
+use x509_parser::nom::Err as NomError;
+use x509_parser::pem::Pem;
+use x509_parser::prelude::{
+  AlgorithmIdentifier, FromDer, PEMError, TbsCertificate, X509Certificate, X509Error,
+};
+use x509_parser::public_key::RSAPublicKey;
+use x509_parser::signature_algorithm::RsaSsaPssParams;
 
-use crate::key::{Error, Key};
+use crate::dhparam;
+use crate::key::{Error, Key, PssParameters, Validity};
 
-static ASYMMETRIC: Lazy<HashMap<&str, Asymmetric>> = Lazy::new(|| {
+pub(crate) static ASYMMETRIC: Lazy<HashMap<&str, Asymmetric>> = Lazy::new(|| {
   let mut m = HashMap::new();
   m.insert("1.2.840.10045.3.0.1", C2PNB163V1.into());
   m.insert("1.2.840.10045.3.0.10", C2PNB208W1.into());
@@ -102,165 +112,548 @@ static ASYMMETRIC: Lazy<HashMap<&str, Asymmetric>> = Lazy::new(|| {
   m
 });
 
+pub(crate) static PQ_SIGNATURES: Lazy<HashMap<&str, Pqs>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert("2.16.840.1.101.3.4.3.17", ML_DSA_44);
+  m.insert("2.16.840.1.101.3.4.3.18", ML_DSA_65);
+  m.insert("2.16.840.1.101.3.4.3.19", ML_DSA_87);
+  m.insert("2.16.840.1.101.3.4.3.20", SLH_DSA_SHA2_128S);
+  m.insert("2.16.840.1.101.3.4.3.21", SLH_DSA_SHA2_128F);
+  m.insert("2.16.840.1.101.3.4.3.22", SLH_DSA_SHA2_192S);
+  m.insert("2.16.840.1.101.3.4.3.23", SLH_DSA_SHA2_192F);
+  m.insert("2.16.840.1.101.3.4.3.24", SLH_DSA_SHA2_256S);
+  m.insert("2.16.840.1.101.3.4.3.25", SLH_DSA_SHA2_256F);
+  m.insert("2.16.840.1.101.3.4.3.26", SLH_DSA_SHAKE_128S);
+  m.insert("2.16.840.1.101.3.4.3.27", SLH_DSA_SHAKE_128F);
+  m.insert("2.16.840.1.101.3.4.3.28", SLH_DSA_SHAKE_192S);
+  m.insert("2.16.840.1.101.3.4.3.29", SLH_DSA_SHAKE_192F);
+  m.insert("2.16.840.1.101.3.4.3.30", SLH_DSA_SHAKE_256S);
+  m.insert("2.16.840.1.101.3.4.3.31", SLH_DSA_SHAKE_256F);
+  m
+});
+
+static HASHES: Lazy<HashMap<&str, Hash>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert("1.2.840.113549.2.5", MD5);
+  m.insert("1.3.14.3.2.26", SHA1);
+  m.insert("2.16.840.1.101.3.4.2.1", SHA256);
+  m.insert("2.16.840.1.101.3.4.2.2", SHA384);
+  m.insert("2.16.840.1.101.3.4.2.3", SHA512);
+  m.insert("2.16.840.1.101.3.4.2.4", SHA224);
+  m.insert("2.16.840.1.101.3.4.2.5", SHA512_224);
+  m.insert("2.16.840.1.101.3.4.2.6", SHA512_256);
+  m.insert("2.16.840.1.101.3.4.2.7", SHA3_224);
+  m.insert("2.16.840.1.101.3.4.2.8", SHA3_256);
+  m.insert("2.16.840.1.101.3.4.2.9", SHA3_384);
+  m.insert("2.16.840.1.101.3.4.2.10", SHA3_512);
+  m.insert("2.16.840.1.101.3.4.2.11", SHAKE128);
+  m.insert("2.16.840.1.101.3.4.2.12", SHAKE256);
+  m
+});
+
+/// Returns the instance of RSA with the key size `k` if there is one
+/// where `scheme` is either `rsa_pkcs1` or `rsa_pss`.
+pub(crate) fn rsa(scheme: &str, k: u16) -> Asymmetric {
+  let ifc: Ifc = format!("{}_{}", scheme, k)
+    .parse()
+    .expect("name of the form <scheme>_<k>");
+  ifc.into()
+}
+
+/// Returns the instance of DSA with the sizes `l` and `n` if there is
+/// one.
+pub(crate) fn dsa(l: u16, n: u16) -> Asymmetric {
+  let ffc: Ffc = format!("dsa_{}_{}", l, n)
+    .parse()
+    .expect("name of the form dsa_<l>_<n>");
+  ffc.into()
+}
+
+/// The family of algorithms an issuer signs a certificate with as
+/// identified by the signature algorithm of the certificate.
+///
+/// The key size, or curve, is a property of the issuer key which is
+/// not part of the certificate except for algorithms with fixed
+/// parameters.
+#[derive(Clone, Copy, Debug)]
+enum Scheme {
+  Dsa,
+  Ecdsa,
+  Fixed(Asymmetric),
+  RsaPkcs1,
+  RsaPss,
+}
+
+impl Scheme {
+  /// Returns the algorithm an issuer with the public key `key` signs
+  /// with under this scheme or `None` if the key cannot be used with
+  /// the scheme.
+  fn with_key(self, key: Asymmetric) -> Option<Asymmetric> {
+    match (self, key) {
+      (Self::Fixed(algorithm), _) => Some(algorithm),
+      (Self::Dsa, Asymmetric::Ffc(_)) => Some(key),
+      (Self::Ecdsa, Asymmetric::Ecc(_)) => Some(key),
+      (Self::RsaPkcs1, Asymmetric::Ifc(ifc)) => Some(rsa("rsa_pkcs1", ifc.k)),
+      (Self::RsaPss, Asymmetric::Ifc(ifc)) => Some(rsa("rsa_pss", ifc.k)),
+      _ => None,
+    }
+  }
+}
+
 /// Represents a TLS certificate.
+///
+/// The subject public key, which is what the certificate certifies, is
+/// kept apart from the signature of the issuer on the certificate.
 #[derive(Debug)]
 pub struct Certificate {
   hash_function: Option<Hash>,
-  signature_algorithm: Asymmetric,
+  subject_key: Asymmetric,
+  scheme: Scheme,
+  issuer_signature: Option<Asymmetric>,
+  pss_parameters: Option<PssParameters>,
+  usage: Usage,
+  validity: Validity,
+  subject: String,
+  issuer: String,
 }
 
 impl Certificate {
+  /// Reads all the certificates in a file which may be a PEM bundle or
+  /// a PKCS#7 structure in addition to a single PEM or DER encoded
+  /// certificate.
+  ///
+  /// The certificates are returned in the order they appear in the
+  /// file which, for server chains, is usually from the leaf to the
+  /// root. The issuer signature of each certificate is resolved using
+  /// the other certificates in the file.
+  pub fn chain_from_file(path: &Path) -> Result<Vec<Certificate>, Error> {
+    let mut file = File::open(path)?;
+    let mut data = Vec::new();
+    file.read_to_end(&mut data)?;
+
+    let chain = if !Self::is_likely_pem(&data) {
+      match X509Certificate::from_der(&data) {
+        Ok(_) => vec![Self::from_der(&data)?],
+        Err(_) => Self::pkcs7(&data)?,
+      }
+    } else {
+      let mut chain = Vec::new();
+      for pem in Pem::iter_from_buffer(&data) {
+        let pem = pem.map_err(NomError::Error)?;
+        match pem.label.as_str() {
+          "CERTIFICATE" | "X509 CERTIFICATE" => chain.push(Self::from_der(&pem.contents)?),
+          "PKCS7" => chain.extend(Self::pkcs7(&pem.contents)?),
+          _ => continue,
+        }
+      }
+      chain
+    };
+    if chain.is_empty() {
+      return Err(NomError::Error(PEMError::MissingHeader).into());
+    }
+    Ok(Self::resolve_issuers(chain))
+  }
+
+  /// Reads a chain of DER encoded certificates such as the one a TLS
+  /// server presents during the handshake.
+  ///
+  /// The issuer signature of each certificate is resolved using the
+  /// other certificates in the chain.
+  pub fn chain_from_der<T: AsRef<[u8]>>(certificates: &[T]) -> Result<Vec<Certificate>, Error> {
+    let chain = certificates
+      .iter()
+      .map(|der| Self::from_der(der.as_ref()))
+      .collect::<Result<Vec<_>, _>>()?;
+    Ok(Self::resolve_issuers(chain))
+  }
+
+  fn resolve_issuers(mut chain: Vec<Certificate>) -> Vec<Certificate> {
+    let issuer_keys: Vec<_> = chain
+      .iter()
+      .map(|certificate| {
+        chain
+          .iter()
+          .find(|issuer| certificate.is_issued_by(issuer))
+          .map(|issuer| issuer.subject_key)
+      })
+      .collect();
+    for (certificate, issuer_key) in chain.iter_mut().zip(issuer_keys) {
+      if let Some(issuer_key) = issuer_key {
+        certificate.resolve_issuer(issuer_key);
+      }
+    }
+    chain
+  }
+
+  /// Returns the distinguished name of the subject of the certificate.
+  pub fn subject(&self) -> &str {
+    &self.subject
+  }
+
+  /// Returns the distinguished name of the issuer of the certificate.
+  pub fn issuer(&self) -> &str {
+    &self.issuer
+  }
+
+  /// Indicates whether `issuer` is named as the issuer of this
+  /// certificate which is the case for self-signed certificates too.
+  pub fn is_issued_by(&self, issuer: &Certificate) -> bool {
+    self.issuer == issuer.subject
+  }
+
+  /// Completes the issuer signature with the public key of the issuer
+  /// unless it is already known or the key does not fit the signature
+  /// algorithm of the certificate.
+  pub fn resolve_issuer(&mut self, issuer_key: Asymmetric) {
+    if self.issuer_signature.is_none() {
+      self.issuer_signature = self.scheme.with_key(issuer_key);
+    }
+  }
+
+  /// Derives the usage of the subject public key from the key usage
+  /// extension where signing takes precedence over key agreement which
+  /// in turn takes precedence over encipherment.
+  fn key_usage(tbs_certificate: &TbsCertificate) -> Usage {
+    let key_usage = match tbs_certificate.key_usage() {
+      Ok(Some(extension)) => extension.value,
+      _ => return Usage::Unspecified,
+    };
+    if key_usage.digital_signature()
+      || key_usage.non_repudiation()
+      || key_usage.key_cert_sign()
+      || key_usage.crl_sign()
+    {
+      Usage::DigitalSignature
+    } else if key_usage.key_agreement() {
+      Usage::KeyEstablishment
+    } else if key_usage.key_encipherment() || key_usage.data_encipherment() {
+      Usage::Encryption
+    } else {
+      Usage::Unspecified
+    }
+  }
+
   fn is_likely_pem(data: &[u8]) -> bool {
-    !matches!((data[0], data[1]), (0x30, 0x81..=0x83))
+    !matches!(data, [0x30, 0x81..=0x83, ..])
   }
 
-  fn edsa_with_sha(tbs_certificate: &TbsCertificate, sha: Hash) -> Result<Certificate, Error> {
-    let hash_function = Some(sha);
-    let parameters = tbs_certificate
-      .subject_pki
-      .algorithm
-      .parameters
-      .as_ref()
-      .expect("elliptic curve should specify curve");
-    let oid = parameters
-      .clone()
-      .oid()
-      .expect("elliptic curve should have identifier")
-      .to_id_string();
-    let signature_algorithm = ASYMMETRIC
-      .get(&oid.as_str())
-      .cloned()
-      .ok_or(Error::Unrecognised(oid))?;
-    let certificate = Self {
-      hash_function,
-      signature_algorithm,
+  fn pkcs7(data: &[u8]) -> Result<Vec<Certificate>, Error> {
+    let pkcs7 = Pkcs7::from_der(data)?;
+    let certificates = match pkcs7.signed().and_then(|signed| signed.certificates()) {
+      Some(certificates) => certificates,
+      None => return Ok(Vec::new()),
     };
-    Ok(certificate)
+    let mut chain = Vec::new();
+    for certificate in certificates {
+      chain.push(Self::from_der(&certificate.to_der()?)?);
+    }
+    Ok(chain)
   }
 
-  fn id_ed25519() -> Result<Certificate, Error> {
-    let certificate = Self {
-      hash_function: None,
-      signature_algorithm: ED25519.into(),
+  fn from_der(data: &[u8]) -> Result<Certificate, Error> {
+    let (_, x509_certificate) = X509Certificate::from_der(data)?;
+    let tbs_certificate = x509_certificate.tbs_certificate;
+    let (scheme, mut hash_function) = Self::signature(&tbs_certificate)?;
+    let pss_parameters = match scheme {
+      Scheme::RsaPss => Some(Self::pss_parameters(&tbs_certificate.signature)?),
+      _ => None,
+    };
+    if let Some(parameters) = pss_parameters {
+      hash_function = Some(parameters.hash);
+    }
+    let subject_key = Self::subject_key(data, &tbs_certificate)?;
+    let subject = tbs_certificate.subject.to_string();
+    let issuer = tbs_certificate.issuer.to_string();
+    let validity = Validity {
+      not_before: tbs_certificate.validity.not_before,
+      not_after: tbs_certificate.validity.not_after,
+    };
+    let issuer_signature = match scheme {
+      Scheme::Fixed(algorithm) => Some(algorithm),
+      _ => None,
     };
+    let mut certificate = Self {
+      hash_function,
+      subject_key,
+      scheme,
+      issuer_signature,
+      pss_parameters,
+      usage: Self::key_usage(&tbs_certificate),
+      validity,
+      subject,
+      issuer,
+    };
+    // The issuer key of a self-signed certificate is its own.
+    if certificate.subject == certificate.issuer {
+      certificate.resolve_issuer(subject_key);
+    }
     Ok(certificate)
   }
 
-  fn id_ed448() -> Result<Certificate, Error> {
-    let certificate = Self {
-      hash_function: None,
-      signature_algorithm: ED448.into(),
+  /// Identifies the signature scheme and hash function the issuer
+  /// signed the certificate with.
+  fn signature(tbs_certificate: &TbsCertificate) -> Result<(Scheme, Option<Hash>), Error> {
+    let oid = tbs_certificate.signature.oid().to_id_string();
+    let signature = match oid.as_str() {
+      "1.2.840.10040.4.3" => (Scheme::Dsa, Some(SHA1)),
+      "1.2.840.10045.4.1" => (Scheme::Ecdsa, Some(SHA1)),
+      "1.2.840.10045.4.3.1" => (Scheme::Ecdsa, Some(SHA224)),
+      "1.2.840.10045.4.3.2" => (Scheme::Ecdsa, Some(SHA256)),
+      "1.2.840.10045.4.3.3" => (Scheme::Ecdsa, Some(SHA384)),
+      "1.2.840.10045.4.3.4" => (Scheme::Ecdsa, Some(SHA512)),
+      "1.2.840.113549.1.1.10" => (Scheme::RsaPss, None),
+      "1.2.840.113549.1.1.11" => (Scheme::RsaPkcs1, Some(SHA256)),
+      "1.2.840.113549.1.1.12" => (Scheme::RsaPkcs1, Some(SHA384)),
+      "1.2.840.113549.1.1.13" => (Scheme::RsaPkcs1, Some(SHA512)),
+      "1.2.840.113549.1.1.14" => (Scheme::RsaPkcs1, Some(SHA224)),
+      "1.2.840.113549.1.1.15" => (Scheme::RsaPkcs1, Some(SHA512_224)),
+      "1.2.840.113549.1.1.16" => (Scheme::RsaPkcs1, Some(SHA512_256)),
+      "1.2.840.113549.1.1.3" => (Scheme::RsaPkcs1, Some(MD4)),
+      "1.2.840.113549.1.1.4" => (Scheme::RsaPkcs1, Some(MD5)),
+      "1.2.840.113549.1.1.5" => (Scheme::RsaPkcs1, Some(SHA1)),
+      "1.3.101.112" => (Scheme::Fixed(ED25519.into()), None),
+      "1.3.101.113" => (Scheme::Fixed(ED448.into()), None),
+      "2.16.840.1.101.3.4.3.1" => (Scheme::Dsa, Some(SHA224)),
+      "2.16.840.1.101.3.4.3.2" => (Scheme::Dsa, Some(SHA256)),
+      "2.16.840.1.101.3.4.3.10" => (Scheme::Ecdsa, Some(SHA3_256)),
+      "2.16.840.1.101.3.4.3.11" => (Scheme::Ecdsa, Some(SHA3_384)),
+      "2.16.840.1.101.3.4.3.12" => (Scheme::Ecdsa, Some(SHA3_512)),
+      "2.16.840.1.101.3.4.3.13" => (Scheme::RsaPkcs1, Some(SHA3_224)),
+      "2.16.840.1.101.3.4.3.14" => (Scheme::RsaPkcs1, Some(SHA3_256)),
+      "2.16.840.1.101.3.4.3.15" => (Scheme::RsaPkcs1, Some(SHA3_384)),
+      "2.16.840.1.101.3.4.3.16" => (Scheme::RsaPkcs1, Some(SHA3_512)),
+      // The message is hashed internally as part of the signing process
+      // so no hash function is identified separately.
+      _ => match PQ_SIGNATURES.get(oid.as_str()) {
+        Some(pqs) => (Scheme::Fixed((*pqs).into()), None),
+        None => return Err(Error::Unrecognised(oid)),
+      },
     };
-    Ok(certificate)
+    Ok(signature)
   }
 
-  fn rsassa_pss(data: &[u8]) -> Result<Certificate, Error> {
-    // The x509_parser crate cannot seem to read rsassa-pss keys so
-    // resort to openssl for that. But even that cannot seem to
-    // extract the hash function so a lower level interface may be
-    // required.
-    let certificate = if Self::is_likely_pem(data) {
-      X509::from_pem(data)?
-    } else {
-      X509::from_der(data)?
+  /// Parses the RSASSA-PSS-params of a signature algorithm where absent
+  /// fields take the default values of RFC 4055.
+  fn pss_parameters(algorithm: &AlgorithmIdentifier) -> Result<PssParameters, Error> {
+    let invalid = || NomError::Error(X509Error::InvalidSignatureValue);
+    let parameters = match algorithm.parameters.as_ref() {
+      Some(parameters) => RsaSsaPssParams::try_from(parameters).map_err(|_| invalid())?,
+      None => return Err(invalid().into()),
     };
-    let public_key = certificate.public_key()?;
-    let k = public_key.bits();
-    let signature_algorithm = match k {
-      1024 => RSA_PSS_1024.into(),
-      1536 => RSA_PSS_1536.into(),
-      2048 => RSA_PSS_2048.into(),
-      3072 => RSA_PSS_3072.into(),
-      4096 => RSA_PSS_4096.into(),
-      7680 => RSA_PSS_7680.into(),
-      8192 => RSA_PSS_8192.into(),
-      15360 => RSA_PSS_15360.into(),
-      _ => Ifc::new(ID_RSA_PSS, k as u16).into(),
+    // The trailer field has a single defined value, 0xbc.
+    if parameters.trailer_field() != 1 {
+      return Err(invalid().into());
+    }
+    let mask_generation = parameters.mask_gen_algorithm().map_err(|_| invalid())?;
+    let mgf = mask_generation.mgf.to_id_string();
+    if mgf != "1.2.840.113549.1.1.8" {
+      return Err(Error::Unrecognised(mgf));
+    }
+    let hash = |oid: String| {
+      HASHES
+        .get(oid.as_str())
+        .cloned()
+        .ok_or(Error::Unrecognised(oid))
     };
-    let certificate = Self {
-      hash_function: None,
-      signature_algorithm,
+    let parameters = PssParameters {
+      hash: hash(parameters.hash_algorithm_oid().to_id_string())?,
+      mask_hash: hash(mask_generation.hash.to_id_string())?,
+      salt_length: parameters.salt_length(),
     };
-    Ok(certificate)
+    Ok(parameters)
   }
 
-  fn with_rsa_encryption(
-    tbs_certificate: &TbsCertificate,
-    sha: Hash,
-  ) -> Result<Certificate, Error> {
-    let hash_function = Some(sha);
-    let k = tbs_certificate
-      .subject_pki
-      .parsed()
-      .expect("should parse rsa public key")
-      .key_size();
-    let signature_algorithm = match k {
-      1024 => RSA_PKCS1_1024.into(),
-      1536 => RSA_PKCS1_1536.into(),
-      2048 => RSA_PKCS1_2048.into(),
-      3072 => RSA_PKCS1_3072.into(),
-      4096 => RSA_PKCS1_4096.into(),
-      7680 => RSA_PKCS1_7680.into(),
-      8192 => RSA_PKCS1_8192.into(),
-      15360 => RSA_PKCS1_15360.into(),
-      _ => Ifc::new(ID_RSA_PKCS1, k as u16).into(),
-    };
-    let certificate = Self {
-      hash_function,
-      signature_algorithm,
+  /// Identifies the public key being certified.
+  fn subject_key(data: &[u8], tbs_certificate: &TbsCertificate) -> Result<Asymmetric, Error> {
+    let subject_pki = &tbs_certificate.subject_pki;
+    let algorithm = &subject_pki.algorithm;
+    let oid = algorithm.algorithm.to_id_string();
+    let subject_key = match oid.as_str() {
+      "1.2.840.113549.1.1.1" => {
+        let k = subject_pki
+          .parsed()
+          .expect("should parse rsa public key")
+          .key_size();
+        rsa("rsa_pkcs1", k as u16)
+      },
+      "1.2.840.113549.1.1.10" => {
+        // The x509_parser crate only parses the public key of the
+        // rsaEncryption algorithm but the encoding is the same.
+        let (_, key) = RSAPublicKey::from_der(&subject_pki.subject_public_key.data)
+          .map_err(|_| NomError::Error(X509Error::InvalidSPKI))?;
+        rsa("rsa_pss", key.key_size() as u16)
+      },
+      "1.2.840.10040.4.1" => {
+        let key = X509::from_der(data)?.public_key()?.dsa()?;
+        dsa(key.p().num_bits() as u16, key.q().num_bits() as u16)
+      },
+      "1.2.840.10046.2.1" => {
+        let key = X509::from_der(data)?.public_key()?.dh()?;
+        dhparam::group(key.prime_p(), key.prime_q())?.into()
+      },
+      "1.2.840.10045.2.1" => {
+        let oid = algorithm
+          .parameters
+          .as_ref()
+          .and_then(|parameters| parameters.clone().oid().ok())
+          .ok_or(Error::Unrecognised(oid))?
+          .to_id_string();
+        ASYMMETRIC
+          .get(oid.as_str())
+          .cloned()
+          .ok_or(Error::Unrecognised(oid))?
+      },
+      "1.3.101.110" => X25519.into(),
+      "1.3.101.111" => X448.into(),
+      "1.3.101.112" => ED25519.into(),
+      "1.3.101.113" => ED448.into(),
+      _ => match PQ_SIGNATURES.get(oid.as_str()) {
+        Some(pqs) => (*pqs).into(),
+        None => return Err(Error::Unrecognised(oid)),
+      },
     };
-    Ok(certificate)
+    Ok(subject_key)
   }
 }
 
 impl Key for Certificate {
+  /// Reads the first certificate in a file, see
+  /// [`Certificate::chain_from_file`] for the whole chain.
   fn from_file(path: &Path) -> Result<Certificate, Error> {
-    let mut file = File::open(path)?;
-    let mut data = Vec::new();
-    file.read_to_end(&mut data)?;
+    let chain = Self::chain_from_file(path)?;
+    let certificate = chain.into_iter().next().expect("chain should not be empty");
+    Ok(certificate)
+  }
 
-    // Certificates do not own their data.
-    let pem;
-    let tbs_certificate = if Self::is_likely_pem(&data) {
-      (_, pem) = pem::parse_x509_pem(&data)?;
-      let x509_certificate = pem.parse_x509()?;
-      x509_certificate.tbs_certificate
-    } else {
-      let (_, x509_certificate) = X509Certificate::from_der(&data)?;
-      x509_certificate.tbs_certificate
-    };
+  /// Returns the hash function of the issuer signature.
+  fn hash_function(&self) -> Option<Hash> {
+    self.hash_function
+  }
 
-    let oid = tbs_certificate.signature.oid().to_id_string();
-    match oid.as_str() {
-      "1.2.840.10045.4.1" => Self::edsa_with_sha(&tbs_certificate, SHA1),
-      "1.2.840.10045.4.3.1" => Self::edsa_with_sha(&tbs_certificate, SHA224),
-      "1.2.840.10045.4.3.2" => Self::edsa_with_sha(&tbs_certificate, SHA256),
-      "1.2.840.10045.4.3.3" => Self::edsa_with_sha(&tbs_certificate, SHA384),
-      "1.2.840.10045.4.3.4" => Self::edsa_with_sha(&tbs_certificate, SHA512),
-      "1.2.840.113549.1.1.10" => Self::rsassa_pss(&data),
-      "1.2.840.113549.1.1.11" => Self::with_rsa_encryption(&tbs_certificate, SHA256),
-      "1.2.840.113549.1.1.12" => Self::with_rsa_encryption(&tbs_certificate, SHA384),
-      "1.2.840.113549.1.1.13" => Self::with_rsa_encryption(&tbs_certificate, SHA512),
-      "1.2.840.113549.1.1.14" => Self::with_rsa_encryption(&tbs_certificate, SHA224),
-      "1.2.840.113549.1.1.15" => Self::with_rsa_encryption(&tbs_certificate, SHA512_224),
-      "1.2.840.113549.1.1.16" => Self::with_rsa_encryption(&tbs_certificate, SHA512_256),
-      "1.2.840.113549.1.1.3" => Self::with_rsa_encryption(&tbs_certificate, MD4),
-      "1.2.840.113549.1.1.4" => Self::with_rsa_encryption(&tbs_certificate, MD5),
-      "1.2.840.113549.1.1.5" => Self::with_rsa_encryption(&tbs_certificate, SHA1),
-      "1.3.101.112" => Self::id_ed25519(),
-      "1.3.101.113" => Self::id_ed448(),
-      "2.16.840.1.101.3.4.3.10" => Self::edsa_with_sha(&tbs_certificate, SHA3_256),
-      "2.16.840.1.101.3.4.3.11" => Self::edsa_with_sha(&tbs_certificate, SHA3_384),
-      "2.16.840.1.101.3.4.3.12" => Self::edsa_with_sha(&tbs_certificate, SHA3_512),
-      _ => Err(Error::Unrecognised(oid)),
+  /// Returns the subject public key.
+  fn signature_algorithm(&self) -> Option<Asymmetric> {
+    Some(self.subject_key)
+  }
+
+  fn issuer_signature(&self) -> Option<Asymmetric> {
+    self.issuer_signature
+  }
+
+  fn pss_parameters(&self) -> Option<PssParameters> {
+    self.pss_parameters
+  }
+
+  fn usage(&self) -> Usage {
+    self.usage
+  }
+
+  fn validity(&self) -> Option<Validity> {
+    Some(self.validity)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::testing::fixture;
+
+  fn subjects(chain: &[Certificate]) -> Vec<&str> {
+    chain.iter().map(Certificate::subject).collect()
+  }
+
+  #[test]
+  fn chain_from_pem_bundle() {
+    let chain = Certificate::chain_from_file(&fixture("chain.pem")).unwrap();
+    assert_eq!(
+      subjects(&chain),
+      [
+        "CN=wardstone.test",
+        "CN=Wardstone Intermediate",
+        "CN=Wardstone Root"
+      ]
+    );
+    for certificate in chain.iter() {
+      assert_eq!(certificate.hash_function(), Some(SHA384));
+      assert_eq!(certificate.signature_algorithm(), Some(P384.into()));
+      assert_eq!(certificate.issuer_signature(), Some(P384.into()));
     }
+    assert!(chain[0].is_issued_by(&chain[1]));
+    assert!(chain[1].is_issued_by(&chain[2]));
+    assert!(chain[2].is_issued_by(&chain[2]));
   }
 
-  fn hash_function(&self) -> Option<Hash> {
-    self.hash_function
+  #[test]
+  fn chain_from_pkcs7() {
+    let chain = Certificate::chain_from_file(&fixture("chain.p7b")).unwrap();
+    assert_eq!(chain.len(), 3);
+    assert!(subjects(&chain).contains(&"CN=wardstone.test"));
+    for certificate in chain.iter() {
+      assert_eq!(certificate.issuer_signature(), Some(P384.into()));
+    }
+  }
+
+  #[test]
+  fn chain_from_der() {
+    let chain = Certificate::chain_from_file(&fixture("leaf.der")).unwrap();
+    assert_eq!(subjects(&chain), ["CN=wardstone.test"]);
+    // The issuer is not part of the file so its key is unknown.
+    assert_eq!(chain[0].issuer_signature(), None);
+    assert_eq!(chain[0].usage(), Usage::DigitalSignature);
+  }
+
+  #[test]
+  fn chain_with_sha1_intermediate() {
+    let chain = Certificate::chain_from_file(&fixture("chain_sha1_intermediate.pem")).unwrap();
+    let hash_functions: Vec<_> = chain.iter().map(Key::hash_function).collect();
+    assert_eq!(hash_functions, [Some(SHA384), Some(SHA1), Some(SHA384)]);
+  }
+
+  #[test]
+  fn pss_parameters_default() {
+    // AlgorithmIdentifier { id-RSASSA-PSS, RSASSA-PSS-params {} }
+    let der = [
+      0x30, 0x0d, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30, 0x00,
+    ];
+    let (_, algorithm) = AlgorithmIdentifier::from_der(&der).unwrap();
+    let want = PssParameters {
+      hash: SHA1,
+      mask_hash: SHA1,
+      salt_length: 20,
+    };
+    assert_eq!(Certificate::pss_parameters(&algorithm).unwrap(), want);
+
+    let certificate = Certificate::from_file(&fixture("pss_default.pem")).unwrap();
+    assert_eq!(certificate.pss_parameters(), Some(want));
+    assert_eq!(certificate.hash_function(), Some(SHA1));
+  }
+
+  #[test]
+  fn pss_parameters_explicit() {
+    let certificate = Certificate::from_file(&fixture("pss_explicit.pem")).unwrap();
+    let want = PssParameters {
+      hash: SHA384,
+      mask_hash: SHA512,
+      salt_length: 64,
+    };
+    assert_eq!(certificate.pss_parameters(), Some(want));
+    assert_eq!(certificate.hash_function(), Some(SHA384));
+    assert_eq!(
+      certificate.signature_algorithm(),
+      Some(RSA_PKCS1_2048.into())
+    );
   }
 
-  fn signature_algorithm(&self) -> Asymmetric {
-    self.signature_algorithm
+  #[test]
+  fn pss_parameters_unrecognised_mask_generation_function() {
+    // AlgorithmIdentifier { id-RSASSA-PSS, RSASSA-PSS-params {
+    // maskGenAlgorithm [1] { id-pSpecified, sha256 } } }
+    let der = [
+      0x30, 0x29, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0a, 0x30, 0x1c,
+      0xa1, 0x1a, 0x30, 0x18, 0x06, 0x09, 0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x09,
+      0x30, 0x0b, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+    ];
+    let (_, algorithm) = AlgorithmIdentifier::from_der(&der).unwrap();
+    let err = Certificate::pss_parameters(&algorithm).unwrap_err();
+    assert!(matches!(err, Error::Unrecognised(oid) if oid == "1.2.840.113549.1.1.9"));
   }
 }
@@ -0,0 +1,673 @@
+//! Create private key representations from PKCS#8, PKCS#1, SEC1 and
+//! OpenSSH files and identify the encryption protecting them.
+use std::fs;
+use std::path::Path;
+
+use openssh_keys::PublicKey;
+use openssl::base64;
+use openssl::bn::BigNum;
+use openssl::pkey::PKey;
+use wardstone_core::context::Usage;
+use wardstone_core::primitive::asymmetric::Asymmetric;
+use wardstone_core::primitive::ecc::*;
+use wardstone_core::primitive::hash::*;
+use wardstone_core::primitive::kdf::*;
+use wardstone_core::primitive::phf::*;
+use wardstone_core::primitive::symmetric::*;
+use x509_parser::der_parser::ber::BerObject;
+use x509_parser::der_parser::parse_der;
+
+use crate::dhparam;
+use crate::key::certificate::{dsa, rsa, ASYMMETRIC, PQ_SIGNATURES};
+use crate::key::ssh::Ssh;
+use crate::key::{Error, Key, KeyDerivation, Protection};
+
+const OPENSSH_MAGIC: &[u8] = b"openssh-key-v1\0";
+
+/// Represents a private key.
+#[derive(Debug)]
+pub struct PrivateKey {
+  signature_algorithm: Option<Asymmetric>,
+  protection: Option<Protection>,
+}
+
+impl PrivateKey {
+  /// Reads a private key from a file using `passphrase` to decrypt it
+  /// if the algorithm cannot be identified otherwise.
+  ///
+  /// The passphrase is only required to identify encrypted PKCS#8 keys
+  /// and encrypted PKCS#1 and SEC1 keys that do not reveal the key size
+  /// or curve in the clear. Without it, only the encryption protecting
+  /// them is known. OpenSSH keys carry their public key in the clear.
+  pub fn from_file_with_passphrase(path: &Path, passphrase: Option<&[u8]>) -> Result<Self, Error> {
+    let data = fs::read(path)?;
+    match Pem::parse(&data) {
+      Some(pem) => Self::from_pem(&data, &pem, passphrase),
+      None => Self::from_der(&data, passphrase),
+    }
+  }
+
+  fn from_pem(data: &[u8], pem: &Pem, passphrase: Option<&[u8]>) -> Result<Self, Error> {
+    if pem.label == "OPENSSH PRIVATE KEY" {
+      return Self::openssh(&pem.contents);
+    }
+
+    // Keys in the traditional format are encrypted in their entirety
+    // with the cipher named in the headers.
+    if let Some(dek_info) = pem.header("DEK-Info") {
+      let cipher_name = dek_info.split(',').next().unwrap_or(dek_info);
+      let protection = Protection {
+        scheme: format!("pem with {}", cipher_name.to_lowercase()),
+        kdf: Some(MD5.into()),
+        cipher: Self::pem_cipher(cipher_name),
+      };
+      let signature_algorithm = match passphrase {
+        Some(passphrase) => {
+          let key =
+            PKey::private_key_from_pem_passphrase(data, passphrase).map_err(|_| Error::Decrypt)?;
+          let der = key
+            .private_key_to_pkcs8()
+            .map_err(|_| Error::ParsePrivateKey)?;
+          Some(Self::pkcs8(&parse(&der)?)?)
+        },
+        None => None,
+      };
+      return Ok(Self {
+        signature_algorithm,
+        protection: Some(protection),
+      });
+    }
+
+    let der = parse(&pem.contents)?;
+    let signature_algorithm = match pem.label.as_str() {
+      "PRIVATE KEY" => Self::pkcs8(&der)?,
+      "ENCRYPTED PRIVATE KEY" => return Self::encrypted_pkcs8(&pem.contents, &der, passphrase),
+      "RSA PRIVATE KEY" => Self::pkcs1(&der)?,
+      "DSA PRIVATE KEY" => Self::dsa(&der)?,
+      "EC PRIVATE KEY" => Self::sec1(&der, None)?,
+      _ => return Err(Error::ParsePrivateKey),
+    };
+    Ok(Self {
+      signature_algorithm: Some(signature_algorithm),
+      protection: None,
+    })
+  }
+
+  fn from_der(data: &[u8], passphrase: Option<&[u8]>) -> Result<Self, Error> {
+    let der = parse(data)?;
+    let items = der.as_sequence().map_err(|_| Error::ParsePrivateKey)?;
+    let signature_algorithm = match items.as_slice() {
+      // EncryptedPrivateKeyInfo ::= SEQUENCE { AlgorithmIdentifier,
+      // OCTET STRING }
+      [algorithm, _] if algorithm.as_sequence().is_ok() => {
+        return Self::encrypted_pkcs8(data, &der, passphrase)
+      },
+      // PrivateKeyInfo ::= SEQUENCE { INTEGER, AlgorithmIdentifier,
+      // OCTET STRING, ... }
+      [_, algorithm, ..] if algorithm.as_sequence().is_ok() => Self::pkcs8(&der)?,
+      // ECPrivateKey ::= SEQUENCE { INTEGER, OCTET STRING, ... }
+      [_, key, ..] if key.as_slice().is_ok() && key.as_bigint().is_err() => Self::sec1(&der, None)?,
+      _ => Self::pkcs1(&der)?,
+    };
+    Ok(Self {
+      signature_algorithm: Some(signature_algorithm),
+      protection: None,
+    })
+  }
+
+  /// Identifies the key in a PrivateKeyInfo structure (see RFC 5208).
+  fn pkcs8(der: &BerObject) -> Result<Asymmetric, Error> {
+    let items = der.as_sequence().map_err(|_| Error::ParsePrivateKey)?;
+    let (algorithm, key) = match items.as_slice() {
+      [_, algorithm, key, ..] => (algorithm, key),
+      _ => return Err(Error::ParsePrivateKey),
+    };
+    let (oid, parameters) = algorithm_identifier(algorithm)?;
+    let key = key.as_slice().map_err(|_| Error::ParsePrivateKey)?;
+    match oid.as_str() {
+      "1.2.840.113549.1.1.1" => Self::pkcs1(&parse(key)?),
+      "1.2.840.113549.1.1.10" => {
+        let k = Self::rsa_modulus_size(&parse(key)?)?;
+        Ok(rsa("rsa_pss", k))
+      },
+      "1.2.840.10040.4.1" => {
+        let parameters = parameters.ok_or(Error::ParsePrivateKey)?;
+        Self::dsa_parameters(parameters)
+      },
+      "1.2.840.113549.1.3.1" | "1.2.840.10046.2.1" => {
+        let parameters = parameters.ok_or(Error::ParsePrivateKey)?;
+        Self::dh_parameters(parameters)
+      },
+      "1.2.840.10045.2.1" => Self::sec1(&parse(key)?, parameters),
+      "1.3.101.110" => Ok(X25519.into()),
+      "1.3.101.111" => Ok(X448.into()),
+      "1.3.101.112" => Ok(ED25519.into()),
+      "1.3.101.113" => Ok(ED448.into()),
+      _ => match PQ_SIGNATURES.get(oid.as_str()) {
+        Some(pqs) => Ok((*pqs).into()),
+        None => Err(Error::Unrecognised(oid)),
+      },
+    }
+  }
+
+  /// Identifies the encryption scheme of an EncryptedPrivateKeyInfo
+  /// structure (see RFC 5208) and the key itself if a passphrase is
+  /// given.
+  ///
+  /// Unlike other formats, the algorithm identifier of the key is part
+  /// of the encrypted data.
+  fn encrypted_pkcs8(
+    data: &[u8],
+    der: &BerObject,
+    passphrase: Option<&[u8]>,
+  ) -> Result<Self, Error> {
+    let items = der.as_sequence().map_err(|_| Error::ParsePrivateKey)?;
+    let algorithm = items.first().ok_or(Error::ParsePrivateKey)?;
+    let protection = Self::pkcs5(algorithm)?;
+
+    let passphrase = match passphrase {
+      Some(passphrase) => passphrase,
+      None => {
+        return Ok(Self {
+          signature_algorithm: None,
+          protection: Some(protection),
+        })
+      },
+    };
+    let key = match Pem::parse(data) {
+      Some(_) => PKey::private_key_from_pem_passphrase(data, passphrase),
+      None => PKey::private_key_from_pkcs8_passphrase(data, passphrase),
+    }
+    .map_err(|_| Error::Decrypt)?;
+    let der = key
+      .private_key_to_pkcs8()
+      .map_err(|_| Error::ParsePrivateKey)?;
+    let signature_algorithm = Some(Self::pkcs8(&parse(&der)?)?);
+    Ok(Self {
+      signature_algorithm,
+      protection: Some(protection),
+    })
+  }
+
+  /// Identifies the password-based encryption scheme of an encrypted
+  /// key (see RFC 8018).
+  fn pkcs5(algorithm: &BerObject) -> Result<Protection, Error> {
+    let (oid, parameters) = algorithm_identifier(algorithm)?;
+    let protection = match oid.as_str() {
+      "1.2.840.113549.1.5.13" => {
+        let parameters = parameters.ok_or(Error::ParsePrivateKey)?;
+        let items = parameters
+          .as_sequence()
+          .map_err(|_| Error::ParsePrivateKey)?;
+        let (kdf, cipher) = match items.as_slice() {
+          [kdf, cipher] => (algorithm_identifier(kdf)?, algorithm_identifier(cipher)?),
+          _ => return Err(Error::ParsePrivateKey),
+        };
+        let (name, derivation) = match (kdf.0.as_str(), kdf.1) {
+          ("1.2.840.113549.1.5.12", Some(parameters)) => ("pbkdf2", Self::pbkdf2(parameters)?),
+          ("1.3.6.1.4.1.11591.4.11", Some(parameters)) => {
+            ("scrypt", Some(Self::scrypt(parameters)?.into()))
+          },
+          (oid, _) => return Err(Error::Unrecognised(oid.to_string())),
+        };
+        Protection {
+          scheme: format!("pbes2 with {}", name),
+          kdf: derivation,
+          cipher: Self::pkcs5_cipher(&cipher.0),
+        }
+      },
+      "1.2.840.113549.1.5.3" => Self::pbes1("pbe with md5 and des", MD5, DES),
+      "1.2.840.113549.1.5.10" => Self::pbes1("pbe with sha1 and des", SHA1, DES),
+      "1.2.840.113549.1.12.1.3" => Self::pbes1("pbe with sha1 and 3des", SHA1, TDEA3),
+      "1.2.840.113549.1.12.1.4" => Self::pbes1("pbe with sha1 and 2des", SHA1, TDEA2),
+      _ => return Err(Error::Unrecognised(oid)),
+    };
+    Ok(protection)
+  }
+
+  fn pbes1(scheme: &str, kdf: Hash, cipher: Symmetric) -> Protection {
+    Protection {
+      scheme: scheme.to_string(),
+      kdf: Some(kdf.into()),
+      cipher: Some(cipher),
+    }
+  }
+
+  /// Identifies PBKDF2 along with its iteration count from its
+  /// parameters (see RFC 8018).
+  ///
+  /// Only PBKDF2 with HMAC-SHA1, HMAC-SHA256 or HMAC-SHA512 can be
+  /// assessed as a key derivation function. Other pseudorandom
+  /// functions are reduced to their hash function.
+  fn pbkdf2(parameters: &BerObject) -> Result<Option<KeyDerivation>, Error> {
+    let items = parameters
+      .as_sequence()
+      .map_err(|_| Error::ParsePrivateKey)?;
+    let iterations = items
+      .get(1)
+      .and_then(|item| item.as_u32().ok())
+      .ok_or(Error::ParsePrivateKey)?;
+    let instance = match Self::pbkdf2_prf(items)? {
+      Some(hash) if hash == SHA1 => PBKDF2_HMAC_SHA1,
+      Some(hash) if hash == SHA256 => PBKDF2_HMAC_SHA256,
+      Some(hash) if hash == SHA512 => PBKDF2_HMAC_SHA512,
+      hash => return Ok(hash.map(KeyDerivation::Hash)),
+    };
+    // The optional key length is given in bytes and otherwise matches
+    // the output of the pseudorandom function.
+    let key = match items.get(2).and_then(|item| item.as_u32().ok()) {
+      Some(length) => u16::try_from(length.saturating_mul(8)).unwrap_or(u16::MAX),
+      None => instance.key,
+    };
+    Ok(Some(Kdf::new(instance.id, key, iterations).into()))
+  }
+
+  /// Returns the hash function of the pseudorandom function used by
+  /// PBKDF2 which defaults to HMAC-SHA1.
+  fn pbkdf2_prf(items: &[BerObject]) -> Result<Option<Hash>, Error> {
+    // The salt may itself be an algorithm identifier so the search for
+    // the pseudorandom function starts after the iteration count.
+    let prf = match items.iter().skip(2).find(|item| item.as_sequence().is_ok()) {
+      Some(prf) => algorithm_identifier(prf)?.0,
+      None => return Ok(Some(SHA1)),
+    };
+    let hash = match prf.as_str() {
+      "1.2.840.113549.2.7" => SHA1,
+      "1.2.840.113549.2.8" => SHA224,
+      "1.2.840.113549.2.9" => SHA256,
+      "1.2.840.113549.2.10" => SHA384,
+      "1.2.840.113549.2.11" => SHA512,
+      "1.2.840.113549.2.12" => SHA512_224,
+      "1.2.840.113549.2.13" => SHA512_256,
+      "2.16.840.1.101.3.4.2.13" => SHA3_224,
+      "2.16.840.1.101.3.4.2.14" => SHA3_256,
+      "2.16.840.1.101.3.4.2.15" => SHA3_384,
+      "2.16.840.1.101.3.4.2.16" => SHA3_512,
+      _ => return Ok(None),
+    };
+    Ok(Some(hash))
+  }
+
+  /// Identifies scrypt along with its cost parameters (see RFC 7914).
+  fn scrypt(parameters: &BerObject) -> Result<Phf, Error> {
+    let items = parameters
+      .as_sequence()
+      .map_err(|_| Error::ParsePrivateKey)?;
+    let parameter = |i: usize| {
+      items
+        .get(i)
+        .and_then(|item| item.as_u32().ok())
+        .ok_or(Error::ParsePrivateKey)
+    };
+    Ok(Phf::scrypt(
+      SCRYPT.id,
+      parameter(1)?,
+      parameter(2)?,
+      parameter(3)?,
+    ))
+  }
+
+  fn pkcs5_cipher(oid: &str) -> Option<Symmetric> {
+    match oid {
+      "1.3.14.3.2.7" => Some(DES),
+      "1.2.840.113549.3.7" => Some(TDEA3),
+      "2.16.840.1.101.3.4.1.2" | "2.16.840.1.101.3.4.1.6" => Some(AES128),
+      "2.16.840.1.101.3.4.1.22" | "2.16.840.1.101.3.4.1.26" => Some(AES192),
+      "2.16.840.1.101.3.4.1.42" | "2.16.840.1.101.3.4.1.46" => Some(AES256),
+      _ => None,
+    }
+  }
+
+  fn pem_cipher(name: &str) -> Option<Symmetric> {
+    match name {
+      "DES-CBC" => Some(DES),
+      "DES-EDE3-CBC" => Some(TDEA3),
+      "AES-128-CBC" => Some(AES128),
+      "AES-192-CBC" => Some(AES192),
+      "AES-256-CBC" => Some(AES256),
+      "CAMELLIA-128-CBC" => Some(CAMELLIA128),
+      "CAMELLIA-192-CBC" => Some(CAMELLIA192),
+      "CAMELLIA-256-CBC" => Some(CAMELLIA256),
+      _ => None,
+    }
+  }
+
+  fn openssh_cipher(name: &str) -> Option<Symmetric> {
+    match name {
+      "3des-cbc" => Some(TDEA3),
+      "aes128-cbc" | "aes128-ctr" | "aes128-gcm@openssh.com" => Some(AES128),
+      "aes192-cbc" | "aes192-ctr" => Some(AES192),
+      "aes256-cbc" | "aes256-ctr" | "aes256-gcm@openssh.com" => Some(AES256),
+      _ => None,
+    }
+  }
+
+  /// Identifies the key in an RSAPrivateKey structure (see RFC 8017).
+  fn pkcs1(der: &BerObject) -> Result<Asymmetric, Error> {
+    let k = Self::rsa_modulus_size(der)?;
+    Ok(rsa("rsa_pkcs1", k))
+  }
+
+  fn rsa_modulus_size(der: &BerObject) -> Result<u16, Error> {
+    let items = der.as_sequence().map_err(|_| Error::ParsePrivateKey)?;
+    let modulus = items.get(1).ok_or(Error::ParsePrivateKey)?;
+    let modulus = modulus.as_slice().map_err(|_| Error::ParsePrivateKey)?;
+    Ok(bit_length(modulus))
+  }
+
+  /// Identifies the key in the DSA private key structure used by
+  /// OpenSSL which consists of the version followed by p, q and g.
+  fn dsa(der: &BerObject) -> Result<Asymmetric, Error> {
+    let items = der.as_sequence().map_err(|_| Error::ParsePrivateKey)?;
+    match items.as_slice() {
+      [_, p, q, ..] => Self::ffc(p, q),
+      _ => Err(Error::ParsePrivateKey),
+    }
+  }
+
+  fn dsa_parameters(parameters: &BerObject) -> Result<Asymmetric, Error> {
+    let items = parameters
+      .as_sequence()
+      .map_err(|_| Error::ParsePrivateKey)?;
+    match items.as_slice() {
+      [p, q, _] => Self::ffc(p, q),
+      _ => Err(Error::ParsePrivateKey),
+    }
+  }
+
+  /// Identifies the group of a Diffie-Hellman key from either the
+  /// DHParameter structure of PKCS#3, which consists of p and g, or
+  /// the DomainParameters structure of X9.42 (see RFC 3279), which
+  /// consists of p, g and q.
+  fn dh_parameters(parameters: &BerObject) -> Result<Asymmetric, Error> {
+    let items = parameters
+      .as_sequence()
+      .map_err(|_| Error::ParsePrivateKey)?;
+    let integer = |item: &BerObject| {
+      let bytes = item.as_slice().map_err(|_| Error::ParsePrivateKey)?;
+      BigNum::from_slice(bytes).map_err(|_| Error::ParsePrivateKey)
+    };
+    let (p, q) = match items.as_slice() {
+      [p, _, q, ..] if q.as_slice().is_ok() => (integer(p)?, Some(integer(q)?)),
+      [p, ..] => (integer(p)?, None),
+      _ => return Err(Error::ParsePrivateKey),
+    };
+    let group = dhparam::group(&p, q.as_deref()).map_err(|_| Error::ParsePrivateKey)?;
+    Ok(group.into())
+  }
+
+  fn ffc(p: &BerObject, q: &BerObject) -> Result<Asymmetric, Error> {
+    let l = bit_length(p.as_slice().map_err(|_| Error::ParsePrivateKey)?);
+    let n = bit_length(q.as_slice().map_err(|_| Error::ParsePrivateKey)?);
+    Ok(dsa(l, n))
+  }
+
+  /// Identifies the curve of an ECPrivateKey structure (see RFC 5915)
+  /// where the curve is either specified by the algorithm identifier
+  /// of a PKCS#8 structure or the optional parameters of the key.
+  fn sec1(der: &BerObject, parameters: Option<&BerObject>) -> Result<Asymmetric, Error> {
+    let oid = match parameters.and_then(|parameters| parameters.as_oid().ok()) {
+      Some(oid) => oid.to_id_string(),
+      None => {
+        let items = der.as_sequence().map_err(|_| Error::ParsePrivateKey)?;
+        let parameters = items
+          .iter()
+          .skip(2)
+          .find_map(|item| match item.as_tagged() {
+            Ok((_, tag, inner)) if tag.0 == 0 => Some(inner.clone()),
+            _ => item
+              .as_slice()
+              .ok()
+              .filter(|_| item.header.tag().0 == 0)
+              .and_then(|inner| parse(inner).ok()),
+          })
+          .ok_or(Error::ParsePrivateKey)?;
+        let oid = parameters.as_oid().map_err(|_| Error::ParsePrivateKey)?;
+        oid.to_id_string()
+      },
+    };
+    ASYMMETRIC
+      .get(oid.as_str())
+      .cloned()
+      .ok_or(Error::Unrecognised(oid))
+  }
+
+  /// Identifies the key in an OpenSSH private key file whose public key
+  /// is stored in the clear (see PROTOCOL.key in the OpenSSH source).
+  fn openssh(data: &[u8]) -> Result<Self, Error> {
+    let mut reader = data
+      .strip_prefix(OPENSSH_MAGIC)
+      .ok_or(Error::ParsePrivateKey)?;
+    let cipher = read_string(&mut reader)?;
+    let kdf = read_string(&mut reader)?;
+    let _kdf_options = read_string(&mut reader)?;
+    let _number_of_keys = read_u32(&mut reader)?;
+    let public_key = read_string(&mut reader)?;
+
+    let mut key_type = public_key;
+    let key_type = read_string(&mut key_type)?;
+    let line = format!(
+      "{} {}",
+      String::from_utf8_lossy(key_type),
+      base64::encode_block(public_key)
+    );
+    let public_key = PublicKey::parse(&line)?;
+    let signature_algorithm = Ssh::from_public_key(&public_key).signature_algorithm();
+
+    let cipher = String::from_utf8_lossy(cipher);
+    let protection = match cipher.as_ref() {
+      "none" => None,
+      name => Some(Protection {
+        scheme: format!("openssh with {} and {}", String::from_utf8_lossy(kdf), name),
+        kdf: None,
+        cipher: Self::openssh_cipher(name),
+      }),
+    };
+    Ok(Self {
+      signature_algorithm,
+      protection,
+    })
+  }
+}
+
+impl Key for PrivateKey {
+  fn from_file(path: &Path) -> Result<Self, Error> {
+    Self::from_file_with_passphrase(path, None)
+  }
+
+  fn hash_function(&self) -> Option<Hash> {
+    None
+  }
+
+  fn signature_algorithm(&self) -> Option<Asymmetric> {
+    self.signature_algorithm
+  }
+
+  fn usage(&self) -> Usage {
+    match self.signature_algorithm {
+      Some(Asymmetric::Ffc(ffc)) if ffc.is_key_agreement_only() => Usage::KeyEstablishment,
+      Some(Asymmetric::Ecc(ecc)) if ecc.is_key_agreement_only() => Usage::KeyEstablishment,
+      _ => Usage::Unspecified,
+    }
+  }
+
+  fn protection(&self) -> Option<&Protection> {
+    self.protection.as_ref()
+  }
+}
+
+/// A PEM encoded structure including the headers used by encrypted
+/// keys in the traditional format (see RFC 1421) which the parser of
+/// the x509_parser crate does not support.
+struct Pem {
+  label: String,
+  headers: Vec<(String, String)>,
+  contents: Vec<u8>,
+}
+
+impl Pem {
+  /// Parses the private key in a PEM file, which may also hold other
+  /// structures such as the certificates of the key, or the first
+  /// structure if none of them is a private key.
+  fn parse(data: &[u8]) -> Option<Self> {
+    let text = std::str::from_utf8(data).ok()?;
+    let mut lines = text.lines().map(str::trim);
+    let mut first = None;
+    while let Some(pem) = Self::parse_next(&mut lines) {
+      if pem.label.ends_with("PRIVATE KEY") {
+        return Some(pem);
+      }
+      first.get_or_insert(pem);
+    }
+    first
+  }
+
+  fn parse_next<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Option<Self> {
+    let label = lines
+      .find_map(|line| line.strip_prefix("-----BEGIN "))?
+      .strip_suffix("-----")?
+      .to_string();
+    let mut headers = Vec::new();
+    let mut body = String::new();
+    for line in lines {
+      if line.starts_with("-----END ") {
+        let contents = base64::decode_block(&body).ok()?;
+        return Some(Self {
+          label,
+          headers,
+          contents,
+        });
+      }
+      match line.split_once(':') {
+        Some((name, value)) => headers.push((name.to_string(), value.trim().to_string())),
+        None => body.push_str(line),
+      }
+    }
+    None
+  }
+
+  fn header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(header, _)| header == name)
+      .map(|(_, value)| value.as_str())
+  }
+}
+
+fn parse(data: &[u8]) -> Result<BerObject<'_>, Error> {
+  parse_der(data)
+    .map(|(_, der)| der)
+    .map_err(|_| Error::ParsePrivateKey)
+}
+
+/// Returns the object identifier and optional parameters of an
+/// AlgorithmIdentifier structure.
+fn algorithm_identifier<'a, 'b>(
+  der: &'b BerObject<'a>,
+) -> Result<(String, Option<&'b BerObject<'a>>), Error> {
+  let items = der.as_sequence().map_err(|_| Error::ParsePrivateKey)?;
+  let oid = items
+    .first()
+    .and_then(|oid| oid.as_oid().ok())
+    .ok_or(Error::ParsePrivateKey)?;
+  Ok((oid.to_id_string(), items.get(1)))
+}
+
+/// Returns the number of bits of a big-endian unsigned integer.
+fn bit_length(bytes: &[u8]) -> u16 {
+  match bytes.iter().position(|&byte| byte != 0) {
+    Some(i) => ((bytes.len() - i) * 8 - bytes[i].leading_zeros() as usize) as u16,
+    None => 0,
+  }
+}
+
+fn read_u32(reader: &mut &[u8]) -> Result<u32, Error> {
+  let (bytes, rest) = reader.split_first_chunk().ok_or(Error::ParsePrivateKey)?;
+  *reader = rest;
+  Ok(u32::from_be_bytes(*bytes))
+}
+
+fn read_string<'a>(reader: &mut &'a [u8]) -> Result<&'a [u8], Error> {
+  let n = read_u32(reader)? as usize;
+  if reader.len() < n {
+    return Err(Error::ParsePrivateKey);
+  }
+  let (string, rest) = reader.split_at(n);
+  *reader = rest;
+  Ok(string)
+}
+
+#[cfg(test)]
+mod tests {
+
+  use super::*;
+  use crate::testing::fixture;
+
+  #[test]
+  fn private_key_alongside_certificate() {
+    let key = PrivateKey::from_file(&fixture("leaf_with_key.pem")).unwrap();
+    assert_eq!(key.signature_algorithm(), Some(P384.into()));
+    assert_eq!(key.protection(), None);
+  }
+
+  #[test]
+  fn x25519() {
+    let key = PrivateKey::from_file(&fixture("x25519.key")).unwrap();
+    assert_eq!(key.signature_algorithm(), Some(X25519.into()));
+    assert_eq!(key.usage(), Usage::KeyEstablishment);
+  }
+
+  #[test]
+  fn x448() {
+    let key = PrivateKey::from_file(&fixture("x448.key")).unwrap();
+    assert_eq!(key.signature_algorithm(), Some(X448.into()));
+    assert_eq!(key.usage(), Usage::KeyEstablishment);
+  }
+
+  #[test]
+  fn signing_key_usage_is_unspecified() {
+    let key = PrivateKey::from_file(&fixture("leaf_with_key.pem")).unwrap();
+    assert_eq!(key.usage(), Usage::Unspecified);
+  }
+
+  #[test]
+  fn encrypted_pkcs8_without_passphrase() {
+    let key = PrivateKey::from_file(&fixture("encrypted_pkcs8.key")).unwrap();
+    let protection = Protection {
+      scheme: "pbes2 with pbkdf2".to_string(),
+      kdf: Some(Kdf::new(PBKDF2_HMAC_SHA256.id, 256, 2048).into()),
+      cipher: Some(AES256),
+    };
+    assert_eq!(key.signature_algorithm(), None);
+    assert_eq!(key.protection(), Some(&protection));
+  }
+
+  #[test]
+  fn encrypted_pkcs8_with_one_iteration() {
+    let key = PrivateKey::from_file(&fixture("encrypted_pkcs8_one_iteration.key")).unwrap();
+    let kdf = Kdf::new(PBKDF2_HMAC_SHA256.id, 256, 1);
+    assert_eq!(key.protection().unwrap().kdf, Some(kdf.into()));
+  }
+
+  #[test]
+  fn encrypted_pkcs8_with_scrypt() {
+    let key = PrivateKey::from_file(&fixture("encrypted_pkcs8_scrypt.key")).unwrap();
+    let protection = Protection {
+      scheme: "pbes2 with scrypt".to_string(),
+      kdf: Some(Phf::scrypt(SCRYPT.id, 16384, 8, 1).into()),
+      cipher: Some(AES256),
+    };
+    assert_eq!(key.protection(), Some(&protection));
+  }
+
+  #[test]
+  fn encrypted_pkcs8_with_passphrase() {
+    let path = fixture("encrypted_pkcs8.key");
+    let key = PrivateKey::from_file_with_passphrase(&path, Some(b"wardstone")).unwrap();
+    assert_eq!(key.signature_algorithm(), Some(P384.into()));
+    assert!(key.protection().is_some());
+    let err = PrivateKey::from_file_with_passphrase(&path, Some(b"wrong")).unwrap_err();
+    assert!(matches!(err, Error::Decrypt));
+  }
+}
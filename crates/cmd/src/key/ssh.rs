@@ -2,27 +2,101 @@
 use std::fs;
 use std::path::Path;
 
+use openssh_keys::errors::OpenSSHKeyError;
 use openssh_keys::{Curve, Data, PublicKey};
+use openssl::base64;
+use serde::Serialize;
+use wardstone_core::context::Usage;
 use wardstone_core::primitive::asymmetric::Asymmetric;
 use wardstone_core::primitive::ecc::*;
 use wardstone_core::primitive::ffc::*;
 use wardstone_core::primitive::hash::*;
 use wardstone_core::primitive::ifc::*;
+use x509_parser::time::ASN1Time;
 
-use crate::key::{Error, Key};
+use crate::key::{Error, Key, Validity};
 
-/// Represents an SSH public key.
+const CERTIFICATE_SUFFIX: &str = "-cert-v01@openssh.com";
+
+// The latest time that can be represented, 9999-12-31T23:59:59Z, which
+// stands in for certificates that never expire.
+const MAX_TIMESTAMP: u64 = 253402300799;
+
+/// Represents an SSH public key or an OpenSSH certificate.
 #[derive(Debug)]
 pub struct Ssh {
   hash_function: Option<Hash>,
   signature_algorithm: Asymmetric,
+  issuer_signature: Option<Asymmetric>,
+  validity: Option<Validity>,
+  certificate: Option<SshCertificate>,
 }
 
-impl Key for Ssh {
-  fn from_file(path: &Path) -> Result<Self, Error> {
-    let contents = fs::read_to_string(path)?;
-    let key = PublicKey::parse(contents.as_str())?;
+/// Represents a line of an `authorized_keys` or `known_hosts` file.
+#[derive(Debug)]
+pub struct Entry {
+  /// The line number starting from one.
+  pub line: usize,
+  /// The comment of the key or, if it has none, what precedes it on the
+  /// line such as a marker and host patterns or options.
+  pub label: String,
+  pub key: Result<Ssh, Error>,
+}
+
+impl Entry {
+  /// Parses a line whose key is found by looking for a key type that is
+  /// followed by a blob of the same type.
+  ///
+  /// This sidesteps parsing options which may be quoted and contain
+  /// spaces, as well as markers such as `@cert-authority` and hashed
+  /// host names.
+  fn parse(line: usize, text: &str) -> Self {
+    let fields: Vec<_> = text.split_whitespace().collect();
+    let found = fields.windows(2).enumerate().find_map(|(i, pair)| {
+      let blob = base64::decode_block(pair[1]).ok()?;
+      let mut reader = blob.as_slice();
+      let key_type = read_string(&mut reader).ok()?;
+      (key_type == pair[0].as_bytes()).then_some((i, blob))
+    });
+    let Some((i, blob)) = found else {
+      return Self {
+        line,
+        label: String::new(),
+        key: Err(OpenSSHKeyError::InvalidFormat.into()),
+      };
+    };
+    let mut label = fields[i + 2..].join(" ");
+    if label.is_empty() {
+      label = fields[..i].join(" ");
+    }
+    let key = if fields[i].ends_with(CERTIFICATE_SUFFIX) {
+      Ssh::from_certificate(&blob)
+    } else {
+      Ssh::parse_public_key(&blob).map(|key| Ssh::from_public_key(&key))
+    };
+    Self { line, label, key }
+  }
+}
+
+/// Represents the attributes of an OpenSSH certificate that restrict
+/// its use (see PROTOCOL.certkeys in the OpenSSH source).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct SshCertificate {
+  /// Either `user` or `host`.
+  pub kind: String,
+  pub key_id: String,
+  /// The users or hosts the certificate is valid for where none means
+  /// that it is valid for any of them.
+  pub principals: Vec<String>,
+  /// The critical options as `name` or `name=value`.
+  pub critical_options: Vec<String>,
+  /// The algorithm the certificate authority signed with such as
+  /// `rsa-sha2-256`.
+  pub signature: String,
+}
 
+impl Ssh {
+  pub(crate) fn from_public_key(key: &PublicKey) -> Self {
     // It is not possible to infer the hash function used by looking at
     // the public key for RSA keys. RFC 4253 Section 6.6 specifies SHA-1
     // but a newer revision RFC 8332 specifies SHA-256 and SHA-512
@@ -48,7 +122,7 @@ impl Key for Ssh {
         };
         ifc.into()
       }),
-      Data::Dsa { ref p, q, .. } => (Some(SHA1), {
+      Data::Dsa { ref p, ref q, .. } => (Some(SHA1), {
         let p = (p.len() * 8) as u16;
         let q = (q.len() * 8) as u16;
         let ffc = match p {
@@ -69,18 +143,322 @@ impl Key for Ssh {
       },
     };
 
-    let key = Self {
+    Self {
       hash_function,
       signature_algorithm,
+      issuer_signature: None,
+      validity: None,
+      certificate: None,
+    }
+  }
+
+  /// Reads every key in a file which may be a single public key or
+  /// certificate, an `authorized_keys` file or a `known_hosts` file.
+  ///
+  /// Blank lines and comments are skipped. A line that cannot be parsed
+  /// does not prevent the others from being read.
+  pub fn entries_from_file(path: &Path) -> Result<Vec<Entry>, Error> {
+    let contents = fs::read_to_string(path)?;
+    let entries: Vec<_> = contents
+      .lines()
+      .enumerate()
+      .filter(|(_, line)| {
+        let line = line.trim();
+        !line.is_empty() && !line.starts_with('#')
+      })
+      .map(|(i, line)| Entry::parse(i + 1, line))
+      .collect();
+    if entries.is_empty() {
+      return Err(OpenSSHKeyError::InvalidFormat.into());
+    }
+    Ok(entries)
+  }
+
+  /// Parses the base64 encoded blob of an OpenSSH certificate whose
+  /// hash function is the one the certificate authority signed with.
+  fn from_certificate(blob: &[u8]) -> Result<Self, Error> {
+    let mut reader = blob;
+    let key_type = String::from_utf8_lossy(read_string(&mut reader)?).into_owned();
+    let base_type = match key_type.strip_suffix(CERTIFICATE_SUFFIX) {
+      Some(base_type) if base_type.starts_with("sk-") => format!("{}@openssh.com", base_type),
+      Some(base_type) => base_type.to_string(),
+      None => return Err(OpenSSHKeyError::InvalidFormat.into()),
+    };
+    let _nonce = read_string(&mut reader)?;
+    // The public key fields are the same as in a plain public key so
+    // they are used to reconstruct one.
+    let fields = match base_type.as_str() {
+      "ssh-dss" => 4,
+      "ssh-ed25519" => 1,
+      "ssh-rsa" | "sk-ssh-ed25519@openssh.com" => 2,
+      "sk-ecdsa-sha2-nistp256@openssh.com" => 3,
+      _ if base_type.starts_with("ecdsa-sha2-") => 2,
+      _ => return Err(Error::Unrecognised(key_type)),
+    };
+    let start = reader;
+    for _ in 0..fields {
+      read_string(&mut reader)?;
+    }
+    let mut public_key = Vec::new();
+    write_string(&mut public_key, base_type.as_bytes());
+    public_key.extend_from_slice(&start[..start.len() - reader.len()]);
+    let public_key = Self::parse_public_key(&public_key)?;
+
+    let _serial = read_u64(&mut reader)?;
+    let kind = match read_u32(&mut reader)? {
+      1 => "user",
+      2 => "host",
+      _ => return Err(OpenSSHKeyError::InvalidFormat.into()),
+    };
+    let key_id = String::from_utf8_lossy(read_string(&mut reader)?).into_owned();
+    let mut principals = Vec::new();
+    let mut packed = read_string(&mut reader)?;
+    while !packed.is_empty() {
+      principals.push(String::from_utf8_lossy(read_string(&mut packed)?).into_owned());
+    }
+    let not_before = timestamp(read_u64(&mut reader)?)?;
+    let not_after = timestamp(read_u64(&mut reader)?)?;
+    let mut critical_options = Vec::new();
+    let mut packed = read_string(&mut reader)?;
+    while !packed.is_empty() {
+      let name = String::from_utf8_lossy(read_string(&mut packed)?);
+      // The value of an option is itself wrapped in a string.
+      let mut data = read_string(&mut packed)?;
+      if data.is_empty() {
+        critical_options.push(name.into_owned());
+      } else {
+        let value = String::from_utf8_lossy(read_string(&mut data)?);
+        critical_options.push(format!("{}={}", name, value));
+      }
+    }
+    let _extensions = read_string(&mut reader)?;
+    let _reserved = read_string(&mut reader)?;
+    let ca_key = Self::parse_public_key(read_string(&mut reader)?)?;
+    let mut signature = read_string(&mut reader)?;
+    let signature = String::from_utf8_lossy(read_string(&mut signature)?).into_owned();
+
+    Ok(Self {
+      hash_function: Self::signature_hash(&signature)?,
+      signature_algorithm: Self::from_public_key(&public_key).signature_algorithm,
+      issuer_signature: Some(Self::from_public_key(&ca_key).signature_algorithm),
+      validity: Some(Validity {
+        not_before,
+        not_after,
+      }),
+      certificate: Some(SshCertificate {
+        kind: kind.to_string(),
+        key_id,
+        principals,
+        critical_options,
+        signature,
+      }),
+    })
+  }
+
+  /// Parses a public key blob which starts with the key type.
+  fn parse_public_key(blob: &[u8]) -> Result<PublicKey, Error> {
+    let mut key_type = blob;
+    let key_type = read_string(&mut key_type)?;
+    let line = format!(
+      "{} {}",
+      String::from_utf8_lossy(key_type),
+      base64::encode_block(blob)
+    );
+    Ok(PublicKey::parse(&line)?)
+  }
+
+  /// Identifies the hash function of an SSH signature algorithm (see
+  /// RFC 4253, RFC 5656 and RFC 8332).
+  fn signature_hash(name: &str) -> Result<Option<Hash>, Error> {
+    let hash = match name {
+      "ssh-rsa" | "ssh-dss" => Some(SHA1),
+      "rsa-sha2-256" | "ecdsa-sha2-nistp256" | "sk-ecdsa-sha2-nistp256@openssh.com" => Some(SHA256),
+      "ecdsa-sha2-nistp384" => Some(SHA384),
+      "rsa-sha2-512" | "ecdsa-sha2-nistp521" => Some(SHA512),
+      "ssh-ed25519" | "ssh-ed448" | "sk-ssh-ed25519@openssh.com" => None,
+      _ => return Err(Error::Unrecognised(name.to_string())),
     };
-    Ok(key)
+    Ok(hash)
+  }
+}
+
+impl Key for Ssh {
+  /// Reads the first key in a file, see [`Ssh::entries_from_file`] for
+  /// all of them.
+  fn from_file(path: &Path) -> Result<Self, Error> {
+    let entries = Self::entries_from_file(path)?;
+    let entry = entries
+      .into_iter()
+      .next()
+      .expect("entries should not be empty");
+    entry.key
   }
 
   fn hash_function(&self) -> Option<Hash> {
     self.hash_function
   }
 
-  fn signature_algorithm(&self) -> Asymmetric {
-    self.signature_algorithm
+  fn signature_algorithm(&self) -> Option<Asymmetric> {
+    Some(self.signature_algorithm)
+  }
+
+  /// Returns the key of the certificate authority if the key is a
+  /// certificate.
+  fn issuer_signature(&self) -> Option<Asymmetric> {
+    self.issuer_signature
+  }
+
+  fn usage(&self) -> Usage {
+    // SSH keys are only used to authenticate by means of signatures.
+    Usage::DigitalSignature
+  }
+
+  fn validity(&self) -> Option<Validity> {
+    self.validity
+  }
+
+  fn ssh_certificate(&self) -> Option<&SshCertificate> {
+    self.certificate.as_ref()
+  }
+}
+
+fn timestamp(seconds: u64) -> Result<ASN1Time, Error> {
+  let seconds = seconds.min(MAX_TIMESTAMP) as i64;
+  ASN1Time::from_timestamp(seconds).map_err(|_| OpenSSHKeyError::InvalidFormat.into())
+}
+
+fn read_u32(reader: &mut &[u8]) -> Result<u32, Error> {
+  let (bytes, rest) = reader
+    .split_first_chunk()
+    .ok_or(OpenSSHKeyError::InvalidFormat)?;
+  *reader = rest;
+  Ok(u32::from_be_bytes(*bytes))
+}
+
+fn read_u64(reader: &mut &[u8]) -> Result<u64, Error> {
+  let (bytes, rest) = reader
+    .split_first_chunk()
+    .ok_or(OpenSSHKeyError::InvalidFormat)?;
+  *reader = rest;
+  Ok(u64::from_be_bytes(*bytes))
+}
+
+fn read_string<'a>(reader: &mut &'a [u8]) -> Result<&'a [u8], Error> {
+  let n = read_u32(reader)? as usize;
+  if reader.len() < n {
+    return Err(OpenSSHKeyError::InvalidFormat.into());
+  }
+  let (string, rest) = reader.split_at(n);
+  *reader = rest;
+  Ok(string)
+}
+
+fn write_string(writer: &mut Vec<u8>, string: &[u8]) {
+  writer.extend_from_slice(&(string.len() as u32).to_be_bytes());
+  writer.extend_from_slice(string);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::testing::fixture;
+
+  fn entries(name: &str) -> Vec<(usize, String, Option<Asymmetric>)> {
+    Ssh::entries_from_file(&fixture(name))
+      .unwrap()
+      .into_iter()
+      .map(|entry| {
+        let key = entry.key.ok().and_then(|key| key.signature_algorithm());
+        (entry.line, entry.label, key)
+      })
+      .collect()
+  }
+
+  #[test]
+  fn certificate_signed_with_ssh_rsa() {
+    let key = Ssh::from_file(&fixture("ssh_ssh-rsa-cert.pub")).unwrap();
+    assert_eq!(key.hash_function(), Some(SHA1));
+    assert_eq!(key.signature_algorithm(), Some(ED25519.into()));
+    assert_eq!(key.issuer_signature(), Some(RSA_PSS_2048.into()));
+    let certificate = SshCertificate {
+      kind: "user".to_string(),
+      key_id: "wardstone-ssh-rsa".to_string(),
+      principals: vec!["alice".to_string(), "bob".to_string()],
+      critical_options: vec!["force-command=/bin/true".to_string()],
+      signature: "ssh-rsa".to_string(),
+    };
+    assert_eq!(key.ssh_certificate(), Some(&certificate));
+    // Certificates valid forever expire at the latest representable
+    // time.
+    assert_eq!(key.validity().unwrap().expiry_year(), 9999);
+  }
+
+  #[test]
+  fn certificate_signed_with_rsa_sha2_512() {
+    let key = Ssh::from_file(&fixture("ssh_rsa-sha2-512-cert.pub")).unwrap();
+    assert_eq!(key.hash_function(), Some(SHA512));
+    assert_eq!(key.issuer_signature(), Some(RSA_PSS_2048.into()));
+    let certificate = key.ssh_certificate().unwrap();
+    assert_eq!(certificate.signature, "rsa-sha2-512");
+    assert_eq!(certificate.key_id, "wardstone-rsa-sha2-512");
+  }
+
+  #[test]
+  fn certificate_expired() {
+    let key = Ssh::from_file(&fixture("ssh_expired-cert.pub")).unwrap();
+    assert_eq!(key.hash_function(), Some(SHA256));
+    let validity = key.validity().unwrap();
+    assert!(validity.is_expired(ASN1Time::now()));
+    assert!(validity.expiry_year() <= 2001);
+    assert_eq!(key.ssh_certificate().unwrap().principals, ["alice"]);
+  }
+
+  #[test]
+  fn authorized_keys_with_options() {
+    assert_eq!(
+      entries("authorized_keys"),
+      [
+        (3, "user@wardstone".to_string(), Some(ED25519.into())),
+        (4, "ecdsa@wardstone".to_string(), Some(P256.into())),
+        (
+          5,
+          "cert-authority,principals=\"alice\"".to_string(),
+          Some(RSA_PSS_2048.into())
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn known_hosts_with_markers() {
+    let entries = entries("known_hosts");
+    assert_eq!(entries.len(), 4);
+    assert_eq!(
+      entries[..3],
+      [
+        (1, "wardstone.test,10.0.0.1".to_string(), Some(P256.into())),
+        (
+          2,
+          "@cert-authority *.wardstone.test".to_string(),
+          Some(RSA_PSS_2048.into())
+        ),
+        (
+          3,
+          "@revoked revoked.wardstone.test".to_string(),
+          Some(ED25519.into())
+        ),
+      ]
+    );
+    // Hashed host names are kept as they are.
+    assert!(entries[3].1.starts_with("|1|"));
+    assert_eq!(entries[3].2, Some(ED25519.into()));
+  }
+
+  #[test]
+  fn entry_without_key() {
+    let entry = Entry::parse(1, "no-pty ssh-ed25519 not-base64");
+    assert_eq!(entry.line, 1);
+    assert!(entry.label.is_empty());
+    assert!(entry.key.is_err());
   }
 }
@@ -9,13 +9,32 @@
 //! Usage: wardstone <COMMAND>
 //!
 //! Commands:
-//!   ssh   Check an SSH public key for compliance
-//!   x509  Check X.509 public key certificates for compliance
-//!   help  Print this message or the help of the given subcommand(s)
+//!   dhparam     Check Diffie-Hellman parameters, such as those generated by openssl dhparam, for compliance including whether they are sound
+//!   key         Check private keys for compliance including the encryption protecting them, if any
+//!   passwd      Check the password hashing functions and cost parameters of password hashes, such as those in /etc/shadow, for compliance
+//!   scan        Find keys and certificates in directories and check them for compliance
+//!   ssh         Check SSH public keys and certificates for compliance, including those listed in authorized_keys and known_hosts files
+//!   ssh-config  Check the algorithms allowed by OpenSSH client and server configuration files for compliance
+//!   tls         Connect to TLS servers and check the protocol versions, cipher suites, groups and certificates they accept for compliance
+//!   tls-suites  Check the cipher suites, groups and signature schemes of a TLS configuration for compliance
+//!   x509        Check X.509 public key certificates for compliance
+//!   help        Print this message or the help of the given subcommand(s)
 //!
 //! Options:
 //!   -h, --help     Print help
 //!   -V, --version  Print version
+//!
+//! Exit codes:
+//!   0  Everything that was assessed complies
+//!   1  Something does not comply, even if other keys could not be read
+//!   2  Some key could not be read or the assessment could not be carried out
 //! ```
+pub mod dhparam;
 pub mod key;
+pub mod passwd;
+pub mod policy;
+pub mod protocol;
 pub mod report;
+pub mod scan;
+#[cfg(test)]
+mod testing;
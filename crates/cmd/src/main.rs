@@ -1,22 +1,40 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use clap::{Parser, Subcommand, ValueEnum};
+use wardstone::dhparam;
 use wardstone::key::certificate::Certificate;
-use wardstone::key::ssh::Ssh;
-use wardstone::key::Key;
-use wardstone::report::{Audit, Exit, Report, Verbosity};
-use wardstone_core::context::Context;
+use wardstone::key::private::PrivateKey;
+use wardstone::key::ssh::{Entry, Ssh};
+use wardstone::key::{Error, Key, KeyDerivation};
+use wardstone::passwd;
+use wardstone::policy;
+use wardstone::protocol::tls::{self, Parameter};
+use wardstone::protocol::{ssh, Component};
+use wardstone::report::{
+  Audit, Establishment, Exit, Format, Location, Report, SettingAudit, Verbosity,
+};
+use wardstone::scan::{Filter, Kind};
+use wardstone_core::context::{Context, Operation, Usage};
 use wardstone_core::primitive::asymmetric::Asymmetric;
 use wardstone_core::primitive::hash::Hash;
+use wardstone_core::primitive::kdf::Kdf;
+use wardstone_core::primitive::kem::Kem;
+use wardstone_core::primitive::mac::Mac;
+use wardstone_core::primitive::mode::Mode;
+use wardstone_core::primitive::phf::Phf;
+use wardstone_core::primitive::symmetric::Symmetric;
 use wardstone_core::primitive::Security;
 use wardstone_core::standard::bsi::Bsi;
 use wardstone_core::standard::cnsa::Cnsa;
 use wardstone_core::standard::ecrypt::Ecrypt;
 use wardstone_core::standard::lenstra::Lenstra;
 use wardstone_core::standard::nist::Nist;
+use wardstone_core::standard::policy::PolicyStandard;
 use wardstone_core::standard::testing::strong::Strong;
 use wardstone_core::standard::testing::weak::Weak;
-use wardstone_core::standard::Standard;
+use wardstone_core::standard::{Standard, Status, Verdict};
+use x509_parser::time::ASN1Time;
 
 // Having this type in the core crate would reduce the amount of case
 // analysis done to find the function to execute but this would run
@@ -45,23 +63,19 @@ enum Guide {
 }
 
 impl Guide {
-  fn validate_hash_function(&self, ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash_function(&self, ctx: Context, hash: Hash) -> Verdict<Hash> {
     match self {
       Self::Bsi => Bsi::validate_hash(ctx, hash),
       Self::Cnsa => Cnsa::validate_hash(ctx, hash),
       Self::Ecrypt => Ecrypt::validate_hash(ctx, hash),
-      Self::Lenstra => Ecrypt::validate_hash(ctx, hash),
+      Self::Lenstra => Lenstra::validate_hash(ctx, hash),
       Self::Nist => Nist::validate_hash(ctx, hash),
       Self::Strong => Strong::validate_hash(ctx, hash),
       Self::Weak => Weak::validate_hash(ctx, hash),
     }
   }
 
-  fn validate_signature_algorithm(
-    &self,
-    ctx: Context,
-    key: Asymmetric,
-  ) -> Result<Asymmetric, Asymmetric> {
+  fn validate_signature_algorithm(&self, ctx: Context, key: Asymmetric) -> Verdict<Asymmetric> {
     match self {
       Self::Bsi => Bsi::validate_asymmetric(ctx, key),
       Self::Cnsa => Cnsa::validate_asymmetric(ctx, key),
@@ -72,11 +86,204 @@ impl Guide {
       Self::Weak => Weak::validate_asymmetric(ctx, key),
     }
   }
+
+  fn validate_symmetric(&self, ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    match self {
+      Self::Bsi => Bsi::validate_symmetric(ctx, key),
+      Self::Cnsa => Cnsa::validate_symmetric(ctx, key),
+      Self::Ecrypt => Ecrypt::validate_symmetric(ctx, key),
+      Self::Lenstra => Lenstra::validate_symmetric(ctx, key),
+      Self::Nist => Nist::validate_symmetric(ctx, key),
+      Self::Strong => Strong::validate_symmetric(ctx, key),
+      Self::Weak => Weak::validate_symmetric(ctx, key),
+    }
+  }
+
+  fn validate_kem(&self, ctx: Context, kem: Kem) -> Verdict<Kem> {
+    match self {
+      Self::Bsi => Bsi::validate_kem(ctx, kem),
+      Self::Cnsa => Cnsa::validate_kem(ctx, kem),
+      Self::Ecrypt => Ecrypt::validate_kem(ctx, kem),
+      Self::Lenstra => Lenstra::validate_kem(ctx, kem),
+      Self::Nist => Nist::validate_kem(ctx, kem),
+      Self::Strong => Strong::validate_kem(ctx, kem),
+      Self::Weak => Weak::validate_kem(ctx, kem),
+    }
+  }
+
+  fn validate_kdf(&self, ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    match self {
+      Self::Bsi => Bsi::validate_kdf(ctx, kdf),
+      Self::Cnsa => Cnsa::validate_kdf(ctx, kdf),
+      Self::Ecrypt => Ecrypt::validate_kdf(ctx, kdf),
+      Self::Lenstra => Lenstra::validate_kdf(ctx, kdf),
+      Self::Nist => Nist::validate_kdf(ctx, kdf),
+      Self::Strong => Strong::validate_kdf(ctx, kdf),
+      Self::Weak => Weak::validate_kdf(ctx, kdf),
+    }
+  }
+
+  fn validate_mac(&self, ctx: Context, mac: Mac) -> Verdict<Mac> {
+    match self {
+      Self::Bsi => Bsi::validate_mac(ctx, mac),
+      Self::Cnsa => Cnsa::validate_mac(ctx, mac),
+      Self::Ecrypt => Ecrypt::validate_mac(ctx, mac),
+      Self::Lenstra => Lenstra::validate_mac(ctx, mac),
+      Self::Nist => Nist::validate_mac(ctx, mac),
+      Self::Strong => Strong::validate_mac(ctx, mac),
+      Self::Weak => Weak::validate_mac(ctx, mac),
+    }
+  }
+
+  fn validate_mode(&self, ctx: Context, mode: Mode) -> Verdict<Mode> {
+    match self {
+      Self::Bsi => Bsi::validate_mode(ctx, mode),
+      Self::Cnsa => Cnsa::validate_mode(ctx, mode),
+      Self::Ecrypt => Ecrypt::validate_mode(ctx, mode),
+      Self::Lenstra => Lenstra::validate_mode(ctx, mode),
+      Self::Nist => Nist::validate_mode(ctx, mode),
+      Self::Strong => Strong::validate_mode(ctx, mode),
+      Self::Weak => Weak::validate_mode(ctx, mode),
+    }
+  }
+
+  fn validate_phf(&self, ctx: Context, phf: Phf) -> Verdict<Phf> {
+    match self {
+      Self::Bsi => Bsi::validate_phf(ctx, phf),
+      Self::Cnsa => Cnsa::validate_phf(ctx, phf),
+      Self::Ecrypt => Ecrypt::validate_phf(ctx, phf),
+      Self::Lenstra => Lenstra::validate_phf(ctx, phf),
+      Self::Nist => Nist::validate_phf(ctx, phf),
+      Self::Strong => Strong::validate_phf(ctx, phf),
+      Self::Weak => Weak::validate_phf(ctx, phf),
+    }
+  }
+
+  fn validate_salt_length(&self, ctx: Context, hash: Hash, salt_length: u32) -> Verdict<u32> {
+    match self {
+      Self::Bsi => Bsi::validate_salt_length(ctx, hash, salt_length),
+      Self::Cnsa => Cnsa::validate_salt_length(ctx, hash, salt_length),
+      Self::Ecrypt => Ecrypt::validate_salt_length(ctx, hash, salt_length),
+      Self::Lenstra => Lenstra::validate_salt_length(ctx, hash, salt_length),
+      Self::Nist => Nist::validate_salt_length(ctx, hash, salt_length),
+      Self::Strong => Strong::validate_salt_length(ctx, hash, salt_length),
+      Self::Weak => Weak::validate_salt_length(ctx, hash, salt_length),
+    }
+  }
+}
+
+/// The purpose a key is put to.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum KeyUsage {
+  /// Digital signatures including those on certificates and revocation
+  /// lists.
+  DigitalSignature,
+  /// Key establishment such as Diffie-Hellman key agreement.
+  KeyEstablishment,
+  /// Encryption of keys or data.
+  Encryption,
+}
+
+impl From<KeyUsage> for Usage {
+  fn from(usage: KeyUsage) -> Self {
+    match usage {
+      KeyUsage::DigitalSignature => Self::DigitalSignature,
+      KeyUsage::KeyEstablishment => Self::KeyEstablishment,
+      KeyUsage::Encryption => Self::Encryption,
+    }
+  }
+}
+
+/// The rules that keys are assessed against which are either one of the
+/// built-in guides or a policy loaded from a file.
+enum Benchmark {
+  Guide(Guide),
+  Policy(Box<PolicyStandard>),
+}
+
+impl Benchmark {
+  fn new(guide: Option<Guide>, policy: &Option<PathBuf>) -> Result<Self, Error> {
+    match (guide, policy) {
+      (_, Some(path)) => Ok(Self::Policy(Box::new(policy::from_file(path)?))),
+      (Some(guide), None) => Ok(Self::Guide(guide)),
+      (None, None) => unreachable!("clap requires either a guide or a policy"),
+    }
+  }
+
+  fn validate_hash_function(&self, ctx: Context, hash: Hash) -> Verdict<Hash> {
+    match self {
+      Self::Guide(guide) => guide.validate_hash_function(ctx, hash),
+      Self::Policy(policy) => policy.validate_hash(ctx, hash),
+    }
+  }
+
+  fn validate_signature_algorithm(&self, ctx: Context, key: Asymmetric) -> Verdict<Asymmetric> {
+    match self {
+      Self::Guide(guide) => guide.validate_signature_algorithm(ctx, key),
+      Self::Policy(policy) => policy.validate_asymmetric(ctx, key),
+    }
+  }
+
+  fn validate_symmetric(&self, ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    match self {
+      Self::Guide(guide) => guide.validate_symmetric(ctx, key),
+      Self::Policy(policy) => policy.validate_symmetric(ctx, key),
+    }
+  }
+
+  fn validate_kem(&self, ctx: Context, kem: Kem) -> Verdict<Kem> {
+    match self {
+      Self::Guide(guide) => guide.validate_kem(ctx, kem),
+      Self::Policy(policy) => policy.validate_kem(ctx, kem),
+    }
+  }
+
+  fn validate_kdf(&self, ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    match self {
+      Self::Guide(guide) => guide.validate_kdf(ctx, kdf),
+      Self::Policy(policy) => policy.validate_kdf(ctx, kdf),
+    }
+  }
+
+  fn validate_mac(&self, ctx: Context, mac: Mac) -> Verdict<Mac> {
+    match self {
+      Self::Guide(guide) => guide.validate_mac(ctx, mac),
+      Self::Policy(policy) => policy.validate_mac(ctx, mac),
+    }
+  }
+
+  fn validate_mode(&self, ctx: Context, mode: Mode) -> Verdict<Mode> {
+    match self {
+      Self::Guide(guide) => guide.validate_mode(ctx, mode),
+      Self::Policy(policy) => policy.validate_mode(ctx, mode),
+    }
+  }
+
+  fn validate_phf(&self, ctx: Context, phf: Phf) -> Verdict<Phf> {
+    match self {
+      Self::Guide(guide) => guide.validate_phf(ctx, phf),
+      Self::Policy(policy) => policy.validate_phf(ctx, phf),
+    }
+  }
+
+  fn validate_salt_length(&self, ctx: Context, hash: Hash, salt_length: u32) -> Verdict<u32> {
+    match self {
+      Self::Guide(guide) => guide.validate_salt_length(ctx, hash, salt_length),
+      Self::Policy(policy) => policy.validate_salt_length(ctx, hash, salt_length),
+    }
+  }
 }
 
+/// Describes the exit codes in the help message.
+const EXIT_CODES: &str = "\
+Exit codes:
+  0  Everything that was assessed complies
+  1  Something does not comply, even if other keys could not be read
+  2  Some key could not be read or the assessment could not be carried out";
+
 /// Assess cryptographic keys for compliance.
 #[derive(Parser)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, after_help = EXIT_CODES)]
 struct Options {
   #[command(subcommand)]
   subcommands: Subcommands,
@@ -84,14 +291,260 @@ struct Options {
 
 #[derive(Subcommand)]
 enum Subcommands {
-  /// Check an SSH public key for compliance.
+  /// Check Diffie-Hellman parameters, such as those generated by
+  /// openssl dhparam, for compliance including whether they are sound.
+  Dhparam {
+    /// Guide to assess the parameters against.
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
+    json: bool,
+    /// Policy file to assess the parameters against instead of a
+    /// guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Do not print output.
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// The minimum security level required.
+    ///
+    /// If a sufficiently low value is used then the application will
+    /// default to the minimum security specified by the standard.
+    #[arg(short, long, default_value_t = 0)]
+    security: Security,
+    /// Verbose output.
+    #[arg(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// The year in which a recommendation is expected to be valid.
+    ///
+    /// Note that this does not necessarily mean that a primitive will
+    /// be deemed insecure beyond this point. Indeed, recommendations
+    /// are usually done with a longer horizon in mind. For example,
+    /// setting this value to 2023, one would expect any passing
+    /// primitive to be secure for the next 5 to 7 years,
+    /// conservatively, subject to cryptanalytic developments.
+    #[arg(short, long, default_value_t = 2023)]
+    year: u16,
+    /// The Diffie-Hellman parameters as PKCS#3 or X9.42 files, either
+    /// PEM or DER encoded.
+    #[clap(value_name = "FILE")]
+    files: Vec<PathBuf>,
+  },
+  /// Check private keys for compliance including the encryption
+  /// protecting them, if any.
+  Key {
+    /// Guide to assess the key against.
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
+    json: bool,
+    /// File containing the passphrase of encrypted keys.
+    ///
+    /// Only needed for keys that do not reveal their algorithm in the
+    /// clear such as encrypted PKCS#8 keys. Without it, only the
+    /// encryption of these keys is checked. A trailing newline is
+    /// ignored.
+    #[arg(long, value_name = "FILE")]
+    passphrase_file: Option<PathBuf>,
+    /// Policy file to assess the key against instead of a guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Assess the key for processing already protected data only.
+    ///
+    /// Most standards allow primitives that are no longer fit to apply
+    /// protection to still be used to verify existing signatures.
+    #[arg(long)]
+    process: bool,
+    /// Do not print output.
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// The minimum security level required.
+    ///
+    /// If a sufficiently low value is used then the application will
+    /// default to the minimum security specified by the standard.
+    #[arg(short, long, default_value_t = 0)]
+    security: Security,
+    /// The purpose the key is put to.
+    #[arg(long, value_enum)]
+    usage: Option<KeyUsage>,
+    /// Verbose output.
+    #[arg(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// The year in which a recommendation is expected to be valid.
+    ///
+    /// Note that this does not necessarily mean that a primitive will
+    /// be deemed insecure beyond this point. Indeed, recommendations
+    /// are usually done with a longer horizon in mind. For example,
+    /// setting this value to 2023, one would expect any passing
+    /// primitive to be secure for the next 5 to 7 years,
+    /// conservatively, subject to cryptanalytic developments.
+    #[arg(short, long, default_value_t = 2023)]
+    year: u16,
+    /// The private keys as PKCS#8, PKCS#1, SEC1 or OpenSSH files.
+    #[clap(value_name = "FILE")]
+    files: Vec<PathBuf>,
+  },
+  /// Check the password hashing functions and cost parameters of
+  /// password hashes, such as those in /etc/shadow, for compliance.
+  Passwd {
+    /// Guide to assess the password hashes against.
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
+    json: bool,
+    /// Policy file to assess the password hashes against instead of a
+    /// guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Do not print output.
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// The minimum security level required.
+    ///
+    /// If a sufficiently low value is used then the application will
+    /// default to the minimum security specified by the standard.
+    #[arg(short, long, default_value_t = 0)]
+    security: Security,
+    /// Verbose output.
+    #[arg(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// The year in which a recommendation is expected to be valid.
+    ///
+    /// Note that this does not necessarily mean that a primitive will
+    /// be deemed insecure beyond this point. Indeed, recommendations
+    /// are usually done with a longer horizon in mind. For example,
+    /// setting this value to 2023, one would expect any passing
+    /// primitive to be secure for the next 5 to 7 years,
+    /// conservatively, subject to cryptanalytic developments.
+    #[arg(short, long, default_value_t = 2023)]
+    year: u16,
+    /// The files that hold password hashes.
+    ///
+    /// Lines are either in the format of /etc/shadow or hold a hash on
+    /// its own, encoded as a PHC string or in one of the formats of
+    /// crypt(3). The passwords themselves are never needed.
+    #[clap(value_name = "FILE")]
+    files: Vec<PathBuf>,
+  },
+  /// Find keys and certificates in directories and check them for
+  /// compliance.
+  Scan {
+    /// Glob pattern of paths to skip, including directories.
+    ///
+    /// Patterns are matched against paths relative to the directory
+    /// being scanned and may be given multiple times.
+    #[arg(short, long, value_name = "GLOB")]
+    exclude: Vec<String>,
+    /// Follow symbolic links instead of skipping them.
+    #[arg(short = 'L', long)]
+    follow_symlinks: bool,
+    /// Guide to assess the keys against.
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Glob pattern of files to check.
+    ///
+    /// Patterns are matched against paths relative to the directory
+    /// being scanned and may be given multiple times. If omitted, all
+    /// files are checked.
+    #[arg(short, long, value_name = "GLOB")]
+    include: Vec<String>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
+    json: bool,
+    /// Policy file to assess the keys against instead of a guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Assess the keys for processing already protected data only.
+    ///
+    /// Most standards allow primitives that are no longer fit to apply
+    /// protection to still be used to verify existing signatures.
+    #[arg(long)]
+    process: bool,
+    /// Do not print output.
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// The minimum security level required.
+    ///
+    /// If a sufficiently low value is used then the application will
+    /// default to the minimum security specified by the standard.
+    #[arg(short, long, default_value_t = 0)]
+    security: Security,
+    /// The purpose the keys are put to.
+    ///
+    /// Defaults to the usage derived from each key.
+    #[arg(long, value_enum)]
+    usage: Option<KeyUsage>,
+    /// Verbose output.
+    #[arg(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// The year in which a recommendation is expected to be valid.
+    ///
+    /// Note that this does not necessarily mean that a primitive will
+    /// be deemed insecure beyond this point. Indeed, recommendations
+    /// are usually done with a longer horizon in mind. For example,
+    /// setting this value to 2023, one would expect any passing
+    /// primitive to be secure for the next 5 to 7 years,
+    /// conservatively, subject to cryptanalytic developments.
+    ///
+    /// Certificates are assessed against the later of this year and
+    /// the year they expire.
+    #[arg(short, long, default_value_t = 2023)]
+    year: u16,
+    /// The directories or files to scan.
+    #[clap(value_name = "PATH", required = true)]
+    roots: Vec<PathBuf>,
+  },
+  /// Check SSH public keys and certificates for compliance, including
+  /// those listed in authorized_keys and known_hosts files.
   Ssh {
     /// Guide to assess the key against.
-    #[arg(short, long, value_enum)]
-    guide: Guide,
-    /// JSON formatted output.
-    #[arg(short, long)]
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
     json: bool,
+    /// Policy file to assess the key against instead of a guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Assess the key for processing already protected data only.
+    ///
+    /// Most standards allow primitives that are no longer fit to apply
+    /// protection to still be used to verify existing signatures.
+    #[arg(long)]
+    process: bool,
     /// Do not print output.
     #[arg(short, long, conflicts_with = "verbose")]
     quiet: bool,
@@ -101,6 +554,11 @@ enum Subcommands {
     /// default to the minimum security specified by the standard.
     #[arg(short, long, default_value_t = 0)]
     security: Security,
+    /// The purpose the key is put to.
+    ///
+    /// Defaults to digital signatures.
+    #[arg(long, value_enum)]
+    usage: Option<KeyUsage>,
     /// Verbose output.
     #[arg(short, long, conflicts_with = "quiet")]
     verbose: bool,
@@ -112,20 +570,203 @@ enum Subcommands {
     /// setting this value to 2023, one would expect any passing
     /// primitive to be secure for the next 5 to 7 years,
     /// conservatively, subject to cryptanalytic developments.
+    ///
+    /// Certificates are assessed against the later of this year and
+    /// the year they expire.
     #[arg(short, long, default_value_t = 2023)]
     year: u16,
-    /// The paths to the public key file(s).
+    /// The paths to the public key, certificate, authorized_keys or
+    /// known_hosts file(s).
     #[clap(value_name = "FILE")]
     files: Vec<PathBuf>,
   },
+  /// Check the algorithms allowed by OpenSSH client and server
+  /// configuration files for compliance.
+  SshConfig {
+    /// Guide to assess the algorithms against.
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
+    json: bool,
+    /// Policy file to assess the algorithms against instead of a guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Do not print output.
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// The minimum security level required.
+    ///
+    /// If a sufficiently low value is used then the application will
+    /// default to the minimum security specified by the standard.
+    #[arg(short, long, default_value_t = 0)]
+    security: Security,
+    /// Verbose output.
+    #[arg(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// The year in which a recommendation is expected to be valid.
+    ///
+    /// Note that this does not necessarily mean that a primitive will
+    /// be deemed insecure beyond this point. Indeed, recommendations
+    /// are usually done with a longer horizon in mind. For example,
+    /// setting this value to 2023, one would expect any passing
+    /// primitive to be secure for the next 5 to 7 years,
+    /// conservatively, subject to cryptanalytic developments.
+    #[arg(short, long, default_value_t = 2023)]
+    year: u16,
+    /// The ssh_config or sshd_config files.
+    ///
+    /// The KexAlgorithms, HostKeyAlgorithms, PubkeyAcceptedAlgorithms,
+    /// Ciphers and MACs settings are checked in every Host and Match
+    /// block as well as in included files.
+    #[clap(value_name = "FILE")]
+    files: Vec<PathBuf>,
+  },
+  /// Connect to TLS servers and check the protocol versions, cipher
+  /// suites, groups and certificates they accept for compliance.
+  Tls {
+    /// Guide to assess the server against.
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
+    json: bool,
+    /// Policy file to assess the server against instead of a guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Do not print output.
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// The minimum security level required.
+    ///
+    /// If a sufficiently low value is used then the application will
+    /// default to the minimum security specified by the standard.
+    #[arg(short, long, default_value_t = 0)]
+    security: Security,
+    /// The number of seconds to wait for the server to respond.
+    #[arg(short, long, default_value_t = 5)]
+    timeout: u64,
+    /// Verbose output.
+    #[arg(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// The year in which a recommendation is expected to be valid.
+    ///
+    /// Note that this does not necessarily mean that a primitive will
+    /// be deemed insecure beyond this point. Indeed, recommendations
+    /// are usually done with a longer horizon in mind. For example,
+    /// setting this value to 2023, one would expect any passing
+    /// primitive to be secure for the next 5 to 7 years,
+    /// conservatively, subject to cryptanalytic developments.
+    ///
+    /// Certificates are assessed against the later of this year and
+    /// the year they expire.
+    #[arg(short, long, default_value_t = 2023)]
+    year: u16,
+    /// The servers to connect to.
+    ///
+    /// The port defaults to 443 and IPv6 addresses are given in
+    /// brackets such as [::1]:8443. Every parameter is probed with a
+    /// separate handshake and the certificates are not verified.
+    #[clap(value_name = "HOST:PORT")]
+    targets: Vec<String>,
+  },
+  /// Check the cipher suites, groups and signature schemes of a TLS
+  /// configuration for compliance.
+  TlsSuites {
+    /// Guide to assess the cipher suites against.
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// The supported groups separated by commas or colons.
+    ///
+    /// Groups are given by their IANA name, such as x25519 or
+    /// ffdhe2048, or by their code point in hexadecimal.
+    #[arg(long, value_name = "GROUPS")]
+    groups: Option<String>,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
+    json: bool,
+    /// Policy file to assess the cipher suites against instead of a
+    /// guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Do not print output.
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+    /// The minimum security level required.
+    ///
+    /// If a sufficiently low value is used then the application will
+    /// default to the minimum security specified by the standard.
+    #[arg(short, long, default_value_t = 0)]
+    security: Security,
+    /// The signature schemes separated by commas or colons.
+    ///
+    /// Schemes are given by their IANA name, such as
+    /// rsa_pss_rsae_sha256, or by their code point in hexadecimal.
+    #[arg(long, value_name = "SCHEMES")]
+    signature_schemes: Option<String>,
+    /// Verbose output.
+    #[arg(short, long, conflicts_with = "quiet")]
+    verbose: bool,
+    /// The year in which a recommendation is expected to be valid.
+    ///
+    /// Note that this does not necessarily mean that a primitive will
+    /// be deemed insecure beyond this point. Indeed, recommendations
+    /// are usually done with a longer horizon in mind. For example,
+    /// setting this value to 2023, one would expect any passing
+    /// primitive to be secure for the next 5 to 7 years,
+    /// conservatively, subject to cryptanalytic developments.
+    #[arg(short, long, default_value_t = 2023)]
+    year: u16,
+    /// The cipher suites as OpenSSL cipher strings or lists of IANA
+    /// names.
+    ///
+    /// Lists of IANA names, such as TLS_AES_128_GCM_SHA256, may also
+    /// give cipher suites by their code point in hexadecimal. Cipher
+    /// strings, such as HIGH:!aNULL, select TLS 1.2 cipher suites only.
+    #[clap(value_name = "SUITES")]
+    suites: Vec<String>,
+  },
   /// Check X.509 public key certificates for compliance.
   X509 {
     /// Guide to assess the certificate against.
-    #[arg(short, long, value_enum)]
-    guide: Guide,
-    /// JSON formatted output.
-    #[arg(short, long)]
+    #[arg(short, long, value_enum, required_unless_present = "policy")]
+    guide: Option<Guide>,
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// JSON formatted output, equivalent to `--format json`.
+    #[arg(short, long, conflicts_with = "format")]
     json: bool,
+    /// Policy file to assess the certificate against instead of a guide.
+    ///
+    /// The policy is read as JSON if the file has the `.json`
+    /// extension and as TOML otherwise.
+    #[arg(short, long, conflicts_with = "guide", value_name = "FILE")]
+    policy: Option<PathBuf>,
+    /// Assess the certificate for processing already protected data only.
+    ///
+    /// Most standards allow primitives that are no longer fit to apply
+    /// protection to still be used to verify existing signatures.
+    #[arg(long)]
+    process: bool,
     /// Do not print output.
     #[arg(short, long, conflicts_with = "verbose")]
     quiet: bool,
@@ -135,6 +776,11 @@ enum Subcommands {
     /// default to the minimum security specified by the standard.
     #[arg(short, long, default_value_t = 0)]
     security: Security,
+    /// The purpose the certificate is put to.
+    ///
+    /// Defaults to the usage derived from the key usage extension.
+    #[arg(long, value_enum)]
+    usage: Option<KeyUsage>,
     /// Verbose output.
     #[arg(short, long, conflicts_with = "quiet")]
     verbose: bool,
@@ -146,73 +792,734 @@ enum Subcommands {
     /// setting this value to 2023, one would expect any passing
     /// primitive to be secure for the next 5 to 7 years,
     /// conservatively, subject to cryptanalytic developments.
+    ///
+    /// Certificates are assessed against the later of this year and
+    /// the year they expire.
     #[arg(short, long, default_value_t = 2023)]
     year: u16,
     /// The certificates as DER or PEM encoded files.
+    ///
+    /// Every certificate in PEM bundles and PKCS#7 files is checked and
+    /// the chain fails if any one of them fails. The key of the issuer
+    /// is looked up among all the certificates given.
     #[clap(value_name = "FILE")]
     files: Vec<PathBuf>,
   },
 }
 
 impl Subcommands {
-  fn assess<T: Key>(
-    ctx: Context,
+  fn context(security: Security, year: u16, usage: Option<KeyUsage>, process: bool) -> Context {
+    let ctx = Context::new(security, year);
+    let ctx = match usage {
+      Some(usage) => ctx.with_usage(usage.into()),
+      None => ctx,
+    };
+    if process {
+      ctx.with_operation(Operation::Process)
+    } else {
+      ctx
+    }
+  }
+
+  fn assess(
     paths: &Vec<PathBuf>,
-    guide: Guide,
-    json: bool,
+    audit: impl Fn(&Benchmark, &Path, &mut Report),
+    guide: Option<Guide>,
+    policy: &Option<PathBuf>,
+    format: Format,
     verbosity: Verbosity,
   ) -> Exit {
-    let mut report = Report::new(verbosity, json);
+    let benchmark = match Benchmark::new(guide, policy) {
+      Ok(benchmark) => benchmark,
+      Err(err) => return Exit::Failure(err),
+    };
+    let mut report = Report::new(verbosity, format);
     for path in paths {
-      let key = match T::from_file(path) {
-        Ok(got) => got,
-        Err(err) => return Exit::Failure(err),
-      };
-      let hash_function = key.hash_function();
-      let signature_algorithm = key.signature_algorithm();
-      let mut audit = Audit::new(path, hash_function, signature_algorithm);
-      if let Some(got) = hash_function {
-        match guide.validate_hash_function(ctx, got) {
-          Ok(want) => audit.compliant_hash_function(want),
-          Err(want) => audit.noncompliant_hash_function(want),
+      audit(&benchmark, path, &mut report);
+    }
+    Exit::Success(report)
+  }
+
+  fn scan(
+    ctx: Context,
+    roots: &Vec<PathBuf>,
+    filter: &Filter,
+    guide: Option<Guide>,
+    policy: &Option<PathBuf>,
+    format: Format,
+    verbosity: Verbosity,
+  ) -> Exit {
+    let benchmark = match Benchmark::new(guide, policy) {
+      Ok(benchmark) => benchmark,
+      Err(err) => return Exit::Failure(err),
+    };
+    let mut report = Report::new(verbosity, format);
+    for root in roots {
+      for path in filter.walk(root) {
+        let path = match path {
+          Ok(path) => path,
+          Err((path, err)) => {
+            report.push_error(&path, &err);
+            continue;
+          },
+        };
+        let kinds = match Kind::sniff(&path) {
+          Ok(kinds) => kinds,
+          Err(err) => {
+            report.push_error(&path, &err);
+            continue;
+          },
+        };
+        for kind in kinds {
+          match kind {
+            Kind::Certificate => {
+              let chain = Certificate::chain_from_file(&path);
+              Self::audit_chain(ctx, &benchmark, &Location::from(&path), chain, &mut report)
+            },
+            Kind::PrivateKey => {
+              let key = PrivateKey::from_file(&path);
+              Self::audit(ctx, &benchmark, &Location::from(&path), key, &mut report)
+            },
+            Kind::Ssh => {
+              let entries = Ssh::entries_from_file(&path);
+              Self::audit_entries(
+                ctx,
+                &benchmark,
+                &Location::from(&path),
+                entries,
+                &mut report,
+              )
+            },
+          }
         }
       }
-      match guide.validate_signature_algorithm(ctx, signature_algorithm) {
-        Ok(want) => audit.compliant_signature(want),
-        Err(want) => audit.noncompliant_signature(want),
-      }
-      report.push(audit);
     }
     Exit::Success(report)
   }
 
+  fn audit<T: Key>(
+    ctx: Context,
+    benchmark: &Benchmark,
+    location: &Location,
+    key: Result<T, Error>,
+    report: &mut Report,
+  ) {
+    match key {
+      Ok(key) => report.push(Self::audit_key(ctx, benchmark, location, &key)),
+      Err(err) => report.push_error(location.clone(), &err),
+    }
+  }
+
+  /// Audits every certificate in a chain and reports the chain as a
+  /// whole unless it consists of a single certificate.
+  fn audit_chain(
+    ctx: Context,
+    benchmark: &Benchmark,
+    location: &Location,
+    chain: Result<Vec<Certificate>, Error>,
+    report: &mut Report,
+  ) {
+    let chain = match chain {
+      Ok(chain) => chain,
+      Err(err) => {
+        report.push_error(location.clone(), &err);
+        return;
+      },
+    };
+    if let [certificate] = chain.as_slice() {
+      report.push(Self::audit_key(ctx, benchmark, location, certificate));
+      return;
+    }
+    let links = chain
+      .iter()
+      .enumerate()
+      .map(|(i, certificate)| {
+        Self::audit_key(ctx, benchmark, location, certificate).in_chain(i, certificate.subject())
+      })
+      .collect();
+    report.push(Audit::from_chain(location.clone(), links));
+  }
+
+  /// Audits every key in a file listing many of them and reports each
+  /// one separately unless there is a single key.
+  fn audit_entries(
+    ctx: Context,
+    benchmark: &Benchmark,
+    location: &Location,
+    entries: Result<Vec<Entry>, Error>,
+    report: &mut Report,
+  ) {
+    let mut entries = match entries {
+      Ok(entries) => entries,
+      Err(err) => {
+        report.push_error(location.clone(), &err);
+        return;
+      },
+    };
+    if entries.len() == 1 {
+      let entry = entries.remove(0);
+      Self::audit(ctx, benchmark, location, entry.key, report);
+      return;
+    }
+    for entry in entries {
+      match entry.key {
+        Ok(key) => {
+          let audit = Self::audit_key(ctx, benchmark, location, &key);
+          report.push(audit.at_line(entry.line, &entry.label));
+        },
+        Err(err) => report.push_error_at(location.clone(), entry.line, &err),
+      }
+    }
+  }
+
+  fn audit_key<T: Key>(ctx: Context, benchmark: &Benchmark, location: &Location, key: &T) -> Audit {
+    let hash_function = key.hash_function();
+    let signature_algorithm = key.signature_algorithm();
+    let mut audit = Audit::new(location.clone(), hash_function, signature_algorithm);
+    // The primitives of a certificate have to remain secure for as long
+    // as the certificate is valid.
+    let validity = key.validity();
+    let horizon = match validity {
+      Some(validity) => ctx.with_year(ctx.year().max(validity.expiry_year())),
+      None => ctx,
+    };
+    // A usage given by the user takes precedence over the one derived
+    // from the key.
+    let key_ctx = match ctx.usage() {
+      Usage::Unspecified => horizon.with_usage(key.usage()),
+      _ => horizon,
+    };
+    let signature_ctx = horizon.with_usage(Usage::DigitalSignature);
+    audit = audit.with_usage(key_ctx.usage());
+    if let Some(got) = hash_function {
+      let verdict = benchmark.validate_hash_function(signature_ctx, got);
+      audit.assess_hash_function(verdict);
+    }
+    if let Some(parameters) = key.pss_parameters() {
+      audit = audit.with_pss_parameters(parameters);
+      // The mask generation function is used to derive a mask from the
+      // salted hash rather than to compress the message.
+      let mask_ctx = horizon.with_usage(Usage::KeyDerivation);
+      let verdict = benchmark.validate_hash_function(mask_ctx, parameters.mask_hash);
+      audit.assess_mask_hash_function(verdict);
+      let verdict =
+        benchmark.validate_salt_length(horizon, parameters.hash, parameters.salt_length);
+      audit.assess_salt_length(verdict);
+    }
+    if let Some(got) = key.issuer_signature() {
+      audit = audit.with_issuer_signature(got);
+      let verdict = benchmark.validate_signature_algorithm(signature_ctx, got);
+      audit.assess_issuer_signature(verdict);
+    }
+    if let Some(got) = signature_algorithm {
+      let verdict = benchmark.validate_signature_algorithm(key_ctx, got);
+      audit.assess_signature(verdict);
+    }
+    if let Some(protection) = key.protection() {
+      audit = audit.with_protection(protection);
+      if let Some(got) = protection.kdf {
+        let ctx = key_ctx.with_usage(Usage::KeyDerivation);
+        let verdict = match got {
+          KeyDerivation::Hash(hash) => benchmark.validate_hash_function(ctx, hash).map(Into::into),
+          KeyDerivation::Kdf(kdf) => benchmark.validate_kdf(ctx, kdf).map(Into::into),
+          KeyDerivation::Phf(phf) => benchmark.validate_phf(ctx, phf).map(Into::into),
+        };
+        audit.assess_key_derivation(verdict);
+      }
+      if let Some(got) = protection.cipher {
+        let ctx = key_ctx.with_usage(Usage::Encryption);
+        audit.assess_key_encryption(benchmark.validate_symmetric(ctx, got));
+      }
+    }
+    if let Some(certificate) = key.ssh_certificate() {
+      audit = audit.with_ssh_certificate(certificate);
+    }
+    if let Some(validity) = validity {
+      audit.assess_validity(validity, ASN1Time::now());
+      // The primitives that comply in the year given by the user but
+      // are deprecated before the certificate expires are what makes
+      // its validity too long.
+      let signature_ctx = signature_ctx.with_year(ctx.year());
+      let key_ctx = key_ctx.with_year(ctx.year());
+      let statuses = [
+        hash_function.map(|got| {
+          benchmark
+            .validate_hash_function(signature_ctx, got)
+            .status()
+        }),
+        key.issuer_signature().map(|got| {
+          benchmark
+            .validate_signature_algorithm(signature_ctx, got)
+            .status()
+        }),
+        signature_algorithm.map(|got| {
+          benchmark
+            .validate_signature_algorithm(key_ctx, got)
+            .status()
+        }),
+      ];
+      let cutoff = statuses
+        .into_iter()
+        .flatten()
+        .filter_map(|status| match status {
+          Status::Deprecated { until } if until < validity.expiry_year() => Some(until),
+          _ => None,
+        })
+        .min();
+      if let Some(cutoff) = cutoff {
+        audit.exceeds_cutoff(cutoff);
+      }
+    }
+    audit
+  }
+
+  /// Audits the algorithms of every setting in an OpenSSH configuration
+  /// file.
+  fn audit_ssh_config(ctx: Context, benchmark: &Benchmark, path: &Path, report: &mut Report) {
+    let settings = match ssh::settings_from_file(path) {
+      Ok(settings) => settings,
+      Err(err) => {
+        report.push_error(path, &err);
+        return;
+      },
+    };
+    for setting in settings {
+      let keyword = setting.keyword.to_string();
+      let mut audit = SettingAudit::new(&setting.path, Some(setting.line), &keyword);
+      for algorithm in setting.algorithms.iter() {
+        match setting.keyword.components(algorithm) {
+          Some(components) => {
+            Self::audit_components(ctx, benchmark, algorithm, &components, &mut audit)
+          },
+          None => audit.unrecognised(algorithm),
+        }
+      }
+      report.push_setting(audit);
+    }
+  }
+
+  /// Audits the group described by a Diffie-Hellman parameter file along
+  /// with the soundness of the parameters themselves.
+  fn audit_dhparam(ctx: Context, benchmark: &Benchmark, path: &Path, report: &mut Report) {
+    let parameters = match dhparam::from_file(path) {
+      Ok(parameters) => parameters,
+      Err(err) => {
+        report.push_error(path, &err);
+        return;
+      },
+    };
+    let name = parameters.group.to_string();
+    let group = Asymmetric::from(parameters.group);
+    let mut audit = SettingAudit::new(path, None, "dhparam");
+    audit.assess(
+      &name,
+      group,
+      benchmark.validate_signature_algorithm(ctx, group),
+    );
+    for flaw in parameters.flaws {
+      audit.invalid(&name, &flaw.to_string(), flaw.requirement());
+    }
+    report.push_setting(audit);
+  }
+
+  /// Audits the function and cost parameters of every password hash in
+  /// a file.
+  fn audit_passwd(ctx: Context, benchmark: &Benchmark, path: &Path, report: &mut Report) {
+    let entries = match passwd::entries_from_file(path) {
+      Ok(entries) => entries,
+      Err(err) => {
+        report.push_error(path, &err);
+        return;
+      },
+    };
+    for entry in entries {
+      let user = entry.user.as_deref().unwrap_or("hash");
+      let mut audit = SettingAudit::new(path, Some(entry.line), user);
+      match entry.phf {
+        Some(phf) => audit.assess(&entry.scheme, phf, benchmark.validate_phf(ctx, phf)),
+        None => audit.unrecognised(&entry.scheme),
+      }
+      report.push_setting(audit);
+    }
+  }
+
+  /// Audits a list of TLS parameters as a whole.
+  fn audit_tls(
+    ctx: Context,
+    benchmark: &Benchmark,
+    parameter: Parameter,
+    list: &str,
+    report: &mut Report,
+  ) {
+    let location = Location::Target(list.to_string());
+    let names = match parameter.resolve(list) {
+      Ok(names) => names,
+      Err(err) => {
+        report.push_error(location, &err);
+        return;
+      },
+    };
+    Self::audit_parameters(ctx, benchmark, &location, parameter, &names, report);
+  }
+
+  /// Audits the TLS parameters of a kind as a whole.
+  fn audit_parameters(
+    ctx: Context,
+    benchmark: &Benchmark,
+    location: &Location,
+    parameter: Parameter,
+    names: &[String],
+    report: &mut Report,
+  ) {
+    let mut audit = SettingAudit::new(location.clone(), None, &parameter.to_string());
+    for name in names.iter() {
+      match parameter.components(name) {
+        Some(components) => Self::audit_components(ctx, benchmark, name, &components, &mut audit),
+        None => audit.unrecognised(name),
+      }
+    }
+    report.push_setting(audit);
+  }
+
+  /// Audits what a TLS server accepts along with the certificates it
+  /// presents.
+  fn audit_endpoint(
+    ctx: Context,
+    benchmark: &Benchmark,
+    address: &str,
+    timeout: Duration,
+    report: &mut Report,
+  ) {
+    let location = Location::Target(address.to_string());
+    let endpoint = match tls::probe(address, timeout) {
+      Ok(endpoint) => endpoint,
+      Err(err) => {
+        report.push_error(location, &err);
+        return;
+      },
+    };
+    let parameters = [
+      (Parameter::Versions, &endpoint.versions),
+      (Parameter::CipherSuites, &endpoint.cipher_suites),
+      (Parameter::Groups, &endpoint.groups),
+    ];
+    for (parameter, names) in parameters {
+      if !names.is_empty() {
+        Self::audit_parameters(ctx, benchmark, &location, parameter, names, report);
+      }
+    }
+    if !endpoint.certificates.is_empty() {
+      let chain = Certificate::chain_from_der(&endpoint.certificates);
+      Self::audit_chain(ctx, benchmark, &location, chain, report);
+    }
+  }
+
+  /// Assesses each of the primitives an algorithm is built on.
+  fn audit_components(
+    ctx: Context,
+    benchmark: &Benchmark,
+    algorithm: &str,
+    components: &[(Usage, Component)],
+    audit: &mut SettingAudit,
+  ) {
+    audit.allow(algorithm);
+    if let Some(establishment) = Self::establishment(components) {
+      audit.establishes_keys(algorithm, establishment);
+    }
+    for (usage, component) in components {
+      let ctx = ctx.with_usage(*usage);
+      match *component {
+        Component::Absent => audit.absent(algorithm, *usage),
+        Component::Asymmetric(got) => audit.assess(
+          algorithm,
+          got,
+          benchmark.validate_signature_algorithm(ctx, got),
+        ),
+        Component::Hash(got) => {
+          audit.assess(algorithm, got, benchmark.validate_hash_function(ctx, got))
+        },
+        Component::Kem(got) => audit.assess(algorithm, got, benchmark.validate_kem(ctx, got)),
+        Component::Mac(got) => audit.assess(algorithm, got, benchmark.validate_mac(ctx, got)),
+        Component::Mode(got) => audit.assess(algorithm, got, benchmark.validate_mode(ctx, got)),
+        Component::Symmetric(got) => {
+          audit.assess(algorithm, got, benchmark.validate_symmetric(ctx, got))
+        },
+      }
+    }
+  }
+
+  /// Returns how an algorithm establishes keys if that is what it is
+  /// for, such as a TLS group or SSH key exchange method, unlike a
+  /// cipher suite which goes on to encrypt with the keys.
+  fn establishment(components: &[(Usage, Component)]) -> Option<Establishment> {
+    let encrypts = components
+      .iter()
+      .any(|(_, component)| matches!(component, Component::Mode(_) | Component::Symmetric(_)));
+    let mut establishing = components
+      .iter()
+      .filter(|(usage, _)| *usage == Usage::KeyEstablishment)
+      .peekable();
+    establishing.peek()?;
+    if encrypts {
+      None
+    } else if establishing.any(|(_, component)| matches!(component, Component::Kem(_))) {
+      Some(Establishment::Encapsulation)
+    } else {
+      Some(Establishment::KeyAgreement)
+    }
+  }
+
+  /// Reads a passphrase from a file ignoring a trailing newline.
+  fn passphrase(path: &Option<PathBuf>) -> Result<Option<Vec<u8>>, Error> {
+    let Some(path) = path else {
+      return Ok(None);
+    };
+    let mut passphrase = std::fs::read(path)?;
+    if passphrase.ends_with(b"\n") {
+      passphrase.pop();
+      if passphrase.ends_with(b"\r") {
+        passphrase.pop();
+      }
+    }
+    Ok(Some(passphrase))
+  }
+
   pub fn run(&self) -> Exit {
     match self {
+      Self::Dhparam {
+        format,
+        guide,
+        json,
+        policy,
+        quiet,
+        verbose,
+        files,
+        security,
+        year,
+      } => {
+        let ctx = Self::context(*security, *year, Some(KeyUsage::KeyEstablishment), false);
+        let verbosity = Verbosity::from_flags(*verbose, *quiet);
+        let format = if *json { Format::Json } else { *format };
+        let audit = |benchmark: &Benchmark, path: &Path, report: &mut Report| {
+          Self::audit_dhparam(ctx, benchmark, path, report)
+        };
+        Self::assess(files, audit, *guide, policy, format, verbosity)
+      },
+      Self::Key {
+        format,
+        guide,
+        json,
+        passphrase_file,
+        policy,
+        process,
+        quiet,
+        verbose,
+        files,
+        security,
+        usage,
+        year,
+      } => {
+        let passphrase = match Self::passphrase(passphrase_file) {
+          Ok(passphrase) => passphrase,
+          Err(err) => return Exit::Failure(err),
+        };
+        let ctx = Self::context(*security, *year, *usage, *process);
+        let verbosity = Verbosity::from_flags(*verbose, *quiet);
+        let format = if *json { Format::Json } else { *format };
+        let audit = |benchmark: &Benchmark, path: &Path, report: &mut Report| {
+          let key = PrivateKey::from_file_with_passphrase(path, passphrase.as_deref());
+          Self::audit(ctx, benchmark, &Location::from(path), key, report)
+        };
+        Self::assess(files, audit, *guide, policy, format, verbosity)
+      },
+      Self::Passwd {
+        format,
+        guide,
+        json,
+        policy,
+        quiet,
+        verbose,
+        files,
+        security,
+        year,
+      } => {
+        let ctx = Self::context(*security, *year, None, false);
+        let verbosity = Verbosity::from_flags(*verbose, *quiet);
+        let format = if *json { Format::Json } else { *format };
+        let audit = |benchmark: &Benchmark, path: &Path, report: &mut Report| {
+          Self::audit_passwd(ctx, benchmark, path, report)
+        };
+        Self::assess(files, audit, *guide, policy, format, verbosity)
+      },
+      Self::Scan {
+        exclude,
+        follow_symlinks,
+        format,
+        guide,
+        include,
+        json,
+        policy,
+        process,
+        quiet,
+        verbose,
+        roots,
+        security,
+        usage,
+        year,
+      } => {
+        let filter = match Filter::new(include, exclude, *follow_symlinks) {
+          Ok(filter) => filter,
+          Err(err) => return Exit::Failure(err),
+        };
+        let ctx = Self::context(*security, *year, *usage, *process);
+        let verbosity = Verbosity::from_flags(*verbose, *quiet);
+        let format = if *json { Format::Json } else { *format };
+        Self::scan(ctx, roots, &filter, *guide, policy, format, verbosity)
+      },
       Self::Ssh {
+        format,
+        guide,
+        json,
+        policy,
+        process,
+        quiet,
+        verbose,
+        files,
+        security,
+        usage,
+        year,
+      } => {
+        let ctx = Self::context(*security, *year, *usage, *process);
+        let verbosity = Verbosity::from_flags(*verbose, *quiet);
+        let format = if *json { Format::Json } else { *format };
+        let audit = |benchmark: &Benchmark, path: &Path, report: &mut Report| {
+          let entries = Ssh::entries_from_file(path);
+          Self::audit_entries(ctx, benchmark, &Location::from(path), entries, report)
+        };
+        Self::assess(files, audit, *guide, policy, format, verbosity)
+      },
+      Self::SshConfig {
+        format,
         guide,
         json,
+        policy,
         quiet,
         verbose,
         files,
         security,
         year,
       } => {
-        let ctx = Context::new(*security, *year);
+        let ctx = Self::context(*security, *year, None, false);
         let verbosity = Verbosity::from_flags(*verbose, *quiet);
-        Self::assess::<Ssh>(ctx, files, *guide, *json, verbosity)
+        let format = if *json { Format::Json } else { *format };
+        let audit = |benchmark: &Benchmark, path: &Path, report: &mut Report| {
+          Self::audit_ssh_config(ctx, benchmark, path, report)
+        };
+        Self::assess(files, audit, *guide, policy, format, verbosity)
+      },
+      Self::Tls {
+        format,
+        guide,
+        json,
+        policy,
+        quiet,
+        verbose,
+        security,
+        targets,
+        timeout,
+        year,
+      } => {
+        let ctx = Self::context(*security, *year, None, false);
+        let verbosity = Verbosity::from_flags(*verbose, *quiet);
+        let format = if *json { Format::Json } else { *format };
+        let timeout = Duration::from_secs(*timeout);
+        let benchmark = match Benchmark::new(*guide, policy) {
+          Ok(benchmark) => benchmark,
+          Err(err) => return Exit::Failure(err),
+        };
+        let mut report = Report::new(verbosity, format);
+        for address in targets {
+          Self::audit_endpoint(ctx, &benchmark, address, timeout, &mut report);
+        }
+        Exit::Success(report)
+      },
+      Self::TlsSuites {
+        format,
+        groups,
+        guide,
+        json,
+        policy,
+        quiet,
+        verbose,
+        security,
+        signature_schemes,
+        suites,
+        year,
+      } => {
+        let ctx = Self::context(*security, *year, None, false);
+        let verbosity = Verbosity::from_flags(*verbose, *quiet);
+        let format = if *json { Format::Json } else { *format };
+        let benchmark = match Benchmark::new(*guide, policy) {
+          Ok(benchmark) => benchmark,
+          Err(err) => return Exit::Failure(err),
+        };
+        let mut report = Report::new(verbosity, format);
+        for list in suites {
+          Self::audit_tls(ctx, &benchmark, Parameter::CipherSuites, list, &mut report);
+        }
+        if let Some(groups) = groups {
+          Self::audit_tls(ctx, &benchmark, Parameter::Groups, groups, &mut report);
+        }
+        if let Some(schemes) = signature_schemes {
+          Self::audit_tls(
+            ctx,
+            &benchmark,
+            Parameter::SignatureSchemes,
+            schemes,
+            &mut report,
+          );
+        }
+        Exit::Success(report)
       },
       Self::X509 {
+        format,
         guide,
         json,
+        policy,
+        process,
         quiet,
         verbose,
         files,
         security,
+        usage,
         year,
       } => {
-        let ctx = Context::new(*security, *year);
+        let ctx = Self::context(*security, *year, *usage, *process);
         let verbosity = Verbosity::from_flags(*verbose, *quiet);
-        Self::assess::<Certificate>(ctx, files, *guide, *json, verbosity)
+        let format = if *json { Format::Json } else { *format };
+        // Issuers may be given in separate files instead of alongside
+        // the certificates they issued.
+        let issuers: Vec<_> = files
+          .iter()
+          .filter_map(|path| Certificate::chain_from_file(path).ok())
+          .flatten()
+          .collect();
+        let audit = |benchmark: &Benchmark, path: &Path, report: &mut Report| {
+          let chain = Certificate::chain_from_file(path).map(|mut chain| {
+            for certificate in chain.iter_mut() {
+              if let Some(key) = issuers
+                .iter()
+                .find(|issuer| certificate.is_issued_by(issuer))
+                .and_then(Key::signature_algorithm)
+              {
+                certificate.resolve_issuer(key);
+              }
+            }
+            chain
+          });
+          Self::audit_chain(ctx, benchmark, &Location::from(path), chain, report)
+        };
+        Self::assess(files, audit, *guide, policy, format, verbosity)
       },
     }
   }
@@ -222,3 +1529,97 @@ fn main() -> Exit {
   let options = Options::parse();
   options.subcommands.run()
 }
+
+#[cfg(test)]
+mod tests {
+  use serde_json::Value;
+
+  use super::*;
+
+  fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+      .join("src/testing/fixtures")
+      .join(name)
+  }
+
+  /// Audits the certificates in a fixture against NIST and returns the
+  /// JSON report.
+  fn audit_chain(name: &str) -> Value {
+    let ctx = Context::new(0, 2023);
+    let path = fixture(name);
+    let mut report = Report::new(Verbosity::Verbose, Format::Json);
+    let chain = Certificate::chain_from_file(&path);
+    Subcommands::audit_chain(
+      ctx,
+      &Benchmark::Guide(Guide::Nist),
+      &Location::from(&path),
+      chain,
+      &mut report,
+    );
+    serde_json::from_str(&report.to_json_string()).unwrap()
+  }
+
+  #[test]
+  fn chain_passes() {
+    let report = audit_chain("chain.pem");
+    let audit = &report["report"][0];
+    assert_eq!(audit["passed"], true);
+    assert_eq!(audit["chain"].as_array().unwrap().len(), 3);
+  }
+
+  #[test]
+  fn chain_fails_on_sha1_intermediate() {
+    let report = audit_chain("chain_sha1_intermediate.pem");
+    let audit = &report["report"][0];
+    assert_eq!(audit["passed"], false);
+    assert_eq!(audit["weakest_link"], 1);
+    let links = audit["chain"].as_array().unwrap();
+    let passed: Vec<_> = links.iter().map(|link| link["passed"].clone()).collect();
+    assert_eq!(passed, [true, false, true]);
+    for (i, link) in links.iter().enumerate() {
+      assert_eq!(link["position"], i);
+    }
+    assert_eq!(links[1]["subject"], "CN=Wardstone Intermediate");
+    assert_eq!(links[1]["got_hash_function"], "sha1");
+    assert_eq!(links[1]["hash_function_verdict"]["status"], "disallowed");
+    assert_eq!(links[1]["issuer_signature_verdict"]["status"], "acceptable");
+  }
+
+  /// Audits an encrypted private key in a fixture against NIST without
+  /// its passphrase and returns the JSON report of its audit.
+  fn audit_encrypted_key(name: &str) -> Value {
+    let ctx = Context::new(0, 2023);
+    let path = fixture(name);
+    let key = PrivateKey::from_file(&path).unwrap();
+    let benchmark = Benchmark::Guide(Guide::Nist);
+    let audit = Subcommands::audit_key(ctx, &benchmark, &Location::from(&path), &key);
+    let mut report = Report::new(Verbosity::Verbose, Format::Json);
+    report.push(audit);
+    let report: Value = serde_json::from_str(&report.to_json_string()).unwrap();
+    report["report"][0].clone()
+  }
+
+  #[test]
+  fn pbkdf2_iterations_are_assessed() {
+    let audit = audit_encrypted_key("encrypted_pkcs8.key");
+    assert_eq!(audit["got_key_derivation"], "pbkdf2_hmac_sha256_256_2048");
+    assert_eq!(audit["key_derivation_verdict"]["status"], "acceptable");
+  }
+
+  #[test]
+  fn encrypted_key_fails_on_one_pbkdf2_iteration() {
+    let audit = audit_encrypted_key("encrypted_pkcs8_one_iteration.key");
+    assert_eq!(audit["passed"], false);
+    assert_eq!(audit["got_key_derivation"], "pbkdf2_hmac_sha256_256_1");
+    assert_eq!(audit["want_key_derivation"], "pbkdf2_hmac_sha256");
+    assert_eq!(audit["key_derivation_verdict"]["status"], "disallowed");
+  }
+
+  #[test]
+  fn scrypt_parameters_are_assessed() {
+    let audit = audit_encrypted_key("encrypted_pkcs8_scrypt.key");
+    assert_eq!(audit["protection"], "pbes2 with scrypt");
+    assert_eq!(audit["got_key_derivation"], "scrypt_n16384_r8_p1");
+    assert_eq!(audit["key_derivation_verdict"]["status"], "unrecognised");
+  }
+}
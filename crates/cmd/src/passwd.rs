@@ -0,0 +1,323 @@
+//! Read password hashes from `/etc/shadow`-style files and identify the
+//! password hashing functions and cost parameters that produced them.
+//!
+//! Hashes are recognised from their encoding alone, either as a PHC
+//! string or in one of the formats of crypt(3), so the passwords are
+//! never needed.
+use std::fs;
+use std::path::Path;
+
+use wardstone_core::primitive::phf::*;
+
+use crate::key::Error;
+
+// The alphabet crypt(3) uses to encode numbers and binary data.
+const ITOA64: &[u8] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+// The SHA-2 based schemes of crypt(3) default to this number of rounds
+// and clamp others to the range below (see
+// https://www.akkadia.org/drepper/SHA-crypt.txt).
+const DEFAULT_SHA_CRYPT_ROUNDS: u32 = 5000;
+const MIN_SHA_CRYPT_ROUNDS: u32 = 1000;
+const MAX_SHA_CRYPT_ROUNDS: u32 = 999_999_999;
+
+/// Represents a password hash on a line of a file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+  /// The line number starting from one.
+  pub line: usize,
+  /// The account the hash belongs to or `None` if the line holds the
+  /// hash on its own.
+  pub user: Option<String>,
+  /// The identifier of the scheme such as `$6$` or `$argon2id$`.
+  pub scheme: String,
+  /// The function and cost parameters that produced the hash or `None`
+  /// if they could not be identified.
+  pub phf: Option<Phf>,
+}
+
+/// Reads every password hash in a file, in the order they appear.
+///
+/// Lines are either in the `user:hash:...` format of `/etc/shadow` and
+/// `/etc/passwd` or hold a hash on its own. Accounts without a usable
+/// password, such as those with `*`, `!` or `x` in place of a hash, are
+/// skipped while locked accounts whose hash is prefixed with `!` are
+/// still read.
+pub fn entries_from_file(path: &Path) -> Result<Vec<Entry>, Error> {
+  let contents = fs::read_to_string(path)?;
+  let mut entries = Vec::new();
+  for (i, line) in contents.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let (user, hash) = match line.split_once(':') {
+      Some((user, rest)) => {
+        let hash = rest.split(':').next().unwrap_or_default();
+        (Some(user.to_string()), hash)
+      },
+      None => (None, line),
+    };
+    let hash = hash.trim_start_matches('!');
+    let Some(scheme) = scheme(hash) else {
+      continue;
+    };
+    entries.push(Entry {
+      line: i + 1,
+      user,
+      scheme,
+      phf: parse(hash),
+    });
+  }
+  Ok(entries)
+}
+
+/// Identifies the scheme of a hash or returns `None` if the field does
+/// not hold a hash at all.
+fn scheme(hash: &str) -> Option<String> {
+  if let Some(rest) = hash.strip_prefix('$') {
+    let id = rest.split('$').next().unwrap_or_default();
+    return Some(format!("${id}$"));
+  }
+  if is_des_crypt(hash) {
+    return Some("DES".to_string());
+  }
+  None
+}
+
+/// Whether a hash is of the traditional DES-based scheme which has no
+/// identifier and encodes a two character salt followed by the hash in
+/// 13 characters.
+fn is_des_crypt(hash: &str) -> bool {
+  hash.len() == 13 && hash.bytes().all(|c| ITOA64.contains(&c))
+}
+
+/// Identifies the function and cost parameters that produced a hash in
+/// the PHC string format or one of the formats of crypt(3).
+///
+/// Returns `None` if the scheme is not recognised or the hash is
+/// malformed.
+pub fn parse(hash: &str) -> Option<Phf> {
+  let Some(hash) = hash.strip_prefix('$') else {
+    return is_des_crypt(hash).then_some(DES_CRYPT);
+  };
+  let mut fields = hash.split('$');
+  let id = fields.next()?;
+  let fields: Vec<&str> = fields.collect();
+  match id {
+    "1" => Some(MD5_CRYPT),
+    "5" => Some(Phf::iterated(SHA256_CRYPT.id, sha_crypt_rounds(&fields))),
+    "6" => Some(Phf::iterated(SHA512_CRYPT.id, sha_crypt_rounds(&fields))),
+    "2" | "2a" | "2b" | "2x" | "2y" => {
+      let cost: u32 = fields.first()?.parse().ok()?;
+      (4..=31).contains(&cost).then(|| Phf::bcrypt(cost))
+    },
+    "7" => scrypt_crypt(fields.first()?),
+    "y" => yescrypt(fields.first()?),
+    "argon2d" | "argon2i" | "argon2id" => {
+      let params = phc_params(&fields)?;
+      let m = phc_param(&params, "m")?;
+      let t = phc_param(&params, "t")?;
+      let p = phc_param(&params, "p")?;
+      let id = match id {
+        "argon2d" => ARGON2D.id,
+        "argon2i" => ARGON2I.id,
+        _ => ARGON2ID.id,
+      };
+      Some(Phf::new(id, m, t, p))
+    },
+    "scrypt" => {
+      let params = phc_params(&fields)?;
+      let ln = phc_param(&params, "ln").filter(|ln| *ln < 32)?;
+      let r = phc_param(&params, "r")?;
+      let p = phc_param(&params, "p")?;
+      Some(Phf::scrypt(SCRYPT.id, 1 << ln, r, p))
+    },
+    "pbkdf2" | "pbkdf2-sha1" | "pbkdf2-sha256" | "pbkdf2-sha512" => {
+      // Passlib writes the iteration count on its own whereas the PHC
+      // string format uses the `i` parameter.
+      let first = fields.first()?;
+      let iterations = match first.parse() {
+        Ok(iterations) => iterations,
+        Err(_) => phc_param(&phc_params(&fields)?, "i")?,
+      };
+      let id = match id {
+        "pbkdf2-sha256" => PBKDF2_SHA256.id,
+        "pbkdf2-sha512" => PBKDF2_SHA512.id,
+        _ => PBKDF2_SHA1.id,
+      };
+      Some(Phf::iterated(id, iterations))
+    },
+    _ => None,
+  }
+}
+
+/// The number of rounds of the SHA-2 based schemes of crypt(3) which
+/// is given by `rounds=<N>` in front of the salt, if at all.
+fn sha_crypt_rounds(fields: &[&str]) -> u32 {
+  fields
+    .first()
+    .and_then(|field| field.strip_prefix("rounds="))
+    .and_then(|rounds| rounds.parse().ok())
+    .map_or(DEFAULT_SHA_CRYPT_ROUNDS, |rounds: u32| {
+      rounds.clamp(MIN_SHA_CRYPT_ROUNDS, MAX_SHA_CRYPT_ROUNDS)
+    })
+}
+
+/// The parameters of a PHC string which are the comma separated
+/// `<name>=<value>` pairs in the field that follows the optional
+/// version.
+fn phc_params<'a>(fields: &[&'a str]) -> Option<Vec<(&'a str, &'a str)>> {
+  let field = fields
+    .iter()
+    .find(|field| !field.starts_with("v=") && field.contains('='))?;
+  field.split(',').map(|pair| pair.split_once('=')).collect()
+}
+
+fn phc_param(params: &[(&str, &str)], name: &str) -> Option<u32> {
+  let (_, value) = params.iter().find(|(key, _)| *key == name)?;
+  value.parse().ok()
+}
+
+/// Decodes a character of the crypt(3) alphabet.
+fn decode64(c: u8) -> Option<u32> {
+  ITOA64.iter().position(|&x| x == c).map(|i| i as u32)
+}
+
+/// Decodes a little-endian number of 30 bits from five characters.
+fn decode64_u30(s: &[u8]) -> Option<u32> {
+  s.iter()
+    .enumerate()
+    .try_fold(0, |value, (i, &c)| Some(value | decode64(c)? << (6 * i)))
+}
+
+/// The parameters of the scrypt-based scheme of crypt(3), identified by
+/// `$7$`, which encodes the base 2 logarithm of `N` in one character
+/// followed by `r` and `p` in five characters each.
+fn scrypt_crypt(field: &str) -> Option<Phf> {
+  let field = field.as_bytes();
+  if field.len() < 11 {
+    return None;
+  }
+  let ln = decode64(field[0]).filter(|ln| *ln < 32)?;
+  let r = decode64_u30(&field[1..6])?;
+  let p = decode64_u30(&field[6..11])?;
+  Some(Phf::scrypt(SCRYPT.id, 1 << ln, r, p))
+}
+
+/// The parameters of the yescrypt scheme of crypt(3), identified by
+/// `$y$`, which encodes the flavour, the base 2 logarithm of `N` minus
+/// one and `r` minus one in the first three characters. The optional
+/// parameters that may follow are left out as libxcrypt does not set
+/// them.
+fn yescrypt(field: &str) -> Option<Phf> {
+  let field = field.as_bytes();
+  if field.len() < 3 {
+    return None;
+  }
+  let ln = decode64(field[1]).map(|ln| ln + 1).filter(|ln| *ln < 32)?;
+  // Values from 48 onwards take more than one character which the
+  // block sizes in use never reach.
+  let r = decode64(field[2]).filter(|r| *r < 48)? + 1;
+  Some(Phf::scrypt(YESCRYPT.id, 1 << ln, r, 1))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::testing::TempDir;
+
+  #[test]
+  fn parse_des_crypt() {
+    assert_eq!(parse("abJnggxhB/yWI"), Some(DES_CRYPT));
+    assert_eq!(parse("abJnggxhB/yW"), None);
+  }
+
+  #[test]
+  fn parse_md5_crypt() {
+    assert_eq!(parse("$1$saltsalt$qjXMvbEw8oaL.CzflDugX/"), Some(MD5_CRYPT));
+  }
+
+  #[test]
+  fn parse_sha_crypt() {
+    let hash = "$5$saltstring$5B8vYYiY.CVt1RlTTf8KbXBH3hsxY/GNooZF5FHEcO5";
+    assert_eq!(parse(hash), Some(SHA256_CRYPT));
+    let hash = "$6$rounds=10000$saltstringsaltst$OW1/O6BYHV6BcXZu8QVeXbDWra3Oeqh0sbHbbMCVNSnCM/UrjmM0Dp8vOuZeHBy/YTBmSK6H9qs/y3RnOaw5v.";
+    assert_eq!(parse(hash), Some(Phf::iterated(SHA512_CRYPT.id, 10000)));
+    // Rounds outside the range are clamped.
+    let hash = "$6$rounds=10$roundstoolow$kUMsbe306n21p9R.FRkW3IGn.S9NPN0x50YhH1xhLsPuWGsUSklZt58jaTfF4ZEQpyUNGc0dqbpBYYBaHHrsX.";
+    assert_eq!(parse(hash), Some(Phf::iterated(SHA512_CRYPT.id, 1000)));
+  }
+
+  #[test]
+  fn parse_bcrypt() {
+    let hash = "$2b$12$GhvMmNVjRW29ulnudl.LbuAnUtN/LRfe1JsBm1Xu6LE3059z5Tr8m";
+    assert_eq!(parse(hash), Some(Phf::bcrypt(12)));
+    assert_eq!(parse("$2b$32$GhvMmNVjRW29ulnudl.Lbu"), None);
+  }
+
+  #[test]
+  fn parse_scrypt_crypt() {
+    let hash = "$7$C6..../....SodiumChloride$kBGj9fHznVYFQMEn/qDCfrDevf9YDtcDdKvEqHJLV8D";
+    assert_eq!(parse(hash), Some(Phf::scrypt(SCRYPT.id, 1 << 14, 8, 1)));
+  }
+
+  #[test]
+  fn parse_yescrypt() {
+    let hash = "$y$j9T$F5Jx5fExrKuPp53xLKQ..1$X3DX6M94c7o.9agCG9G317fhZg9SqC.5i5rd.RhAtQ7";
+    assert_eq!(parse(hash), Some(YESCRYPT));
+  }
+
+  #[test]
+  fn parse_argon2() {
+    let hash = "$argon2id$v=19$m=65536,t=3,p=4$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG";
+    assert_eq!(parse(hash), Some(ARGON2ID));
+    let hash = "$argon2i$v=19$m=4096,t=2,p=1$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG";
+    assert_eq!(parse(hash), Some(Phf::new(ARGON2I.id, 4096, 2, 1)));
+    assert_eq!(parse("$argon2id$v=19$m=65536,p=4$c29tZXNhbHQ$"), None);
+  }
+
+  #[test]
+  fn parse_scrypt_phc() {
+    let hash = "$scrypt$ln=17,r=8,p=1$c29tZXNhbHQ$RdescudvJCsgt3ub+b+dWRWJTmaaJObG";
+    assert_eq!(parse(hash), Some(SCRYPT));
+  }
+
+  #[test]
+  fn parse_pbkdf2() {
+    let hash = "$pbkdf2-sha256$29000$N2bMWSsFgPA.$ODmgrx4Hm0gJCpyBdpvUGMjxfmUjbJAjtvzM3pfY2zA";
+    assert_eq!(parse(hash), Some(Phf::iterated(PBKDF2_SHA256.id, 29000)));
+    let hash = "$pbkdf2-sha512$i=210000,l=64$c29tZXNhbHQ$RdescudvJCsgt3ub";
+    assert_eq!(parse(hash), Some(PBKDF2_SHA512));
+    let hash = "$pbkdf2$1300000$c29tZXNhbHQ$RdescudvJCsgt3ub";
+    assert_eq!(parse(hash), Some(PBKDF2_SHA1));
+  }
+
+  #[test]
+  fn parse_unrecognised() {
+    assert_eq!(parse("$unknown$c29tZXNhbHQ"), None);
+    assert_eq!(parse("x"), None);
+  }
+
+  #[test]
+  fn entries_skip_accounts_without_password() {
+    let dir = TempDir::new("passwd-entries");
+    let path = dir.write(
+      "shadow",
+      b"root:*:19000:0:99999:7:::\n\
+        daemon:x:19000::::::\n\
+        alice:!abJnggxhB/yWI:19000::::::\n\
+        # A comment.\n\
+        $1$saltsalt$qjXMvbEw8oaL.CzflDugX/\n",
+    );
+    let entries = entries_from_file(&path).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].line, 3);
+    assert_eq!(entries[0].user.as_deref(), Some("alice"));
+    assert_eq!(entries[0].scheme, "DES");
+    assert_eq!(entries[0].phf, Some(DES_CRYPT));
+    assert_eq!(entries[1].line, 5);
+    assert_eq!(entries[1].user, None);
+    assert_eq!(entries[1].scheme, "$1$");
+  }
+}
@@ -0,0 +1,19 @@
+//! Load user defined policies to assess keys against.
+use std::ffi::OsStr;
+use std::fs;
+use std::path::Path;
+
+use wardstone_core::standard::policy::PolicyStandard;
+
+use crate::key::Error;
+
+/// Reads a policy from a JSON file if the file has the `.json`
+/// extension and a TOML file otherwise.
+pub fn from_file(path: &Path) -> Result<PolicyStandard, Error> {
+  let contents = fs::read_to_string(path).map_err(|err| Error::Policy(err.to_string()))?;
+  let policy = match path.extension().and_then(OsStr::to_str) {
+    Some("json") => serde_json::from_str(&contents).map_err(|err| err.to_string()),
+    _ => toml::from_str(&contents).map_err(|err| err.to_string()),
+  };
+  policy.map_err(Error::Policy)
+}
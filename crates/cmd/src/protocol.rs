@@ -0,0 +1,26 @@
+//! Protocols whose negotiable algorithms are assessed by breaking them
+//! down into the primitives they are built on.
+use wardstone_core::primitive::asymmetric::Asymmetric;
+use wardstone_core::primitive::hash::Hash;
+use wardstone_core::primitive::kem::Kem;
+use wardstone_core::primitive::mac::Mac;
+use wardstone_core::primitive::mode::Mode;
+use wardstone_core::primitive::symmetric::Symmetric;
+
+pub mod ssh;
+pub mod tls;
+
+/// Represents a primitive an algorithm is built on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Component {
+  /// The algorithm goes without a primitive it should have such as a
+  /// cipher suite that does not encrypt.
+  Absent,
+  Asymmetric(Asymmetric),
+  Hash(Hash),
+  Kem(Kem),
+  Mac(Mac),
+  /// The mode of operation of the cipher that accompanies it.
+  Mode(Mode),
+  Symmetric(Symmetric),
+}
@@ -0,0 +1,554 @@
+//! Read the algorithms allowed by OpenSSH client and server
+//! configuration files and identify the primitives they are built on.
+use std::path::{Path, PathBuf};
+use std::{fmt, fs};
+
+use globset::Glob;
+use wardstone_core::context::Usage;
+use wardstone_core::primitive::ecc::*;
+use wardstone_core::primitive::ffc::*;
+use wardstone_core::primitive::hash::*;
+use wardstone_core::primitive::kem::*;
+use wardstone_core::primitive::mac::*;
+use wardstone_core::primitive::mode::*;
+use wardstone_core::primitive::symmetric::*;
+
+use crate::key::Error;
+use crate::protocol::Component;
+
+// OpenSSH gives up on configuration files nested deeper than this which
+// also guards against files that include each other.
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+// The defaults of OpenSSH 9.9 which modified lists are based on (see
+// myproposal.h in the OpenSSH source).
+const DEFAULT_KEX_ALGORITHMS: &[&str] = &[
+  "mlkem768x25519-sha256",
+  "sntrup761x25519-sha512",
+  "sntrup761x25519-sha512@openssh.com",
+  "curve25519-sha256",
+  "curve25519-sha256@libssh.org",
+  "ecdh-sha2-nistp256",
+  "ecdh-sha2-nistp384",
+  "ecdh-sha2-nistp521",
+  "diffie-hellman-group-exchange-sha256",
+  "diffie-hellman-group16-sha512",
+  "diffie-hellman-group18-sha512",
+  "diffie-hellman-group14-sha256",
+];
+
+const DEFAULT_PUBLIC_KEY_ALGORITHMS: &[&str] = &[
+  "ssh-ed25519-cert-v01@openssh.com",
+  "ecdsa-sha2-nistp256-cert-v01@openssh.com",
+  "ecdsa-sha2-nistp384-cert-v01@openssh.com",
+  "ecdsa-sha2-nistp521-cert-v01@openssh.com",
+  "sk-ssh-ed25519-cert-v01@openssh.com",
+  "sk-ecdsa-sha2-nistp256-cert-v01@openssh.com",
+  "rsa-sha2-512-cert-v01@openssh.com",
+  "rsa-sha2-256-cert-v01@openssh.com",
+  "ssh-ed25519",
+  "ecdsa-sha2-nistp256",
+  "ecdsa-sha2-nistp384",
+  "ecdsa-sha2-nistp521",
+  "sk-ssh-ed25519@openssh.com",
+  "sk-ecdsa-sha2-nistp256@openssh.com",
+  "rsa-sha2-512",
+  "rsa-sha2-256",
+];
+
+const DEFAULT_CIPHERS: &[&str] = &[
+  "chacha20-poly1305@openssh.com",
+  "aes128-ctr",
+  "aes192-ctr",
+  "aes256-ctr",
+  "aes128-gcm@openssh.com",
+  "aes256-gcm@openssh.com",
+];
+
+const DEFAULT_MACS: &[&str] = &[
+  "umac-64-etm@openssh.com",
+  "umac-128-etm@openssh.com",
+  "hmac-sha2-256-etm@openssh.com",
+  "hmac-sha2-512-etm@openssh.com",
+  "hmac-sha1-etm@openssh.com",
+  "umac-64@openssh.com",
+  "umac-128@openssh.com",
+  "hmac-sha2-256",
+  "hmac-sha2-512",
+  "hmac-sha1",
+];
+
+/// The kind of algorithms a setting allows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Keyword {
+  KexAlgorithms,
+  HostKeyAlgorithms,
+  PubkeyAcceptedAlgorithms,
+  Ciphers,
+  Macs,
+}
+
+impl Keyword {
+  /// Keywords are case insensitive and some have older aliases.
+  fn parse(s: &str) -> Option<Self> {
+    let keyword = match s.to_ascii_lowercase().as_str() {
+      "kexalgorithms" => Self::KexAlgorithms,
+      "hostkeyalgorithms" => Self::HostKeyAlgorithms,
+      "pubkeyacceptedalgorithms" | "pubkeyacceptedkeytypes" => Self::PubkeyAcceptedAlgorithms,
+      "ciphers" => Self::Ciphers,
+      "macs" => Self::Macs,
+      _ => return None,
+    };
+    Some(keyword)
+  }
+
+  fn defaults(&self) -> &'static [&'static str] {
+    match self {
+      Self::KexAlgorithms => DEFAULT_KEX_ALGORITHMS,
+      Self::HostKeyAlgorithms | Self::PubkeyAcceptedAlgorithms => DEFAULT_PUBLIC_KEY_ALGORITHMS,
+      Self::Ciphers => DEFAULT_CIPHERS,
+      Self::Macs => DEFAULT_MACS,
+    }
+  }
+
+  /// Identifies the primitives an algorithm is built on along with the
+  /// purpose each one is put to.
+  ///
+  /// Returns `None` if the algorithm is not recognised.
+  pub fn components(&self, algorithm: &str) -> Option<Vec<(Usage, Component)>> {
+    match self {
+      Self::KexAlgorithms => kex(algorithm),
+      Self::HostKeyAlgorithms | Self::PubkeyAcceptedAlgorithms => public_key(algorithm),
+      Self::Ciphers => cipher(algorithm),
+      Self::Macs => mac(algorithm),
+    }
+  }
+}
+
+impl fmt::Display for Keyword {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::KexAlgorithms => write!(f, "KexAlgorithms"),
+      Self::HostKeyAlgorithms => write!(f, "HostKeyAlgorithms"),
+      Self::PubkeyAcceptedAlgorithms => write!(f, "PubkeyAcceptedAlgorithms"),
+      Self::Ciphers => write!(f, "Ciphers"),
+      Self::Macs => write!(f, "MACs"),
+    }
+  }
+}
+
+/// Represents a setting of an `ssh_config` or `sshd_config` file that
+/// lists algorithms.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Setting {
+  /// The file the setting is in which may have been included by the
+  /// file that was read.
+  pub path: PathBuf,
+  /// The line number starting from one.
+  pub line: usize,
+  pub keyword: Keyword,
+  /// The algorithms allowed once modifiers have been applied to the
+  /// defaults.
+  pub algorithms: Vec<String>,
+}
+
+/// Reads every setting that lists algorithms in a configuration file
+/// and the files it includes, in the order they appear.
+///
+/// Settings are read regardless of the `Host` or `Match` block they are
+/// in since each of them may apply to some connection.
+pub fn settings_from_file(path: &Path) -> Result<Vec<Setting>, Error> {
+  let mut settings = Vec::new();
+  read(path, 0, &mut settings)?;
+  Ok(settings)
+}
+
+fn read(path: &Path, depth: usize, settings: &mut Vec<Setting>) -> Result<(), Error> {
+  if depth > MAX_INCLUDE_DEPTH {
+    let reason = format!("{} is included too deeply", path.display());
+    return Err(Error::Config(reason));
+  }
+  let contents = fs::read_to_string(path)?;
+  for (i, line) in contents.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    // The keyword is separated from its arguments by whitespace or an
+    // optional equals sign.
+    let split = line
+      .find(|c: char| c.is_whitespace() || c == '=')
+      .unwrap_or(line.len());
+    let (keyword, arguments) = line.split_at(split);
+    let arguments = arguments.trim_start();
+    let arguments = arguments.strip_prefix('=').unwrap_or(arguments).trim();
+    if keyword.eq_ignore_ascii_case("include") {
+      for pattern in arguments.split_whitespace() {
+        for included in include(path, pattern)? {
+          read(&included, depth + 1, settings)?;
+        }
+      }
+      continue;
+    }
+    let Some(keyword) = Keyword::parse(keyword) else {
+      continue;
+    };
+    let arguments = arguments.trim_matches('"');
+    if arguments.is_empty() {
+      let reason = format!("{}:{}: missing {} argument", path.display(), i + 1, keyword);
+      return Err(Error::Config(reason));
+    }
+    settings.push(Setting {
+      path: path.to_path_buf(),
+      line: i + 1,
+      keyword,
+      algorithms: modify(keyword.defaults(), arguments)?,
+    });
+  }
+  Ok(())
+}
+
+/// Resolves the files an `Include` pattern refers to in lexical order.
+///
+/// Relative paths are taken to be relative to the directory of the
+/// including file which, for the system and user configuration, is
+/// where OpenSSH would look for them.
+fn include(path: &Path, pattern: &str) -> Result<Vec<PathBuf>, Error> {
+  let pattern = match (pattern.strip_prefix("~/"), std::env::var_os("HOME")) {
+    (Some(rest), Some(home)) => Path::new(&home).join(rest),
+    _ => PathBuf::from(pattern),
+  };
+  let pattern = match path.parent() {
+    Some(parent) if pattern.is_relative() => parent.join(pattern),
+    _ => pattern,
+  };
+  let name = pattern.file_name().unwrap_or_default().to_string_lossy();
+  if !name.contains(['*', '?', '[']) {
+    return Ok(vec![pattern]);
+  }
+  let glob = Glob::new(&name)
+    .map_err(|err| Error::Pattern(err.to_string()))?
+    .compile_matcher();
+  let parent = pattern.parent().unwrap_or(Path::new("."));
+  let mut paths: Vec<_> = match fs::read_dir(parent) {
+    Ok(entries) => entries
+      .filter_map(|entry| entry.ok())
+      .map(|entry| entry.path())
+      .filter(|path| path.is_file() && path.file_name().is_some_and(|name| glob.is_match(name)))
+      .collect(),
+    // Like OpenSSH, patterns that match nothing are not an error.
+    Err(_) => Vec::new(),
+  };
+  paths.sort();
+  Ok(paths)
+}
+
+/// Applies the algorithms of a setting to the defaults where a leading
+/// `+` appends them, `-` removes those matching them, and `^` places
+/// them first. Otherwise the algorithms replace the defaults.
+fn modify(defaults: &[&str], arguments: &str) -> Result<Vec<String>, Error> {
+  let split = |s: &str| -> Vec<String> {
+    s.split(',')
+      .map(str::trim)
+      .filter(|s| !s.is_empty())
+      .map(String::from)
+      .collect()
+  };
+  let defaults: Vec<_> = defaults.iter().map(|s| s.to_string()).collect();
+  let algorithms = if let Some(appended) = arguments.strip_prefix('+') {
+    let mut algorithms = defaults;
+    algorithms.extend(split(appended));
+    algorithms
+  } else if let Some(removed) = arguments.strip_prefix('-') {
+    let mut globs = Vec::new();
+    for pattern in split(removed) {
+      let glob = Glob::new(&pattern).map_err(|err| Error::Pattern(err.to_string()))?;
+      globs.push(glob.compile_matcher());
+    }
+    defaults
+      .into_iter()
+      .filter(|algorithm| !globs.iter().any(|glob| glob.is_match(algorithm)))
+      .collect()
+  } else if let Some(prepended) = arguments.strip_prefix('^') {
+    let mut algorithms = split(prepended);
+    algorithms.extend(defaults);
+    algorithms
+  } else {
+    split(arguments)
+  };
+  let mut unique = Vec::new();
+  for algorithm in algorithms {
+    if !unique.contains(&algorithm) {
+      unique.push(algorithm);
+    }
+  }
+  Ok(unique)
+}
+
+/// Identifies the primitives of a key exchange method (see RFC 4253,
+/// RFC 5656, RFC 8268 and RFC 8731).
+fn kex(algorithm: &str) -> Option<Vec<(Usage, Component)>> {
+  // The exchange hash is signed by the server so it has to be collision
+  // resistant like any other hash function used in a signature.
+  let agreement = |asymmetric: Component, hash: Hash| {
+    Some(vec![
+      (Usage::KeyEstablishment, asymmetric),
+      (Usage::DigitalSignature, Component::Hash(hash)),
+    ])
+  };
+  // The size of the group is negotiated in the exchange so only the
+  // hash function can be assessed.
+  let exchange = |hash: Hash| Some(vec![(Usage::DigitalSignature, Component::Hash(hash))]);
+  let x25519 = Component::Asymmetric(X25519.into());
+  let modp = |group: Ffc| Component::Asymmetric(group.into());
+  match algorithm {
+    "curve25519-sha256" | "curve25519-sha256@libssh.org" => agreement(x25519, SHA256),
+    "curve448-sha512" => agreement(Component::Asymmetric(X448.into()), SHA512),
+    "diffie-hellman-group1-sha1" => agreement(modp(MODP_1024), SHA1),
+    "diffie-hellman-group14-sha1" => agreement(modp(MODP_2048), SHA1),
+    "diffie-hellman-group14-sha256" => agreement(modp(MODP_2048), SHA256),
+    "diffie-hellman-group15-sha512" => agreement(modp(MODP_3072), SHA512),
+    "diffie-hellman-group16-sha512" => agreement(modp(MODP_4096), SHA512),
+    "diffie-hellman-group17-sha512" => agreement(modp(MODP_6144), SHA512),
+    "diffie-hellman-group18-sha512" => agreement(modp(MODP_8192), SHA512),
+    "diffie-hellman-group-exchange-sha1" => exchange(SHA1),
+    "diffie-hellman-group-exchange-sha256" => exchange(SHA256),
+    "ecdh-sha2-nistp256" => agreement(Component::Asymmetric(P256.into()), SHA256),
+    "ecdh-sha2-nistp384" => agreement(Component::Asymmetric(P384.into()), SHA384),
+    "ecdh-sha2-nistp521" => agreement(Component::Asymmetric(P521.into()), SHA512),
+    "mlkem768x25519-sha256" => Some(vec![
+      (Usage::KeyEstablishment, Component::Kem(X25519MLKEM768)),
+      (Usage::DigitalSignature, Component::Hash(SHA256)),
+    ]),
+    // Streamlined NTRU Prime is not covered by any of the guides so only
+    // the classical half of the hybrid is assessed.
+    "sntrup761x25519-sha512" | "sntrup761x25519-sha512@openssh.com" => agreement(x25519, SHA512),
+    _ => None,
+  }
+}
+
+/// Identifies the primitives of a public key algorithm (see RFC 4253,
+/// RFC 5656, RFC 8332 and RFC 8709).
+///
+/// The size of RSA keys is not part of the algorithm so only the hash
+/// function is assessed for them.
+fn public_key(algorithm: &str) -> Option<Vec<(Usage, Component)>> {
+  let algorithm = algorithm.replace("-cert-v01@openssh.com", "");
+  let algorithm = algorithm.trim_end_matches("@openssh.com");
+  let signature = |ecc: Option<Ecc>, hash: Option<Hash>| {
+    let mut components = Vec::new();
+    if let Some(ecc) = ecc {
+      components.push((Usage::DigitalSignature, Component::Asymmetric(ecc.into())));
+    }
+    if let Some(hash) = hash {
+      components.push((Usage::DigitalSignature, Component::Hash(hash)));
+    }
+    Some(components)
+  };
+  match algorithm {
+    "ssh-rsa" => signature(None, Some(SHA1)),
+    "rsa-sha2-256" => signature(None, Some(SHA256)),
+    "rsa-sha2-512" => signature(None, Some(SHA512)),
+    // DSA keys are limited to 1024 bits by RFC 4253.
+    "ssh-dss" => Some(vec![
+      (
+        Usage::DigitalSignature,
+        Component::Asymmetric(DSA_1024_160.into()),
+      ),
+      (Usage::DigitalSignature, Component::Hash(SHA1)),
+    ]),
+    "ssh-ed25519" | "sk-ssh-ed25519" => signature(Some(ED25519), None),
+    "ssh-ed448" => signature(Some(ED448), None),
+    "ecdsa-sha2-nistp256" | "sk-ecdsa-sha2-nistp256" => signature(Some(P256), Some(SHA256)),
+    "ecdsa-sha2-nistp384" => signature(Some(P384), Some(SHA384)),
+    "ecdsa-sha2-nistp521" => signature(Some(P521), Some(SHA512)),
+    _ => None,
+  }
+}
+
+/// Identifies the cipher and its mode of operation of an encryption
+/// algorithm (see RFC 4253, RFC 4344 and RFC 5647).
+fn cipher(algorithm: &str) -> Option<Vec<(Usage, Component)>> {
+  let (symmetric, mode) = match algorithm {
+    // The stream cipher RC4 goes without a mode.
+    "arcfour" | "arcfour128" | "arcfour256" => (RC4, None),
+    "chacha20-poly1305@openssh.com" => (CHACHA20, Some(POLY1305)),
+    "rijndael-cbc@lysator.liu.se" => (AES256, Some(CBC)),
+    _ => {
+      let (cipher, mode) = algorithm
+        .trim_end_matches("@openssh.com")
+        .rsplit_once('-')?;
+      let mode = match mode {
+        "cbc" => CBC,
+        "ctr" => CTR,
+        "gcm" => GCM,
+        _ => return None,
+      };
+      let symmetric = match cipher {
+        "3des" => TDEA3,
+        "aes128" => AES128,
+        "aes192" => AES192,
+        "aes256" => AES256,
+        "des" => DES,
+        "idea" => IDEA,
+        "serpent128" => SERPENT128,
+        "serpent192" => SERPENT192,
+        "serpent256" => SERPENT256,
+        _ => return None,
+      };
+      (symmetric, Some(mode))
+    },
+  };
+  let mut components = vec![(Usage::Encryption, Component::Symmetric(symmetric))];
+  if let Some(mode) = mode {
+    components.push((Usage::Encryption, Component::Mode(mode)));
+  }
+  Some(components)
+}
+
+/// Identifies a message authentication code along with the length of
+/// its tag (see RFC 4253, RFC 4418 and RFC 6668).
+///
+/// The encrypt-then-MAC variants only differ in what is authenticated.
+fn mac(algorithm: &str) -> Option<Vec<(Usage, Component)>> {
+  let algorithm = algorithm.replace("-etm@openssh.com", "");
+  let truncated = |mac: Mac, tag: u16| Mac::new(mac.id, mac.key, tag);
+  let mac = match algorithm.trim_end_matches("@openssh.com") {
+    "hmac-md5" => HMAC_MD5,
+    "hmac-md5-96" => truncated(HMAC_MD5, 96),
+    "hmac-ripemd160" => HMAC_RIPEMD160,
+    "hmac-sha1" => HMAC_SHA1,
+    "hmac-sha1-96" => truncated(HMAC_SHA1, 96),
+    "hmac-sha2-256" => HMAC_SHA256,
+    "hmac-sha2-512" => HMAC_SHA512,
+    "umac-64" => truncated(UMAC, 64),
+    "umac-128" => UMAC,
+    _ => return None,
+  };
+  Some(vec![(Usage::MessageAuthentication, Component::Mac(mac))])
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::testing::TempDir;
+
+  fn macs(arguments: &str) -> Vec<String> {
+    modify(DEFAULT_MACS, arguments).unwrap()
+  }
+
+  #[test]
+  fn modify_replaces() {
+    assert_eq!(
+      macs("hmac-sha1, hmac-sha1,umac-64@openssh.com"),
+      ["hmac-sha1", "umac-64@openssh.com"]
+    );
+  }
+
+  #[test]
+  fn modify_appends() {
+    let algorithms = macs("+hmac-md5,hmac-sha1");
+    assert_eq!(algorithms.len(), DEFAULT_MACS.len() + 1);
+    assert_eq!(algorithms.last().unwrap(), "hmac-md5");
+  }
+
+  #[test]
+  fn modify_removes() {
+    assert_eq!(
+      macs("-umac-*,*-etm@openssh.com,hmac-sha1"),
+      ["hmac-sha2-256", "hmac-sha2-512"]
+    );
+    assert!(matches!(
+      modify(DEFAULT_MACS, "-[umac"),
+      Err(Error::Pattern(_))
+    ));
+  }
+
+  #[test]
+  fn modify_prepends() {
+    let algorithms = macs("^hmac-sha2-512,hmac-md5");
+    assert_eq!(algorithms[..2], ["hmac-sha2-512", "hmac-md5"]);
+    assert_eq!(algorithms.len(), DEFAULT_MACS.len() + 1);
+  }
+
+  #[test]
+  fn macs_are_identified() {
+    let mac = |algorithm: &str| match Keyword::Macs.components(algorithm).as_deref() {
+      Some([(Usage::MessageAuthentication, Component::Mac(mac))]) => Some(*mac),
+      _ => None,
+    };
+    for algorithm in DEFAULT_MACS {
+      assert!(mac(algorithm).is_some(), "{} is not identified", algorithm);
+    }
+    assert_eq!(
+      mac("umac-64-etm@openssh.com"),
+      Some(Mac::new(UMAC.id, 128, 64))
+    );
+    assert_eq!(mac("umac-128@openssh.com"), Some(UMAC));
+    assert_eq!(mac("hmac-sha1-96"), Some(Mac::new(HMAC_SHA1.id, 160, 96)));
+    assert_eq!(mac("hmac-sha2-512-etm@openssh.com"), Some(HMAC_SHA512));
+    assert_eq!(mac("hmac-sha3-256"), None);
+  }
+
+  #[test]
+  fn include_relative_and_glob() {
+    let dir = TempDir::new("ssh-include");
+    let config = dir.write(
+      "sshd_config",
+      b"Include sshd_config.d/*.conf extra\nCiphers aes256-gcm@openssh.com\n",
+    );
+    dir.write("sshd_config.d/20-macs.conf", b"MACs = -umac-*\n");
+    dir.write(
+      "sshd_config.d/10-kex.conf",
+      b"KexAlgorithms curve25519-sha256\n",
+    );
+    dir.write("sshd_config.d/ignored.txt", b"Ciphers 3des-cbc\n");
+    dir.write("extra", b"Match User alice\n  HostKeyAlgorithms ^ssh-rsa\n");
+    let settings = settings_from_file(&config).unwrap();
+    let keywords: Vec<_> = settings.iter().map(|setting| setting.keyword).collect();
+    assert_eq!(
+      keywords,
+      [
+        Keyword::KexAlgorithms,
+        Keyword::Macs,
+        Keyword::HostKeyAlgorithms,
+        Keyword::Ciphers
+      ]
+    );
+    assert_eq!(
+      settings[0].path,
+      dir.path().join("sshd_config.d/10-kex.conf")
+    );
+    assert_eq!(settings[0].line, 1);
+    assert_eq!(settings[2].algorithms[0], "ssh-rsa");
+    assert_eq!(settings[3].path, config);
+    assert_eq!(settings[3].line, 2);
+  }
+
+  #[test]
+  fn include_too_deep() {
+    let dir = TempDir::new("ssh-include-loop");
+    let config = dir.write("ssh_config", b"Include ssh_config\n");
+    let err = settings_from_file(&config).unwrap_err();
+    assert!(matches!(err, Error::Config(reason) if reason.contains("included too deeply")));
+
+    // Nesting up to the limit is fine.
+    for depth in 0..MAX_INCLUDE_DEPTH {
+      let contents = format!("Include {}\n", depth + 1);
+      dir.write(&depth.to_string(), contents.as_bytes());
+    }
+    dir.write(&MAX_INCLUDE_DEPTH.to_string(), b"Ciphers aes128-ctr\n");
+    let settings = settings_from_file(&dir.path().join("0")).unwrap();
+    assert_eq!(settings.len(), 1);
+  }
+
+  #[test]
+  fn missing_argument() {
+    let dir = TempDir::new("ssh-missing-argument");
+    let config = dir.write("ssh_config", b"Host *\n  Ciphers\n");
+    let err = settings_from_file(&config).unwrap_err();
+    assert!(
+      matches!(err, Error::Config(reason) if reason.ends_with(":2: missing Ciphers argument"))
+    );
+  }
+}
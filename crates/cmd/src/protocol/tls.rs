@@ -0,0 +1,1191 @@
+//! Resolve the cipher suites, supported groups and signature schemes a
+//! TLS endpoint may negotiate, either from its configuration or by
+//! probing it, and identify the primitives they are built on.
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::net::{IpAddr, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use openssl::error::ErrorStack;
+use openssl::ssl::{Ssl, SslContext, SslContextBuilder, SslMethod, SslStream, SslVersion};
+use wardstone_core::context::Usage;
+use wardstone_core::primitive::ecc::*;
+use wardstone_core::primitive::ffc::*;
+use wardstone_core::primitive::hash::*;
+use wardstone_core::primitive::ifc::*;
+use wardstone_core::primitive::kem::*;
+use wardstone_core::primitive::mode::*;
+use wardstone_core::primitive::symmetric::*;
+
+use crate::key::Error;
+use crate::protocol::Component;
+
+// The cipher suites registered by IANA that are in common use, others
+// are looked up in OpenSSL.
+const CIPHER_SUITES: &[(u16, &str)] = &[
+  (0x0000, "TLS_NULL_WITH_NULL_NULL"),
+  (0x0001, "TLS_RSA_WITH_NULL_MD5"),
+  (0x0002, "TLS_RSA_WITH_NULL_SHA"),
+  (0x0003, "TLS_RSA_EXPORT_WITH_RC4_40_MD5"),
+  (0x0004, "TLS_RSA_WITH_RC4_128_MD5"),
+  (0x0005, "TLS_RSA_WITH_RC4_128_SHA"),
+  (0x0006, "TLS_RSA_EXPORT_WITH_RC2_CBC_40_MD5"),
+  (0x0007, "TLS_RSA_WITH_IDEA_CBC_SHA"),
+  (0x0008, "TLS_RSA_EXPORT_WITH_DES40_CBC_SHA"),
+  (0x0009, "TLS_RSA_WITH_DES_CBC_SHA"),
+  (0x000a, "TLS_RSA_WITH_3DES_EDE_CBC_SHA"),
+  (0x000b, "TLS_DH_DSS_EXPORT_WITH_DES40_CBC_SHA"),
+  (0x000c, "TLS_DH_DSS_WITH_DES_CBC_SHA"),
+  (0x000d, "TLS_DH_DSS_WITH_3DES_EDE_CBC_SHA"),
+  (0x000e, "TLS_DH_RSA_EXPORT_WITH_DES40_CBC_SHA"),
+  (0x000f, "TLS_DH_RSA_WITH_DES_CBC_SHA"),
+  (0x0010, "TLS_DH_RSA_WITH_3DES_EDE_CBC_SHA"),
+  (0x0011, "TLS_DHE_DSS_EXPORT_WITH_DES40_CBC_SHA"),
+  (0x0012, "TLS_DHE_DSS_WITH_DES_CBC_SHA"),
+  (0x0013, "TLS_DHE_DSS_WITH_3DES_EDE_CBC_SHA"),
+  (0x0014, "TLS_DHE_RSA_EXPORT_WITH_DES40_CBC_SHA"),
+  (0x0015, "TLS_DHE_RSA_WITH_DES_CBC_SHA"),
+  (0x0016, "TLS_DHE_RSA_WITH_3DES_EDE_CBC_SHA"),
+  (0x0017, "TLS_DH_anon_EXPORT_WITH_RC4_40_MD5"),
+  (0x0018, "TLS_DH_anon_WITH_RC4_128_MD5"),
+  (0x0019, "TLS_DH_anon_EXPORT_WITH_DES40_CBC_SHA"),
+  (0x001a, "TLS_DH_anon_WITH_DES_CBC_SHA"),
+  (0x001b, "TLS_DH_anon_WITH_3DES_EDE_CBC_SHA"),
+  (0x002c, "TLS_PSK_WITH_NULL_SHA"),
+  (0x002d, "TLS_DHE_PSK_WITH_NULL_SHA"),
+  (0x002e, "TLS_RSA_PSK_WITH_NULL_SHA"),
+  (0x002f, "TLS_RSA_WITH_AES_128_CBC_SHA"),
+  (0x0030, "TLS_DH_DSS_WITH_AES_128_CBC_SHA"),
+  (0x0031, "TLS_DH_RSA_WITH_AES_128_CBC_SHA"),
+  (0x0032, "TLS_DHE_DSS_WITH_AES_128_CBC_SHA"),
+  (0x0033, "TLS_DHE_RSA_WITH_AES_128_CBC_SHA"),
+  (0x0034, "TLS_DH_anon_WITH_AES_128_CBC_SHA"),
+  (0x0035, "TLS_RSA_WITH_AES_256_CBC_SHA"),
+  (0x0036, "TLS_DH_DSS_WITH_AES_256_CBC_SHA"),
+  (0x0037, "TLS_DH_RSA_WITH_AES_256_CBC_SHA"),
+  (0x0038, "TLS_DHE_DSS_WITH_AES_256_CBC_SHA"),
+  (0x0039, "TLS_DHE_RSA_WITH_AES_256_CBC_SHA"),
+  (0x003a, "TLS_DH_anon_WITH_AES_256_CBC_SHA"),
+  (0x003b, "TLS_RSA_WITH_NULL_SHA256"),
+  (0x003c, "TLS_RSA_WITH_AES_128_CBC_SHA256"),
+  (0x003d, "TLS_RSA_WITH_AES_256_CBC_SHA256"),
+  (0x003e, "TLS_DH_DSS_WITH_AES_128_CBC_SHA256"),
+  (0x003f, "TLS_DH_RSA_WITH_AES_128_CBC_SHA256"),
+  (0x0040, "TLS_DHE_DSS_WITH_AES_128_CBC_SHA256"),
+  (0x0041, "TLS_RSA_WITH_CAMELLIA_128_CBC_SHA"),
+  (0x0044, "TLS_DHE_DSS_WITH_CAMELLIA_128_CBC_SHA"),
+  (0x0045, "TLS_DHE_RSA_WITH_CAMELLIA_128_CBC_SHA"),
+  (0x0067, "TLS_DHE_RSA_WITH_AES_128_CBC_SHA256"),
+  (0x0068, "TLS_DH_DSS_WITH_AES_256_CBC_SHA256"),
+  (0x0069, "TLS_DH_RSA_WITH_AES_256_CBC_SHA256"),
+  (0x006a, "TLS_DHE_DSS_WITH_AES_256_CBC_SHA256"),
+  (0x006b, "TLS_DHE_RSA_WITH_AES_256_CBC_SHA256"),
+  (0x006c, "TLS_DH_anon_WITH_AES_128_CBC_SHA256"),
+  (0x006d, "TLS_DH_anon_WITH_AES_256_CBC_SHA256"),
+  (0x0084, "TLS_RSA_WITH_CAMELLIA_256_CBC_SHA"),
+  (0x0087, "TLS_DHE_DSS_WITH_CAMELLIA_256_CBC_SHA"),
+  (0x0088, "TLS_DHE_RSA_WITH_CAMELLIA_256_CBC_SHA"),
+  (0x008a, "TLS_PSK_WITH_RC4_128_SHA"),
+  (0x008b, "TLS_PSK_WITH_3DES_EDE_CBC_SHA"),
+  (0x008c, "TLS_PSK_WITH_AES_128_CBC_SHA"),
+  (0x008d, "TLS_PSK_WITH_AES_256_CBC_SHA"),
+  (0x009c, "TLS_RSA_WITH_AES_128_GCM_SHA256"),
+  (0x009d, "TLS_RSA_WITH_AES_256_GCM_SHA384"),
+  (0x009e, "TLS_DHE_RSA_WITH_AES_128_GCM_SHA256"),
+  (0x009f, "TLS_DHE_RSA_WITH_AES_256_GCM_SHA384"),
+  (0x00a2, "TLS_DHE_DSS_WITH_AES_128_GCM_SHA256"),
+  (0x00a3, "TLS_DHE_DSS_WITH_AES_256_GCM_SHA384"),
+  (0x00a6, "TLS_DH_anon_WITH_AES_128_GCM_SHA256"),
+  (0x00a7, "TLS_DH_anon_WITH_AES_256_GCM_SHA384"),
+  (0x00a8, "TLS_PSK_WITH_AES_128_GCM_SHA256"),
+  (0x00a9, "TLS_PSK_WITH_AES_256_GCM_SHA384"),
+  (0x1301, "TLS_AES_128_GCM_SHA256"),
+  (0x1302, "TLS_AES_256_GCM_SHA384"),
+  (0x1303, "TLS_CHACHA20_POLY1305_SHA256"),
+  (0x1304, "TLS_AES_128_CCM_SHA256"),
+  (0x1305, "TLS_AES_128_CCM_8_SHA256"),
+  (0xc001, "TLS_ECDH_ECDSA_WITH_NULL_SHA"),
+  (0xc002, "TLS_ECDH_ECDSA_WITH_RC4_128_SHA"),
+  (0xc003, "TLS_ECDH_ECDSA_WITH_3DES_EDE_CBC_SHA"),
+  (0xc004, "TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA"),
+  (0xc005, "TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA"),
+  (0xc006, "TLS_ECDHE_ECDSA_WITH_NULL_SHA"),
+  (0xc007, "TLS_ECDHE_ECDSA_WITH_RC4_128_SHA"),
+  (0xc008, "TLS_ECDHE_ECDSA_WITH_3DES_EDE_CBC_SHA"),
+  (0xc009, "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA"),
+  (0xc00a, "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA"),
+  (0xc00b, "TLS_ECDH_RSA_WITH_NULL_SHA"),
+  (0xc00c, "TLS_ECDH_RSA_WITH_RC4_128_SHA"),
+  (0xc00d, "TLS_ECDH_RSA_WITH_3DES_EDE_CBC_SHA"),
+  (0xc00e, "TLS_ECDH_RSA_WITH_AES_128_CBC_SHA"),
+  (0xc00f, "TLS_ECDH_RSA_WITH_AES_256_CBC_SHA"),
+  (0xc010, "TLS_ECDHE_RSA_WITH_NULL_SHA"),
+  (0xc011, "TLS_ECDHE_RSA_WITH_RC4_128_SHA"),
+  (0xc012, "TLS_ECDHE_RSA_WITH_3DES_EDE_CBC_SHA"),
+  (0xc013, "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA"),
+  (0xc014, "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA"),
+  (0xc015, "TLS_ECDH_anon_WITH_NULL_SHA"),
+  (0xc016, "TLS_ECDH_anon_WITH_RC4_128_SHA"),
+  (0xc017, "TLS_ECDH_anon_WITH_3DES_EDE_CBC_SHA"),
+  (0xc018, "TLS_ECDH_anon_WITH_AES_128_CBC_SHA"),
+  (0xc019, "TLS_ECDH_anon_WITH_AES_256_CBC_SHA"),
+  (0xc023, "TLS_ECDHE_ECDSA_WITH_AES_128_CBC_SHA256"),
+  (0xc024, "TLS_ECDHE_ECDSA_WITH_AES_256_CBC_SHA384"),
+  (0xc025, "TLS_ECDH_ECDSA_WITH_AES_128_CBC_SHA256"),
+  (0xc026, "TLS_ECDH_ECDSA_WITH_AES_256_CBC_SHA384"),
+  (0xc027, "TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA256"),
+  (0xc028, "TLS_ECDHE_RSA_WITH_AES_256_CBC_SHA384"),
+  (0xc029, "TLS_ECDH_RSA_WITH_AES_128_CBC_SHA256"),
+  (0xc02a, "TLS_ECDH_RSA_WITH_AES_256_CBC_SHA384"),
+  (0xc02b, "TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256"),
+  (0xc02c, "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384"),
+  (0xc02d, "TLS_ECDH_ECDSA_WITH_AES_128_GCM_SHA256"),
+  (0xc02e, "TLS_ECDH_ECDSA_WITH_AES_256_GCM_SHA384"),
+  (0xc02f, "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256"),
+  (0xc030, "TLS_ECDHE_RSA_WITH_AES_256_GCM_SHA384"),
+  (0xc031, "TLS_ECDH_RSA_WITH_AES_128_GCM_SHA256"),
+  (0xc032, "TLS_ECDH_RSA_WITH_AES_256_GCM_SHA384"),
+  (0xc09c, "TLS_RSA_WITH_AES_128_CCM"),
+  (0xc09d, "TLS_RSA_WITH_AES_256_CCM"),
+  (0xc09e, "TLS_DHE_RSA_WITH_AES_128_CCM"),
+  (0xc09f, "TLS_DHE_RSA_WITH_AES_256_CCM"),
+  (0xc0a0, "TLS_RSA_WITH_AES_128_CCM_8"),
+  (0xc0a1, "TLS_RSA_WITH_AES_256_CCM_8"),
+  (0xc0a2, "TLS_DHE_RSA_WITH_AES_128_CCM_8"),
+  (0xc0a3, "TLS_DHE_RSA_WITH_AES_256_CCM_8"),
+  (0xc0ac, "TLS_ECDHE_ECDSA_WITH_AES_128_CCM"),
+  (0xc0ad, "TLS_ECDHE_ECDSA_WITH_AES_256_CCM"),
+  (0xc0ae, "TLS_ECDHE_ECDSA_WITH_AES_128_CCM_8"),
+  (0xc0af, "TLS_ECDHE_ECDSA_WITH_AES_256_CCM_8"),
+  (0xcca8, "TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256"),
+  (0xcca9, "TLS_ECDHE_ECDSA_WITH_CHACHA20_POLY1305_SHA256"),
+  (0xccaa, "TLS_DHE_RSA_WITH_CHACHA20_POLY1305_SHA256"),
+  (0xccab, "TLS_PSK_WITH_CHACHA20_POLY1305_SHA256"),
+  (0xccac, "TLS_ECDHE_PSK_WITH_CHACHA20_POLY1305_SHA256"),
+  (0xccad, "TLS_DHE_PSK_WITH_CHACHA20_POLY1305_SHA256"),
+  (0xccae, "TLS_RSA_PSK_WITH_CHACHA20_POLY1305_SHA256"),
+];
+
+// The supported groups registered by IANA (see RFC 8422, RFC 8446 and
+// RFC 8734 among others).
+const NAMED_GROUPS: &[(u16, &str)] = &[
+  (1, "sect163k1"),
+  (2, "sect163r1"),
+  (3, "sect163r2"),
+  (4, "sect193r1"),
+  (5, "sect193r2"),
+  (6, "sect233k1"),
+  (7, "sect233r1"),
+  (8, "sect239k1"),
+  (9, "sect283k1"),
+  (10, "sect283r1"),
+  (11, "sect409k1"),
+  (12, "sect409r1"),
+  (13, "sect571k1"),
+  (14, "sect571r1"),
+  (15, "secp160k1"),
+  (16, "secp160r1"),
+  (17, "secp160r2"),
+  (18, "secp192k1"),
+  (19, "secp192r1"),
+  (20, "secp224k1"),
+  (21, "secp224r1"),
+  (22, "secp256k1"),
+  (23, "secp256r1"),
+  (24, "secp384r1"),
+  (25, "secp521r1"),
+  (26, "brainpoolP256r1"),
+  (27, "brainpoolP384r1"),
+  (28, "brainpoolP512r1"),
+  (29, "x25519"),
+  (30, "x448"),
+  (31, "brainpoolP256r1tls13"),
+  (32, "brainpoolP384r1tls13"),
+  (33, "brainpoolP512r1tls13"),
+  (256, "ffdhe2048"),
+  (257, "ffdhe3072"),
+  (258, "ffdhe4096"),
+  (259, "ffdhe6144"),
+  (260, "ffdhe8192"),
+  (4587, "SecP256r1MLKEM768"),
+  (4588, "X25519MLKEM768"),
+  (4589, "SecP384r1MLKEM1024"),
+];
+
+// The signature schemes registered by IANA (see RFC 8446 and RFC 8734).
+const SIGNATURE_SCHEMES: &[(u16, &str)] = &[
+  (0x0201, "rsa_pkcs1_sha1"),
+  (0x0203, "ecdsa_sha1"),
+  (0x0401, "rsa_pkcs1_sha256"),
+  (0x0403, "ecdsa_secp256r1_sha256"),
+  (0x0501, "rsa_pkcs1_sha384"),
+  (0x0503, "ecdsa_secp384r1_sha384"),
+  (0x0601, "rsa_pkcs1_sha512"),
+  (0x0603, "ecdsa_secp521r1_sha512"),
+  (0x0804, "rsa_pss_rsae_sha256"),
+  (0x0805, "rsa_pss_rsae_sha384"),
+  (0x0806, "rsa_pss_rsae_sha512"),
+  (0x0807, "ed25519"),
+  (0x0808, "ed448"),
+  (0x0809, "rsa_pss_pss_sha256"),
+  (0x080a, "rsa_pss_pss_sha384"),
+  (0x080b, "rsa_pss_pss_sha512"),
+  (0x081a, "ecdsa_brainpoolP256r1tls13_sha256"),
+  (0x081b, "ecdsa_brainpoolP384r1tls13_sha384"),
+  (0x081c, "ecdsa_brainpoolP512r1tls13_sha512"),
+];
+
+// The protocol versions probed for from the oldest to the newest.
+const VERSIONS: &[(SslVersion, &str)] = &[
+  (SslVersion::SSL3, "SSLv3"),
+  (SslVersion::TLS1, "TLSv1.0"),
+  (SslVersion::TLS1_1, "TLSv1.1"),
+  (SslVersion::TLS1_2, "TLSv1.2"),
+  (SslVersion::TLS1_3, "TLSv1.3"),
+];
+
+// The TLS 1.3 cipher suites OpenSSL is able to offer.
+const TLS13_CIPHER_SUITES: &[&str] = &[
+  "TLS_AES_128_GCM_SHA256",
+  "TLS_AES_256_GCM_SHA384",
+  "TLS_CHACHA20_POLY1305_SHA256",
+  "TLS_AES_128_CCM_SHA256",
+  "TLS_AES_128_CCM_8_SHA256",
+];
+
+/// The kind of parameters a TLS endpoint negotiates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Parameter {
+  CipherSuites,
+  Groups,
+  SignatureSchemes,
+  Versions,
+}
+
+impl Parameter {
+  /// Resolves a list of parameters into their IANA names.
+  ///
+  /// Parameters are separated by commas, colons or whitespace and are
+  /// given either by name or by their code point in hexadecimal such
+  /// as `0x1301`. Cipher suites may also be given as an OpenSSL cipher
+  /// string such as `HIGH:!aNULL` which selects TLS 1.2 cipher suites
+  /// in the order a client would offer them.
+  ///
+  /// Code points that are not known are kept as is.
+  pub fn resolve(&self, list: &str) -> Result<Vec<String>, Error> {
+    let tokens: Vec<_> = list
+      .split(|c: char| c == ',' || c == ':' || c.is_whitespace())
+      .filter(|token| !token.is_empty())
+      .collect();
+    if tokens.is_empty() {
+      return Err(Error::Config(format!("no {} given", self)));
+    }
+    let names = match self {
+      Self::CipherSuites if tokens.iter().all(|token| is_cipher_suite(token)) => {
+        let mut names = Vec::new();
+        for token in tokens {
+          match code_point(token) {
+            Some(code) => names.push(cipher_suite_name(code)?),
+            None => names.push(token.to_string()),
+          }
+        }
+        names
+      },
+      Self::CipherSuites => expand(list)?,
+      Self::Groups => names(&tokens, NAMED_GROUPS),
+      Self::SignatureSchemes => names(&tokens, SIGNATURE_SCHEMES),
+      Self::Versions => tokens.iter().map(|token| token.to_string()).collect(),
+    };
+    let mut unique = Vec::new();
+    for name in names {
+      if !unique.contains(&name) {
+        unique.push(name);
+      }
+    }
+    Ok(unique)
+  }
+
+  /// Identifies the primitives a parameter is built on along with the
+  /// purpose each one is put to.
+  ///
+  /// Returns `None` if the parameter is not recognised.
+  pub fn components(&self, name: &str) -> Option<Vec<(Usage, Component)>> {
+    match self {
+      Self::CipherSuites => cipher_suite(name),
+      Self::Groups => group(name),
+      Self::SignatureSchemes => signature_scheme(name),
+      Self::Versions => version(name),
+    }
+  }
+}
+
+impl fmt::Display for Parameter {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::CipherSuites => write!(f, "cipher suites"),
+      Self::Groups => write!(f, "supported groups"),
+      Self::SignatureSchemes => write!(f, "signature schemes"),
+      Self::Versions => write!(f, "protocol versions"),
+    }
+  }
+}
+
+fn is_cipher_suite(token: &str) -> bool {
+  code_point(token).is_some() || token.to_ascii_uppercase().starts_with("TLS_")
+}
+
+fn code_point(token: &str) -> Option<u16> {
+  let hex = token
+    .strip_prefix("0x")
+    .or_else(|| token.strip_prefix("0X"))?;
+  u16::from_str_radix(hex, 16).ok()
+}
+
+/// Looks up the names of groups or signature schemes given by code
+/// point and the canonical spelling of those given by name.
+fn names(tokens: &[&str], registry: &[(u16, &str)]) -> Vec<String> {
+  tokens
+    .iter()
+    .map(|token| {
+      let entry = match code_point(token) {
+        Some(code) => registry.iter().find(|(c, _)| *c == code),
+        None => registry
+          .iter()
+          .find(|(_, name)| name.eq_ignore_ascii_case(token)),
+      };
+      entry.map_or(token.to_string(), |(_, name)| name.to_string())
+    })
+    .collect()
+}
+
+/// Creates a client that is willing to offer every cipher suite
+/// OpenSSL knows of no matter how weak.
+fn client(cipher_string: &str) -> Result<Ssl, Error> {
+  let config = |err: openssl::error::ErrorStack| Error::Config(err.to_string());
+  let mut builder = SslContext::builder(SslMethod::tls_client()).map_err(config)?;
+  builder.set_security_level(0);
+  builder.set_min_proto_version(None).map_err(config)?;
+  builder
+    .set_max_proto_version(Some(SslVersion::TLS1_2))
+    .map_err(config)?;
+  builder.set_cipher_list(cipher_string).map_err(|_| {
+    Error::Config(format!(
+      "{} does not select any cipher suites",
+      cipher_string
+    ))
+  })?;
+  Ssl::new(&builder.build()).map_err(config)
+}
+
+/// Looks up the IANA name of a cipher suite by its code point.
+fn cipher_suite_name(code: u16) -> Result<String, Error> {
+  if let Some((_, name)) = CIPHER_SUITES.iter().find(|(c, _)| *c == code) {
+    return Ok(name.to_string());
+  }
+  let ssl = client("ALL:COMPLEMENTOFALL")?;
+  let name = ssl
+    .bytes_to_cipher_list(&code.to_be_bytes(), false)
+    .ok()
+    .and_then(|ciphers| {
+      ciphers
+        .suites
+        .iter()
+        .find_map(|cipher| cipher.standard_name())
+    })
+    .map_or(format!("0x{:04x}", code), String::from);
+  Ok(name)
+}
+
+/// Lists the cipher suites an OpenSSL cipher string selects.
+///
+/// OpenSSL does not expose the list it builds so it is read from the
+/// ClientHello it would send instead.
+fn expand(cipher_string: &str) -> Result<Vec<String>, Error> {
+  let mut ssl = client(cipher_string)?;
+  ssl.set_connect_state();
+  let mut stream =
+    SslStream::new(ssl, Capture::default()).map_err(|err| Error::Config(err.to_string()))?;
+  // The handshake cannot complete as no server answers.
+  let _ = stream.do_handshake();
+  let suites = client_hello_suites(&stream.get_ref().0).ok_or_else(|| {
+    Error::Config(format!(
+      "{} does not select any cipher suites",
+      cipher_string
+    ))
+  })?;
+  let ciphers = stream
+    .ssl()
+    .bytes_to_cipher_list(suites, false)
+    .map_err(|err| Error::Config(err.to_string()))?;
+  Ok(
+    ciphers
+      .suites
+      .iter()
+      .filter_map(|cipher| cipher.standard_name())
+      .map(String::from)
+      .collect(),
+  )
+}
+
+/// Returns the cipher suites of a ClientHello record (see RFC 5246
+/// section 7.4.1.2).
+fn client_hello_suites(record: &[u8]) -> Option<&[u8]> {
+  // The record header is followed by the handshake header, the client
+  // version and the client random.
+  const SESSION_ID: usize = 5 + 4 + 2 + 32;
+  if record.first() != Some(&0x16) || record.get(5) != Some(&0x01) {
+    return None;
+  }
+  let session_id = *record.get(SESSION_ID)? as usize;
+  let start = SESSION_ID + 1 + session_id;
+  let length = u16::from_be_bytes([*record.get(start)?, *record.get(start + 1)?]) as usize;
+  record.get(start + 2..start + 2 + length)
+}
+
+/// A stream that records what is written to it and never has anything
+/// to read.
+#[derive(Debug, Default)]
+struct Capture(Vec<u8>);
+
+impl Read for Capture {
+  fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+    Err(io::ErrorKind::WouldBlock.into())
+  }
+}
+
+impl Write for Capture {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    self.0.extend_from_slice(buf);
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+/// What a TLS server accepted when it was probed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Endpoint {
+  /// The protocol versions from the oldest to the newest.
+  pub versions: Vec<String>,
+  /// The cipher suites in the order the server picked them.
+  pub cipher_suites: Vec<String>,
+  pub groups: Vec<String>,
+  /// The DER encoded certificates the server presented starting with
+  /// its own.
+  pub certificates: Vec<Vec<u8>>,
+}
+
+/// Connects to a TLS server at `host:port` and enumerates the protocol
+/// versions, cipher suites and groups it accepts along with the
+/// certificates it presents.
+///
+/// Every parameter is probed with a separate handshake. Only the
+/// parameters the local OpenSSL is able to offer can be found and
+/// finite field groups are only found over TLS 1.3 since servers pick
+/// them on their own in earlier versions. Certificates are not
+/// verified.
+pub fn probe(address: &str, timeout: Duration) -> Result<Endpoint, Error> {
+  let target = Target::new(address, timeout)?;
+  let mut endpoint = Endpoint::default();
+  let Some(stream) = target.handshake(|_| Ok(()))? else {
+    return Err(Error::Connect(format!(
+      "{} does not complete a TLS handshake",
+      address
+    )));
+  };
+  if let Some(chain) = stream.ssl().peer_cert_chain() {
+    for certificate in chain {
+      endpoint.certificates.push(certificate.to_der()?);
+    }
+  }
+  // Some servers only handle one connection at a time so it is closed
+  // before probing any further.
+  drop(stream);
+  for (version, name) in VERSIONS {
+    let only = |builder: &mut SslContextBuilder| {
+      builder.set_min_proto_version(Some(*version))?;
+      builder.set_max_proto_version(Some(*version))
+    };
+    if target.handshake(only)?.is_some() {
+      endpoint.versions.push(name.to_string());
+    }
+  }
+  let tls13 = endpoint.versions.iter().any(|version| version == "TLSv1.3");
+  let legacy = endpoint.versions.iter().any(|version| version != "TLSv1.3");
+  if tls13 {
+    let mut remaining = TLS13_CIPHER_SUITES.to_vec();
+    while !remaining.is_empty() {
+      let offer = remaining.join(":");
+      let configure = |builder: &mut SslContextBuilder| {
+        builder.set_min_proto_version(Some(SslVersion::TLS1_3))?;
+        builder.set_ciphersuites(&offer)
+      };
+      let Some(name) = target.handshake(configure)?.and_then(|stream| {
+        let cipher = stream.ssl().current_cipher()?;
+        cipher.standard_name()
+      }) else {
+        break;
+      };
+      remaining.retain(|suite| *suite != name);
+      endpoint.cipher_suites.push(name.to_string());
+    }
+  }
+  if legacy {
+    // Each cipher suite the server picks is taken out of the next offer
+    // until it has nothing left it accepts.
+    let mut cipher_string = String::from("ALL:COMPLEMENTOFALL");
+    loop {
+      let configure = |builder: &mut SslContextBuilder| {
+        builder.set_max_proto_version(Some(SslVersion::TLS1_2))?;
+        builder.set_cipher_list(&cipher_string)
+      };
+      let Some((name, standard_name)) = target.handshake(configure)?.and_then(|stream| {
+        let cipher = stream.ssl().current_cipher()?;
+        Some((cipher.name(), cipher.standard_name()?))
+      }) else {
+        break;
+      };
+      cipher_string.push_str(format!(":-{}", name).as_str());
+      endpoint.cipher_suites.push(standard_name.to_string());
+    }
+  }
+  for (_, group) in NAMED_GROUPS {
+    let ffdhe = group.starts_with("ffdhe");
+    let hybrid = group.contains("MLKEM");
+    if !tls13 && (ffdhe || hybrid) {
+      continue;
+    }
+    let configure = |builder: &mut SslContextBuilder| {
+      if tls13 {
+        builder.set_min_proto_version(Some(SslVersion::TLS1_3))?;
+      } else {
+        builder.set_max_proto_version(Some(SslVersion::TLS1_2))?;
+        builder.set_cipher_list("kECDHE")?;
+      }
+      builder.set_groups_list(group)
+    };
+    if target.handshake(configure)?.is_some() {
+      endpoint.groups.push(group.to_string());
+    }
+  }
+  Ok(endpoint)
+}
+
+/// The server to probe.
+struct Target {
+  address: String,
+  /// The name sent in the server name indication unless the server is
+  /// given by its IP address.
+  host: Option<String>,
+  timeout: Duration,
+}
+
+impl Target {
+  /// Parses a `host:port` address where the port defaults to 443.
+  ///
+  /// IPv6 addresses are enclosed in brackets when followed by a port.
+  fn new(address: &str, timeout: Duration) -> Result<Self, Error> {
+    let (host, port) = match address.rsplit_once(':') {
+      _ if address.parse::<IpAddr>().is_ok() => (address, "443"),
+      Some((host, port)) if !port.contains(']') => (host, port),
+      _ => (address, "443"),
+    };
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    if host.is_empty() || port.parse::<u16>().is_err() {
+      return Err(Error::Connect(format!(
+        "{} is not a valid host:port",
+        address
+      )));
+    }
+    Ok(Self {
+      address: if host.contains(':') {
+        format!("[{}]:{}", host, port)
+      } else {
+        format!("{}:{}", host, port)
+      },
+      host: host.parse::<IpAddr>().is_err().then(|| host.to_string()),
+      timeout,
+    })
+  }
+
+  /// Performs a handshake with a client that is willing to offer every
+  /// parameter OpenSSL knows of unless it is configured otherwise.
+  ///
+  /// Returns `None` if the server rejects the handshake or if OpenSSL
+  /// cannot offer what is asked of it.
+  fn handshake(
+    &self,
+    configure: impl FnOnce(&mut SslContextBuilder) -> Result<(), ErrorStack>,
+  ) -> Result<Option<SslStream<TcpStream>>, Error> {
+    let mut builder = SslContext::builder(SslMethod::tls_client())?;
+    builder.set_security_level(0);
+    builder.set_min_proto_version(None)?;
+    builder.set_cipher_list("ALL:COMPLEMENTOFALL")?;
+    if configure(&mut builder).is_err() {
+      return Ok(None);
+    }
+    let mut ssl = Ssl::new(&builder.build())?;
+    if let Some(host) = self.host.as_ref() {
+      ssl.set_hostname(host)?;
+    }
+    let stream = self.connect()?;
+    Ok(ssl.connect(stream).ok())
+  }
+
+  fn connect(&self) -> Result<TcpStream, Error> {
+    let connect = |err: io::Error| Error::Connect(format!("{}: {}", self.address, err));
+    let addresses = self.address.to_socket_addrs().map_err(connect)?;
+    let mut last = io::Error::new(io::ErrorKind::NotFound, "no addresses found");
+    for address in addresses {
+      match TcpStream::connect_timeout(&address, self.timeout) {
+        Ok(stream) => {
+          stream
+            .set_read_timeout(Some(self.timeout))
+            .map_err(connect)?;
+          stream
+            .set_write_timeout(Some(self.timeout))
+            .map_err(connect)?;
+          return Ok(stream);
+        },
+        Err(err) => last = err,
+      }
+    }
+    Err(connect(last))
+  }
+}
+
+/// Identifies the primitives of a cipher suite (see RFC 5246 and RFC
+/// 8446).
+///
+/// The size of ephemeral keys depends on the negotiated group and the
+/// size of signing keys on the certificate so neither is assessed here.
+/// The PRF of TLS 1.2 and the HKDF of TLS 1.3 are assessed by way of
+/// their hash function.
+fn cipher_suite(name: &str) -> Option<Vec<(Usage, Component)>> {
+  let name = name.to_ascii_uppercase();
+  let name = name.strip_prefix("TLS_")?;
+  // TLS 1.3 cipher suites leave out the key exchange.
+  let (mut components, protection) = match name.split_once("_WITH_") {
+    Some((exchange, protection)) => (key_exchange(exchange)?, protection),
+    None => (Vec::new(), name),
+  };
+  let (cipher, hash) = match protection.rsplit_once('_') {
+    Some((cipher, "MD5")) => (cipher, Some(MD5)),
+    Some((cipher, "SHA")) => (cipher, Some(SHA1)),
+    Some((cipher, "SHA256")) => (cipher, Some(SHA256)),
+    Some((cipher, "SHA384")) => (cipher, Some(SHA384)),
+    Some((cipher, "NULL")) => (cipher, None),
+    _ => (protection, None),
+  };
+  let unauthenticated = protection.ends_with("_NULL");
+  if unauthenticated {
+    components.push((Usage::MessageAuthentication, Component::Absent));
+  }
+  let modes = [
+    ("_CBC", CBC),
+    ("_CCM", CCM),
+    ("_CCM_8", CCM),
+    ("_GCM", GCM),
+    ("_POLY1305", POLY1305),
+  ];
+  let (cipher, mode) = modes
+    .iter()
+    .find_map(|&(suffix, mode)| Some((cipher.strip_suffix(suffix)?, Some(mode))))
+    .unwrap_or((cipher, None));
+  let aead = mode.is_some_and(|mode| mode.is_authenticated());
+  let symmetric = match cipher {
+    "3DES_EDE" => TDEA3,
+    "AES_128" => AES128,
+    "AES_256" => AES256,
+    "CAMELLIA_128" => CAMELLIA128,
+    "CAMELLIA_256" => CAMELLIA256,
+    "CHACHA20" => CHACHA20,
+    "DES" => DES,
+    "IDEA" => IDEA,
+    // The stream cipher RC4 goes without a mode.
+    "RC4_128" => RC4,
+    "NULL" => {
+      components.push((Usage::Encryption, Component::Absent));
+      return Some(components);
+    },
+    _ => return None,
+  };
+  components.push((Usage::Encryption, Component::Symmetric(symmetric)));
+  if let Some(mode) = mode {
+    components.push((Usage::Encryption, Component::Mode(mode)));
+  }
+  match hash {
+    // AEAD cipher suites only name the hash function of the PRF which
+    // defaults to SHA-256.
+    _ if aead => {
+      let prf = hash.unwrap_or(SHA256);
+      components.push((Usage::KeyDerivation, Component::Hash(prf)));
+    },
+    Some(hash) => {
+      let prf = if hash == SHA384 { SHA384 } else { SHA256 };
+      components.push((Usage::MessageAuthentication, Component::Hash(hash)));
+      components.push((Usage::KeyDerivation, Component::Hash(prf)));
+    },
+    None if unauthenticated => {},
+    None => return None,
+  }
+  Some(components)
+}
+
+/// Identifies the primitives of the key exchange and authentication of
+/// a TLS 1.2 cipher suite.
+///
+/// Export cipher suites limit the size of the exchanged keys which is
+/// what gets assessed for them.
+fn key_exchange(exchange: &str) -> Option<Vec<(Usage, Component)>> {
+  let mut components = Vec::new();
+  let mut parts = exchange.split('_');
+  let export: fn(u16) -> Option<Component> = match parts.next()? {
+    "NULL" => {
+      components.push((Usage::KeyEstablishment, Component::Absent));
+      components.push((Usage::DigitalSignature, Component::Absent));
+      return Some(components);
+    },
+    "RSA" => |k| Some(Component::Asymmetric(Ifc::new(ID_RSA_PKCS1, k).into())),
+    "DH" | "DHE" => |l| Some(Component::Asymmetric(Ffc::new(ID_DH, l, 160).into())),
+    "ECDH" | "ECDHE" | "PSK" | "SRP" | "KRB5" => |_| None,
+    _ => return None,
+  };
+  for part in parts {
+    match part {
+      "RSA" | "DSS" | "ECDSA" | "PSK" | "SHA" => {},
+      "ANON" => components.push((Usage::DigitalSignature, Component::Absent)),
+      "EXPORT" => components.push((Usage::KeyEstablishment, export(512)?)),
+      "EXPORT1024" => components.push((Usage::KeyEstablishment, export(1024)?)),
+      _ => return None,
+    }
+  }
+  Some(components)
+}
+
+/// Identifies the primitive of a supported group.
+fn group(name: &str) -> Option<Vec<(Usage, Component)>> {
+  let ffdhe = |group: Ffc| Component::Asymmetric(group.into());
+  let component = match name.to_ascii_lowercase().as_str() {
+    "ffdhe2048" => ffdhe(FFDHE2048),
+    "ffdhe3072" => ffdhe(FFDHE3072),
+    "ffdhe4096" => ffdhe(FFDHE4096),
+    "ffdhe6144" => ffdhe(FFDHE6144),
+    "ffdhe8192" => ffdhe(FFDHE8192),
+    "secp256r1mlkem768" => Component::Kem(SECP256R1MLKEM768),
+    "secp384r1mlkem1024" => Component::Kem(SECP384R1MLKEM1024),
+    "x25519mlkem768" => Component::Kem(X25519MLKEM768),
+    name => {
+      let ecc = match name.trim_end_matches("tls13") {
+        "sect163k1" => SECT163K1,
+        "sect163r1" => SECT163R1,
+        "sect163r2" => SECT163R2,
+        "sect193r1" => SECT193R1,
+        "sect193r2" => SECT193R2,
+        "sect233k1" => SECT233K1,
+        "sect233r1" => SECT233R1,
+        "sect239k1" => SECT239K1,
+        "sect283k1" => SECT283K1,
+        "sect283r1" => SECT283R1,
+        "sect409k1" => SECT409K1,
+        "sect409r1" => SECT409R1,
+        "sect571k1" => SECT571K1,
+        "sect571r1" => SECT571R1,
+        "secp160k1" => SECP160K1,
+        "secp160r1" => SECP160R1,
+        "secp160r2" => SECP160R2,
+        "secp192k1" => SECP192K1,
+        "secp192r1" | "p-192" => SECP192R1,
+        "secp224k1" => SECP224K1,
+        "secp224r1" | "p-224" => SECP224R1,
+        "secp256k1" => SECP256K1,
+        "secp256r1" | "prime256v1" | "p-256" => SECP256R1,
+        "secp384r1" | "p-384" => SECP384R1,
+        "secp521r1" | "p-521" => SECP521R1,
+        "brainpoolp256r1" => BRAINPOOLP256R1,
+        "brainpoolp384r1" => BRAINPOOLP384R1,
+        "brainpoolp512r1" => BRAINPOOLP512R1,
+        "x25519" => X25519,
+        "x448" => X448,
+        _ => return None,
+      };
+      Component::Asymmetric(ecc.into())
+    },
+  };
+  Some(vec![(Usage::KeyEstablishment, component)])
+}
+
+/// Identifies the primitives of a signature scheme.
+///
+/// The size of RSA keys is not part of the scheme so only the hash
+/// function is assessed for them.
+fn signature_scheme(name: &str) -> Option<Vec<(Usage, Component)>> {
+  let name = name.to_ascii_lowercase();
+  let signature = |ecc: Option<Ecc>, hash: Option<Hash>| {
+    let mut components = Vec::new();
+    if let Some(ecc) = ecc {
+      components.push((Usage::DigitalSignature, Component::Asymmetric(ecc.into())));
+    }
+    if let Some(hash) = hash {
+      components.push((Usage::DigitalSignature, Component::Hash(hash)));
+    }
+    Some(components)
+  };
+  match name.as_str() {
+    "rsa_pkcs1_sha1" | "ecdsa_sha1" => signature(None, Some(SHA1)),
+    "rsa_pkcs1_sha256" | "rsa_pss_rsae_sha256" | "rsa_pss_pss_sha256" => {
+      signature(None, Some(SHA256))
+    },
+    "rsa_pkcs1_sha384" | "rsa_pss_rsae_sha384" | "rsa_pss_pss_sha384" => {
+      signature(None, Some(SHA384))
+    },
+    "rsa_pkcs1_sha512" | "rsa_pss_rsae_sha512" | "rsa_pss_pss_sha512" => {
+      signature(None, Some(SHA512))
+    },
+    "ecdsa_secp256r1_sha256" => signature(Some(P256), Some(SHA256)),
+    "ecdsa_secp384r1_sha384" => signature(Some(P384), Some(SHA384)),
+    "ecdsa_secp521r1_sha512" => signature(Some(P521), Some(SHA512)),
+    "ecdsa_brainpoolp256r1tls13_sha256" => signature(Some(BRAINPOOLP256R1), Some(SHA256)),
+    "ecdsa_brainpoolp384r1tls13_sha384" => signature(Some(BRAINPOOLP384R1), Some(SHA384)),
+    "ecdsa_brainpoolp512r1tls13_sha512" => signature(Some(BRAINPOOLP512R1), Some(SHA512)),
+    "ed25519" => signature(Some(ED25519), None),
+    "ed448" => signature(Some(ED448), None),
+    _ => None,
+  }
+}
+
+/// Identifies the primitives a protocol version prescribes.
+///
+/// Versions before TLS 1.2 derive keys with a PRF built on both MD5 and
+/// SHA-1 and sign the key exchange with SHA-1.
+fn version(name: &str) -> Option<Vec<(Usage, Component)>> {
+  match name {
+    "SSLv3" | "TLSv1.0" | "TLSv1.1" => Some(vec![
+      (Usage::KeyDerivation, Component::Hash(MD5)),
+      (Usage::KeyDerivation, Component::Hash(SHA1)),
+      (Usage::DigitalSignature, Component::Hash(SHA1)),
+    ]),
+    "TLSv1.2" | "TLSv1.3" => Some(Vec::new()),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::net::TcpListener;
+  use std::thread;
+
+  use openssl::asn1::Asn1Time;
+  use openssl::ec::{EcGroup, EcKey};
+  use openssl::hash::MessageDigest;
+  use openssl::nid::Nid;
+  use openssl::pkey::{PKey, Private};
+  use openssl::ssl::{SslAcceptor, SslMethod as Method};
+  use openssl::x509::{X509NameBuilder, X509};
+
+  use super::*;
+
+  /// Generates a self-signed certificate for an ECDSA key on P-256.
+  fn certificate() -> (X509, PKey<Private>) {
+    let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+    let key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+    let mut name = X509NameBuilder::new().unwrap();
+    name.append_entry_by_text("CN", "localhost").unwrap();
+    let name = name.build();
+    let mut builder = X509::builder().unwrap();
+    builder.set_version(2).unwrap();
+    builder.set_subject_name(&name).unwrap();
+    builder.set_issuer_name(&name).unwrap();
+    builder.set_pubkey(&key).unwrap();
+    builder
+      .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+      .unwrap();
+    builder
+      .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+      .unwrap();
+    builder.sign(&key, MessageDigest::sha256()).unwrap();
+    (builder.build(), key)
+  }
+
+  /// Starts a server on an ephemeral port that only accepts TLS 1.2
+  /// with a single cipher suite and group, and returns its address.
+  fn server(certificate: &X509, key: &PKey<Private>) -> String {
+    let mut builder = SslAcceptor::mozilla_intermediate_v5(Method::tls_server()).unwrap();
+    builder.set_certificate(certificate).unwrap();
+    builder.set_private_key(key).unwrap();
+    builder
+      .set_min_proto_version(Some(SslVersion::TLS1_2))
+      .unwrap();
+    builder
+      .set_max_proto_version(Some(SslVersion::TLS1_2))
+      .unwrap();
+    builder
+      .set_cipher_list("ECDHE-ECDSA-AES128-GCM-SHA256")
+      .unwrap();
+    builder.set_groups_list("P-256").unwrap();
+    let acceptor = builder.build();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    // The thread serves one handshake after another for as long as the
+    // tests run.
+    thread::spawn(move || {
+      for stream in listener.incoming().flatten() {
+        let _ = acceptor.accept(stream);
+      }
+    });
+    address
+  }
+
+  #[test]
+  fn probe_server() {
+    let (certificate, key) = certificate();
+    let address = server(&certificate, &key);
+    let endpoint = probe(&address, Duration::from_secs(5)).unwrap();
+    assert_eq!(endpoint.versions, ["TLSv1.2"]);
+    assert_eq!(
+      endpoint.cipher_suites,
+      ["TLS_ECDHE_ECDSA_WITH_AES_128_GCM_SHA256"]
+    );
+    assert_eq!(endpoint.groups, ["secp256r1"]);
+    assert_eq!(endpoint.certificates, [certificate.to_der().unwrap()]);
+  }
+
+  #[test]
+  fn probe_nothing_listening() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    drop(listener);
+    let err = probe(&address, Duration::from_secs(5)).unwrap_err();
+    assert!(matches!(err, Error::Connect(_)));
+  }
+
+  #[test]
+  fn target_with_port() {
+    let target = Target::new("example.com:8443", Duration::ZERO).unwrap();
+    assert_eq!(target.address, "example.com:8443");
+    assert_eq!(target.host.as_deref(), Some("example.com"));
+  }
+
+  #[test]
+  fn target_default_port() {
+    let target = Target::new("example.com", Duration::ZERO).unwrap();
+    assert_eq!(target.address, "example.com:443");
+    let target = Target::new("192.0.2.1", Duration::ZERO).unwrap();
+    assert_eq!(target.address, "192.0.2.1:443");
+    assert_eq!(target.host, None);
+  }
+
+  #[test]
+  fn target_ipv6() {
+    let target = Target::new("[2001:db8::1]:8443", Duration::ZERO).unwrap();
+    assert_eq!(target.address, "[2001:db8::1]:8443");
+    assert_eq!(target.host, None);
+    for address in ["[2001:db8::1]", "2001:db8::1"] {
+      let target = Target::new(address, Duration::ZERO).unwrap();
+      assert_eq!(target.address, "[2001:db8::1]:443");
+    }
+  }
+
+  #[test]
+  fn target_invalid() {
+    for address in ["", ":443", "example.com:https", "example.com:65536"] {
+      let err = Target::new(address, Duration::ZERO).err();
+      assert!(matches!(err, Some(Error::Connect(_))), "{}", address);
+    }
+  }
+
+  #[test]
+  fn client_hello_suites_of_captured_client_hello() {
+    let mut ssl = client("AES128-SHA:AES256-SHA").unwrap();
+    ssl.set_connect_state();
+    let mut stream = SslStream::new(ssl, Capture::default()).unwrap();
+    let _ = stream.do_handshake();
+    let record = &stream.get_ref().0;
+    let suites = client_hello_suites(record).unwrap();
+    // The client also signals secure renegotiation with a cipher suite.
+    assert_eq!(&suites[..4], [0x00, 0x2f, 0x00, 0x35]);
+    assert!(client_hello_suites(&record[..50]).is_none());
+    assert!(client_hello_suites(&[0x17, 0x03, 0x03]).is_none());
+  }
+
+  #[test]
+  fn expand_cipher_string() {
+    assert_eq!(
+      expand("AES128-SHA:AES256-SHA").unwrap(),
+      [
+        "TLS_RSA_WITH_AES_128_CBC_SHA",
+        "TLS_RSA_WITH_AES_256_CBC_SHA"
+      ]
+    );
+  }
+
+  #[test]
+  fn resolve_cipher_suites_by_name_and_code_point() {
+    let names = Parameter::CipherSuites
+      .resolve("0x1301, TLS_AES_256_GCM_SHA384:0xc02f 0x1301")
+      .unwrap();
+    assert_eq!(
+      names,
+      [
+        "TLS_AES_128_GCM_SHA256",
+        "TLS_AES_256_GCM_SHA384",
+        "TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256",
+      ]
+    );
+  }
+
+  #[test]
+  fn resolve_cipher_string() {
+    let names = Parameter::CipherSuites
+      .resolve("ECDHE-ECDSA-AES256-GCM-SHA384:AES128-SHA")
+      .unwrap();
+    assert_eq!(
+      names,
+      [
+        "TLS_ECDHE_ECDSA_WITH_AES_256_GCM_SHA384",
+        "TLS_RSA_WITH_AES_128_CBC_SHA",
+      ]
+    );
+  }
+
+  #[test]
+  fn resolve_cipher_string_without_cipher_suites() {
+    let err = Parameter::CipherSuites.resolve("!ALL").unwrap_err();
+    assert!(matches!(err, Error::Config(_)));
+  }
+
+  #[test]
+  fn resolve_groups_and_signature_schemes() {
+    let groups = Parameter::Groups
+      .resolve("X25519, 0x0017,0x11ec, 0xfefe")
+      .unwrap();
+    assert_eq!(groups, ["x25519", "secp256r1", "X25519MLKEM768", "0xfefe"]);
+    let schemes = Parameter::SignatureSchemes
+      .resolve("0x0804 ED25519")
+      .unwrap();
+    assert_eq!(schemes, ["rsa_pss_rsae_sha256", "ed25519"]);
+  }
+
+  #[test]
+  fn resolve_nothing() {
+    let err = Parameter::Versions.resolve(" , ").unwrap_err();
+    assert!(matches!(err, Error::Config(_)));
+  }
+
+  #[test]
+  fn cipher_suite_tls13() {
+    assert_eq!(
+      cipher_suite("TLS_AES_256_GCM_SHA384").unwrap(),
+      [
+        (Usage::Encryption, Component::Symmetric(AES256)),
+        (Usage::Encryption, Component::Mode(GCM)),
+        (Usage::KeyDerivation, Component::Hash(SHA384)),
+      ]
+    );
+  }
+
+  #[test]
+  fn cipher_suite_cbc() {
+    assert_eq!(
+      cipher_suite("TLS_ECDHE_RSA_WITH_AES_128_CBC_SHA").unwrap(),
+      [
+        (Usage::Encryption, Component::Symmetric(AES128)),
+        (Usage::Encryption, Component::Mode(CBC)),
+        (Usage::MessageAuthentication, Component::Hash(SHA1)),
+        (Usage::KeyDerivation, Component::Hash(SHA256)),
+      ]
+    );
+  }
+
+  #[test]
+  fn cipher_suite_export() {
+    let rsa = Ifc::new(ID_RSA_PKCS1, 1024);
+    assert_eq!(
+      cipher_suite("TLS_RSA_EXPORT1024_WITH_DES_CBC_SHA").unwrap(),
+      [
+        (Usage::KeyEstablishment, Component::Asymmetric(rsa.into())),
+        (Usage::Encryption, Component::Symmetric(DES)),
+        (Usage::Encryption, Component::Mode(CBC)),
+        (Usage::MessageAuthentication, Component::Hash(SHA1)),
+        (Usage::KeyDerivation, Component::Hash(SHA256)),
+      ]
+    );
+  }
+
+  #[test]
+  fn cipher_suite_without_protection() {
+    assert_eq!(
+      cipher_suite("TLS_NULL_WITH_NULL_NULL").unwrap(),
+      [
+        (Usage::KeyEstablishment, Component::Absent),
+        (Usage::DigitalSignature, Component::Absent),
+        (Usage::MessageAuthentication, Component::Absent),
+        (Usage::Encryption, Component::Absent),
+      ]
+    );
+    assert!(cipher_suite("TLS_DH_anon_WITH_AES_128_CBC_SHA")
+      .unwrap()
+      .contains(&(Usage::DigitalSignature, Component::Absent)));
+  }
+
+  #[test]
+  fn cipher_suite_unrecognised() {
+    assert_eq!(cipher_suite("TLS_RSA_WITH_UNKNOWN_CBC_SHA"), None);
+    assert_eq!(cipher_suite("AES128-SHA"), None);
+  }
+
+  #[test]
+  fn groups() {
+    assert_eq!(
+      group("X25519").unwrap(),
+      [(
+        Usage::KeyEstablishment,
+        Component::Asymmetric(X25519.into())
+      )]
+    );
+    assert_eq!(
+      group("brainpoolP256r1tls13").unwrap(),
+      [(
+        Usage::KeyEstablishment,
+        Component::Asymmetric(BRAINPOOLP256R1.into())
+      )]
+    );
+    assert_eq!(
+      group("ffdhe3072").unwrap(),
+      [(
+        Usage::KeyEstablishment,
+        Component::Asymmetric(FFDHE3072.into())
+      )]
+    );
+    assert_eq!(
+      group("X25519MLKEM768").unwrap(),
+      [(Usage::KeyEstablishment, Component::Kem(X25519MLKEM768))]
+    );
+    assert_eq!(group("0xfefe"), None);
+  }
+
+  #[test]
+  fn signature_schemes() {
+    assert_eq!(
+      signature_scheme("ecdsa_secp384r1_sha384").unwrap(),
+      [
+        (Usage::DigitalSignature, Component::Asymmetric(P384.into())),
+        (Usage::DigitalSignature, Component::Hash(SHA384)),
+      ]
+    );
+    assert_eq!(
+      signature_scheme("rsa_pss_rsae_sha256").unwrap(),
+      [(Usage::DigitalSignature, Component::Hash(SHA256))]
+    );
+    assert_eq!(
+      signature_scheme("ed25519").unwrap(),
+      [(
+        Usage::DigitalSignature,
+        Component::Asymmetric(ED25519.into())
+      )]
+    );
+    assert_eq!(signature_scheme("rsa_pkcs1_md5"), None);
+  }
+}
@@ -3,18 +3,38 @@ use std::fmt::{self, Display, Formatter};
 use std::path::{Path, PathBuf};
 use std::process::{ExitCode, Termination};
 
+use clap::ValueEnum;
 use serde::Serialize;
-use serde_json::json;
+use serde_json::{json, Value};
+use wardstone_core::context::Usage;
 use wardstone_core::primitive::asymmetric::Asymmetric;
 use wardstone_core::primitive::hash::Hash;
+use wardstone_core::primitive::symmetric::Symmetric;
+use wardstone_core::primitive::{Primitive, Security};
+use wardstone_core::standard::{Citation, Status, Verdict};
+use x509_parser::time::ASN1Time;
 
-use crate::key::Error;
+use crate::key::ssh::SshCertificate;
+use crate::key::{Error, KeyDerivation, Protection, PssParameters, Validity};
+
+/// The exit code used when at least one key is not compliant.
+pub const EXIT_NON_COMPLIANT: u8 = 1;
+
+/// The exit code used when at least one key could not be read or the
+/// assessment could not be carried out at all.
+pub const EXIT_ERROR: u8 = 2;
 
 /// Represents the exit status of the program.
 ///
-/// It implements [`Termination`] such that if any one of the audits
-/// fail or an error occurs, a helpful message is printed and the exit
-/// code is set to [`ExitCode::FAILURE`].
+/// It implements [`Termination`] such that the exit code is set to
+/// [`ExitCode::SUCCESS`] if all audits pass, [`EXIT_NON_COMPLIANT`] if
+/// any one of them fail, and [`EXIT_ERROR`] if any key could not be
+/// read or an error prevented the assessment, in which case a helpful
+/// message is printed.
+///
+/// Non-compliance takes precedence over unreadable keys so that a
+/// non-compliant key is never masked by a stray file that could not be
+/// read.
 pub enum Exit {
   Success(Report),
   Failure(Error),
@@ -26,12 +46,26 @@ impl Termination for Exit {
       Exit::Success(report) => report.report(),
       Exit::Failure(err) => {
         eprintln!("{}", err);
-        ExitCode::FAILURE
+        ExitCode::from(EXIT_ERROR)
       },
     }
   }
 }
 
+/// Output format of a report.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+  /// Human readable text.
+  #[default]
+  Text,
+  /// JSON formatted output.
+  Json,
+  /// CycloneDX 1.6 Cryptographic Bill of Materials.
+  Cbom,
+  /// SARIF 2.1.0 for code scanning tools.
+  Sarif,
+}
+
 /// Output verbosity level.
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Verbosity {
@@ -60,85 +94,1338 @@ impl Verbosity {
   }
 }
 
+/// The reasoning behind the assessment of a single primitive.
+#[derive(Clone, Serialize)]
+pub struct Finding {
+  #[serde(flatten)]
+  status: Status,
+  security: Security,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  citation: Option<Citation>,
+}
+
+impl<T> From<&Verdict<T>> for Finding {
+  fn from(verdict: &Verdict<T>) -> Self {
+    Self {
+      status: verdict.status(),
+      security: verdict.security(),
+      citation: verdict.citation(),
+    }
+  }
+}
+
+impl Display for Finding {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self.citation {
+      Some(citation) => write!(f, "{}, see {}", self.status, citation),
+      None => write!(f, "{}", self.status),
+    }
+  }
+}
+
+/// Where the findings of an audit come from.
+///
+/// This is usually a file but may also be what stands in for one, such
+/// as a server or a list of algorithms given on the command line.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub enum Location {
+  #[serde(rename = "path")]
+  File(PathBuf),
+  #[serde(rename = "target")]
+  Target(String),
+}
+
+impl Location {
+  /// Returns the location as expected in SARIF results where only files
+  /// are physical locations.
+  fn to_sarif(&self, line: Option<usize>) -> Value {
+    match self {
+      Self::File(path) => {
+        let mut location = json!({
+          "physicalLocation": { "artifactLocation": { "uri": artifact_uri(path) } },
+        });
+        if let Some(line) = line {
+          location["physicalLocation"]["region"] = json!({ "startLine": line });
+        }
+        location
+      },
+      Self::Target(target) => json!({ "logicalLocations": [{ "name": target }] }),
+    }
+  }
+}
+
+impl Display for Location {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::File(path) => write!(f, "{}", path.display()),
+      Self::Target(target) => write!(f, "{}", target),
+    }
+  }
+}
+
+impl From<&Path> for Location {
+  fn from(path: &Path) -> Self {
+    Self::File(path.to_path_buf())
+  }
+}
+
+impl From<&PathBuf> for Location {
+  fn from(path: &PathBuf) -> Self {
+    Self::File(path.clone())
+  }
+}
+
 /// Represents an audit of a single key.
-#[derive(Serialize)]
+///
+/// The audit of a certificate chain holds the audits of each of its
+/// certificates and otherwise mirrors the audit of its weakest link.
+#[derive(Clone, Serialize)]
 pub struct Audit {
   passed: bool,
-  path: PathBuf,
+  #[serde(flatten)]
+  location: Location,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  position: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  subject: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  line: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  entry: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   got_hash_function: Option<Hash>,
   #[serde(skip_serializing_if = "Option::is_none")]
   want_hash_function: Option<Hash>,
-  got_signature: Asymmetric,
-  want_signature: Asymmetric,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  hash_function_verdict: Option<Finding>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  got_mask_hash_function: Option<Hash>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  want_mask_hash_function: Option<Hash>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  mask_hash_function_verdict: Option<Finding>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  got_salt_length: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  want_salt_length: Option<u32>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  salt_length_verdict: Option<Finding>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  got_signature: Option<Asymmetric>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  want_signature: Option<Asymmetric>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  signature_verdict: Option<Finding>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  got_issuer_signature: Option<Asymmetric>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  want_issuer_signature: Option<Asymmetric>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  issuer_signature_verdict: Option<Finding>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  protection: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  got_key_derivation: Option<KeyDerivation>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  want_key_derivation: Option<KeyDerivation>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  key_derivation_verdict: Option<Finding>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  got_key_encryption: Option<Symmetric>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  want_key_encryption: Option<Symmetric>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  key_encryption_verdict: Option<Finding>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  ssh_certificate: Option<SshCertificate>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  not_before: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  not_after: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  validity: Option<Period>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  cutoff_year: Option<u16>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  weakest_link: Option<usize>,
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  chain: Vec<Audit>,
+  #[serde(skip)]
+  usage: Usage,
 }
 
 impl Audit {
-  pub fn new(path: &Path, hash: Option<Hash>, signature: Asymmetric) -> Self {
+  pub fn new(
+    location: impl Into<Location>,
+    hash: Option<Hash>,
+    signature: Option<Asymmetric>,
+  ) -> Self {
     Self {
       passed: true,
-      path: path.to_path_buf(),
+      location: location.into(),
+      position: None,
+      subject: None,
+      line: None,
+      entry: None,
       got_hash_function: hash,
       want_hash_function: None,
+      hash_function_verdict: None,
+      got_mask_hash_function: None,
+      want_mask_hash_function: None,
+      mask_hash_function_verdict: None,
+      got_salt_length: None,
+      want_salt_length: None,
+      salt_length_verdict: None,
       got_signature: signature,
       want_signature: signature,
+      signature_verdict: None,
+      got_issuer_signature: None,
+      want_issuer_signature: None,
+      issuer_signature_verdict: None,
+      protection: None,
+      got_key_derivation: None,
+      want_key_derivation: None,
+      key_derivation_verdict: None,
+      got_key_encryption: None,
+      want_key_encryption: None,
+      key_encryption_verdict: None,
+      ssh_certificate: None,
+      not_before: None,
+      not_after: None,
+      validity: None,
+      cutoff_year: None,
+      weakest_link: None,
+      chain: Vec::new(),
+      usage: Usage::Unspecified,
     }
   }
 
-  pub fn noncompliant_hash_function(&mut self, want: Hash) {
-    self.passed = false;
-    self.want_hash_function = Some(want);
+  /// Records the purpose the key was assessed for.
+  pub fn with_usage(mut self, usage: Usage) -> Self {
+    self.usage = usage;
+    self
   }
 
-  pub fn compliant_hash_function(&mut self, want: Hash) {
-    self.want_hash_function = Some(want);
+  /// Combines the audits of the certificates in a chain into a single
+  /// audit which passes only if every certificate passes.
+  ///
+  /// The weakest link is the first certificate that fails or, if all
+  /// of them pass, the first one with the lowest security.
+  pub fn from_chain(location: impl Into<Location>, links: Vec<Audit>) -> Self {
+    let weakest_link = links
+      .iter()
+      .enumerate()
+      .min_by_key(|(_, link)| (link.passed, link.security()))
+      .map(|(i, _)| i)
+      .expect("chain should not be empty");
+    let mut audit = links[weakest_link].clone();
+    audit.location = location.into();
+    audit.passed = links.iter().all(|link| link.passed);
+    audit.weakest_link = Some(weakest_link);
+    audit.chain = links;
+    audit
   }
 
-  pub fn noncompliant_signature(&mut self, want: Asymmetric) {
-    self.passed = false;
-    self.want_signature = want;
+  /// Records the position of a certificate in its chain, starting from
+  /// zero, along with its subject.
+  pub fn in_chain(mut self, position: usize, subject: &str) -> Self {
+    self.position = Some(position);
+    self.subject = Some(subject.to_string());
+    self
+  }
+
+  /// Records the line of a file listing many keys, such as an
+  /// `authorized_keys` file, along with what identifies the key there.
+  pub fn at_line(mut self, line: usize, entry: &str) -> Self {
+    self.line = Some(line);
+    self.entry = Some(entry.to_string());
+    self
+  }
+
+  /// Returns the location of the key with its line, if any, and what
+  /// identifies it there.
+  fn location(&self) -> String {
+    match (self.line, &self.entry) {
+      (Some(line), Some(entry)) if !entry.is_empty() => {
+        format!("{}:{} ({})", self.location, line, entry)
+      },
+      (Some(line), _) => format!("{}:{}", self.location, line),
+      _ => self.location.to_string(),
+    }
+  }
+
+  /// Returns the lowest security of the assessed primitives.
+  fn security(&self) -> Security {
+    [
+      &self.hash_function_verdict,
+      &self.mask_hash_function_verdict,
+      &self.signature_verdict,
+      &self.issuer_signature_verdict,
+    ]
+    .into_iter()
+    .flatten()
+    .map(|finding| finding.security)
+    .min()
+    .unwrap_or(Security::MAX)
+  }
+
+  /// Records the algorithm the issuer signed the key with.
+  pub fn with_issuer_signature(mut self, signature: Asymmetric) -> Self {
+    self.got_issuer_signature = Some(signature);
+    self
+  }
+
+  /// Records the parameters of an RSASSA-PSS issuer signature.
+  pub fn with_pss_parameters(mut self, parameters: PssParameters) -> Self {
+    self.got_mask_hash_function = Some(parameters.mask_hash);
+    self.got_salt_length = Some(parameters.salt_length);
+    self
+  }
+
+  /// Records the scheme used to encrypt a private key along with the
+  /// primitives it is built on.
+  pub fn with_protection(mut self, protection: &Protection) -> Self {
+    self.protection = Some(protection.scheme.clone());
+    self.got_key_derivation = protection.kdf;
+    self.got_key_encryption = protection.cipher;
+    self
+  }
+
+  pub fn assess_hash_function(&mut self, verdict: Verdict<Hash>) {
+    self.passed &= verdict.is_compliant();
+    self.hash_function_verdict = Some(Finding::from(&verdict));
+    self.want_hash_function = Some(verdict.recommendation());
+  }
+
+  pub fn assess_mask_hash_function(&mut self, verdict: Verdict<Hash>) {
+    self.passed &= verdict.is_compliant();
+    self.mask_hash_function_verdict = Some(Finding::from(&verdict));
+    self.want_mask_hash_function = Some(verdict.recommendation());
   }
 
-  pub fn compliant_signature(&mut self, want: Asymmetric) {
-    self.want_signature = want;
+  pub fn assess_salt_length(&mut self, verdict: Verdict<u32>) {
+    self.passed &= verdict.is_compliant();
+    self.salt_length_verdict = Some(Finding::from(&verdict));
+    self.want_salt_length = Some(verdict.recommendation());
+  }
+
+  pub fn assess_signature(&mut self, verdict: Verdict<Asymmetric>) {
+    self.passed &= verdict.is_compliant();
+    self.signature_verdict = Some(Finding::from(&verdict));
+    self.want_signature = Some(verdict.recommendation());
+  }
+
+  pub fn assess_issuer_signature(&mut self, verdict: Verdict<Asymmetric>) {
+    self.passed &= verdict.is_compliant();
+    self.issuer_signature_verdict = Some(Finding::from(&verdict));
+    self.want_issuer_signature = Some(verdict.recommendation());
+  }
+
+  pub fn assess_key_derivation(&mut self, verdict: Verdict<KeyDerivation>) {
+    self.passed &= verdict.is_compliant();
+    self.key_derivation_verdict = Some(Finding::from(&verdict));
+    self.want_key_derivation = Some(verdict.recommendation());
+  }
+
+  pub fn assess_key_encryption(&mut self, verdict: Verdict<Symmetric>) {
+    self.passed &= verdict.is_compliant();
+    self.key_encryption_verdict = Some(Finding::from(&verdict));
+    self.want_key_encryption = Some(verdict.recommendation());
+  }
+
+  /// Records the restrictions of an OpenSSH certificate.
+  pub fn with_ssh_certificate(mut self, certificate: &SshCertificate) -> Self {
+    self.ssh_certificate = Some(certificate.clone());
+    self
+  }
+
+  /// Records the validity period of a certificate and fails the audit
+  /// if the certificate is not valid at the given time.
+  pub fn assess_validity(&mut self, validity: Validity, at: ASN1Time) {
+    let period = if validity.is_expired(at) {
+      Period::Expired
+    } else if validity.is_premature(at) {
+      Period::NotYetValid
+    } else {
+      Period::Current
+    };
+    self.passed &= period == Period::Current;
+    self.not_before = Some(validity.not_before.to_string());
+    self.not_after = Some(validity.not_after.to_string());
+    self.validity = Some(period);
+  }
+
+  /// Records the year after which some primitive of a certificate is
+  /// deprecated even though the certificate remains valid.
+  pub fn exceeds_cutoff(&mut self, year: u16) {
+    self.cutoff_year = Some(year);
   }
 }
 
-impl Display for Audit {
+/// Whether a certificate is valid at the time of the audit.
+#[derive(Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Period {
+  Current,
+  Expired,
+  NotYetValid,
+}
+
+impl Display for Period {
   fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Current => write!(f, "current"),
+      Self::Expired => write!(f, "expired"),
+      Self::NotYetValid => write!(f, "not yet valid"),
+    }
+  }
+}
+
+impl Audit {
+  /// Describes the assessment of each primitive on a separate line.
+  fn details(&self) -> String {
     let mut s = String::new();
     if let (Some(got), Some(want)) = (self.got_hash_function, self.want_hash_function) {
-      s.push_str(format!("hash function: got {}, want {}\n", got, want).as_str());
+      s.push_str(format!("hash function: got {}, want {}", got, want).as_str());
+      if let Some(finding) = &self.hash_function_verdict {
+        s.push_str(format!(" ({})", finding).as_str());
+      }
+      s.push('\n');
+    }
+    if let (Some(got), Some(want)) = (self.got_mask_hash_function, self.want_mask_hash_function) {
+      s.push_str(format!("mask generation hash function: got {}, want {}", got, want).as_str());
+      if let Some(finding) = &self.mask_hash_function_verdict {
+        s.push_str(format!(" ({})", finding).as_str());
+      }
+      s.push('\n');
+    }
+    if let (Some(got), Some(want)) = (self.got_salt_length, self.want_salt_length) {
+      s.push_str(format!("salt length: got {}, want {}", got, want).as_str());
+      if let Some(finding) = &self.salt_length_verdict {
+        s.push_str(format!(" ({})", finding).as_str());
+      }
+      s.push('\n');
+    }
+    match (self.got_signature, self.want_signature) {
+      (Some(got), Some(want)) => {
+        s.push_str(format!("signature algorithm: got {}, want {}", got, want).as_str());
+        if let Some(finding) = &self.signature_verdict {
+          s.push_str(format!(" ({})", finding).as_str());
+        }
+        s.push('\n');
+      },
+      _ => s.push_str("signature algorithm: unknown without the passphrase\n"),
+    }
+    if let (Some(got), Some(want)) = (self.got_issuer_signature, self.want_issuer_signature) {
+      s.push_str(format!("issuer signature: got {}, want {}", got, want).as_str());
+      if let Some(finding) = &self.issuer_signature_verdict {
+        s.push_str(format!(" ({})", finding).as_str());
+      }
+      s.push('\n');
+    }
+    if let Some(scheme) = &self.protection {
+      s.push_str(format!("key protection: {}\n", scheme).as_str());
+    }
+    if let (Some(got), Some(want)) = (self.got_key_derivation, self.want_key_derivation) {
+      s.push_str(format!("key derivation: got {}, want {}", got, want).as_str());
+      if let Some(finding) = &self.key_derivation_verdict {
+        s.push_str(format!(" ({})", finding).as_str());
+      }
+      s.push('\n');
+    }
+    if let (Some(got), Some(want)) = (self.got_key_encryption, self.want_key_encryption) {
+      s.push_str(format!("key encryption: got {}, want {}", got, want).as_str());
+      if let Some(finding) = &self.key_encryption_verdict {
+        s.push_str(format!(" ({})", finding).as_str());
+      }
+      s.push('\n');
+    }
+    if let Some(certificate) = &self.ssh_certificate {
+      s.push_str(
+        format!(
+          "ssh certificate: {} certificate \"{}\" signed with {}\n",
+          certificate.kind, certificate.key_id, certificate.signature
+        )
+        .as_str(),
+      );
+      let principals = if certificate.principals.is_empty() {
+        "any".to_string()
+      } else {
+        certificate.principals.join(", ")
+      };
+      s.push_str(format!("principals: {}\n", principals).as_str());
+      let options = if certificate.critical_options.is_empty() {
+        "none".to_string()
+      } else {
+        certificate.critical_options.join(", ")
+      };
+      s.push_str(format!("critical options: {}\n", options).as_str());
+    }
+    if let (Some(not_before), Some(not_after), Some(period)) =
+      (&self.not_before, &self.not_after, self.validity)
+    {
+      s.push_str(format!("validity: {} to {} ({}", not_before, not_after, period).as_str());
+      if let Some(year) = self.cutoff_year {
+        s.push_str(format!(", beyond the {} cutoff", year).as_str());
+      }
+      s.push_str(")\n");
+    }
+    s
+  }
+}
+
+impl Display for Audit {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let mut s = String::new();
+    if self.chain.is_empty() {
+      s.push_str(&self.details());
+    }
+    for link in self.chain.iter() {
+      if let (Some(position), Some(subject)) = (link.position, &link.subject) {
+        s.push_str(format!("certificate {}: {}\n", position, subject).as_str());
+      }
+      for line in link.details().lines() {
+        s.push_str(format!("  {}\n", line).as_str());
+      }
+    }
+    if let (Some(position), Some(subject)) = (self.weakest_link, &self.subject) {
+      s.push_str(format!("weakest link: certificate {} ({})\n", position, subject).as_str());
     }
-    s.push_str(
-      format!(
-        "signature algorithm: got {}, want {}\n",
-        self.got_signature, self.want_signature
-      )
-      .as_str(),
-    );
     if self.passed {
-      s.push_str(format!("ok: {}", self.path.display()).as_str());
+      s.push_str(format!("ok: {}", self.location()).as_str());
     } else {
-      s.push_str(format!("fail: {}", self.path.display()).as_str());
+      s.push_str(format!("fail: {}", self.location()).as_str());
     }
     write!(f, "{s}")
   }
 }
 
+impl Audit {
+  /// Describes the audited key as a CycloneDX cryptographic asset with
+  /// the hash function, if any, as a nested component.
+  ///
+  /// Each certificate of a chain is described as a separate asset.
+  fn to_cbom_components(&self) -> Vec<Value> {
+    if !self.chain.is_empty() {
+      return self
+        .chain
+        .iter()
+        .flat_map(Audit::to_cbom_components)
+        .collect();
+    }
+    let location = &self.location;
+    let reference = match (self.position, self.line) {
+      (Some(position), _) => format!("{}#{}", location, position),
+      (None, Some(line)) => format!("{}:{}", location, line),
+      (None, None) => location.to_string(),
+    };
+    let got = match self.got_signature {
+      Some(got) => got,
+      // The primitives protecting a key that cannot be identified are
+      // described on their own.
+      None => return self.to_cbom_protection(&reference, location),
+    };
+    let mut component = cbom_asset(
+      &reference,
+      location,
+      &got.to_string(),
+      asymmetric_properties(got, self.usage),
+      got.security(),
+      self.signature_verdict.as_ref(),
+      &self.want_signature.unwrap_or(got).to_string(),
+    );
+    if let (Some(not_after), Some(period)) = (&self.not_after, self.validity) {
+      let properties = component["properties"].as_array_mut().expect("properties");
+      properties.push(json!({ "name": "wardstone:notValidAfter", "value": not_after }));
+      properties.push(json!({ "name": "wardstone:validity", "value": period.to_string() }));
+    }
+    if let Some(got) = self.got_hash_function {
+      let want = self.want_hash_function.unwrap_or(got).to_string();
+      let properties = json!({
+        "primitive": "hash",
+        "parameterSetIdentifier": got.n.to_string(),
+        "cryptoFunctions": ["digest"],
+      });
+      let hash = cbom_asset(
+        &format!("{}#hash", reference),
+        location,
+        &got.to_string(),
+        properties,
+        got.security(),
+        self.hash_function_verdict.as_ref(),
+        &want,
+      );
+      component["components"] = json!([hash]);
+    }
+    let mut components = Vec::new();
+    if let Some(got) = self.got_issuer_signature {
+      let want = self.want_issuer_signature.unwrap_or(got).to_string();
+      components.push(cbom_asset(
+        &format!("{}#signature", reference),
+        location,
+        &got.to_string(),
+        asymmetric_properties(got, Usage::DigitalSignature),
+        got.security(),
+        self.issuer_signature_verdict.as_ref(),
+        &want,
+      ));
+    }
+    components.extend(self.to_cbom_protection(&reference, location));
+    if !components.is_empty() {
+      match component["components"].as_array_mut() {
+        Some(nested) => nested.extend(components),
+        None => component["components"] = json!(components),
+      }
+    }
+    if let Some(line) = self.line {
+      component["evidence"]["occurrences"][0]["line"] = json!(line);
+    }
+    vec![component]
+  }
+
+  /// Describes the key derivation function and cipher encrypting a
+  /// private key as CycloneDX cryptographic assets.
+  fn to_cbom_protection(&self, reference: &str, location: &Location) -> Vec<Value> {
+    let mut components = Vec::new();
+    if let Some(got) = self.got_key_derivation {
+      let want = self.want_key_derivation.unwrap_or(got).to_string();
+      let parameters = match got {
+        KeyDerivation::Hash(hash) => hash.n.to_string(),
+        KeyDerivation::Kdf(kdf) => kdf.key.to_string(),
+        KeyDerivation::Phf(phf) => phf.to_string(),
+      };
+      let properties = json!({
+        "primitive": "kdf",
+        "parameterSetIdentifier": parameters,
+        "cryptoFunctions": ["keyderive"],
+      });
+      components.push(cbom_asset(
+        &format!("{}#kdf", reference),
+        location,
+        &got.to_string(),
+        properties,
+        got.security(),
+        self.key_derivation_verdict.as_ref(),
+        &want,
+      ));
+    }
+    if let Some(got) = self.got_key_encryption {
+      let want = self.want_key_encryption.unwrap_or(got).to_string();
+      let properties = json!({
+        "primitive": "block-cipher",
+        "cryptoFunctions": ["encrypt", "decrypt"],
+      });
+      components.push(cbom_asset(
+        &format!("{}#cipher", reference),
+        location,
+        &got.to_string(),
+        properties,
+        got.security(),
+        self.key_encryption_verdict.as_ref(),
+        &want,
+      ));
+    }
+    components
+  }
+}
+
+/// The kinds of failures reported as SARIF rules by their identifier
+/// and description.
+const SARIF_RULES: [(&str, &str); 13] = [
+  (
+    "weak-hash-function",
+    "The hash function does not comply with the guide.",
+  ),
+  (
+    "weak-mask-hash-function",
+    "The hash function of the RSASSA-PSS mask generation function does not comply with the guide.",
+  ),
+  (
+    "non-conformant-salt-length",
+    "The RSASSA-PSS salt length does not comply with the guide.",
+  ),
+  (
+    "weak-signature-key",
+    "The signature algorithm or key size does not comply with the guide.",
+  ),
+  (
+    "disallowed-curve",
+    "The elliptic curve does not comply with the guide.",
+  ),
+  (
+    "weak-issuer-signature",
+    "The algorithm or key size the issuer signed with does not comply with the guide.",
+  ),
+  (
+    "weak-key-derivation",
+    "The key derivation protecting a private key does not comply with the guide.",
+  ),
+  (
+    "weak-key-encryption",
+    "The cipher protecting a private key does not comply with the guide.",
+  ),
+  ("expired-certificate", "The certificate has expired."),
+  (
+    "not-yet-valid-certificate",
+    "The certificate is not valid yet.",
+  ),
+  (
+    "validity-beyond-cutoff",
+    "The certificate remains valid after the guide deprecates its primitives.",
+  ),
+  (
+    "weak-algorithm",
+    "An algorithm allowed by the setting does not comply with the guide.",
+  ),
+  (
+    "unrecognised-algorithm",
+    "The primitives of an algorithm allowed by the setting could not be identified.",
+  ),
+];
+
+impl Audit {
+  /// Describes each non-compliant primitive of the audit as a SARIF
+  /// result.
+  ///
+  /// SARIF fixes must describe concrete edits to the artifact so the
+  /// recommended primitive is given in the message and the properties
+  /// of the result instead.
+  fn to_sarif_results(&self) -> Vec<Value> {
+    if !self.chain.is_empty() {
+      return self
+        .chain
+        .iter()
+        .flat_map(Audit::to_sarif_results)
+        .collect();
+    }
+    let link = match (self.position, &self.subject, &self.entry) {
+      (Some(position), Some(subject), _) => format!("certificate {} ({}): ", position, subject),
+      (None, _, Some(entry)) if !entry.is_empty() => format!("{}: ", entry),
+      _ => String::new(),
+    };
+    let mut results = Vec::new();
+    if let (Some(got), Some(want), Some(finding)) = (
+      self.got_hash_function,
+      self.want_hash_function,
+      &self.hash_function_verdict,
+    ) {
+      if !finding.status.is_compliant() {
+        let message = format!(
+          "{}hash function {} is not compliant ({}), use {} instead",
+          link, got, finding, want
+        );
+        results.push(sarif_result(
+          "weak-hash-function",
+          finding,
+          &message,
+          &self.location,
+          &want.to_string(),
+        ));
+      }
+    }
+    if let (Some(got), Some(want), Some(finding)) = (
+      self.got_mask_hash_function,
+      self.want_mask_hash_function,
+      &self.mask_hash_function_verdict,
+    ) {
+      if !finding.status.is_compliant() {
+        let message = format!(
+          "{}mask generation hash function {} is not compliant ({}), use {} instead",
+          link, got, finding, want
+        );
+        results.push(sarif_result(
+          "weak-mask-hash-function",
+          finding,
+          &message,
+          &self.location,
+          &want.to_string(),
+        ));
+      }
+    }
+    if let (Some(got), Some(want), Some(finding)) = (
+      self.got_salt_length,
+      self.want_salt_length,
+      &self.salt_length_verdict,
+    ) {
+      if !finding.status.is_compliant() {
+        let message = format!(
+          "{}salt length of {} bytes is not compliant ({}), use {} bytes instead",
+          link, got, finding, want
+        );
+        results.push(sarif_result(
+          "non-conformant-salt-length",
+          finding,
+          &message,
+          &self.location,
+          &want.to_string(),
+        ));
+      }
+    }
+    if let (Some(got), Some(want), Some(finding)) = (
+      self.got_signature,
+      self.want_signature,
+      &self.signature_verdict,
+    ) {
+      if !finding.status.is_compliant() {
+        // A weak but recognised curve is no different from a short
+        // RSA key, so only curves the guide does not allow are singled
+        // out.
+        let rule = match (got, finding.status) {
+          (Asymmetric::Ecc(_), Status::Disallowed | Status::Unrecognised) => "disallowed-curve",
+          _ => "weak-signature-key",
+        };
+        let message = format!(
+          "{}signature algorithm {} is not compliant ({}), use {} instead",
+          link, got, finding, want
+        );
+        results.push(sarif_result(
+          rule,
+          finding,
+          &message,
+          &self.location,
+          &want.to_string(),
+        ));
+      }
+    }
+    if let (Some(got), Some(want), Some(finding)) = (
+      self.got_issuer_signature,
+      self.want_issuer_signature,
+      &self.issuer_signature_verdict,
+    ) {
+      if !finding.status.is_compliant() {
+        let message = format!(
+          "{}issuer signature {} is not compliant ({}), use {} instead",
+          link, got, finding, want
+        );
+        results.push(sarif_result(
+          "weak-issuer-signature",
+          finding,
+          &message,
+          &self.location,
+          &want.to_string(),
+        ));
+      }
+    }
+    if let (Some(got), Some(want), Some(finding)) = (
+      self.got_key_derivation,
+      self.want_key_derivation,
+      &self.key_derivation_verdict,
+    ) {
+      if !finding.status.is_compliant() {
+        let message = format!(
+          "{}key derivation using {} is not compliant ({}), use {} instead",
+          link, got, finding, want
+        );
+        results.push(sarif_result(
+          "weak-key-derivation",
+          finding,
+          &message,
+          &self.location,
+          &want.to_string(),
+        ));
+      }
+    }
+    if let (Some(got), Some(want), Some(finding)) = (
+      self.got_key_encryption,
+      self.want_key_encryption,
+      &self.key_encryption_verdict,
+    ) {
+      if !finding.status.is_compliant() {
+        let message = format!(
+          "{}key encryption using {} is not compliant ({}), use {} instead",
+          link, got, finding, want
+        );
+        results.push(sarif_result(
+          "weak-key-encryption",
+          finding,
+          &message,
+          &self.location,
+          &want.to_string(),
+        ));
+      }
+    }
+    if let (Some(not_before), Some(not_after), Some(period)) =
+      (&self.not_before, &self.not_after, self.validity)
+    {
+      let notice = match period {
+        Period::Current => None,
+        Period::Expired => Some(("expired-certificate", "expired on", not_after)),
+        Period::NotYetValid => Some((
+          "not-yet-valid-certificate",
+          "is not valid before",
+          not_before,
+        )),
+      };
+      if let Some((rule, event, time)) = notice {
+        let message = format!("{}certificate {} {}", link, event, time);
+        results.push(sarif_notice(rule, "error", &message, &self.location));
+      }
+      if let Some(year) = self.cutoff_year {
+        let message = format!(
+          "{}certificate is valid until {} which is beyond the {} cutoff of the guide",
+          link, not_after, year
+        );
+        let notice = sarif_notice(
+          "validity-beyond-cutoff",
+          "warning",
+          &message,
+          &self.location,
+        );
+        results.push(notice);
+      }
+    }
+    if let Some(line) = self.line {
+      for result in results.iter_mut() {
+        result["locations"][0]["physicalLocation"]["region"] = json!({ "startLine": line });
+      }
+    }
+    results
+  }
+}
+
+/// Returns a SARIF result for a non-compliant primitive.
+fn sarif_result(
+  rule: &str,
+  finding: &Finding,
+  message: &str,
+  location: &Location,
+  recommendation: &str,
+) -> Value {
+  let level = match finding.status {
+    Status::Legacy => "warning",
+    Status::Unrecognised => "note",
+    _ => "error",
+  };
+  let mut result = sarif_notice(rule, level, message, location);
+  result["properties"] = json!({
+    "status": finding.status.to_string(),
+    "recommendation": recommendation,
+  });
+  result
+}
+
+/// Returns a SARIF result that carries no recommendation.
+fn sarif_notice(rule: &str, level: &str, message: &str, location: &Location) -> Value {
+  json!({
+    "ruleId": rule,
+    "level": level,
+    "message": { "text": message },
+    "locations": [location.to_sarif(None)],
+  })
+}
+
+/// Returns the URI of a file as expected in SARIF artifact locations.
+fn artifact_uri(path: &Path) -> String {
+  let path = path.display().to_string().replace('\\', "/");
+  let path = path.replace('%', "%25").replace(' ', "%20");
+  if path.starts_with('/') {
+    format!("file://{}", path)
+  } else {
+    path
+  }
+}
+
+/// Returns the CycloneDX algorithm properties of an asymmetric key put
+/// to the given usage.
+///
+/// Keys that can only be used for key agreement, such as those of
+/// Diffie-Hellman groups and X25519, are described as such whatever
+/// the usage.
+fn asymmetric_properties(key: Asymmetric, usage: Usage) -> Value {
+  let key_agreement_only = match key {
+    Asymmetric::Ecc(ecc) => ecc.is_key_agreement_only(),
+    Asymmetric::Ffc(ffc) => ffc.is_key_agreement_only(),
+    _ => false,
+  };
+  let (primitive, functions) = match (key, usage) {
+    _ if key_agreement_only => ("key-agree", json!(["keygen"])),
+    (Asymmetric::Pqs(_), _) => ("signature", json!(["sign", "verify"])),
+    (Asymmetric::Ifc(_), Usage::KeyEstablishment | Usage::Encryption) => {
+      ("pke", json!(["encrypt", "decrypt"]))
+    },
+    (_, Usage::KeyEstablishment) => ("key-agree", json!(["keygen"])),
+    _ => ("signature", json!(["sign", "verify"])),
+  };
+  let mut properties = json!({
+    "primitive": primitive,
+    "cryptoFunctions": functions,
+    "nistQuantumSecurityLevel": 0,
+  });
+  match key {
+    Asymmetric::Ecc(ecc) => {
+      // Curves are displayed with all their aliases such as "nistp256,
+      // prime256v1, or secp256r1" of which the SECG name comes last.
+      let name = ecc.to_string();
+      let curve = name.rsplit([',', ' ']).next().unwrap_or(&name);
+      properties["curve"] = json!(curve);
+      properties["parameterSetIdentifier"] = json!(ecc.f.to_string());
+    },
+    Asymmetric::Ifc(ifc) => {
+      properties["parameterSetIdentifier"] = json!(ifc.k.to_string());
+    },
+    Asymmetric::Ffc(ffc) => {
+      properties["parameterSetIdentifier"] = json!(format!("{}-{}", ffc.l, ffc.n));
+    },
+    Asymmetric::Pqs(pqs) => {
+      properties["parameterSetIdentifier"] = json!(pqs.to_string());
+      properties["nistQuantumSecurityLevel"] = json!(pqs.category);
+    },
+  }
+  properties
+}
+
+/// Returns a CycloneDX cryptographic asset component for a primitive
+/// along with the verdict of the assessment as properties.
+///
+/// Only assets found in files carry evidence of where they occur.
+fn cbom_asset(
+  reference: &str,
+  location: &Location,
+  name: &str,
+  mut algorithm_properties: Value,
+  security: Security,
+  finding: Option<&Finding>,
+  recommendation: &str,
+) -> Value {
+  algorithm_properties["classicalSecurityLevel"] = json!(security);
+  let mut properties = vec![json!({
+    "name": "wardstone:recommendation",
+    "value": recommendation,
+  })];
+  if let Some(finding) = finding {
+    properties.push(json!({
+      "name": "wardstone:compliant",
+      "value": finding.status.is_compliant().to_string(),
+    }));
+    properties.push(json!({
+      "name": "wardstone:status",
+      "value": finding.status.to_string(),
+    }));
+    if let Some(citation) = finding.citation {
+      properties.push(json!({
+        "name": "wardstone:citation",
+        "value": citation.to_string(),
+      }));
+    }
+  }
+  let mut asset = json!({
+    "type": "cryptographic-asset",
+    "bom-ref": reference,
+    "name": name,
+    "cryptoProperties": {
+      "assetType": "algorithm",
+      "algorithmProperties": algorithm_properties,
+    },
+    "properties": properties,
+  });
+  if let Location::File(_) = location {
+    asset["evidence"] = json!({ "occurrences": [{ "location": location.to_string() }] });
+  }
+  asset
+}
+
+/// Represents an audit of the algorithms a setting allows such as the
+/// ciphers an SSH server accepts.
+///
+/// Each algorithm is assessed by way of the primitives it is built on
+/// and the setting passes only if all of them comply.
+#[derive(Clone, Serialize)]
+pub struct SettingAudit {
+  passed: bool,
+  #[serde(flatten)]
+  location: Location,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  line: Option<usize>,
+  setting: String,
+  algorithms: Vec<AlgorithmFinding>,
+}
+
+/// The assessment of an algorithm allowed by a setting.
+#[derive(Clone, Serialize)]
+struct AlgorithmFinding {
+  name: String,
+  passed: bool,
+  recognised: bool,
+  primitives: Vec<PrimitiveFinding>,
+  #[serde(skip)]
+  establishment: Option<Establishment>,
+}
+
+/// The way an algorithm allowed by a setting establishes keys, if that
+/// is all it does, such as the groups of a TLS server.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Establishment {
+  KeyAgreement,
+  Encapsulation,
+}
+
+/// The assessment of one of the primitives an algorithm is built on.
+#[derive(Clone, Serialize)]
+struct PrimitiveFinding {
+  got: String,
+  want: String,
+  verdict: Finding,
+}
+
+impl SettingAudit {
+  pub fn new(location: impl Into<Location>, line: Option<usize>, setting: &str) -> Self {
+    Self {
+      passed: true,
+      location: location.into(),
+      line,
+      setting: setting.to_string(),
+      algorithms: Vec::new(),
+    }
+  }
+
+  /// Returns the finding of an algorithm, adding it if it is not the
+  /// one that was last assessed.
+  fn algorithm(&mut self, name: &str) -> &mut AlgorithmFinding {
+    if self
+      .algorithms
+      .last()
+      .is_none_or(|algorithm| algorithm.name != name)
+    {
+      self.algorithms.push(AlgorithmFinding {
+        name: name.to_string(),
+        passed: true,
+        recognised: true,
+        primitives: Vec::new(),
+        establishment: None,
+      });
+    }
+    self
+      .algorithms
+      .last_mut()
+      .expect("algorithm was just added")
+  }
+
+  /// Records an algorithm the setting allows even if none of its
+  /// primitives end up being assessed.
+  pub fn allow(&mut self, algorithm: &str) {
+    self.algorithm(algorithm);
+  }
+
+  /// Records that an algorithm does nothing but establish keys.
+  pub fn establishes_keys(&mut self, algorithm: &str, establishment: Establishment) {
+    self.algorithm(algorithm).establishment = Some(establishment);
+  }
+
+  /// Records the assessment of one of the primitives of an algorithm.
+  pub fn assess<T: Copy + Display>(&mut self, algorithm: &str, got: T, verdict: Verdict<T>) {
+    let compliant = verdict.is_compliant();
+    self.passed &= compliant;
+    let algorithm = self.algorithm(algorithm);
+    algorithm.passed &= compliant;
+    algorithm.primitives.push(PrimitiveFinding {
+      got: got.to_string(),
+      want: verdict.recommendation().to_string(),
+      verdict: Finding::from(&verdict),
+    });
+  }
+
+  /// Records an algorithm that goes without a primitive for the given
+  /// purpose which fails it outright.
+  pub fn absent(&mut self, algorithm: &str, usage: Usage) {
+    self.invalid(algorithm, &format!("no {}", usage), &usage.to_string());
+  }
+
+  /// Records a property of an algorithm that falls short of what is
+  /// wanted whatever the guide, such as a modulus that is not prime,
+  /// which fails it outright.
+  pub fn invalid(&mut self, algorithm: &str, got: &str, want: &str) {
+    self.passed = false;
+    let algorithm = self.algorithm(algorithm);
+    algorithm.passed = false;
+    algorithm.primitives.push(PrimitiveFinding {
+      got: got.to_string(),
+      want: want.to_string(),
+      verdict: Finding {
+        status: Status::Disallowed,
+        security: 0,
+        citation: None,
+      },
+    });
+  }
+
+  /// Records an algorithm whose primitives could not be identified.
+  ///
+  /// It does not fail the audit as it may well be compliant.
+  pub fn unrecognised(&mut self, algorithm: &str) {
+    self.algorithm(algorithm).recognised = false;
+  }
+
+  fn location(&self) -> String {
+    match self.line {
+      Some(line) => format!("{}:{} ({})", self.location, line, self.setting),
+      None => format!("{} ({})", self.location, self.setting),
+    }
+  }
+
+  /// Describes each non-compliant primitive and unrecognised algorithm
+  /// as a SARIF result.
+  fn to_sarif_results(&self) -> Vec<Value> {
+    let mut results = Vec::new();
+    for algorithm in self.algorithms.iter() {
+      if !algorithm.recognised {
+        let message = format!(
+          "{} allows unrecognised algorithm {}",
+          self.setting, algorithm.name
+        );
+        results.push(sarif_notice(
+          "unrecognised-algorithm",
+          "note",
+          &message,
+          &self.location,
+        ));
+      }
+      for primitive in algorithm.primitives.iter() {
+        if primitive.verdict.status.is_compliant() {
+          continue;
+        }
+        let message = format!(
+          "{} allows {} whose {} is not compliant ({}), use {} instead",
+          self.setting, algorithm.name, primitive.got, primitive.verdict, primitive.want
+        );
+        results.push(sarif_result(
+          "weak-algorithm",
+          &primitive.verdict,
+          &message,
+          &self.location,
+          &primitive.want,
+        ));
+      }
+    }
+    if let Some(line) = self.line {
+      for result in results.iter_mut() {
+        result["locations"][0]["physicalLocation"]["region"] = json!({ "startLine": line });
+      }
+    }
+    results
+  }
+
+  /// Describes each algorithm as a CycloneDX cryptographic asset whose
+  /// verdict is that of its weakest primitive.
+  fn to_cbom_components(&self) -> Vec<Value> {
+    let location = &self.location;
+    let reference = match self.line {
+      Some(line) => format!("{}:{}", location, line),
+      None => location.to_string(),
+    };
+    self
+      .algorithms
+      .iter()
+      .map(|algorithm| {
+        let weakest = algorithm.primitives.iter().min_by_key(|primitive| {
+          (
+            primitive.verdict.status.is_compliant(),
+            primitive.verdict.security,
+          )
+        });
+        let properties = match algorithm.establishment {
+          Some(Establishment::KeyAgreement) => json!({
+            "primitive": "key-agree",
+            "cryptoFunctions": ["keygen"],
+          }),
+          Some(Establishment::Encapsulation) => json!({
+            "primitive": "kem",
+            "cryptoFunctions": ["keygen", "encapsulate", "decapsulate"],
+          }),
+          None => json!({ "primitive": "other" }),
+        };
+        cbom_asset(
+          &format!("{}#{}", reference, algorithm.name),
+          location,
+          &algorithm.name,
+          properties,
+          weakest.map_or(0, |primitive| primitive.verdict.security),
+          weakest.map(|primitive| &primitive.verdict),
+          weakest.map_or(algorithm.name.as_str(), |primitive| primitive.want.as_str()),
+        )
+      })
+      .collect()
+  }
+}
+
+impl SettingAudit {
+  /// Describes the assessment of each primitive on a separate line
+  /// followed by the outcome, leaving out compliant primitives unless
+  /// the output is verbose.
+  fn describe(&self, verbose: bool) -> String {
+    let mut s = String::new();
+    for algorithm in self.algorithms.iter() {
+      if !algorithm.recognised {
+        s.push_str(format!("{}: unrecognised\n", algorithm.name).as_str());
+      }
+      for primitive in algorithm.primitives.iter() {
+        if primitive.verdict.status.is_compliant() && !verbose {
+          continue;
+        }
+        s.push_str(
+          format!(
+            "{}: got {}, want {} ({})\n",
+            algorithm.name, primitive.got, primitive.want, primitive.verdict
+          )
+          .as_str(),
+        );
+      }
+    }
+    if self.passed {
+      s.push_str(format!("ok: {}", self.location()).as_str());
+    } else {
+      s.push_str(format!("fail: {}", self.location()).as_str());
+    }
+    s
+  }
+}
+
+/// Represents a key that could not be audited because it could not be
+/// read, parsed, or recognised.
+#[derive(Serialize)]
+pub struct Unreadable {
+  #[serde(flatten)]
+  location: Location,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  line: Option<usize>,
+  error: String,
+}
+
+impl Unreadable {
+  pub fn new(location: impl Into<Location>, err: &Error) -> Self {
+    Self {
+      location: location.into(),
+      line: None,
+      error: err.to_string(),
+    }
+  }
+}
+
+impl Display for Unreadable {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self.line {
+      Some(line) => write!(f, "error: {}:{}: {}", self.location, line, self.error),
+      None => write!(f, "error: {}: {}", self.location, self.error),
+    }
+  }
+}
+
 /// Status report of a series of key audits.
 pub struct Report {
   audits: Vec<Audit>,
+  settings: Vec<SettingAudit>,
+  errors: Vec<Unreadable>,
   verbosity: Verbosity,
-  json: bool,
+  format: Format,
 }
 
 impl Report {
-  pub fn new(verbosity: Verbosity, json: bool) -> Self {
+  pub fn new(verbosity: Verbosity, format: Format) -> Self {
     Self {
       audits: Vec::new(),
+      settings: Vec::new(),
+      errors: Vec::new(),
       verbosity,
-      json,
+      format,
     }
   }
 
@@ -146,6 +1433,23 @@ impl Report {
     self.audits.push(audit);
   }
 
+  pub fn push_setting(&mut self, audit: SettingAudit) {
+    self.settings.push(audit);
+  }
+
+  /// Records a key that could not be audited so that the remaining
+  /// keys can still be assessed.
+  pub fn push_error(&mut self, location: impl Into<Location>, err: &Error) {
+    self.errors.push(Unreadable::new(location, err));
+  }
+
+  /// Records a key on a given line of a file that could not be audited.
+  pub fn push_error_at(&mut self, location: impl Into<Location>, line: usize, err: &Error) {
+    let mut unreadable = Unreadable::new(location, err);
+    unreadable.line = Some(line);
+    self.errors.push(unreadable);
+  }
+
   pub fn to_json_string(&self) -> String {
     let mut v = Vec::new();
     for audit in self.audits.iter() {
@@ -160,7 +1464,110 @@ impl Report {
     // Partition by compliance status.
     let (mut v, failed): (Vec<_>, Vec<_>) = v.into_iter().partition(|a| a.passed);
     v.extend::<Vec<&Audit>>(failed);
-    json!({ "report": &v }).to_string()
+    let (mut settings, failed): (Vec<_>, Vec<_>) = self
+      .settings
+      .iter()
+      .filter(|audit| !audit.passed || self.verbosity.is_verbose())
+      .partition(|audit| audit.passed);
+    settings.extend(failed);
+    let mut report = json!({ "report": &v, "errors": &self.errors });
+    if !settings.is_empty() {
+      report["settings"] = json!(settings);
+    }
+    report.to_string()
+  }
+
+  /// Returns the non-compliant audits as a SARIF log.
+  ///
+  /// Keys that could not be read are reported as tool execution
+  /// notifications.
+  pub fn to_sarif_string(&self) -> String {
+    let rules: Vec<_> = SARIF_RULES
+      .iter()
+      .map(|(id, description)| {
+        json!({
+          "id": id,
+          "shortDescription": { "text": description },
+        })
+      })
+      .collect();
+    let results: Vec<_> = self
+      .audits
+      .iter()
+      .flat_map(Audit::to_sarif_results)
+      .chain(
+        self
+          .settings
+          .iter()
+          .flat_map(SettingAudit::to_sarif_results),
+      )
+      .collect();
+    let notifications: Vec<_> = self
+      .errors
+      .iter()
+      .map(|unreadable| {
+        json!({
+          "level": "error",
+          "message": { "text": unreadable.error },
+          "locations": [unreadable.location.to_sarif(unreadable.line)],
+        })
+      })
+      .collect();
+    json!({
+      "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+      "version": "2.1.0",
+      "runs": [{
+        "tool": {
+          "driver": {
+            "name": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+            "rules": rules,
+          },
+        },
+        "invocations": [{
+          "executionSuccessful": self.errors.is_empty(),
+          "toolExecutionNotifications": notifications,
+        }],
+        "results": results,
+      }],
+    })
+    .to_string()
+  }
+
+  /// Returns the audited keys as a CycloneDX Cryptographic Bill of
+  /// Materials.
+  ///
+  /// Unlike the other formats, all audits are included regardless of
+  /// the verbosity as the bill of materials is meant to be an
+  /// inventory.
+  pub fn to_cbom_string(&self) -> String {
+    let components: Vec<_> = self
+      .audits
+      .iter()
+      .flat_map(Audit::to_cbom_components)
+      .chain(
+        self
+          .settings
+          .iter()
+          .flat_map(SettingAudit::to_cbom_components),
+      )
+      .collect();
+    json!({
+      "bomFormat": "CycloneDX",
+      "specVersion": "1.6",
+      "version": 1,
+      "metadata": {
+        "tools": {
+          "components": [{
+            "type": "application",
+            "name": env!("CARGO_PKG_NAME"),
+            "version": env!("CARGO_PKG_VERSION"),
+          }],
+        },
+      },
+      "components": components,
+    })
+    .to_string()
   }
 }
 
@@ -179,25 +1586,352 @@ impl Display for Report {
         s.push_str(format!("{}\n", audit).as_str())
       }
     }
+    let (mut settings, failed): (Vec<_>, Vec<_>) =
+      self.settings.iter().partition(|audit| audit.passed);
+    settings.extend(failed);
+    for audit in settings.iter() {
+      if !audit.passed || self.verbosity.is_verbose() {
+        s.push_str(format!("{}\n", audit.describe(self.verbosity.is_verbose())).as_str());
+      }
+    }
+    for unreadable in self.errors.iter() {
+      s.push_str(format!("{}\n", unreadable).as_str())
+    }
     write!(f, "{}", s)
   }
 }
 
+impl Report {
+  /// Returns the exit code of the report where non-compliance takes
+  /// precedence over keys that could not be read.
+  fn exit_code(&self) -> u8 {
+    let failed = self.audits.iter().any(|audit| !audit.passed)
+      || self.settings.iter().any(|audit| !audit.passed);
+    if failed {
+      EXIT_NON_COMPLIANT
+    } else if !self.errors.is_empty() {
+      EXIT_ERROR
+    } else {
+      0
+    }
+  }
+}
+
 impl Termination for Report {
   fn report(self) -> ExitCode {
-    let (failed, _): (Vec<_>, Vec<_>) = self.audits.iter().partition(|audit| !audit.passed);
     if !self.verbosity.is_quiet() {
-      let repr = if self.json {
-        self.to_json_string()
-      } else {
-        format!("{}", self)
+      let repr = match self.format {
+        Format::Text => format!("{}", self),
+        Format::Json => self.to_json_string(),
+        Format::Cbom => self.to_cbom_string(),
+        Format::Sarif => self.to_sarif_string(),
       };
       print!("{}", repr)
     }
-    if failed.is_empty() {
-      ExitCode::SUCCESS
-    } else {
-      ExitCode::FAILURE
+    ExitCode::from(self.exit_code())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use wardstone_core::primitive::ecc::{P256, X25519};
+  use wardstone_core::primitive::ffc::FFDHE2048;
+  use wardstone_core::primitive::hash::SHA256;
+  use wardstone_core::primitive::ifc::{RSA_PKCS1_1024, RSA_PSS_2048};
+  use wardstone_core::primitive::kdf::PBKDF2_HMAC_SHA256;
+  use wardstone_core::primitive::kem::X25519MLKEM768;
+  use wardstone_core::primitive::symmetric::AES256;
+
+  use super::*;
+
+  fn audit(key: Asymmetric, verdict: Verdict<Asymmetric>) -> Audit {
+    let mut audit = Audit::new(Path::new("key.pem"), None, Some(key));
+    audit.assess_signature(verdict);
+    audit
+  }
+
+  fn compliant() -> Audit {
+    audit(
+      P256.into(),
+      Verdict::new(Status::Acceptable, P256.into(), 128),
+    )
+  }
+
+  fn non_compliant() -> Audit {
+    let verdict = Verdict::new(Status::Disallowed, RSA_PSS_2048.into(), 80);
+    audit(RSA_PKCS1_1024.into(), verdict)
+  }
+
+  fn unreadable() -> Error {
+    Error::Unrecognised("1.2.3.4".to_string())
+  }
+
+  #[test]
+  fn exit_code_is_success_if_all_audits_pass() {
+    let mut report = Report::new(Verbosity::Quiet, Format::Text);
+    report.push(compliant());
+    assert_eq!(report.exit_code(), 0);
+  }
+
+  #[test]
+  fn exit_code_is_non_compliant_if_any_audit_fails() {
+    let mut report = Report::new(Verbosity::Quiet, Format::Text);
+    report.push(compliant());
+    report.push(non_compliant());
+    assert_eq!(report.exit_code(), EXIT_NON_COMPLIANT);
+  }
+
+  #[test]
+  fn exit_code_is_error_if_a_key_is_unreadable() {
+    let mut report = Report::new(Verbosity::Quiet, Format::Text);
+    report.push(compliant());
+    report.push_error(Path::new("junk.pem"), &unreadable());
+    assert_eq!(report.exit_code(), EXIT_ERROR);
+  }
+
+  #[test]
+  fn exit_code_prefers_non_compliance_over_unreadable_keys() {
+    let mut report = Report::new(Verbosity::Quiet, Format::Text);
+    report.push(non_compliant());
+    report.push_error(Path::new("junk.pem"), &unreadable());
+    assert_eq!(report.exit_code(), EXIT_NON_COMPLIANT);
+  }
+
+  #[test]
+  fn exit_code_is_non_compliant_if_a_setting_fails() {
+    let mut report = Report::new(Verbosity::Quiet, Format::Text);
+    let mut setting = SettingAudit::new(Path::new("sshd_config"), Some(1), "Ciphers");
+    setting.absent("none", Usage::Encryption);
+    report.push_setting(setting);
+    assert_eq!(report.exit_code(), EXIT_NON_COMPLIANT);
+  }
+
+  // Primitives and cryptographic functions defined by CycloneDX 1.6.
+  const CBOM_PRIMITIVES: [&str; 15] = [
+    "drbg",
+    "mac",
+    "block-cipher",
+    "stream-cipher",
+    "signature",
+    "hash",
+    "pke",
+    "xof",
+    "kdf",
+    "key-agree",
+    "kem",
+    "ae",
+    "combiner",
+    "other",
+    "unknown",
+  ];
+  const CBOM_FUNCTIONS: [&str; 13] = [
+    "generate",
+    "keygen",
+    "encrypt",
+    "decrypt",
+    "digest",
+    "tag",
+    "keyderive",
+    "sign",
+    "verify",
+    "encapsulate",
+    "decapsulate",
+    "other",
+    "unknown",
+  ];
+
+  /// Checks that a component is a CycloneDX cryptographic asset and
+  /// returns it along with its nested components.
+  fn cbom_assets(component: &Value) -> Vec<&Value> {
+    assert_eq!(component["type"], "cryptographic-asset");
+    assert!(component["bom-ref"].is_string());
+    assert!(component["name"].is_string());
+    assert!(component["evidence"]["occurrences"][0]["location"].is_string());
+    let crypto = &component["cryptoProperties"];
+    assert_eq!(crypto["assetType"], "algorithm");
+    let algorithm = &crypto["algorithmProperties"];
+    let primitive = algorithm["primitive"].as_str().expect("primitive");
+    assert!(CBOM_PRIMITIVES.contains(&primitive), "{}", primitive);
+    for function in algorithm["cryptoFunctions"]
+      .as_array()
+      .unwrap_or(&Vec::new())
+    {
+      let function = function.as_str().expect("crypto function");
+      assert!(CBOM_FUNCTIONS.contains(&function), "{}", function);
+    }
+    for property in component["properties"].as_array().expect("properties") {
+      assert!(property["name"].is_string() && property["value"].is_string());
     }
+    let mut assets = vec![component];
+    if let Some(nested) = component["components"].as_array() {
+      assets.extend(nested.iter().flat_map(cbom_assets));
+    }
+    assets
+  }
+
+  fn primitive<'a>(assets: &[&'a Value], reference: &str) -> &'a Value {
+    let asset = assets
+      .iter()
+      .find(|asset| asset["bom-ref"] == reference)
+      .unwrap_or_else(|| panic!("no asset {}", reference));
+    &asset["cryptoProperties"]["algorithmProperties"]
+  }
+
+  #[test]
+  fn cbom_follows_cyclonedx() {
+    let mut report = Report::new(Verbosity::Normal, Format::Cbom);
+    let mut certificate = Audit::new(Path::new("cert.pem"), Some(SHA256), Some(P256.into()))
+      .with_usage(Usage::DigitalSignature);
+    certificate.assess_hash_function(Verdict::new(Status::Acceptable, SHA256, 128));
+    certificate.assess_signature(Verdict::new(Status::Acceptable, P256.into(), 128));
+    report.push(certificate);
+    report.push(audit(
+      X25519.into(),
+      Verdict::new(Status::Acceptable, X25519.into(), 128),
+    ));
+    report.push(
+      audit(
+        FFDHE2048.into(),
+        Verdict::new(Status::Acceptable, FFDHE2048.into(), 112),
+      )
+      .with_usage(Usage::KeyEstablishment),
+    );
+    report.push(
+      audit(
+        RSA_PSS_2048.into(),
+        Verdict::new(Status::Acceptable, RSA_PSS_2048.into(), 112),
+      )
+      .with_usage(Usage::Encryption),
+    );
+    let mut setting = SettingAudit::new(Path::new("server.conf"), None, "groups");
+    setting.allow("X25519MLKEM768");
+    setting.establishes_keys("X25519MLKEM768", Establishment::Encapsulation);
+    let verdict = Verdict::new(Status::Acceptable, X25519MLKEM768, 192);
+    setting.assess("X25519MLKEM768", X25519MLKEM768, verdict);
+    report.push_setting(setting);
+
+    let bom: Value = serde_json::from_str(&report.to_cbom_string()).unwrap();
+    assert_eq!(bom["bomFormat"], "CycloneDX");
+    assert_eq!(bom["specVersion"], "1.6");
+    assert_eq!(bom["version"], 1);
+    let tool = &bom["metadata"]["tools"]["components"][0];
+    assert_eq!(tool["type"], "application");
+    assert_eq!(tool["name"], "wardstone");
+
+    let components = bom["components"].as_array().expect("components");
+    let assets: Vec<_> = components.iter().flat_map(cbom_assets).collect();
+    assert_eq!(assets.len(), 6);
+
+    let signature = primitive(&assets, "cert.pem");
+    assert_eq!(signature["primitive"], "signature");
+    assert_eq!(signature["cryptoFunctions"], json!(["sign", "verify"]));
+    assert_eq!(signature["curve"], "secp256r1");
+    assert_eq!(primitive(&assets, "cert.pem#hash")["primitive"], "hash");
+    let x25519 = primitive(&assets, "key.pem");
+    assert_eq!(x25519["primitive"], "key-agree");
+    assert_eq!(x25519["cryptoFunctions"], json!(["keygen"]));
+    let kem = primitive(&assets, "server.conf#X25519MLKEM768");
+    assert_eq!(kem["primitive"], "kem");
+    assert_eq!(
+      kem["cryptoFunctions"],
+      json!(["keygen", "encapsulate", "decapsulate"])
+    );
+  }
+
+  #[test]
+  fn cbom_key_agreement_and_encryption_keys() {
+    let dh = asymmetric_properties(FFDHE2048.into(), Usage::Unspecified);
+    assert_eq!(dh["primitive"], "key-agree");
+    let ecdh = asymmetric_properties(P256.into(), Usage::KeyEstablishment);
+    assert_eq!(ecdh["primitive"], "key-agree");
+    let rsa = asymmetric_properties(RSA_PSS_2048.into(), Usage::Encryption);
+    assert_eq!(rsa["primitive"], "pke");
+    assert_eq!(rsa["cryptoFunctions"], json!(["encrypt", "decrypt"]));
+    let ecdsa = asymmetric_properties(P256.into(), Usage::Unspecified);
+    assert_eq!(ecdsa["primitive"], "signature");
+  }
+
+  #[test]
+  fn encrypted_key_without_algorithm() {
+    let protection = Protection {
+      scheme: "pbes2 with pbkdf2".to_string(),
+      kdf: Some(PBKDF2_HMAC_SHA256.into()),
+      cipher: Some(AES256),
+    };
+    let mut audit = Audit::new(Path::new("key.pem"), None, None).with_protection(&protection);
+    let verdict = Verdict::new(Status::Acceptable, PBKDF2_HMAC_SHA256.into(), 256);
+    audit.assess_key_derivation(verdict);
+    audit.assess_key_encryption(Verdict::new(Status::Acceptable, AES256, 256));
+    assert!(audit.passed);
+    let text = audit.to_string();
+    assert!(text.contains("signature algorithm: unknown without the passphrase"));
+    assert!(text.contains("key protection: pbes2 with pbkdf2"));
+    assert!(text.contains("key encryption: got "));
+
+    let mut report = Report::new(Verbosity::Normal, Format::Cbom);
+    report.push(audit);
+    let bom: Value = serde_json::from_str(&report.to_cbom_string()).unwrap();
+    let components = bom["components"].as_array().expect("components");
+    let assets: Vec<_> = components.iter().flat_map(cbom_assets).collect();
+    assert_eq!(assets.len(), 2);
+    assert_eq!(primitive(&assets, "key.pem#kdf")["primitive"], "kdf");
+    assert_eq!(
+      primitive(&assets, "key.pem#cipher")["primitive"],
+      "block-cipher"
+    );
+  }
+
+  #[test]
+  fn target_is_not_a_file() {
+    let target = Location::Target("example.com:443".to_string());
+    let mut setting = SettingAudit::new(target.clone(), None, "groups");
+    setting.absent("none", Usage::KeyEstablishment);
+    let mut report = Report::new(Verbosity::Normal, Format::Sarif);
+    report.push_setting(setting);
+    report.push_error(target, &unreadable());
+
+    let sarif: Value = serde_json::from_str(&report.to_sarif_string()).unwrap();
+    let run = &sarif["runs"][0];
+    let location = &run["results"][0]["locations"][0];
+    assert_eq!(location["logicalLocations"][0]["name"], "example.com:443");
+    assert!(location.get("physicalLocation").is_none());
+    let notification = &run["invocations"][0]["toolExecutionNotifications"][0];
+    assert!(notification["locations"][0]
+      .get("physicalLocation")
+      .is_none());
+
+    let json: Value = serde_json::from_str(&report.to_json_string()).unwrap();
+    assert_eq!(json["settings"][0]["target"], "example.com:443");
+    assert!(json["settings"][0].get("path").is_none());
+
+    let bom: Value = serde_json::from_str(&report.to_cbom_string()).unwrap();
+    for component in bom["components"].as_array().expect("components") {
+      assert!(component.get("evidence").is_none());
+    }
+  }
+
+  fn sarif_rule(audit: Audit) -> Value {
+    let mut report = Report::new(Verbosity::Normal, Format::Sarif);
+    report.push(audit);
+    let sarif: Value = serde_json::from_str(&report.to_sarif_string()).unwrap();
+    sarif["runs"][0]["results"][0]["ruleId"].clone()
+  }
+
+  #[test]
+  fn disallowed_curves_are_reported_as_such() {
+    let verdict = Verdict::new(Status::Disallowed, P256.into(), 128);
+    assert_eq!(
+      sarif_rule(audit(X25519.into(), verdict)),
+      "disallowed-curve"
+    );
+  }
+
+  #[test]
+  fn weak_curves_are_reported_as_weak_keys() {
+    let verdict = Verdict::new(Status::Legacy, P256.into(), 112);
+    assert_eq!(
+      sarif_rule(audit(P256.into(), verdict)),
+      "weak-signature-key"
+    );
   }
 }
@@ -0,0 +1,335 @@
+//! Discover keys in directory trees and identify their type from their
+//! contents.
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use openssl::pkcs7::Pkcs7;
+use walkdir::WalkDir;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use crate::key::Error;
+
+// Keys and certificates are small so larger files are skipped, and
+// reported as such, without reading them in their entirety.
+const MAX_FILE_SIZE: u64 = 1 << 20;
+
+const PEM_CERTIFICATE: &[u8] = b"-----BEGIN CERTIFICATE-----";
+
+const PEM_PKCS7: &[u8] = b"-----BEGIN PKCS7-----";
+
+const PEM_PRIVATE_KEY: &[u8] = b"PRIVATE KEY-----";
+
+// Prefixes of the key types of public keys and certificates which may
+// be preceded by options, markers or host patterns.
+const SSH_KEY_TYPES: [&str; 6] = [
+  "ecdsa-sha2-",
+  "sk-ecdsa-sha2-",
+  "sk-ssh-ed25519",
+  "ssh-dss",
+  "ssh-ed25519",
+  "ssh-rsa",
+];
+/// The type of key a file contains.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+  /// A PEM or DER encoded X.509 certificate, certificate bundle, or
+  /// PKCS#7 structure.
+  Certificate,
+  /// A PEM encoded private key.
+  PrivateKey,
+  /// An OpenSSH public key or certificate, or a file listing many of
+  /// them such as `authorized_keys` and `known_hosts`.
+  Ssh,
+}
+
+impl Kind {
+  /// Identifies the types of key in a file by looking at its contents.
+  ///
+  /// A file may hold more than one type of key such as a PEM file with
+  /// a certificate chain along with its private key. Returns an empty
+  /// list if the file does not appear to contain a supported key and
+  /// an error if it is too large to contain one.
+  pub fn sniff(path: &Path) -> Result<Vec<Kind>, Error> {
+    let file = File::open(path)?;
+    if file.metadata()?.len() > MAX_FILE_SIZE {
+      return Err(Error::TooLarge(MAX_FILE_SIZE));
+    }
+    let mut data = Vec::new();
+    file.take(MAX_FILE_SIZE).read_to_end(&mut data)?;
+    Ok(Self::from_bytes(&data))
+  }
+
+  fn from_bytes(data: &[u8]) -> Vec<Kind> {
+    let contains = |marker: &[u8]| data.windows(marker.len()).any(|window| window == marker);
+    let mut kinds = Vec::new();
+    if contains(PEM_CERTIFICATE) || contains(PEM_PKCS7) {
+      kinds.push(Self::Certificate);
+    }
+    // Labels such as "RSA PRIVATE KEY" and "ENCRYPTED PRIVATE KEY" all
+    // end the same way.
+    if contains(PEM_PRIVATE_KEY) {
+      kinds.push(Self::PrivateKey);
+    }
+    if !kinds.is_empty() {
+      return kinds;
+    }
+    if matches!(data, [0x30, 0x81..=0x83, ..])
+      && (X509Certificate::from_der(data).is_ok() || Pkcs7::from_der(data).is_ok())
+    {
+      return vec![Self::Certificate];
+    }
+    Self::ssh(data).into_iter().collect()
+  }
+
+  fn ssh(data: &[u8]) -> Option<Kind> {
+    let text = std::str::from_utf8(data).ok()?;
+    let line = text
+      .lines()
+      .map(str::trim)
+      .find(|line| !line.is_empty() && !line.starts_with('#'))?;
+    // Blobs start with the length of the key type which is encoded as
+    // "AAAA" for any reasonable length.
+    let fields: Vec<_> = line.split_whitespace().collect();
+    let is_ssh = fields.windows(2).any(|pair| {
+      SSH_KEY_TYPES
+        .iter()
+        .any(|prefix| pair[0].starts_with(prefix))
+        && pair[1].starts_with("AAAA")
+    });
+    if is_ssh {
+      Some(Self::Ssh)
+    } else {
+      None
+    }
+  }
+}
+
+/// Determines which files are visited when walking a directory.
+pub struct Filter {
+  include: GlobSet,
+  exclude: GlobSet,
+  follow_symlinks: bool,
+}
+
+impl Filter {
+  /// Creates a new filter.
+  ///
+  /// Patterns are matched against paths relative to the directory being
+  /// walked. If `include` is empty then all files are included. A
+  /// directory that matches an `exclude` pattern is not descended into.
+  /// Symbolic links are skipped unless `follow_symlinks` is set.
+  pub fn new(include: &[String], exclude: &[String], follow_symlinks: bool) -> Result<Self, Error> {
+    let filter = Self {
+      include: Self::glob_set(include)?,
+      exclude: Self::glob_set(exclude)?,
+      follow_symlinks,
+    };
+    Ok(filter)
+  }
+
+  fn glob_set(patterns: &[String]) -> Result<GlobSet, Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+      let glob = Glob::new(pattern).map_err(|err| Error::Pattern(err.to_string()))?;
+      builder.add(glob);
+    }
+    builder
+      .build()
+      .map_err(|err| Error::Pattern(err.to_string()))
+  }
+
+  /// Walks `root` and returns the files that pass the filter along with
+  /// the paths that could not be visited.
+  ///
+  /// If `root` is a file then it is returned as is.
+  pub fn walk(&self, root: &Path) -> Vec<Result<PathBuf, (PathBuf, Error)>> {
+    let walker = WalkDir::new(root)
+      .follow_links(self.follow_symlinks)
+      .sort_by_file_name()
+      .into_iter()
+      .filter_entry(|entry| {
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        entry.depth() == 0 || !self.exclude.is_match(relative)
+      });
+    let mut paths = Vec::new();
+    for entry in walker {
+      let entry = match entry {
+        Ok(entry) => entry,
+        Err(err) => {
+          let path = err.path().unwrap_or(root).to_path_buf();
+          paths.push(Err((path, io::Error::from(err).into())));
+          continue;
+        },
+      };
+      if !entry.file_type().is_file() {
+        continue;
+      }
+      let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+      if entry.depth() > 0 && !self.include.is_empty() && !self.include.is_match(relative) {
+        continue;
+      }
+      paths.push(Ok(entry.into_path()));
+    }
+    paths
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::testing::{read_fixture, TempDir};
+
+  fn relative_paths(filter: &Filter, root: &Path) -> Vec<String> {
+    filter
+      .walk(root)
+      .into_iter()
+      .map(|path| {
+        let path = path.expect("path should be visited");
+        let relative = path.strip_prefix(root).expect("path should be under root");
+        relative.display().to_string()
+      })
+      .collect()
+  }
+
+  fn tree(name: &str) -> TempDir {
+    let dir = TempDir::new(name);
+    dir.write("a.pem", b"");
+    dir.write("b.key", b"");
+    dir.write("certs/c.pem", b"");
+    dir.write("vendor/d.pem", b"");
+    dir
+  }
+
+  #[test]
+  fn from_bytes_pem_certificate() {
+    let data = read_fixture("leaf.pem");
+    assert_eq!(Kind::from_bytes(&data), [Kind::Certificate]);
+  }
+
+  #[test]
+  fn from_bytes_der_certificate() {
+    let data = read_fixture("leaf.der");
+    assert_eq!(Kind::from_bytes(&data), [Kind::Certificate]);
+  }
+
+  #[test]
+  fn from_bytes_pkcs7() {
+    let data = read_fixture("chain.p7b");
+    assert_eq!(Kind::from_bytes(&data), [Kind::Certificate]);
+  }
+
+  #[test]
+  fn from_bytes_private_key() {
+    let data = read_fixture("x25519.key");
+    assert_eq!(Kind::from_bytes(&data), [Kind::PrivateKey]);
+  }
+
+  #[test]
+  fn from_bytes_certificate_with_private_key() {
+    let data = read_fixture("leaf_with_key.pem");
+    assert_eq!(
+      Kind::from_bytes(&data),
+      [Kind::Certificate, Kind::PrivateKey]
+    );
+  }
+
+  #[test]
+  fn from_bytes_ssh() {
+    for name in ["authorized_keys", "known_hosts", "ssh_ssh-rsa-cert.pub"] {
+      let data = read_fixture(name);
+      assert_eq!(Kind::from_bytes(&data), [Kind::Ssh], "{}", name);
+    }
+  }
+
+  #[test]
+  fn from_bytes_junk() {
+    assert!(Kind::from_bytes(b"").is_empty());
+    assert!(Kind::from_bytes(b"# ssh-rsa AAAA\n").is_empty());
+    assert!(Kind::from_bytes(b"fn main() {}\n").is_empty());
+    assert!(Kind::from_bytes(&[0x30, 0x82, 0x01, 0x00, 0xff]).is_empty());
+  }
+
+  #[test]
+  fn sniff_reports_large_files() {
+    let dir = TempDir::new("sniff-large");
+    let mut data = read_fixture("leaf.pem");
+    data.resize(MAX_FILE_SIZE as usize + 1, b'\n');
+    let path = dir.write("large.pem", &data);
+    match Kind::sniff(&path) {
+      Err(Error::TooLarge(limit)) => assert_eq!(limit, MAX_FILE_SIZE),
+      got => panic!("got {:?}, want a file that is too large", got),
+    }
+  }
+
+  #[test]
+  fn filter_includes_everything_by_default() {
+    let dir = tree("filter-default");
+    let filter = Filter::new(&[], &[], false).unwrap();
+    let paths = relative_paths(&filter, dir.path());
+    assert_eq!(paths, ["a.pem", "b.key", "certs/c.pem", "vendor/d.pem"]);
+  }
+
+  #[test]
+  fn filter_include() {
+    let dir = tree("filter-include");
+    let filter = Filter::new(&["**/*.pem".to_string()], &[], false).unwrap();
+    let paths = relative_paths(&filter, dir.path());
+    assert_eq!(paths, ["a.pem", "certs/c.pem", "vendor/d.pem"]);
+  }
+
+  #[test]
+  fn filter_exclude_skips_directories() {
+    let dir = tree("filter-exclude");
+    let include = ["*.pem".to_string(), "**/*.pem".to_string()];
+    let filter = Filter::new(&include, &["vendor".to_string()], false).unwrap();
+    let paths = relative_paths(&filter, dir.path());
+    assert_eq!(paths, ["a.pem", "certs/c.pem"]);
+  }
+
+  #[test]
+  fn filter_returns_root_file() {
+    let dir = tree("filter-file");
+    let filter = Filter::new(&["*.key".to_string()], &[], false).unwrap();
+    let root = dir.path().join("a.pem");
+    let paths: Vec<_> = filter.walk(&root).into_iter().flatten().collect();
+    assert_eq!(paths, [root]);
+  }
+
+  #[test]
+  fn filter_invalid_pattern() {
+    assert!(matches!(
+      Filter::new(&["a{".to_string()], &[], false),
+      Err(Error::Pattern(_))
+    ));
+  }
+
+  #[cfg(unix)]
+  #[test]
+  fn filter_symlinks() {
+    let dir = tree("filter-symlinks");
+    let target = TempDir::new("filter-symlinks-target");
+    target.write("e.pem", b"");
+    std::os::unix::fs::symlink(target.path(), dir.path().join("linked")).unwrap();
+    std::os::unix::fs::symlink(dir.path().join("a.pem"), dir.path().join("f.pem")).unwrap();
+
+    let filter = Filter::new(&[], &[], false).unwrap();
+    let paths = relative_paths(&filter, dir.path());
+    assert_eq!(paths, ["a.pem", "b.key", "certs/c.pem", "vendor/d.pem"]);
+
+    let filter = Filter::new(&[], &[], true).unwrap();
+    let paths = relative_paths(&filter, dir.path());
+    assert_eq!(
+      paths,
+      [
+        "a.pem",
+        "b.key",
+        "certs/c.pem",
+        "f.pem",
+        "linked/e.pem",
+        "vendor/d.pem"
+      ]
+    );
+  }
+}
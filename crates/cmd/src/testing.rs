@@ -0,0 +1,49 @@
+//! Testing utilities.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Returns the path of a file generated by `generate_fixtures.sh`.
+pub fn fixture(name: &str) -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR"))
+    .join("src/testing/fixtures")
+    .join(name)
+}
+
+/// Reads a file generated by `generate_fixtures.sh`.
+pub fn read_fixture(name: &str) -> Vec<u8> {
+  fs::read(fixture(name)).expect("fixture should be readable")
+}
+
+/// A directory that is removed along with its contents when dropped.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+  /// Creates an empty directory whose name is unique to the test and
+  /// the process running it.
+  pub fn new(name: &str) -> Self {
+    let path = std::env::temp_dir().join(format!("wardstone-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).expect("temporary directory should be created");
+    Self(path)
+  }
+
+  pub fn path(&self) -> &Path {
+    &self.0
+  }
+
+  /// Writes a file relative to the directory creating its parents.
+  pub fn write(&self, name: &str, contents: &[u8]) -> PathBuf {
+    let path = self.0.join(name);
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent).expect("parent directory should be created");
+    }
+    fs::write(&path, contents).expect("file should be written");
+    path
+  }
+}
+
+impl Drop for TempDir {
+  fn drop(&mut self) {
+    let _ = fs::remove_dir_all(&self.0);
+  }
+}
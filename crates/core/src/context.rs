@@ -1,7 +1,57 @@
 //! Specifies the context in which a cryptographic primitive will be
 //! assessed against.
+use std::fmt::{self, Display, Formatter};
+
 use crate::primitive::Security;
 
+/// The purpose a cryptographic primitive is put to.
+///
+/// Standards may set different requirements for the same primitive
+/// depending on its use. A hash function used in a message
+/// authentication code, for example, only needs to offer pre-image
+/// resistance rather than collision resistance.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Usage {
+  /// The use is unknown in which case the strictest requirements apply.
+  #[default]
+  Unspecified,
+  DigitalSignature,
+  KeyEstablishment,
+  Encryption,
+  MessageAuthentication,
+  KeyDerivation,
+}
+
+impl Display for Usage {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Unspecified => write!(f, "unspecified"),
+      Self::DigitalSignature => write!(f, "digital signature"),
+      Self::KeyEstablishment => write!(f, "key establishment"),
+      Self::Encryption => write!(f, "encryption"),
+      Self::MessageAuthentication => write!(f, "message authentication"),
+      Self::KeyDerivation => write!(f, "key derivation"),
+    }
+  }
+}
+
+/// Whether a primitive is used to protect new data or to process data
+/// that has already been protected.
+///
+/// Most standards permit primitives that are no longer fit to apply
+/// protection to still be used for processing such as verifying
+/// existing signatures or decrypting stored ciphertext.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Operation {
+  /// Apply cryptographic protection such as signing or encrypting.
+  #[default]
+  Protect,
+  /// Process already protected data such as verifying or decrypting.
+  Process,
+}
+
 /// Represents the context in which a cryptographic primitive will be
 /// assessed against such as the year and minimum security required by
 /// the user.
@@ -10,6 +60,8 @@ use crate::primitive::Security;
 pub struct Context {
   security: Security,
   year: u16,
+  usage: Usage,
+  operation: Operation,
 }
 
 impl Context {
@@ -25,8 +77,31 @@ impl Context {
   /// to `0` then it will default to using the minimum security outlined
   /// in the standard. `year` is the year one expects the primitive to
   /// remain secure.
+  ///
+  /// The usage is unspecified and the primitive is assumed to be used
+  /// to apply protection.
   pub fn new(security: Security, year: u16) -> Self {
-    Self { security, year }
+    Self {
+      security,
+      year,
+      usage: Usage::default(),
+      operation: Operation::default(),
+    }
+  }
+
+  /// Returns a copy of the context with the intended usage set.
+  pub fn with_usage(self, usage: Usage) -> Self {
+    Self { usage, ..self }
+  }
+
+  /// Returns a copy of the context with the year set.
+  pub fn with_year(self, year: u16) -> Self {
+    Self { year, ..self }
+  }
+
+  /// Returns a copy of the context with the operation set.
+  pub fn with_operation(self, operation: Operation) -> Self {
+    Self { operation, ..self }
   }
 
   pub fn security(&self) -> Security {
@@ -36,6 +111,21 @@ impl Context {
   pub fn year(&self) -> u16 {
     self.year
   }
+
+  pub fn usage(&self) -> Usage {
+    self.usage
+  }
+
+  pub fn operation(&self) -> Operation {
+    self.operation
+  }
+
+  /// Whether the primitive is only used to process already protected
+  /// data in which case primitives reserved for legacy use are
+  /// acceptable.
+  pub fn is_processing(&self) -> bool {
+    self.operation == Operation::Process
+  }
 }
 
 impl Default for Context {
@@ -1,9 +1,21 @@
 //! Specifies a cryptographic primitive.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+
 pub mod asymmetric;
+pub mod drbg;
 pub mod ecc;
 pub mod ffc;
 pub mod hash;
 pub mod ifc;
+pub mod kdf;
+pub mod kem;
+pub mod mac;
+pub mod mode;
+pub mod phf;
+pub mod pqs;
 pub mod symmetric;
 
 /// The level of security of a symmetric cryptosystem which is a
@@ -15,3 +27,45 @@ pub type Security = u16;
 pub trait Primitive {
   fn security(&self) -> Security;
 }
+
+/// An error returned when a name does not correspond to any known
+/// instance of a primitive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsePrimitiveError(String);
+
+impl ParsePrimitiveError {
+  pub(crate) fn new(name: &str) -> Self {
+    Self(name.to_string())
+  }
+}
+
+impl Display for ParsePrimitiveError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "unrecognised primitive: {}", self.0)
+  }
+}
+
+impl Error for ParsePrimitiveError {}
+
+/// Finds the primitive called `name` in a lookup table that maps
+/// instances to their names ignoring case.
+///
+/// Names in the table may list aliases such as "nistp384 or secp384r1"
+/// in which case any one of the aliases will match.
+pub(crate) fn from_repr<T: Copy + Eq + Hash>(
+  repr: &HashMap<T, &str>,
+  name: &str,
+) -> Result<T, ParsePrimitiveError> {
+  let matches = |repr: &str| {
+    repr
+      .split(", ")
+      .flat_map(|alias| alias.split(" or "))
+      .map(|alias| alias.trim_start_matches("or "))
+      .any(|alias| alias.eq_ignore_ascii_case(name))
+  };
+  repr
+    .iter()
+    .find(|(_, &v)| matches(v))
+    .map(|(&k, _)| k)
+    .ok_or_else(|| ParsePrimitiveError::new(name))
+}
@@ -10,6 +10,7 @@ use serde::Serialize;
 use crate::primitive::ecc::Ecc;
 use crate::primitive::ffc::Ffc;
 use crate::primitive::ifc::Ifc;
+use crate::primitive::pqs::Pqs;
 use crate::primitive::{Primitive, Security};
 
 /// Represents an asymmetric key primitive.
@@ -18,6 +19,7 @@ pub enum Asymmetric {
   Ecc(Ecc),
   Ifc(Ifc),
   Ffc(Ffc),
+  Pqs(Pqs),
 }
 
 impl Primitive for Asymmetric {
@@ -26,6 +28,7 @@ impl Primitive for Asymmetric {
       Asymmetric::Ecc(ecc) => ecc.security(),
       Asymmetric::Ifc(ifc) => ifc.security(),
       Asymmetric::Ffc(ffc) => ffc.security(),
+      Asymmetric::Pqs(pqs) => pqs.security(),
     }
   }
 }
@@ -36,6 +39,7 @@ impl Display for Asymmetric {
       Asymmetric::Ecc(ecc) => ecc.fmt(f),
       Asymmetric::Ifc(ifc) => ifc.fmt(f),
       Asymmetric::Ffc(ffc) => ffc.fmt(f),
+      Asymmetric::Pqs(pqs) => pqs.fmt(f),
     }
   }
 }
@@ -58,6 +62,12 @@ impl From<Ffc> for Asymmetric {
   }
 }
 
+impl From<Pqs> for Asymmetric {
+  fn from(pqs: Pqs) -> Self {
+    Self::Pqs(pqs)
+  }
+}
+
 impl Serialize for Asymmetric {
   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
   where
@@ -0,0 +1,253 @@
+//! Deterministic random bit generator primitive and some common
+//! instances.
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
+
+/// Represents a deterministic random bit generator (DRBG) such as those
+/// of NIST SP 800-90A.
+///
+/// `security` is the highest security strength, in bits, that the
+/// generator supports when it is instantiated with enough entropy.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Drbg {
+  pub id: u16,
+  pub security: u16,
+}
+
+impl Drbg {
+  pub const fn new(id: u16, security: u16) -> Self {
+    Self { id, security }
+  }
+}
+
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Drbg, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(CTR_DRBG_AES128, "ctr_drbg_aes128");
+  m.insert(CTR_DRBG_AES192, "ctr_drbg_aes192");
+  m.insert(CTR_DRBG_AES256, "ctr_drbg_aes256");
+  m.insert(CTR_DRBG_AES128_NO_DF, "ctr_drbg_aes128_no_df");
+  m.insert(CTR_DRBG_AES192_NO_DF, "ctr_drbg_aes192_no_df");
+  m.insert(CTR_DRBG_AES256_NO_DF, "ctr_drbg_aes256_no_df");
+  m.insert(CTR_DRBG_TDEA, "ctr_drbg_tdea");
+  m.insert(DUAL_EC_DRBG_P256, "dual_ec_drbg_p256");
+  m.insert(DUAL_EC_DRBG_P384, "dual_ec_drbg_p384");
+  m.insert(DUAL_EC_DRBG_P521, "dual_ec_drbg_p521");
+  m.insert(HASH_DRBG_SHA1, "hash_drbg_sha1");
+  m.insert(HASH_DRBG_SHA224, "hash_drbg_sha224");
+  m.insert(HASH_DRBG_SHA256, "hash_drbg_sha256");
+  m.insert(HASH_DRBG_SHA384, "hash_drbg_sha384");
+  m.insert(HASH_DRBG_SHA512, "hash_drbg_sha512");
+  m.insert(HASH_DRBG_SHA512_256, "hash_drbg_sha512_256");
+  m.insert(HMAC_DRBG_SHA1, "hmac_drbg_sha1");
+  m.insert(HMAC_DRBG_SHA224, "hmac_drbg_sha224");
+  m.insert(HMAC_DRBG_SHA256, "hmac_drbg_sha256");
+  m.insert(HMAC_DRBG_SHA384, "hmac_drbg_sha384");
+  m.insert(HMAC_DRBG_SHA512, "hmac_drbg_sha512");
+  m.insert(HMAC_DRBG_SHA512_256, "hmac_drbg_sha512_256");
+  m
+});
+
+impl Display for Drbg {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let unrecognised = "unrecognised";
+    let name = REPR.get(self).unwrap_or(&unrecognised);
+    write!(f, "{name}")
+  }
+}
+
+impl FromStr for Drbg {
+  type Err = ParsePrimitiveError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    from_repr(&REPR, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for Drbg {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Serialize for Drbg {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let s = format!("{}", self);
+    serializer.serialize_str(&s)
+  }
+}
+
+impl Primitive for Drbg {
+  /// The maximum security strength of the generator as given in table 2
+  /// and table 3 of SP 800-90A Rev. 1.
+  fn security(&self) -> Security {
+    self.security
+  }
+}
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-128 with a derivation
+/// function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static CTR_DRBG_AES128: Drbg = Drbg::new(1, 128);
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-192 with a derivation
+/// function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static CTR_DRBG_AES192: Drbg = Drbg::new(2, 192);
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-256 with a derivation
+/// function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static CTR_DRBG_AES256: Drbg = Drbg::new(3, 256);
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-128 without a
+/// derivation function which requires full entropy input.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static CTR_DRBG_AES128_NO_DF: Drbg = Drbg::new(4, 128);
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-192 without a
+/// derivation function which requires full entropy input.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static CTR_DRBG_AES192_NO_DF: Drbg = Drbg::new(5, 192);
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-256 without a
+/// derivation function which requires full entropy input.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static CTR_DRBG_AES256_NO_DF: Drbg = Drbg::new(6, 256);
+
+/// CTR_DRBG as defined in [SP800-90A] using three-key TDEA with a
+/// derivation function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static CTR_DRBG_TDEA: Drbg = Drbg::new(7, 112);
+
+/// Dual_EC_DRBG as defined in the original [SP800-90A] using the P-256
+/// curve.
+///
+/// **Warning:** The generator was withdrawn in 2014 as its default
+/// points may hide a backdoor and it should not be used.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90A
+#[no_mangle]
+pub static DUAL_EC_DRBG_P256: Drbg = Drbg::new(8, 128);
+
+/// Dual_EC_DRBG as defined in the original [SP800-90A] using the P-384
+/// curve.
+///
+/// **Warning:** The generator was withdrawn in 2014 as its default
+/// points may hide a backdoor and it should not be used.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90A
+#[no_mangle]
+pub static DUAL_EC_DRBG_P384: Drbg = Drbg::new(9, 192);
+
+/// Dual_EC_DRBG as defined in the original [SP800-90A] using the P-521
+/// curve.
+///
+/// **Warning:** The generator was withdrawn in 2014 as its default
+/// points may hide a backdoor and it should not be used.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90A
+#[no_mangle]
+pub static DUAL_EC_DRBG_P521: Drbg = Drbg::new(10, 256);
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA1.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HASH_DRBG_SHA1: Drbg = Drbg::new(11, 128);
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA224.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HASH_DRBG_SHA224: Drbg = Drbg::new(12, 192);
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HASH_DRBG_SHA256: Drbg = Drbg::new(13, 256);
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA384.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HASH_DRBG_SHA384: Drbg = Drbg::new(14, 256);
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA512.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HASH_DRBG_SHA512: Drbg = Drbg::new(15, 256);
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA512/256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HASH_DRBG_SHA512_256: Drbg = Drbg::new(16, 256);
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA1.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HMAC_DRBG_SHA1: Drbg = Drbg::new(17, 128);
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA224.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HMAC_DRBG_SHA224: Drbg = Drbg::new(18, 192);
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HMAC_DRBG_SHA256: Drbg = Drbg::new(19, 256);
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA384.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HMAC_DRBG_SHA384: Drbg = Drbg::new(20, 256);
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA512.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HMAC_DRBG_SHA512: Drbg = Drbg::new(21, 256);
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA512/256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static HMAC_DRBG_SHA512_256: Drbg = Drbg::new(22, 256);
@@ -1,10 +1,12 @@
 //! Elliptic curve primitive and some common instances.
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
 
 use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer};
 
-use crate::primitive::{Primitive, Security};
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
 
 /// Represents an elliptic curve cryptography primitive used for digital
 /// signatures and key establishment where f is the key size (the size
@@ -20,6 +22,18 @@ impl Ecc {
   pub const fn new(id: u16, f: u16) -> Self {
     Self { id, f }
   }
+
+  /// Whether the curve is only used for digital signatures as is the
+  /// case for the Edwards curves of EdDSA.
+  pub fn is_signature_only(&self) -> bool {
+    *self == ED25519 || *self == ED448
+  }
+
+  /// Whether the curve is only used for key agreement as is the case
+  /// for the Montgomery curves of X25519 and X448.
+  pub fn is_key_agreement_only(&self) -> bool {
+    *self == X25519 || *self == X448
+  }
 }
 
 // The name is kept in a lookup table instead of being embedded in the
@@ -120,6 +134,24 @@ impl Display for Ecc {
   }
 }
 
+impl FromStr for Ecc {
+  type Err = ParsePrimitiveError;
+
+  fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    from_repr(&REPR, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for Ecc {
+  fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
 impl Primitive for Ecc {
   /// Returns the security level of an elliptic curve key (which is
   /// approximately len(n)/2).
@@ -1,7 +1,12 @@
 //! Finite field primitive and some common instances.
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result};
+use std::str::FromStr;
 
-use crate::primitive::{Primitive, Security};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
 
 /// Represents a finite field cryptography primitive used to implement
 /// discrete logarithm cryptography.
@@ -12,6 +17,13 @@ use crate::primitive::{Primitive, Security};
 /// Some of the primitives that fall under this category include
 /// signature algorithms such as DSA and key establishment algorithms
 /// such as Diffie-Hellman and MQV.
+///
+/// The named Diffie-Hellman groups of [RFC 7919] and [RFC 3526] fix the
+/// prime modulus so N is taken to be the size of the private exponent
+/// that matches the security of the modulus.
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Ffc {
@@ -39,11 +51,89 @@ impl Ffc {
   pub const fn new(id: u16, l: u16, n: u16) -> Self {
     Self { id, l, n }
   }
+
+  /// Whether the parameters describe a Diffie-Hellman group which is
+  /// only used for key agreement.
+  pub fn is_key_agreement_only(&self) -> bool {
+    self.id == ID_DH || REPR.contains_key(self)
+  }
 }
 
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Ffc, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(FFDHE2048, "ffdhe2048");
+  m.insert(FFDHE3072, "ffdhe3072");
+  m.insert(FFDHE4096, "ffdhe4096");
+  m.insert(FFDHE6144, "ffdhe6144");
+  m.insert(FFDHE8192, "ffdhe8192");
+  m.insert(MODP_768, "modp768");
+  m.insert(MODP_1024, "modp1024");
+  m.insert(MODP_1536, "modp1536");
+  m.insert(MODP_2048, "modp2048");
+  m.insert(MODP_3072, "modp3072");
+  m.insert(MODP_4096, "modp4096");
+  m.insert(MODP_6144, "modp6144");
+  m.insert(MODP_8192, "modp8192");
+  m
+});
+
 impl Display for Ffc {
   fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-    write!(f, "dsa_{}_{}", self.l, self.n)
+    match REPR.get(self) {
+      Some(name) => write!(f, "{name}"),
+      None if self.id == ID_DH => write!(f, "dh_{}_{}", self.l, self.n),
+      None => write!(f, "dsa_{}_{}", self.l, self.n),
+    }
+  }
+}
+
+impl FromStr for Ffc {
+  type Err = ParsePrimitiveError;
+
+  /// Parses the names of the named Diffie-Hellman groups and names of
+  /// the form `dsa_<l>_<n>` or `dh_<l>_<n>` into one of the instances
+  /// below or a custom key if there is no instance with the choice of
+  /// `l` and `n`.
+  fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    if let Ok(group) = from_repr(&REPR, s) {
+      return Ok(group);
+    }
+    let err = || ParsePrimitiveError::new(s);
+    if let Some((l, n)) = s.strip_prefix("dh_").and_then(|ln| ln.split_once('_')) {
+      let l = l.parse().map_err(|_| err())?;
+      let n = n.parse().map_err(|_| err())?;
+      return Ok(Ffc::new(ID_DH, l, n));
+    }
+    let (l, n) = s
+      .strip_prefix("dsa_")
+      .and_then(|ln| ln.split_once('_'))
+      .ok_or_else(err)?;
+    let l = l.parse().map_err(|_| err())?;
+    let n = n.parse().map_err(|_| err())?;
+    let instances = [
+      DSA_1024_160,
+      DSA_2048_224,
+      DSA_2048_256,
+      DSA_3072_256,
+      DSA_7680_384,
+      DSA_15360_512,
+    ];
+    let custom = Ffc::new(ID_DSA, l, n);
+    let instance = instances.into_iter().find(|ffc| ffc.l == l && ffc.n == n);
+    Ok(instance.unwrap_or(custom))
+  }
+}
+
+impl<'de> Deserialize<'de> for Ffc {
+  fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
   }
 }
 
@@ -51,6 +141,10 @@ impl Display for Ffc {
 #[no_mangle]
 pub static ID_DSA: u16 = 65534;
 
+/// An identifier for custom Diffie-Hellman groups.
+#[no_mangle]
+pub static ID_DH: u16 = 65533;
+
 /// Generic instance that represents a choice of L = 1024 and N = 160
 /// for a finite field cryptography primitive.
 #[no_mangle]
@@ -81,6 +175,97 @@ pub static DSA_7680_384: Ffc = Ffc::new(5, 7680, 384);
 #[no_mangle]
 pub static DSA_15360_512: Ffc = Ffc::new(6, 15360, 512);
 
+/// The 2048-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static FFDHE2048: Ffc = Ffc::new(7, 2048, 224);
+
+/// The 3072-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static FFDHE3072: Ffc = Ffc::new(8, 3072, 256);
+
+/// The 4096-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static FFDHE4096: Ffc = Ffc::new(9, 4096, 256);
+
+/// The 6144-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static FFDHE6144: Ffc = Ffc::new(10, 6144, 384);
+
+/// The 8192-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static FFDHE8192: Ffc = Ffc::new(11, 8192, 384);
+
+/// The 768-bit MODP group, also known as the First Oakley Group or
+/// group 1, as defined in [RFC 2409].
+///
+/// [RFC 2409]: https://datatracker.ietf.org/doc/html/rfc2409
+#[no_mangle]
+pub static MODP_768: Ffc = Ffc::new(12, 768, 128);
+
+/// The 1024-bit MODP group, also known as the Second Oakley Group or
+/// group 2, as defined in [RFC 2409].
+///
+/// [RFC 2409]: https://datatracker.ietf.org/doc/html/rfc2409
+#[no_mangle]
+pub static MODP_1024: Ffc = Ffc::new(13, 1024, 160);
+
+/// The 1536-bit MODP group, also known as group 5, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static MODP_1536: Ffc = Ffc::new(14, 1536, 160);
+
+/// The 2048-bit MODP group, also known as group 14, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static MODP_2048: Ffc = Ffc::new(15, 2048, 224);
+
+/// The 3072-bit MODP group, also known as group 15, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static MODP_3072: Ffc = Ffc::new(16, 3072, 256);
+
+/// The 4096-bit MODP group, also known as group 16, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static MODP_4096: Ffc = Ffc::new(17, 4096, 256);
+
+/// The 6144-bit MODP group, also known as group 17, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static MODP_6144: Ffc = Ffc::new(18, 6144, 384);
+
+/// The 8192-bit MODP group, also known as group 18, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static MODP_8192: Ffc = Ffc::new(19, 8192, 384);
+
 /// Placeholder for use in where this primitive is not supported.
 #[no_mangle]
 pub static FFC_NOT_SUPPORTED: Ffc = Ffc::new(u16::MAX, u16::MAX, u16::MAX);
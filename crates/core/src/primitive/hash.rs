@@ -1,11 +1,12 @@
 //! Hash function primitive and some common instances.
 use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
 use once_cell::sync::Lazy;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::primitive::{Primitive, Security};
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
 
 /// Represents a hash or hash-based function cryptographic primitive
 /// where `id` is a unique identifier and `n` the digest length.
@@ -64,6 +65,24 @@ impl Display for Hash {
   }
 }
 
+impl FromStr for Hash {
+  type Err = ParsePrimitiveError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    from_repr(&REPR, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for Hash {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
 impl Primitive for Hash {
   /// Returns the security of a hash function measured as the collision
   /// resistance strength of a hash function.
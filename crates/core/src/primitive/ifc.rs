@@ -1,7 +1,10 @@
 //! Integer factorisation primitive and some common instances.
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
-use crate::primitive::{Primitive, Security};
+use serde::{Deserialize, Deserializer};
+
+use crate::primitive::{ParsePrimitiveError, Primitive, Security};
 
 /// Represents an integer factorisation cryptography primitive the most
 /// common of which is the RSA signature algorithm where k indicates the
@@ -33,6 +36,56 @@ impl Display for Ifc {
   }
 }
 
+impl FromStr for Ifc {
+  type Err = ParsePrimitiveError;
+
+  /// Parses names of the form `rsa_pkcs1_<k>` and `rsa_pss_<k>` into
+  /// one of the instances below or a custom key if there is no instance
+  /// with the key size `k`.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (id, k) = if let Some(k) = s.strip_prefix("rsa_pkcs1_") {
+      (ID_RSA_PKCS1, k)
+    } else if let Some(k) = s.strip_prefix("rsa_pss_") {
+      (ID_RSA_PSS, k)
+    } else {
+      return Err(ParsePrimitiveError::new(s));
+    };
+    let k = k.parse().map_err(|_| ParsePrimitiveError::new(s))?;
+    let instances = [
+      RSA_PKCS1_1024,
+      RSA_PKCS1_1536,
+      RSA_PKCS1_2048,
+      RSA_PKCS1_3072,
+      RSA_PKCS1_4096,
+      RSA_PKCS1_7680,
+      RSA_PKCS1_8192,
+      RSA_PKCS1_15360,
+      RSA_PSS_1024,
+      RSA_PSS_1280,
+      RSA_PSS_1536,
+      RSA_PSS_2048,
+      RSA_PSS_3072,
+      RSA_PSS_4096,
+      RSA_PSS_7680,
+      RSA_PSS_8192,
+      RSA_PSS_15360,
+    ];
+    let custom = Ifc::new(id, k);
+    let instance = instances.into_iter().find(|ifc| ifc.to_string() == s);
+    Ok(instance.unwrap_or(custom))
+  }
+}
+
+impl<'de> Deserialize<'de> for Ifc {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
 impl Primitive for Ifc {
   /// Returns the approximate *minimum* security provided by a key of
   /// the size `k`.
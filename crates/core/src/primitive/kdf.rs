@@ -0,0 +1,263 @@
+//! Key derivation function primitive and some common instances.
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
+
+/// Represents a key derivation function such as HKDF, the key-based
+/// KDFs of SP 800-108, the one-step KDFs of SP 800-56C, PBKDF2 or the
+/// TLS pseudorandom function.
+///
+/// The choice `key` represents the bit length of the derived key and
+/// `iterations` the iteration count of password-based functions such as
+/// PBKDF2. The iteration count is `0` for all other functions.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Kdf {
+  pub id: u16,
+  pub key: u16,
+  pub iterations: u32,
+}
+
+impl Kdf {
+  pub const fn new(id: u16, key: u16, iterations: u32) -> Self {
+    Self {
+      id,
+      key,
+      iterations,
+    }
+  }
+
+  /// Whether the function derives keys from passwords and therefore
+  /// relies on an iteration count to slow down guessing attacks.
+  pub fn is_password_based(&self) -> bool {
+    matches!(self.id, 8..=10)
+  }
+
+  /// The instance that shares the construction of this KDF using the
+  /// default choice of key length and iteration count.
+  fn instance(&self) -> Option<(Kdf, &'static str)> {
+    REPR
+      .iter()
+      .find(|(kdf, _)| kdf.id == self.id)
+      .map(|(&kdf, &name)| (kdf, name))
+  }
+}
+
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Kdf, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(HKDF_SHA256, "hkdf_sha256");
+  m.insert(HKDF_SHA384, "hkdf_sha384");
+  m.insert(HKDF_SHA512, "hkdf_sha512");
+  m.insert(KBKDF_CMAC, "kbkdf_cmac");
+  m.insert(KBKDF_HMAC_SHA256, "kbkdf_hmac_sha256");
+  m.insert(KBKDF_HMAC_SHA384, "kbkdf_hmac_sha384");
+  m.insert(KBKDF_HMAC_SHA512, "kbkdf_hmac_sha512");
+  m.insert(PBKDF2_HMAC_SHA1, "pbkdf2_hmac_sha1");
+  m.insert(PBKDF2_HMAC_SHA256, "pbkdf2_hmac_sha256");
+  m.insert(PBKDF2_HMAC_SHA512, "pbkdf2_hmac_sha512");
+  m.insert(SP800_56C_SHA256, "sp800_56c_sha256");
+  m.insert(SP800_56C_SHA384, "sp800_56c_sha384");
+  m.insert(SP800_56C_SHA512, "sp800_56c_sha512");
+  m.insert(TLS10_PRF, "tls10_prf");
+  m.insert(TLS12_PRF_SHA256, "tls12_prf_sha256");
+  m.insert(TLS12_PRF_SHA384, "tls12_prf_sha384");
+  m
+});
+
+impl Display for Kdf {
+  /// Writes the name of the function on its own if the key length and
+  /// iteration count are the default ones, as `<name>_<key>` if only
+  /// the key length differs or as `<name>_<key>_<iterations>` for
+  /// password-based functions otherwise.
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self.instance() {
+      Some((instance, name)) if instance == *self => write!(f, "{name}"),
+      Some((instance, name)) if instance.iterations == self.iterations => {
+        write!(f, "{name}_{}", self.key)
+      },
+      Some((_, name)) => {
+        write!(f, "{name}_{}_{}", self.key, self.iterations)
+      },
+      None => write!(f, "unrecognised"),
+    }
+  }
+}
+
+impl FromStr for Kdf {
+  type Err = ParsePrimitiveError;
+
+  /// Parses names of the form `<name>` into one of the instances below
+  /// or `<name>_<key>` and `<name>_<key>_<iterations>` into a custom
+  /// choice of key length and iteration count for the function.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Ok(instance) = from_repr(&REPR, s) {
+      return Ok(instance);
+    }
+    let err = || ParsePrimitiveError::new(s);
+    let (rest, last) = s.rsplit_once('_').ok_or_else(err)?;
+    if let Ok(instance) = from_repr(&REPR, rest) {
+      let key = last.parse().map_err(|_| err())?;
+      return Ok(Kdf::new(instance.id, key, instance.iterations));
+    }
+    let iterations = last.parse().map_err(|_| err())?;
+    let (name, key) = rest.rsplit_once('_').ok_or_else(err)?;
+    let key = key.parse().map_err(|_| err())?;
+    let instance = from_repr(&REPR, name).map_err(|_| err())?;
+    Ok(Kdf::new(instance.id, key, iterations))
+  }
+}
+
+impl<'de> Deserialize<'de> for Kdf {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Serialize for Kdf {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let s = format!("{}", self);
+    serializer.serialize_str(&s)
+  }
+}
+
+impl Primitive for Kdf {
+  /// The security of a key derivation function defined as the length
+  /// of the derived key capped by the security of the underlying
+  /// pseudorandom function.
+  ///
+  /// The iteration count of password-based functions is not taken into
+  /// account as the security of the derived key ultimately depends on
+  /// the entropy of the password.
+  fn security(&self) -> Security {
+    let cap = match self.id {
+      4 => 128,
+      8 | 14 => 160,
+      1 | 5 | 9 | 11 | 15 => 256,
+      2 | 6 | 12 | 16 => 384,
+      3 | 7 | 10 | 13 => 512,
+      _ => 256,
+    };
+    self.key.min(cap)
+  }
+}
+
+/// HKDF as defined in [RFC 5869] using HMAC-SHA256.
+///
+/// [RFC 5869]: https://datatracker.ietf.org/doc/html/rfc5869
+#[no_mangle]
+pub static HKDF_SHA256: Kdf = Kdf::new(1, 256, 0);
+
+/// HKDF as defined in [RFC 5869] using HMAC-SHA384.
+///
+/// [RFC 5869]: https://datatracker.ietf.org/doc/html/rfc5869
+#[no_mangle]
+pub static HKDF_SHA384: Kdf = Kdf::new(2, 384, 0);
+
+/// HKDF as defined in [RFC 5869] using HMAC-SHA512.
+///
+/// [RFC 5869]: https://datatracker.ietf.org/doc/html/rfc5869
+#[no_mangle]
+pub static HKDF_SHA512: Kdf = Kdf::new(3, 512, 0);
+
+/// The key-based KDF as defined in [SP800-108] using CMAC with AES.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static KBKDF_CMAC: Kdf = Kdf::new(4, 128, 0);
+
+/// The key-based KDF as defined in [SP800-108] using HMAC-SHA256.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static KBKDF_HMAC_SHA256: Kdf = Kdf::new(5, 256, 0);
+
+/// The key-based KDF as defined in [SP800-108] using HMAC-SHA384.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static KBKDF_HMAC_SHA384: Kdf = Kdf::new(6, 384, 0);
+
+/// The key-based KDF as defined in [SP800-108] using HMAC-SHA512.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static KBKDF_HMAC_SHA512: Kdf = Kdf::new(7, 512, 0);
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA1
+/// with the minimum iteration count of [SP800-132].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [SP800-132]: https://doi.org/10.6028/NIST.SP.800-132
+#[no_mangle]
+pub static PBKDF2_HMAC_SHA1: Kdf = Kdf::new(8, 160, 1000);
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA256
+/// with the minimum iteration count of [SP800-132].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [SP800-132]: https://doi.org/10.6028/NIST.SP.800-132
+#[no_mangle]
+pub static PBKDF2_HMAC_SHA256: Kdf = Kdf::new(9, 256, 1000);
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA512
+/// with the minimum iteration count of [SP800-132].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [SP800-132]: https://doi.org/10.6028/NIST.SP.800-132
+#[no_mangle]
+pub static PBKDF2_HMAC_SHA512: Kdf = Kdf::new(10, 512, 1000);
+
+/// The one-step KDF as defined in [SP800-56C] using SHA256.
+///
+/// [SP800-56C]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+#[no_mangle]
+pub static SP800_56C_SHA256: Kdf = Kdf::new(11, 256, 0);
+
+/// The one-step KDF as defined in [SP800-56C] using SHA384.
+///
+/// [SP800-56C]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+#[no_mangle]
+pub static SP800_56C_SHA384: Kdf = Kdf::new(12, 384, 0);
+
+/// The one-step KDF as defined in [SP800-56C] using SHA512.
+///
+/// [SP800-56C]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+#[no_mangle]
+pub static SP800_56C_SHA512: Kdf = Kdf::new(13, 512, 0);
+
+/// The pseudorandom function of TLS 1.0 and 1.1 as defined in
+/// [RFC 2246] which combines HMAC-MD5 and HMAC-SHA1. The default key
+/// length is that of the master secret.
+///
+/// [RFC 2246]: https://datatracker.ietf.org/doc/html/rfc2246
+#[no_mangle]
+pub static TLS10_PRF: Kdf = Kdf::new(14, 384, 0);
+
+/// The pseudorandom function of TLS 1.2 as defined in [RFC 5246] using
+/// HMAC-SHA256. The default key length is that of the master secret.
+///
+/// [RFC 5246]: https://datatracker.ietf.org/doc/html/rfc5246
+#[no_mangle]
+pub static TLS12_PRF_SHA256: Kdf = Kdf::new(15, 384, 0);
+
+/// The pseudorandom function of TLS 1.2 as defined in [RFC 5246] using
+/// HMAC-SHA384. The default key length is that of the master secret.
+///
+/// [RFC 5246]: https://datatracker.ietf.org/doc/html/rfc5246
+#[no_mangle]
+pub static TLS12_PRF_SHA384: Kdf = Kdf::new(16, 384, 0);
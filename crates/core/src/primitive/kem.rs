@@ -0,0 +1,200 @@
+//! Key encapsulation mechanism primitive and some common instances.
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
+
+/// Represents a key encapsulation mechanism used for key establishment
+/// where `category` is the NIST post-quantum security category (1 to
+/// 5) that the parameter set targets.
+///
+/// Hybrid schemes that combine a traditional elliptic curve key
+/// agreement with a post-quantum key encapsulation mechanism are
+/// assigned the category of the post-quantum component.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Kem {
+  pub id: u16,
+  pub category: u16,
+}
+
+impl Kem {
+  pub const fn new(id: u16, category: u16) -> Self {
+    Self { id, category }
+  }
+}
+
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Kem, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(FRODOKEM_1344, "frodokem1344");
+  m.insert(FRODOKEM_640, "frodokem640");
+  m.insert(FRODOKEM_976, "frodokem976");
+  m.insert(KEM_NOT_SUPPORTED, "not supported");
+  m.insert(MCELIECE348864, "mceliece348864");
+  m.insert(MCELIECE460896, "mceliece460896");
+  m.insert(MCELIECE6688128, "mceliece6688128");
+  m.insert(MCELIECE6960119, "mceliece6960119");
+  m.insert(MCELIECE8192128, "mceliece8192128");
+  m.insert(ML_KEM_1024, "ml_kem_1024");
+  m.insert(ML_KEM_512, "ml_kem_512");
+  m.insert(ML_KEM_768, "ml_kem_768");
+  m.insert(SECP256R1MLKEM768, "secp256r1mlkem768");
+  m.insert(SECP384R1MLKEM1024, "secp384r1mlkem1024");
+  m.insert(X25519MLKEM768, "x25519mlkem768");
+  m
+});
+
+impl Display for Kem {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let unrecognised = "unrecognised";
+    let name = REPR.get(self).unwrap_or(&unrecognised);
+    write!(f, "{name}")
+  }
+}
+
+impl FromStr for Kem {
+  type Err = ParsePrimitiveError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    from_repr(&REPR, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for Kem {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Primitive for Kem {
+  /// Returns the classical security level of the parameter set implied
+  /// by its NIST security category.
+  ///
+  /// Categories 1 and 2 are at least as hard to break as AES-128 and
+  /// SHA-256 respectively, 3 and 4 as AES-192 and SHA-384, and 5 as
+  /// AES-256 (see section 4.A.5 of the NIST call for proposals).
+  fn security(&self) -> Security {
+    match self.category {
+      1..=2 => 128,
+      3..=4 => 192,
+      5.. => 256,
+      _ => 0,
+    }
+  }
+}
+
+/// The FrodoKEM-640 key encapsulation mechanism with either the AES or
+/// SHAKE pseudorandom generator as defined in the [FrodoKEM]
+/// specification.
+///
+/// [FrodoKEM]: https://frodokem.org/files/FrodoKEM-specification-20210604.pdf
+#[no_mangle]
+pub static FRODOKEM_640: Kem = Kem::new(1, 1);
+
+/// The FrodoKEM-976 key encapsulation mechanism with either the AES or
+/// SHAKE pseudorandom generator as defined in the [FrodoKEM]
+/// specification.
+///
+/// [FrodoKEM]: https://frodokem.org/files/FrodoKEM-specification-20210604.pdf
+#[no_mangle]
+pub static FRODOKEM_976: Kem = Kem::new(2, 3);
+
+/// The FrodoKEM-1344 key encapsulation mechanism with either the AES
+/// or SHAKE pseudorandom generator as defined in the [FrodoKEM]
+/// specification.
+///
+/// [FrodoKEM]: https://frodokem.org/files/FrodoKEM-specification-20210604.pdf
+#[no_mangle]
+pub static FRODOKEM_1344: Kem = Kem::new(3, 5);
+
+/// The Classic McEliece parameter set mceliece348864 as defined in the
+/// [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static MCELIECE348864: Kem = Kem::new(4, 1);
+
+/// The Classic McEliece parameter set mceliece460896 as defined in the
+/// [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static MCELIECE460896: Kem = Kem::new(5, 3);
+
+/// The Classic McEliece parameter set mceliece6688128 as defined in
+/// the [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static MCELIECE6688128: Kem = Kem::new(6, 5);
+
+/// The Classic McEliece parameter set mceliece6960119 as defined in
+/// the [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static MCELIECE6960119: Kem = Kem::new(7, 5);
+
+/// The Classic McEliece parameter set mceliece8192128 as defined in
+/// the [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static MCELIECE8192128: Kem = Kem::new(8, 5);
+
+/// The Module-Lattice-Based Key-Encapsulation Mechanism parameter set
+/// ML-KEM-512 as defined in [FIPS 203].
+///
+/// [FIPS 203]: https://doi.org/10.6028/NIST.FIPS.203
+#[no_mangle]
+pub static ML_KEM_512: Kem = Kem::new(9, 1);
+
+/// The Module-Lattice-Based Key-Encapsulation Mechanism parameter set
+/// ML-KEM-768 as defined in [FIPS 203].
+///
+/// [FIPS 203]: https://doi.org/10.6028/NIST.FIPS.203
+#[no_mangle]
+pub static ML_KEM_768: Kem = Kem::new(10, 3);
+
+/// The Module-Lattice-Based Key-Encapsulation Mechanism parameter set
+/// ML-KEM-1024 as defined in [FIPS 203].
+///
+/// [FIPS 203]: https://doi.org/10.6028/NIST.FIPS.203
+#[no_mangle]
+pub static ML_KEM_1024: Kem = Kem::new(11, 5);
+
+/// The hybrid combination of ECDH over P-256 and ML-KEM-768 as defined
+/// in [draft-ietf-tls-ecdhe-mlkem].
+///
+/// [draft-ietf-tls-ecdhe-mlkem]: https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/
+#[no_mangle]
+pub static SECP256R1MLKEM768: Kem = Kem::new(12, 3);
+
+/// The hybrid combination of ECDH over P-384 and ML-KEM-1024 as
+/// defined in [draft-ietf-tls-ecdhe-mlkem].
+///
+/// [draft-ietf-tls-ecdhe-mlkem]: https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/
+#[no_mangle]
+pub static SECP384R1MLKEM1024: Kem = Kem::new(13, 5);
+
+/// The hybrid combination of X25519 and ML-KEM-768 as defined in
+/// [draft-ietf-tls-ecdhe-mlkem].
+///
+/// [draft-ietf-tls-ecdhe-mlkem]: https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/
+#[no_mangle]
+pub static X25519MLKEM768: Kem = Kem::new(14, 3);
+
+/// Placeholder for use in where this primitive is not supported.
+#[no_mangle]
+pub static KEM_NOT_SUPPORTED: Kem = Kem::new(u16::MAX, u16::MAX);
@@ -0,0 +1,247 @@
+//! Message authentication code primitive and some common instances.
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
+
+/// Represents a message authentication code such as HMAC, KMAC, CMAC,
+/// GMAC or Poly1305.
+///
+/// The choices `key` and `tag` represent the bit lengths of the secret
+/// key and of the authentication tag. The `id` identifies the
+/// construction and underlying function so a truncated HMAC-SHA256
+/// keeps the identifier of [`HMAC_SHA256`] with a shorter `tag`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Mac {
+  pub id: u16,
+  pub key: u16,
+  pub tag: u16,
+}
+
+impl Mac {
+  pub const fn new(id: u16, key: u16, tag: u16) -> Self {
+    Self { id, key, tag }
+  }
+
+  /// The instance that shares the construction of this MAC using the
+  /// default choice of key and tag length.
+  fn instance(&self) -> Option<(Mac, &'static str)> {
+    REPR
+      .iter()
+      .find(|(mac, _)| mac.id == self.id)
+      .map(|(&mac, &name)| (mac, name))
+  }
+}
+
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Mac, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(CMAC, "cmac");
+  m.insert(GMAC, "gmac");
+  m.insert(HMAC_MD5, "hmac_md5");
+  m.insert(HMAC_RIPEMD160, "hmac_ripemd160");
+  m.insert(HMAC_SHA1, "hmac_sha1");
+  m.insert(HMAC_SHA224, "hmac_sha224");
+  m.insert(HMAC_SHA256, "hmac_sha256");
+  m.insert(HMAC_SHA384, "hmac_sha384");
+  m.insert(HMAC_SHA512, "hmac_sha512");
+  m.insert(HMAC_SHA3_256, "hmac_sha3_256");
+  m.insert(HMAC_SHA3_384, "hmac_sha3_384");
+  m.insert(HMAC_SHA3_512, "hmac_sha3_512");
+  m.insert(KMAC128, "kmac128");
+  m.insert(KMAC256, "kmac256");
+  m.insert(POLY1305_MAC, "poly1305");
+  m.insert(UMAC, "umac");
+  m
+});
+
+impl Display for Mac {
+  /// Writes the name of the construction on its own if the key and tag
+  /// lengths are the default ones or as `<name>_<key>_<tag>` otherwise.
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self.instance() {
+      Some((instance, name)) if instance == *self => write!(f, "{name}"),
+      Some((_, name)) => write!(f, "{name}_{}_{}", self.key, self.tag),
+      None => write!(f, "unrecognised"),
+    }
+  }
+}
+
+impl FromStr for Mac {
+  type Err = ParsePrimitiveError;
+
+  /// Parses names of the form `<name>` into one of the instances below
+  /// or `<name>_<key>_<tag>` into a custom choice of key and tag length
+  /// for the construction.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Ok(instance) = from_repr(&REPR, s) {
+      return Ok(instance);
+    }
+    let err = || ParsePrimitiveError::new(s);
+    let mut parts = s.rsplitn(3, '_');
+    let tag = parts.next().and_then(|t| t.parse().ok()).ok_or_else(err)?;
+    let key = parts.next().and_then(|k| k.parse().ok()).ok_or_else(err)?;
+    let name = parts.next().ok_or_else(err)?;
+    let instance = from_repr(&REPR, name).map_err(|_| err())?;
+    Ok(Mac::new(instance.id, key, tag))
+  }
+}
+
+impl<'de> Deserialize<'de> for Mac {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Serialize for Mac {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let s = format!("{}", self);
+    serializer.serialize_str(&s)
+  }
+}
+
+impl Primitive for Mac {
+  /// The security of a message authentication code defined as the
+  /// length of the key capped by the security that the underlying
+  /// function can provide.
+  ///
+  /// The length of the tag is not taken into account as it only bounds
+  /// the probability of guessing a valid tag on a single attempt which
+  /// standards tend to assess separately.
+  fn security(&self) -> Security {
+    // SP 800-107 Rev. 1 gives the security of HMAC as the minimum of
+    // the key length and the length of the hash output (2012, p. 9).
+    let cap = match self.id {
+      3 => 128,
+      4 => 160,
+      5 => 224,
+      6 | 9 => 256,
+      7 | 10 => 384,
+      8 | 11 => 512,
+      12 => 128,
+      13 => 256,
+      14 => 128,
+      15 => 128,
+      16 => 160,
+      _ => 256,
+    };
+    self.key.min(cap)
+  }
+}
+
+/// The cipher-based MAC as defined in [SP800-38B] using AES where `key`
+/// is the length of the AES key.
+///
+/// [SP800-38B]: https://doi.org/10.6028/NIST.SP.800-38B
+#[no_mangle]
+pub static CMAC: Mac = Mac::new(1, 128, 128);
+
+/// The Galois MAC as defined in [SP800-38D] using AES where `key` is
+/// the length of the AES key.
+///
+/// [SP800-38D]: https://doi.org/10.6028/NIST.SP.800-38D
+#[no_mangle]
+pub static GMAC: Mac = Mac::new(2, 128, 128);
+
+/// HMAC as defined in [RFC 2104] using MD5.
+///
+/// **Warning:** MD5 is broken and should not be used in new designs.
+///
+/// [RFC 2104]: https://datatracker.ietf.org/doc/html/rfc2104
+#[no_mangle]
+pub static HMAC_MD5: Mac = Mac::new(3, 128, 128);
+
+/// HMAC as defined in [FIPS 198-1] using SHA1.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA1: Mac = Mac::new(4, 160, 160);
+
+/// HMAC as defined in [FIPS 198-1] using SHA224.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA224: Mac = Mac::new(5, 224, 224);
+
+/// HMAC as defined in [FIPS 198-1] using SHA256.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA256: Mac = Mac::new(6, 256, 256);
+
+/// HMAC as defined in [FIPS 198-1] using SHA384.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA384: Mac = Mac::new(7, 384, 384);
+
+/// HMAC as defined in [FIPS 198-1] using SHA512.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA512: Mac = Mac::new(8, 512, 512);
+
+/// HMAC as defined in [FIPS 198-1] using SHA3-256.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA3_256: Mac = Mac::new(9, 256, 256);
+
+/// HMAC as defined in [FIPS 198-1] using SHA3-384.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA3_384: Mac = Mac::new(10, 384, 384);
+
+/// HMAC as defined in [FIPS 198-1] using SHA3-512.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static HMAC_SHA3_512: Mac = Mac::new(11, 512, 512);
+
+/// The Keccak MAC with a security of 128 bits as defined in
+/// [SP800-185].
+///
+/// [SP800-185]: https://doi.org/10.6028/NIST.SP.800-185
+#[no_mangle]
+pub static KMAC128: Mac = Mac::new(12, 128, 256);
+
+/// The Keccak MAC with a security of 256 bits as defined in
+/// [SP800-185].
+///
+/// [SP800-185]: https://doi.org/10.6028/NIST.SP.800-185
+#[no_mangle]
+pub static KMAC256: Mac = Mac::new(13, 256, 512);
+
+/// The Poly1305 one-time authenticator as defined in [RFC 8439].
+///
+/// [RFC 8439]: https://datatracker.ietf.org/doc/html/rfc8439
+#[no_mangle]
+pub static POLY1305_MAC: Mac = Mac::new(14, 256, 128);
+
+/// UMAC as defined in [RFC 4418] using AES-128 where the tag is 32, 64,
+/// 96 or 128 bits long.
+///
+/// [RFC 4418]: https://datatracker.ietf.org/doc/html/rfc4418
+#[no_mangle]
+pub static UMAC: Mac = Mac::new(15, 128, 128);
+
+/// HMAC as defined in [RFC 2104] using RIPEMD-160.
+///
+/// [RFC 2104]: https://datatracker.ietf.org/doc/html/rfc2104
+#[no_mangle]
+pub static HMAC_RIPEMD160: Mac = Mac::new(16, 160, 160);
@@ -0,0 +1,177 @@
+//! Mode of operation primitive and some common instances.
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
+
+/// Represents a mode of operation of a block cipher or the construction
+/// that turns a stream cipher into an authenticated encryption scheme.
+///
+/// The mode is assessed separately from the cipher it is used with such
+/// that AES in ECB mode is the [`AES128`](super::symmetric::AES128)
+/// symmetric key primitive together with the [`ECB`] mode.
+///
+/// `security` is the security, in bits, of the integrity protection
+/// offered by the mode assuming a full length authentication tag. It is
+/// `0` for modes that only provide confidentiality.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Mode {
+  pub id: u16,
+  pub security: u16,
+}
+
+impl Mode {
+  pub const fn new(id: u16, security: u16) -> Self {
+    Self { id, security }
+  }
+
+  /// Whether the mode provides authenticated encryption.
+  pub fn is_authenticated(&self) -> bool {
+    self.security > 0
+  }
+}
+
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Mode, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(CBC, "cbc");
+  m.insert(CCM, "ccm");
+  m.insert(CFB, "cfb");
+  m.insert(CTR, "ctr");
+  m.insert(ECB, "ecb");
+  m.insert(GCM, "gcm");
+  m.insert(GCM_SIV, "gcm_siv");
+  m.insert(OCB, "ocb");
+  m.insert(OFB, "ofb");
+  m.insert(POLY1305, "poly1305");
+  m.insert(XTS, "xts");
+  m
+});
+
+impl Display for Mode {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let unrecognised = "unrecognised";
+    let name = REPR.get(self).unwrap_or(&unrecognised);
+    write!(f, "{name}")
+  }
+}
+
+impl FromStr for Mode {
+  type Err = ParsePrimitiveError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    from_repr(&REPR, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for Mode {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Serialize for Mode {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let s = format!("{}", self);
+    serializer.serialize_str(&s)
+  }
+}
+
+impl Primitive for Mode {
+  /// Indicates the security of the integrity protection provided by
+  /// the mode.
+  fn security(&self) -> Security {
+    self.security
+  }
+}
+
+/// The Electronic Codebook mode as defined in [SP800-38A].
+///
+/// **Warning:** Identical blocks of plaintext are encrypted to
+/// identical blocks of ciphertext so this mode does not hide patterns
+/// in the data.
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static ECB: Mode = Mode::new(1, 0);
+
+/// The Cipher Block Chaining mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static CBC: Mode = Mode::new(2, 0);
+
+/// The Cipher Feedback mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static CFB: Mode = Mode::new(3, 0);
+
+/// The Output Feedback mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static OFB: Mode = Mode::new(4, 0);
+
+/// The Counter mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static CTR: Mode = Mode::new(5, 0);
+
+/// The XEX-based tweaked-codebook mode with ciphertext stealing as
+/// defined in [SP800-38E] which is intended for storage devices.
+///
+/// [SP800-38E]: https://doi.org/10.6028/NIST.SP.800-38E
+#[no_mangle]
+pub static XTS: Mode = Mode::new(6, 0);
+
+/// The Counter with CBC-MAC authenticated encryption mode as defined
+/// in [SP800-38C].
+///
+/// [SP800-38C]: https://doi.org/10.6028/NIST.SP.800-38C
+#[no_mangle]
+pub static CCM: Mode = Mode::new(7, 128);
+
+/// The Galois/Counter authenticated encryption mode as defined in
+/// [SP800-38D].
+///
+/// [SP800-38D]: https://doi.org/10.6028/NIST.SP.800-38D
+#[no_mangle]
+pub static GCM: Mode = Mode::new(8, 128);
+
+/// The nonce misuse-resistant variant of the Galois/Counter mode as
+/// defined in [RFC 8452].
+///
+/// [RFC 8452]: https://datatracker.ietf.org/doc/html/rfc8452
+#[no_mangle]
+pub static GCM_SIV: Mode = Mode::new(9, 128);
+
+/// The Offset Codebook authenticated encryption mode as defined in
+/// [RFC 7253].
+///
+/// [RFC 7253]: https://datatracker.ietf.org/doc/html/rfc7253
+#[no_mangle]
+pub static OCB: Mode = Mode::new(10, 128);
+
+/// The authenticated encryption construction that combines a stream
+/// cipher such as ChaCha20 with the Poly1305 authenticator as defined
+/// in [RFC 8439].
+///
+/// [RFC 8439]: https://datatracker.ietf.org/doc/html/rfc8439
+#[no_mangle]
+pub static POLY1305: Mode = Mode::new(11, 128);
@@ -0,0 +1,330 @@
+//! Password hashing function primitive and some common instances.
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
+
+/// Represents a password hashing function such as Argon2, scrypt,
+/// bcrypt, PBKDF2 or one of the schemes of crypt(3).
+///
+/// The choices `memory`, `iterations` and `parallelism` represent the
+/// cost parameters of the function. Their meaning depends on the
+/// family of the function:
+///
+/// - Argon2 uses the memory in KiB, the number of passes over that
+///   memory and the number of lanes.
+/// - scrypt and yescrypt use the memory in KiB, derived from the block
+///   size `r` as `128 * N * r` bytes, the CPU/memory cost `N` and the
+///   parallelisation parameter `p`.
+/// - bcrypt uses the 4 KiB of its key schedule and `2^cost` iterations.
+/// - PBKDF2 and the schemes of crypt(3) based on MD5 or SHA-2 only use
+///   the iteration count and leave the memory at `0`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Phf {
+  pub id: u16,
+  pub memory: u32,
+  pub iterations: u32,
+  pub parallelism: u32,
+}
+
+impl Phf {
+  pub const fn new(id: u16, memory: u32, iterations: u32, parallelism: u32) -> Self {
+    Self {
+      id,
+      memory,
+      iterations,
+      parallelism,
+    }
+  }
+
+  /// bcrypt with the given cost factor, the base 2 logarithm of the
+  /// iteration count.
+  pub const fn bcrypt(cost: u32) -> Self {
+    Self::new(BCRYPT.id, BCRYPT.memory, 1 << cost, 1)
+  }
+
+  /// PBKDF2, or one of the iterated schemes of crypt(3), with the given
+  /// iteration count where `id` selects the function.
+  pub const fn iterated(id: u16, iterations: u32) -> Self {
+    Self::new(id, 0, iterations, 1)
+  }
+
+  /// scrypt or yescrypt, depending on `id`, with the parameters `N`,
+  /// `r` and `p` as they appear in encoded hashes.
+  pub const fn scrypt(id: u16, n: u32, r: u32, p: u32) -> Self {
+    Self::new(id, (n as u64 * r as u64 / 8) as u32, n, p)
+  }
+
+  /// Whether the function is designed to require a large amount of
+  /// memory to slow down guessing attacks on dedicated hardware.
+  pub fn is_memory_hard(&self) -> bool {
+    matches!(self.id, 1..=3 | 9 | 12)
+  }
+
+  /// The cost factor of bcrypt, the block size of scrypt and yescrypt
+  /// or the number of passes of Argon2, depending on the family.
+  fn cost(&self) -> u32 {
+    match self.id {
+      4 => self.iterations.trailing_zeros(),
+      9 | 12 if self.iterations > 0 => (self.memory as u64 * 8 / self.iterations as u64) as u32,
+      _ => self.iterations,
+    }
+  }
+
+  /// The instance that shares the construction of this function using
+  /// the default choice of cost parameters.
+  fn instance(&self) -> Option<(Phf, &'static str)> {
+    REPR
+      .iter()
+      .find(|(phf, _)| phf.id == self.id)
+      .map(|(&phf, &name)| (phf, name))
+  }
+}
+
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Phf, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(ARGON2D, "argon2d");
+  m.insert(ARGON2I, "argon2i");
+  m.insert(ARGON2ID, "argon2id");
+  m.insert(BCRYPT, "bcrypt");
+  m.insert(DES_CRYPT, "des_crypt");
+  m.insert(MD5_CRYPT, "md5_crypt");
+  m.insert(PBKDF2_SHA1, "pbkdf2_sha1");
+  m.insert(PBKDF2_SHA256, "pbkdf2_sha256");
+  m.insert(PBKDF2_SHA512, "pbkdf2_sha512");
+  m.insert(PHF_NOT_SUPPORTED, "not supported");
+  m.insert(SCRYPT, "scrypt");
+  m.insert(SHA256_CRYPT, "sha256_crypt");
+  m.insert(SHA512_CRYPT, "sha512_crypt");
+  m.insert(YESCRYPT, "yescrypt");
+  m
+});
+
+impl Display for Phf {
+  /// Writes the name of the function on its own if the cost parameters
+  /// are the default ones. Otherwise the parameters are appended as
+  /// `<name>_m<memory>_t<passes>_p<lanes>` for Argon2,
+  /// `<name>_n<N>_r<r>_p<p>` for scrypt and yescrypt, `<name>_<cost>`
+  /// for bcrypt and `<name>_<iterations>` for all other functions.
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self.instance() {
+      Some((instance, name)) if instance == *self => write!(f, "{name}"),
+      Some((_, name)) => match self.id {
+        1..=3 => write!(
+          f,
+          "{name}_m{}_t{}_p{}",
+          self.memory, self.iterations, self.parallelism
+        ),
+        9 | 12 => write!(
+          f,
+          "{name}_n{}_r{}_p{}",
+          self.iterations,
+          self.cost(),
+          self.parallelism
+        ),
+        _ => write!(f, "{name}_{}", self.cost()),
+      },
+      None => write!(f, "unrecognised"),
+    }
+  }
+}
+
+impl FromStr for Phf {
+  type Err = ParsePrimitiveError;
+
+  /// Parses names of the form `<name>` into one of the instances below
+  /// or names followed by cost parameters, as written by the `Display`
+  /// implementation, into a custom choice of parameters.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Ok(instance) = from_repr(&REPR, s) {
+      return Ok(instance);
+    }
+    let err = || ParsePrimitiveError::new(s);
+    let (instance, params) = REPR
+      .iter()
+      .filter(|(phf, _)| **phf != PHF_NOT_SUPPORTED)
+      .find_map(|(&phf, name)| {
+        let params = s.strip_prefix(name)?.strip_prefix('_')?;
+        Some((phf, params))
+      })
+      .ok_or_else(err)?;
+    let labelled = |labels: [char; 3]| -> Option<[u32; 3]> {
+      let mut values = [0; 3];
+      let mut parts = params.split('_');
+      for (value, label) in values.iter_mut().zip(labels) {
+        *value = parts.next()?.strip_prefix(label)?.parse().ok()?;
+      }
+      parts.next().is_none().then_some(values)
+    };
+    let phf = match instance.id {
+      1..=3 => labelled(['m', 't', 'p']).map(|[m, t, p]| Phf::new(instance.id, m, t, p)),
+      9 | 12 => labelled(['n', 'r', 'p']).map(|[n, r, p]| Phf::scrypt(instance.id, n, r, p)),
+      4 => params
+        .parse()
+        .ok()
+        .filter(|cost| *cost < 32)
+        .map(Phf::bcrypt),
+      id => params.parse().ok().map(|n| Phf::iterated(id, n)),
+    };
+    phf.ok_or_else(err)
+  }
+}
+
+impl<'de> Deserialize<'de> for Phf {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Serialize for Phf {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let s = format!("{}", self);
+    serializer.serialize_str(&s)
+  }
+}
+
+impl Primitive for Phf {
+  /// The security of a password hashing function defined as the base 2
+  /// logarithm of the work that the function adds to each guess of the
+  /// password. The work is measured in KiB of memory filled per pass
+  /// times the number of passes for memory-hard functions and bcrypt
+  /// and in iterations of the inner function otherwise.
+  ///
+  /// Unlike other primitives the number of bits does not represent the
+  /// security of the result on its own as that ultimately depends on
+  /// the entropy of the password.
+  fn security(&self) -> Security {
+    let memory = u64::from(self.memory.max(1));
+    let work = match self.id {
+      // The ROMix function of scrypt fills the memory and then reads it
+      // back once for each of the `p` independent lanes.
+      9 | 12 => 2 * memory * u64::from(self.parallelism.max(1)),
+      _ => memory * u64::from(self.iterations.max(1)),
+    };
+    (u64::BITS - 1 - work.leading_zeros()) as Security
+  }
+}
+
+/// The data-dependent variant of Argon2 as defined in [RFC 9106] using
+/// the second recommended option of 64 MiB of memory, 3 passes and 4
+/// lanes.
+///
+/// **Warning:** The data-dependent memory access makes this variant
+/// susceptible to side-channel attacks.
+///
+/// [RFC 9106]: https://datatracker.ietf.org/doc/html/rfc9106
+#[no_mangle]
+pub static ARGON2D: Phf = Phf::new(1, 65536, 3, 4);
+
+/// The data-independent variant of Argon2 as defined in [RFC 9106]
+/// using the second recommended option of 64 MiB of memory, 3 passes
+/// and 4 lanes.
+///
+/// [RFC 9106]: https://datatracker.ietf.org/doc/html/rfc9106
+#[no_mangle]
+pub static ARGON2I: Phf = Phf::new(2, 65536, 3, 4);
+
+/// The hybrid variant of Argon2 as defined in [RFC 9106] using the
+/// second recommended option of 64 MiB of memory, 3 passes and 4 lanes.
+///
+/// [RFC 9106]: https://datatracker.ietf.org/doc/html/rfc9106
+#[no_mangle]
+pub static ARGON2ID: Phf = Phf::new(3, 65536, 3, 4);
+
+/// The password hashing function of OpenBSD as defined in [bcrypt]
+/// with a cost factor of 10.
+///
+/// [bcrypt]: https://www.usenix.org/legacy/events/usenix99/provos/provos.pdf
+#[no_mangle]
+pub static BCRYPT: Phf = Phf::new(4, 4, 1 << 10, 1);
+
+/// The traditional DES-based scheme of crypt(3), which has no
+/// identifier, uses a fixed iteration count of 25 and only the first
+/// eight characters of the password.
+///
+/// **Warning:** DES keys can be searched exhaustively and the
+/// iteration count is far too low to slow down guessing attacks.
+#[no_mangle]
+pub static DES_CRYPT: Phf = Phf::new(13, 0, 25, 1);
+
+/// The MD5-based scheme of crypt(3), identified by `$1$`, which uses a
+/// fixed iteration count of 1000.
+///
+/// **Warning:** MD5 is broken and the iteration count is far too low to
+/// slow down guessing attacks.
+#[no_mangle]
+pub static MD5_CRYPT: Phf = Phf::new(5, 0, 1000, 1);
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA1 with
+/// the iteration count recommended by [OWASP].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [OWASP]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+#[no_mangle]
+pub static PBKDF2_SHA1: Phf = Phf::new(6, 0, 1_300_000, 1);
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA256
+/// with the iteration count recommended by [OWASP].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [OWASP]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+#[no_mangle]
+pub static PBKDF2_SHA256: Phf = Phf::new(7, 0, 600_000, 1);
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA512
+/// with the iteration count recommended by [OWASP].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [OWASP]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+#[no_mangle]
+pub static PBKDF2_SHA512: Phf = Phf::new(8, 0, 210_000, 1);
+
+/// The memory-hard function as defined in [RFC 7914] with `N = 2^17`,
+/// `r = 8` and `p = 1` which uses 128 MiB of memory.
+///
+/// [RFC 7914]: https://datatracker.ietf.org/doc/html/rfc7914
+#[no_mangle]
+pub static SCRYPT: Phf = Phf::new(9, 131072, 1 << 17, 1);
+
+/// The SHA256-based scheme of crypt(3), identified by `$5$`, as defined
+/// in [Unix crypt using SHA-256 and SHA-512] with the default of 5000
+/// rounds.
+///
+/// [Unix crypt using SHA-256 and SHA-512]: https://www.akkadia.org/drepper/SHA-crypt.txt
+#[no_mangle]
+pub static SHA256_CRYPT: Phf = Phf::new(10, 0, 5000, 1);
+
+/// The SHA512-based scheme of crypt(3), identified by `$6$`, as defined
+/// in [Unix crypt using SHA-256 and SHA-512] with the default of 5000
+/// rounds.
+///
+/// [Unix crypt using SHA-256 and SHA-512]: https://www.akkadia.org/drepper/SHA-crypt.txt
+#[no_mangle]
+pub static SHA512_CRYPT: Phf = Phf::new(11, 0, 5000, 1);
+
+/// The scrypt-based scheme of crypt(3), identified by `$y$`, as used by
+/// [libxcrypt] with its default of `N = 2^12`, `r = 32` and `p = 1`
+/// which uses 16 MiB of memory.
+///
+/// [libxcrypt]: https://github.com/besser82/libxcrypt
+#[no_mangle]
+pub static YESCRYPT: Phf = Phf::new(12, 16384, 1 << 12, 1);
+
+/// Placeholder for use in where this primitive is not supported.
+#[no_mangle]
+pub static PHF_NOT_SUPPORTED: Phf = Phf::new(u16::MAX, 0, 0, 0);
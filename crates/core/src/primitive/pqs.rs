@@ -0,0 +1,290 @@
+//! Post-quantum signature primitive and some common instances.
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
+
+/// Represents a post-quantum digital signature primitive such as the
+/// lattice-based ML-DSA, the stateless hash-based SLH-DSA, or the
+/// stateful hash-based LMS and XMSS schemes where `category` is the
+/// NIST post-quantum security category (1 to 5) that the parameter set
+/// targets.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Pqs {
+  pub id: u16,
+  pub category: u16,
+}
+
+impl Pqs {
+  pub const fn new(id: u16, category: u16) -> Self {
+    Self { id, category }
+  }
+
+  /// Whether the parameter set belongs to the stateless hash-based
+  /// SLH-DSA.
+  pub fn is_stateless_hash_based(&self) -> bool {
+    (SLH_DSA_SHA2_128F.id..=SLH_DSA_SHAKE_256S.id).contains(&self.id)
+  }
+
+  /// Whether the parameter set belongs to one of the stateful
+  /// hash-based schemes LMS and XMSS.
+  pub fn is_stateful_hash_based(&self) -> bool {
+    (LMS_SHA256_M24.id..=LMS_SHAKE_M32.id).contains(&self.id)
+      || (XMSS_SHA2_192.id..=XMSS_SHAKE256_256.id).contains(&self.id)
+  }
+}
+
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Pqs, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(LMS_SHA256_M24, "lms_sha256_m24");
+  m.insert(LMS_SHA256_M32, "lms_sha256_m32");
+  m.insert(LMS_SHAKE_M24, "lms_shake_m24");
+  m.insert(LMS_SHAKE_M32, "lms_shake_m32");
+  m.insert(ML_DSA_44, "ml_dsa_44");
+  m.insert(ML_DSA_65, "ml_dsa_65");
+  m.insert(ML_DSA_87, "ml_dsa_87");
+  m.insert(PQS_NOT_SUPPORTED, "not supported");
+  m.insert(SLH_DSA_SHA2_128F, "slh_dsa_sha2_128f");
+  m.insert(SLH_DSA_SHA2_128S, "slh_dsa_sha2_128s");
+  m.insert(SLH_DSA_SHA2_192F, "slh_dsa_sha2_192f");
+  m.insert(SLH_DSA_SHA2_192S, "slh_dsa_sha2_192s");
+  m.insert(SLH_DSA_SHA2_256F, "slh_dsa_sha2_256f");
+  m.insert(SLH_DSA_SHA2_256S, "slh_dsa_sha2_256s");
+  m.insert(SLH_DSA_SHAKE_128F, "slh_dsa_shake_128f");
+  m.insert(SLH_DSA_SHAKE_128S, "slh_dsa_shake_128s");
+  m.insert(SLH_DSA_SHAKE_192F, "slh_dsa_shake_192f");
+  m.insert(SLH_DSA_SHAKE_192S, "slh_dsa_shake_192s");
+  m.insert(SLH_DSA_SHAKE_256F, "slh_dsa_shake_256f");
+  m.insert(SLH_DSA_SHAKE_256S, "slh_dsa_shake_256s");
+  m.insert(XMSS_SHA2_192, "xmss_sha2_192");
+  m.insert(XMSS_SHA2_256, "xmss_sha2_256");
+  m.insert(XMSS_SHAKE256_192, "xmss_shake256_192");
+  m.insert(XMSS_SHAKE256_256, "xmss_shake256_256");
+  m
+});
+
+impl Display for Pqs {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let unrecognised = "unrecognised";
+    let name = REPR.get(self).unwrap_or(&unrecognised);
+    write!(f, "{name}")
+  }
+}
+
+impl FromStr for Pqs {
+  type Err = ParsePrimitiveError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    from_repr(&REPR, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for Pqs {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Primitive for Pqs {
+  /// Returns the classical security level of the parameter set implied
+  /// by its NIST security category.
+  ///
+  /// Categories 1 and 2 are at least as hard to break as AES-128 and
+  /// SHA-256 respectively, 3 and 4 as AES-192 and SHA-384, and 5 as
+  /// AES-256 (see section 4.A.5 of the NIST call for proposals).
+  fn security(&self) -> Security {
+    match self.category {
+      1..=2 => 128,
+      3..=4 => 192,
+      5.. => 256,
+      _ => 0,
+    }
+  }
+}
+
+/// The Leighton-Micali signature scheme using SHA-256/192 as defined in
+/// [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static LMS_SHA256_M24: Pqs = Pqs::new(1, 3);
+
+/// The Leighton-Micali signature scheme using SHA-256 as defined in
+/// [RFC 8554] and [SP 800-208]. The tree height does not affect
+/// security and so a single instance stands in for all heights.
+///
+/// [RFC 8554]: https://www.rfc-editor.org/rfc/rfc8554.html
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static LMS_SHA256_M32: Pqs = Pqs::new(2, 5);
+
+/// The Leighton-Micali signature scheme using SHAKE256/192 as defined
+/// in [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static LMS_SHAKE_M24: Pqs = Pqs::new(3, 3);
+
+/// The Leighton-Micali signature scheme using SHAKE256 as defined in
+/// [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static LMS_SHAKE_M32: Pqs = Pqs::new(4, 5);
+
+/// The Module-Lattice-Based Digital Signature Algorithm parameter set
+/// ML-DSA-44 as defined in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+#[no_mangle]
+pub static ML_DSA_44: Pqs = Pqs::new(5, 2);
+
+/// The Module-Lattice-Based Digital Signature Algorithm parameter set
+/// ML-DSA-65 as defined in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+#[no_mangle]
+pub static ML_DSA_65: Pqs = Pqs::new(6, 3);
+
+/// The Module-Lattice-Based Digital Signature Algorithm parameter set
+/// ML-DSA-87 as defined in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+#[no_mangle]
+pub static ML_DSA_87: Pqs = Pqs::new(7, 5);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-128f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHA2_128F: Pqs = Pqs::new(8, 1);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-128s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHA2_128S: Pqs = Pqs::new(9, 1);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-192f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHA2_192F: Pqs = Pqs::new(10, 3);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-192s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHA2_192S: Pqs = Pqs::new(11, 3);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-256f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHA2_256F: Pqs = Pqs::new(12, 5);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-256s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHA2_256S: Pqs = Pqs::new(13, 5);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-128f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHAKE_128F: Pqs = Pqs::new(14, 1);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-128s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHAKE_128S: Pqs = Pqs::new(15, 1);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-192f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHAKE_192F: Pqs = Pqs::new(16, 3);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-192s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHAKE_192S: Pqs = Pqs::new(17, 3);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-256f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHAKE_256F: Pqs = Pqs::new(18, 5);
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-256s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static SLH_DSA_SHAKE_256S: Pqs = Pqs::new(19, 5);
+
+/// The eXtended Merkle Signature Scheme using SHA-256/192 as defined
+/// in [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static XMSS_SHA2_192: Pqs = Pqs::new(20, 3);
+
+/// The eXtended Merkle Signature Scheme using SHA-256 as defined in
+/// [RFC 8391] and [SP 800-208]. The tree height does not affect
+/// security and so a single instance stands in for all heights.
+///
+/// [RFC 8391]: https://www.rfc-editor.org/rfc/rfc8391.html
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static XMSS_SHA2_256: Pqs = Pqs::new(21, 5);
+
+/// The eXtended Merkle Signature Scheme using SHAKE256/192 as defined
+/// in [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static XMSS_SHAKE256_192: Pqs = Pqs::new(22, 3);
+
+/// The eXtended Merkle Signature Scheme using SHAKE256 as defined in
+/// [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static XMSS_SHAKE256_256: Pqs = Pqs::new(23, 5);
+
+/// Placeholder for use in where this primitive is not supported.
+#[no_mangle]
+pub static PQS_NOT_SUPPORTED: Pqs = Pqs::new(u16::MAX, u16::MAX);
@@ -1,5 +1,12 @@
 //! Symmetric key primitive and some common instances.
-use crate::primitive::{Primitive, Security};
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::primitive::{from_repr, ParsePrimitiveError, Primitive, Security};
 
 /// Represents a symmetric key cryptography primitive.
 #[repr(C)]
@@ -15,6 +22,68 @@ impl Symmetric {
   }
 }
 
+// The name is kept in a lookup table instead of being embedded in the
+// type because sharing strings across language boundaries is a bit
+// dicey.
+static REPR: Lazy<HashMap<Symmetric, &str>> = Lazy::new(|| {
+  let mut m = HashMap::new();
+  m.insert(AES128, "aes128");
+  m.insert(AES192, "aes192");
+  m.insert(AES256, "aes256");
+  m.insert(CAMELLIA128, "camellia128");
+  m.insert(CAMELLIA192, "camellia192");
+  m.insert(CAMELLIA256, "camellia256");
+  m.insert(CHACHA20, "chacha20");
+  m.insert(DES, "des");
+  m.insert(DESX, "desx");
+  m.insert(IDEA, "idea");
+  m.insert(RC4, "rc4");
+  m.insert(SALSA20, "salsa20");
+  m.insert(SERPENT128, "serpent128");
+  m.insert(SERPENT192, "serpent192");
+  m.insert(SERPENT256, "serpent256");
+  m.insert(TDEA2, "tdea2");
+  m.insert(TDEA3, "tdea3");
+  m.insert(XCHACHA20, "xchacha20");
+  m
+});
+
+impl Display for Symmetric {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let unrecognised = "unrecognised";
+    let name = REPR.get(self).unwrap_or(&unrecognised);
+    write!(f, "{name}")
+  }
+}
+
+impl FromStr for Symmetric {
+  type Err = ParsePrimitiveError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    from_repr(&REPR, s)
+  }
+}
+
+impl<'de> Deserialize<'de> for Symmetric {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+impl Serialize for Symmetric {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    let s = format!("{}", self);
+    serializer.serialize_str(&s)
+  }
+}
+
 impl Primitive for Symmetric {
   /// Indicates the security provided by a symmetric key primitive.
   fn security(&self) -> Security {
@@ -58,6 +127,12 @@ pub static CAMELLIA192: Symmetric = Symmetric::new(5, 192);
 #[no_mangle]
 pub static CAMELLIA256: Symmetric = Symmetric::new(6, 256);
 
+/// The ChaCha20 stream cipher as defined in [RFC 8439].
+///
+/// [RFC 8439]: https://datatracker.ietf.org/doc/html/rfc8439
+#[no_mangle]
+pub static CHACHA20: Symmetric = Symmetric::new(16, 256);
+
 /// The Data Encryption Standard algorithm.
 #[no_mangle]
 pub static DES: Symmetric = Symmetric::new(8, 56);
@@ -70,6 +145,18 @@ pub static DESX: Symmetric = Symmetric::new(9, 120);
 #[no_mangle]
 pub static IDEA: Symmetric = Symmetric::new(10, 126 /* See Wikipedia article. */);
 
+/// The RC4 stream cipher also known as ARCFOUR.
+///
+/// **Warning:** This algorithm has been shown to be broken. It should
+/// only be used where compatibility with legacy systems, not security,
+/// is the goal.
+#[no_mangle]
+pub static RC4: Symmetric = Symmetric::new(18, 128);
+
+/// The Salsa20 stream cipher with 20 rounds.
+#[no_mangle]
+pub static SALSA20: Symmetric = Symmetric::new(19, 256);
+
 /// The Serpent encryption algorithm.
 #[no_mangle]
 pub static SERPENT128: Symmetric = Symmetric::new(11, 128);
@@ -95,3 +182,8 @@ pub static TDEA2: Symmetric = Symmetric::new(14, 95);
 /// [SP800-67]: https://doi.org/10.6028/NIST.SP.800-67r2
 #[no_mangle]
 pub static TDEA3: Symmetric = Symmetric::new(15, 112);
+
+/// The XChaCha20 stream cipher which extends the nonce of ChaCha20 to
+/// 192 bits.
+#[no_mangle]
+pub static XCHACHA20: Symmetric = Symmetric::new(17, 256);
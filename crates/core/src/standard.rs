@@ -5,39 +5,274 @@ pub mod cnsa;
 pub mod ecrypt;
 pub mod lenstra;
 pub mod nist;
+pub mod policy;
 pub mod testing;
 mod utilities;
 
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+
 use crate::context::Context;
 use crate::primitive::asymmetric::Asymmetric;
+use crate::primitive::drbg::Drbg;
 use crate::primitive::ecc::Ecc;
 use crate::primitive::ffc::Ffc;
 use crate::primitive::hash::Hash;
 use crate::primitive::ifc::Ifc;
+use crate::primitive::kdf::Kdf;
+use crate::primitive::kem::Kem;
+use crate::primitive::mac::Mac;
+use crate::primitive::mode::Mode;
+use crate::primitive::phf::Phf;
+use crate::primitive::pqs::Pqs;
 use crate::primitive::symmetric::Symmetric;
+use crate::primitive::{Primitive, Security};
 
 /// Represents a cryptographic standard or research publication.
 ///
 /// The functions are used to assess the validity of various
 /// cryptographic primitives against the standard.
 pub trait Standard {
-  fn validate_asymmetric(ctx: Context, key: Asymmetric) -> Result<Asymmetric, Asymmetric> {
+  fn validate_asymmetric(ctx: Context, key: Asymmetric) -> Verdict<Asymmetric> {
     match key {
-      Asymmetric::Ecc(ecc) => Self::validate_ecc(ctx, ecc)
-        .map(Into::into)
-        .map_err(Into::into),
-      Asymmetric::Ifc(ifc) => Self::validate_ifc(ctx, ifc)
-        .map(Into::into)
-        .map_err(Into::into),
-      Asymmetric::Ffc(ffc) => Self::validate_ffc(ctx, ffc)
-        .map(Into::into)
-        .map_err(Into::into),
-    }
-  }
-
-  fn validate_ecc(ctx: Context, key: Ecc) -> Result<Ecc, Ecc>;
-  fn validate_ffc(ctx: Context, key: Ffc) -> Result<Ffc, Ffc>;
-  fn validate_ifc(ctx: Context, key: Ifc) -> Result<Ifc, Ifc>;
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash>;
-  fn validate_symmetric(ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric>;
+      Asymmetric::Ecc(ecc) => Self::validate_ecc(ctx, ecc).map(Into::into),
+      Asymmetric::Ifc(ifc) => Self::validate_ifc(ctx, ifc).map(Into::into),
+      Asymmetric::Ffc(ffc) => Self::validate_ffc(ctx, ffc).map(Into::into),
+      Asymmetric::Pqs(pqs) => Self::validate_pqs(ctx, pqs).map(Into::into),
+    }
+  }
+
+  fn validate_drbg(ctx: Context, drbg: Drbg) -> Verdict<Drbg>;
+  fn validate_ecc(ctx: Context, key: Ecc) -> Verdict<Ecc>;
+  fn validate_ffc(ctx: Context, key: Ffc) -> Verdict<Ffc>;
+  fn validate_ifc(ctx: Context, key: Ifc) -> Verdict<Ifc>;
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf>;
+  fn validate_kem(ctx: Context, kem: Kem) -> Verdict<Kem>;
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac>;
+  fn validate_mode(ctx: Context, mode: Mode) -> Verdict<Mode>;
+  fn validate_phf(ctx: Context, phf: Phf) -> Verdict<Phf>;
+  fn validate_pqs(ctx: Context, key: Pqs) -> Verdict<Pqs>;
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash>;
+  fn validate_symmetric(ctx: Context, key: Symmetric) -> Verdict<Symmetric>;
+
+  /// Validates the length of the salt, in bytes, of an RSASSA-PSS
+  /// signature that uses the hash function `hash`.
+  ///
+  /// Unless a standard says otherwise, the salt may not be longer than
+  /// the output of the hash function as required by FIPS 186-5 and the
+  /// recommendation is a salt of that length.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate the salt length of a
+  /// signature using SHA256.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::hash::SHA256;
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Nist::validate_salt_length(ctx, SHA256, 64);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.into_result(), Err(32));
+  /// ```
+  fn validate_salt_length(_ctx: Context, hash: Hash, salt_length: u32) -> Verdict<u32> {
+    salt_no_longer_than_hash(hash, salt_length)
+  }
+}
+
+/// Checks that the salt of an RSASSA-PSS signature is no longer than
+/// the output of the hash function as required by FIPS 186-5.
+pub(crate) fn salt_no_longer_than_hash(hash: Hash, salt_length: u32) -> Verdict<u32> {
+  let h_len = u32::from(hash.n >> 3);
+  let status = if salt_length <= h_len {
+    Status::Acceptable
+  } else {
+    Status::Disallowed
+  };
+  Verdict::new(status, h_len, hash.security()).cite(Citation::new("NIST FIPS 186-5", None))
+}
+
+/// The reason a standard gives for accepting or rejecting a primitive.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "status")]
+pub enum Status {
+  /// The primitive is acceptable for use.
+  Acceptable,
+  /// The primitive is acceptable for use up to and including the year
+  /// `until` after which it is no longer compliant.
+  Deprecated { until: u16 },
+  /// The primitive may only be used to process already protected data
+  /// such as verifying existing signatures.
+  Legacy,
+  /// The primitive is not allowed.
+  Disallowed,
+  /// The primitive is not specified by the standard.
+  Unrecognised,
+}
+
+impl Status {
+  /// Whether a primitive with this status complies with the standard.
+  pub fn is_compliant(&self) -> bool {
+    matches!(self, Self::Acceptable | Self::Deprecated { .. })
+  }
+}
+
+impl Display for Status {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Acceptable => write!(f, "acceptable"),
+      Self::Deprecated { until } => write!(f, "deprecated after {until}"),
+      Self::Legacy => write!(f, "legacy use only"),
+      Self::Disallowed => write!(f, "disallowed"),
+      Self::Unrecognised => write!(f, "unrecognised"),
+    }
+  }
+}
+
+/// A reference to the part of a document that a verdict is based on.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub struct Citation {
+  pub document: &'static str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub page: Option<u16>,
+}
+
+impl Citation {
+  pub const fn new(document: &'static str, page: Option<u16>) -> Self {
+    Self { document, page }
+  }
+}
+
+impl Display for Citation {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self.page {
+      Some(page) => write!(f, "{}, p. {}", self.document, page),
+      None => write!(f, "{}", self.document),
+    }
+  }
+}
+
+/// The result of assessing a primitive against a standard.
+///
+/// Besides the recommended primitive, a verdict carries the reason for
+/// the assessment, the security level that was assessed, and where in
+/// the standard the guidance can be found.
+///
+/// A verdict can be compared to and converted into the `Result` that
+/// was returned by the functions in this module prior to its
+/// introduction where `Ok` and `Err` hold the recommendation for a
+/// compliant and non-compliant primitive respectively.
+///
+/// ```
+/// use wardstone_core::context::Context;
+/// use wardstone_core::primitive::hash::{SHA1, SHA224};
+/// use wardstone_core::standard::nist::Nist;
+/// use wardstone_core::standard::{Standard, Status};
+///
+/// let ctx = Context::default();
+/// let verdict = Nist::validate_hash(ctx, SHA1);
+/// assert_eq!(verdict.status(), Status::Disallowed);
+/// assert_eq!(verdict.into_result(), Err(SHA224));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Verdict<T> {
+  status: Status,
+  recommendation: T,
+  security: Security,
+  citation: Option<Citation>,
+}
+
+impl<T> Verdict<T> {
+  /// Creates a new verdict.
+  ///
+  /// `security` denotes the security level of the assessed primitive
+  /// as understood by the standard.
+  pub fn new(status: Status, recommendation: T, security: Security) -> Self {
+    Self {
+      status,
+      recommendation,
+      security,
+      citation: None,
+    }
+  }
+
+  /// Attaches the part of the standard that the verdict is based on.
+  pub fn cite(mut self, citation: Citation) -> Self {
+    self.citation = Some(citation);
+    self
+  }
+
+  pub fn status(&self) -> Status {
+    self.status
+  }
+
+  pub fn security(&self) -> Security {
+    self.security
+  }
+
+  pub fn citation(&self) -> Option<Citation> {
+    self.citation
+  }
+
+  pub fn is_compliant(&self) -> bool {
+    self.status.is_compliant()
+  }
+
+  /// Adjusts the verdict to the operation in the context.
+  ///
+  /// Primitives that are deprecated or only fit for legacy use remain
+  /// acceptable when they are used to process already protected data.
+  pub(crate) fn for_operation(mut self, ctx: Context) -> Self {
+    if ctx.is_processing() && matches!(self.status, Status::Deprecated { .. } | Status::Legacy) {
+      self.status = Status::Acceptable;
+    }
+    self
+  }
+
+  /// Maps the recommendation to another type leaving the rest of the
+  /// verdict untouched.
+  pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Verdict<U> {
+    Verdict {
+      status: self.status,
+      recommendation: f(self.recommendation),
+      security: self.security,
+      citation: self.citation,
+    }
+  }
+
+  /// Converts the verdict into a `Result` where `Ok` holds the
+  /// recommendation for a compliant primitive and `Err` the
+  /// recommendation for a non-compliant one.
+  pub fn into_result(self) -> Result<T, T> {
+    if self.is_compliant() {
+      Ok(self.recommendation)
+    } else {
+      Err(self.recommendation)
+    }
+  }
+}
+
+impl<T: Copy> Verdict<T> {
+  pub fn recommendation(&self) -> T {
+    self.recommendation
+  }
+}
+
+impl<T> From<Verdict<T>> for Result<T, T> {
+  fn from(verdict: Verdict<T>) -> Self {
+    verdict.into_result()
+  }
+}
+
+impl<T: PartialEq> PartialEq<Result<T, T>> for Verdict<T> {
+  fn eq(&self, other: &Result<T, T>) -> bool {
+    match other {
+      Ok(recommendation) => self.is_compliant() && self.recommendation == *recommendation,
+      Err(recommendation) => !self.is_compliant() && self.recommendation == *recommendation,
+    }
+  }
 }
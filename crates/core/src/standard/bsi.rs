@@ -7,17 +7,31 @@ use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
 
-use crate::context::Context;
+use crate::context::{Context, Usage};
+use crate::primitive::drbg::*;
 use crate::primitive::ecc::*;
 use crate::primitive::ffc::*;
 use crate::primitive::hash::*;
 use crate::primitive::ifc::*;
+use crate::primitive::kdf::*;
+use crate::primitive::kem::*;
+use crate::primitive::mac::*;
+use crate::primitive::mode::*;
+use crate::primitive::phf::*;
+use crate::primitive::pqs::*;
 use crate::primitive::symmetric::*;
 use crate::primitive::Primitive;
-use crate::standard::Standard;
+use crate::standard::{Citation, Standard, Status, Verdict};
 
 const CUTOFF_YEAR_RSA: u16 = 2023; // See p. 17.
 
+const MIN_PHF_MEMORY: u32 = 65536; // See RFC 9106 p. 17.
+const MIN_TAG_LENGTH: u16 = 96; // See p. 45.
+
+const TR_02102_1: &str = "BSI TR-02102-1";
+
+// The guide recommends neither Curve25519 nor Curve448 so X25519, X448,
+// and EdDSA are not compliant.
 static SPECIFIED_CURVES: Lazy<HashSet<Ecc>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(SECP256R1);
@@ -30,6 +44,25 @@ static SPECIFIED_CURVES: Lazy<HashSet<Ecc>> = Lazy::new(|| {
   s
 });
 
+static SPECIFIED_DRBGS: Lazy<HashSet<Drbg>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CTR_DRBG_AES128);
+  s.insert(CTR_DRBG_AES128_NO_DF);
+  s.insert(CTR_DRBG_AES192);
+  s.insert(CTR_DRBG_AES192_NO_DF);
+  s.insert(CTR_DRBG_AES256);
+  s.insert(CTR_DRBG_AES256_NO_DF);
+  s.insert(HASH_DRBG_SHA256);
+  s.insert(HASH_DRBG_SHA384);
+  s.insert(HASH_DRBG_SHA512);
+  s.insert(HASH_DRBG_SHA512_256);
+  s.insert(HMAC_DRBG_SHA256);
+  s.insert(HMAC_DRBG_SHA384);
+  s.insert(HMAC_DRBG_SHA512);
+  s.insert(HMAC_DRBG_SHA512_256);
+  s
+});
+
 static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(SHA256);
@@ -42,6 +75,84 @@ static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   s
 });
 
+// Key and tag lengths are parameters of these primitives so the sets
+// below hold the identifiers of the constructions instead.
+static SPECIFIED_KDFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(HKDF_SHA256.id);
+  s.insert(HKDF_SHA384.id);
+  s.insert(HKDF_SHA512.id);
+  s.insert(KBKDF_CMAC.id);
+  s.insert(KBKDF_HMAC_SHA256.id);
+  s.insert(KBKDF_HMAC_SHA384.id);
+  s.insert(KBKDF_HMAC_SHA512.id);
+  s.insert(SP800_56C_SHA256.id);
+  s.insert(SP800_56C_SHA384.id);
+  s.insert(SP800_56C_SHA512.id);
+  s
+});
+
+static SPECIFIED_KEMS: Lazy<HashSet<Kem>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(SECP256R1MLKEM768);
+  s.insert(SECP384R1MLKEM1024);
+  s
+});
+
+// See p. 25. The guide also recommends CBC and CTR only in combination
+// with a method for data authentication.
+static SPECIFIED_MACS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CMAC.id);
+  s.insert(GMAC.id);
+  s.insert(HMAC_SHA256.id);
+  s.insert(HMAC_SHA384.id);
+  s.insert(HMAC_SHA512.id);
+  s.insert(HMAC_SHA3_256.id);
+  s.insert(HMAC_SHA3_384.id);
+  s.insert(HMAC_SHA3_512.id);
+  s
+});
+
+static SPECIFIED_MODES: Lazy<HashSet<Mode>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CBC);
+  s.insert(CCM);
+  s.insert(CTR);
+  s.insert(GCM);
+  s
+});
+
+// Parameter sets below NIST security category 3 are known but
+// disallowed.
+static SPECIFIED_PQ_SIGNATURES: Lazy<HashSet<Pqs>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(LMS_SHA256_M24);
+  s.insert(LMS_SHA256_M32);
+  s.insert(LMS_SHAKE_M24);
+  s.insert(LMS_SHAKE_M32);
+  s.insert(ML_DSA_44);
+  s.insert(ML_DSA_65);
+  s.insert(ML_DSA_87);
+  s.insert(SLH_DSA_SHA2_128F);
+  s.insert(SLH_DSA_SHA2_128S);
+  s.insert(SLH_DSA_SHA2_192F);
+  s.insert(SLH_DSA_SHA2_192S);
+  s.insert(SLH_DSA_SHA2_256F);
+  s.insert(SLH_DSA_SHA2_256S);
+  s.insert(SLH_DSA_SHAKE_128F);
+  s.insert(SLH_DSA_SHAKE_128S);
+  s.insert(SLH_DSA_SHAKE_192F);
+  s.insert(SLH_DSA_SHAKE_192S);
+  s.insert(SLH_DSA_SHAKE_256F);
+  s.insert(SLH_DSA_SHAKE_256S);
+  s.insert(XMSS_SHA2_192);
+  s.insert(XMSS_SHA2_256);
+  s.insert(XMSS_SHAKE256_192);
+  s.insert(XMSS_SHAKE256_256);
+  s
+});
+
 // "The present version of this Technical Guideline does not recommend
 // any other block ciphers besides AES" (2023, p. 24).
 static SPECIFIED_SYMMETRIC_KEYS: Lazy<HashSet<Symmetric>> = Lazy::new(|| {
@@ -69,12 +180,11 @@ impl Bsi {
   /// signatures use
   /// [`validate_hash`](crate::standard::bsi::Bsi::validate_hash).
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** For an HMAC the minimum security required is ≥ 128 (see
   /// p. 45) but the minimum digest length for a hash function that can
@@ -97,39 +207,93 @@ impl Bsi {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHA256};
   /// use wardstone_core::standard::bsi::Bsi;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let hmac_sha1 = SHA1;
   /// let hmac_sha256 = SHA256;
-  /// assert_eq!(Bsi::validate_hash_based(ctx, hmac_sha1), Err(hmac_sha256));
+  /// let verdict = Bsi::validate_hash_based(ctx, hmac_sha1);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), hmac_sha256);
   /// ```
-  pub fn validate_hash_based(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  pub fn validate_hash_based(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    let pre_image_resistance = hash.security() << 1;
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, pre_image_resistance)
+        .cite(Citation::new(TR_02102_1, Some(45)))
+        .for_operation(ctx)
+    };
     if SPECIFIED_HASH_FUNCTIONS.contains(&hash) {
-      let pre_image_resistance = hash.security() << 1;
       let security = ctx.security().max(pre_image_resistance);
       match security {
-        ..=127 => Err(SHA256),
-        128..=256 => Ok(SHA256),
-        257..=384 => Ok(SHA384),
-        385.. => Ok(SHA512),
+        ..=127 => verdict(Status::Disallowed, SHA256),
+        128..=256 => verdict(Status::Acceptable, SHA256),
+        257..=384 => verdict(Status::Acceptable, SHA384),
+        385.. => verdict(Status::Acceptable, SHA512),
       }
     } else {
-      Err(SHA256)
+      verdict(Status::Unrecognised, SHA256)
     }
   }
 }
 
 impl Standard for Bsi {
+  /// Validates a deterministic random bit generator.
+  ///
+  /// The guide recommends generators of the DRG.3 or DRG.4 classes of
+  /// AIS 20/31 which include Hash_DRBG, HMAC_DRBG, and CTR_DRBG of NIST
+  /// SP 800-90A when they are built on a hash function or block cipher
+  /// that the guide recommends. Dual_EC_DRBG is disallowed.
+  ///
+  /// Any other generator is unrecognised. A recommended generator with
+  /// less than 120 bits of security is disallowed and any other is
+  /// acceptable. The recommendation is CTR_DRBG with the AES key size
+  /// that matches the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a generator that is
+  /// built on SHA1.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::drbg::{CTR_DRBG_AES128, HMAC_DRBG_SHA1};
+  /// use wardstone_core::standard::bsi::Bsi;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Bsi::validate_drbg(ctx, HMAC_DRBG_SHA1);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), CTR_DRBG_AES128);
+  /// ```
+  fn validate_drbg(ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, drbg.security()).cite(Citation::new(TR_02102_1, None))
+    };
+    if [DUAL_EC_DRBG_P256, DUAL_EC_DRBG_P384, DUAL_EC_DRBG_P521].contains(&drbg) {
+      return verdict(Status::Disallowed, CTR_DRBG_AES128);
+    }
+    if SPECIFIED_DRBGS.contains(&drbg) {
+      let security = ctx.security().max(drbg.security());
+      match security {
+        ..=119 => verdict(Status::Disallowed, CTR_DRBG_AES128),
+        120..=128 => verdict(Status::Acceptable, CTR_DRBG_AES128),
+        129..=192 => verdict(Status::Acceptable, CTR_DRBG_AES192),
+        193.. => verdict(Status::Acceptable, CTR_DRBG_AES256),
+      }
+    } else {
+      verdict(Status::Unrecognised, CTR_DRBG_AES128)
+    }
+  }
+
   /// Validate an elliptic curve cryptography primitive used for digital
   /// signatures and key establishment where f is the key size.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// **Note:** While the guide allows for elliptic curve system
   /// parameters "that are provided by a trustworthy authority"
@@ -146,23 +310,30 @@ impl Standard for Bsi {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ecc::BRAINPOOLP256R1;
   /// use wardstone_core::standard::bsi::Bsi;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Bsi::validate_ecc(ctx, BRAINPOOLP256R1), Ok(BRAINPOOLP256R1));
+  /// let verdict = Bsi::validate_ecc(ctx, BRAINPOOLP256R1);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), BRAINPOOLP256R1);
   /// ```
-  fn validate_ecc(ctx: Context, key: Ecc) -> Result<Ecc, Ecc> {
+  fn validate_ecc(ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(TR_02102_1, Some(73)))
+        .for_operation(ctx)
+    };
     if SPECIFIED_CURVES.contains(&key) {
       let security = ctx.security().max(key.security());
       match security {
-        ..=124 => Err(BRAINPOOLP256R1),
-        125..=128 => Ok(BRAINPOOLP256R1),
-        129..=160 => Ok(BRAINPOOLP320R1),
-        161..=192 => Ok(BRAINPOOLP384R1),
-        193.. => Ok(BRAINPOOLP512R1),
+        ..=124 => verdict(Status::Disallowed, BRAINPOOLP256R1),
+        125..=128 => verdict(Status::Acceptable, BRAINPOOLP256R1),
+        129..=160 => verdict(Status::Acceptable, BRAINPOOLP320R1),
+        161..=192 => verdict(Status::Acceptable, BRAINPOOLP384R1),
+        193.. => verdict(Status::Acceptable, BRAINPOOLP512R1),
       }
     } else {
-      Err(BRAINPOOLP256R1)
+      verdict(Status::Unrecognised, BRAINPOOLP256R1)
     }
   }
 
@@ -171,12 +342,11 @@ impl Standard for Bsi {
   /// Examples include the DSA and key establishment algorithms such as
   /// Diffie-Hellman.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key sizes L and N that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key sizes L
-  /// and N with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key sizes L and N that one
+  /// should use instead. If it is but the context specifies a higher
+  /// security level, the recommendation is the key sizes L and N with
+  /// the desired security level.
   ///
   /// # Example
   ///
@@ -186,21 +356,28 @@ impl Standard for Bsi {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ffc::{DSA_2048_224, DSA_3072_256};
   /// use wardstone_core::standard::bsi::Bsi;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let dsa_2048 = DSA_2048_224;
   /// let dsa_3072 = DSA_3072_256;
-  /// assert_eq!(Bsi::validate_ffc(ctx, dsa_2048), Err(dsa_3072));
+  /// let verdict = Bsi::validate_ffc(ctx, dsa_2048);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), dsa_3072);
   /// ```
-  fn validate_ffc(ctx: Context, key: Ffc) -> Result<Ffc, Ffc> {
+  fn validate_ffc(ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(TR_02102_1, Some(48)))
+        .for_operation(ctx)
+    };
     let security = ctx.security().max(key.security());
     match security {
       // Page 48 says q > 2²⁵⁰.
-      ..=124 => Err(DSA_3072_256),
-      125..=128 => Ok(DSA_3072_256),
-      129..=192 => Ok(DSA_7680_384),
-      193.. => Ok(DSA_15360_512),
+      ..=124 => verdict(Status::Disallowed, DSA_3072_256),
+      125..=128 => verdict(Status::Acceptable, DSA_3072_256),
+      129..=192 => verdict(Status::Acceptable, DSA_7680_384),
+      193.. => verdict(Status::Acceptable, DSA_15360_512),
     }
   }
 
@@ -213,12 +390,11 @@ impl Standard for Bsi {
   /// (KDFs), and random bit generation use
   /// [`validate_hash_based`](crate::standard::bsi::Bsi::validate_hash_based).
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** An alternative might be suggested for a compliant hash
   /// function with a similar security level in which a switch to the
@@ -236,34 +412,47 @@ impl Standard for Bsi {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHA256};
   /// use wardstone_core::standard::bsi::Bsi;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Bsi::validate_hash(ctx, SHA1), Err(SHA256));
+  /// let verdict = Bsi::validate_hash(ctx, SHA1);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), SHA256);
   /// ```
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    if matches!(
+      ctx.usage(),
+      Usage::MessageAuthentication | Usage::KeyDerivation
+    ) {
+      return Self::validate_hash_based(ctx, hash);
+    }
+
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, hash.security())
+        .cite(Citation::new(TR_02102_1, Some(41)))
+        .for_operation(ctx)
+    };
     if SPECIFIED_HASH_FUNCTIONS.contains(&hash) {
       let security = ctx.security().max(hash.security());
       match security {
-        ..=119 => Err(SHA256),
-        120..=128 => Ok(SHA256),
-        129..=192 => Ok(SHA384),
-        193.. => Ok(SHA512),
+        ..=119 => verdict(Status::Disallowed, SHA256),
+        120..=128 => verdict(Status::Acceptable, SHA256),
+        129..=192 => verdict(Status::Acceptable, SHA384),
+        193.. => verdict(Status::Acceptable, SHA512),
       }
     } else {
-      Err(SHA256)
+      verdict(Status::Unrecognised, SHA256)
     }
   }
 
   /// Validates  an integer factorisation cryptography primitive the
   /// most common of which is the RSA signature algorithm.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key size that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key size
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key size that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the key size with the desired
+  /// security level.
   ///
   /// **Note:** Unlike other functions in this module, this will return
   /// a generic structure that specifies minimum private and public
@@ -277,43 +466,331 @@ impl Standard for Bsi {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ifc::RSA_PSS_2048;
   /// use wardstone_core::standard::bsi::Bsi;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Bsi::validate_ifc(ctx, RSA_PSS_2048), Ok(RSA_PSS_2048));
+  /// let verdict = Bsi::validate_ifc(ctx, RSA_PSS_2048);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// assert_eq!(verdict.recommendation(), RSA_PSS_2048);
   /// ```
-  fn validate_ifc(ctx: Context, key: Ifc) -> Result<Ifc, Ifc> {
+  fn validate_ifc(ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(TR_02102_1, Some(17)))
+        .for_operation(ctx)
+    };
     let security = ctx.security().max(key.security());
     match security {
       ..=111 => {
         if ctx.year() > CUTOFF_YEAR_RSA {
-          Err(RSA_PSS_3072)
+          verdict(Status::Disallowed, RSA_PSS_3072)
         } else {
-          Err(RSA_PSS_2048)
+          verdict(Status::Disallowed, RSA_PSS_2048)
         }
       },
       112..=127 => {
         if ctx.year() > CUTOFF_YEAR_RSA {
-          Err(RSA_PSS_3072)
+          verdict(Status::Legacy, RSA_PSS_3072)
         } else {
-          Ok(RSA_PSS_2048)
+          let until = CUTOFF_YEAR_RSA;
+          verdict(Status::Deprecated { until }, RSA_PSS_2048)
         }
       },
-      128..=191 => Ok(RSA_PSS_3072),
-      192..=255 => Ok(RSA_PSS_7680),
-      256.. => Ok(RSA_PSS_15360),
+      128..=191 => verdict(Status::Acceptable, RSA_PSS_3072),
+      192..=255 => verdict(Status::Acceptable, RSA_PSS_7680),
+      256.. => verdict(Status::Acceptable, RSA_PSS_15360),
+    }
+  }
+
+  /// Validates a key derivation function.
+  ///
+  /// The guide recommends the key derivation functions of NIST SP
+  /// 800-56C and NIST SP 800-108 which include HKDF and the key-based
+  /// KDFs using HMAC or CMAC.
+  ///
+  /// A function the guide does not recommend is unrecognised and a
+  /// recommended one that derives a key with less than 120 bits of
+  /// security is disallowed. Otherwise the function is acceptable. The
+  /// recommendation is HKDF with the desired security level.
+  ///
+  /// **Note:** The guide recommends Argon2id instead of PBKDF2 to
+  /// derive keys from passwords and so PBKDF2 is not recognised. The
+  /// pseudorandom functions of TLS are covered by TR-02102-2 instead.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kdf::HKDF_SHA256;
+  /// use wardstone_core::standard::bsi::Bsi;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Bsi::validate_kdf(ctx, HKDF_SHA256);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), HKDF_SHA256);
+  /// ```
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kdf.security())
+        .cite(Citation::new(TR_02102_1, None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_KDFS.contains(&kdf.id) {
+      let security = ctx.security().max(kdf.security());
+      match security {
+        ..=119 => verdict(Status::Disallowed, HKDF_SHA256),
+        120..=256 => verdict(Status::Acceptable, HKDF_SHA256),
+        257..=384 => verdict(Status::Acceptable, HKDF_SHA384),
+        385.. => verdict(Status::Acceptable, HKDF_SHA512),
+      }
+    } else {
+      verdict(Status::Unrecognised, HKDF_SHA256)
+    }
+  }
+
+  /// Validates a key encapsulation mechanism.
+  ///
+  /// The guide recommends ML-KEM-768, ML-KEM-1024, FrodoKEM, and
+  /// Classic McEliece only in combination with a traditional key
+  /// agreement scheme over one of its recommended curves. Hybrid
+  /// schemes that use X25519 are therefore not compliant.
+  ///
+  /// The status of the verdict tells whether the key encapsulation
+  /// mechanism is compliant. If it is not, the recommendation is the
+  /// primitive that one should use instead. If it is but the context
+  /// specifies a higher security level, the recommendation is the
+  /// primitive with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key
+  /// encapsulation mechanism.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kem::{SECP256R1MLKEM768, X25519MLKEM768};
+  /// use wardstone_core::standard::bsi::Bsi;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Bsi::validate_kem(ctx, X25519MLKEM768);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), SECP256R1MLKEM768);
+  /// ```
+  fn validate_kem(ctx: Context, kem: Kem) -> Verdict<Kem> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kem.security())
+        .cite(Citation::new(TR_02102_1, None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_KEMS.contains(&kem) {
+      let security = ctx.security().max(kem.security());
+      match security {
+        ..=124 => verdict(Status::Disallowed, SECP256R1MLKEM768),
+        125..=192 => verdict(Status::Acceptable, SECP256R1MLKEM768),
+        193.. => verdict(Status::Acceptable, SECP384R1MLKEM1024),
+      }
+    } else {
+      verdict(Status::Unrecognised, SECP256R1MLKEM768)
+    }
+  }
+
+  /// Validates a message authentication code.
+  ///
+  /// The guide recommends HMAC with a recommended hash function, CMAC,
+  /// and GMAC with a key of at least 128 bits and a tag of at least 96
+  /// bits.
+  ///
+  /// A MAC the guide does not recommend, such as HMAC with SHA1, is
+  /// unrecognised. A tag shorter than 96 bits is disallowed, in which
+  /// case the same MAC with a 96-bit tag is recommended, and so is a
+  /// key shorter than 128 bits. Otherwise the MAC is acceptable. The
+  /// recommendation is HMAC with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant MAC.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mac::{HMAC_SHA1, HMAC_SHA256};
+  /// use wardstone_core::standard::bsi::Bsi;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Bsi::validate_mac(ctx, HMAC_SHA1);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), HMAC_SHA256);
+  /// ```
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, mac.security())
+        .cite(Citation::new(TR_02102_1, Some(45)))
+        .for_operation(ctx)
+    };
+    if !SPECIFIED_MACS.contains(&mac.id) {
+      return verdict(Status::Unrecognised, HMAC_SHA256);
+    }
+    if mac.tag < MIN_TAG_LENGTH {
+      let recommendation = Mac::new(mac.id, mac.key, MIN_TAG_LENGTH);
+      return verdict(Status::Disallowed, recommendation);
+    }
+    let security = ctx.security().max(mac.security());
+    match security {
+      ..=127 => verdict(Status::Disallowed, HMAC_SHA256),
+      128..=256 => verdict(Status::Acceptable, HMAC_SHA256),
+      257..=384 => verdict(Status::Acceptable, HMAC_SHA384),
+      385.. => verdict(Status::Acceptable, HMAC_SHA512),
+    }
+  }
+
+  /// Validates a mode of operation of a block cipher.
+  ///
+  /// The guide recommends the GCM and CCM authenticated encryption
+  /// modes as well as the CBC and CTR modes. Other modes such as ECB
+  /// and constructions based on stream ciphers are not recommended.
+  ///
+  /// The recommended modes are acceptable and recommended as they are.
+  /// ECB is disallowed and any other mode is unrecognised, in which
+  /// case GCM is recommended instead.
+  ///
+  /// **Note:** The CBC and CTR modes do not protect the integrity of
+  /// the data and the guide requires them to be combined with a
+  /// message authentication code. This function is unable to verify
+  /// whether this is the case.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant mode.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mode::{ECB, GCM};
+  /// use wardstone_core::standard::bsi::Bsi;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Bsi::validate_mode(ctx, ECB);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), GCM);
+  /// ```
+  fn validate_mode(ctx: Context, mode: Mode) -> Verdict<Mode> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, mode.security())
+        .cite(Citation::new(TR_02102_1, Some(25)))
+        .for_operation(ctx)
+    };
+    if mode == ECB {
+      verdict(Status::Disallowed, GCM)
+    } else if SPECIFIED_MODES.contains(&mode) {
+      verdict(Status::Acceptable, mode)
+    } else {
+      verdict(Status::Unrecognised, GCM)
+    }
+  }
+
+  /// Validates a password hashing function.
+  ///
+  /// The guide recommends Argon2id to hash passwords using the
+  /// parameters recommended in RFC 9106. The second option of the RFC,
+  /// meant for environments with less memory, is taken as the minimum
+  /// so the function must use at least 64 MiB of memory and do at least
+  /// as much work as that option.
+  ///
+  /// Any other function, including scrypt and PBKDF2, is therefore
+  /// unrecognised and Argon2id with less memory or work is disallowed.
+  /// Otherwise the function is acceptable. The recommendation is always
+  /// Argon2id with the parameters of the second option.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate Argon2id with too
+  /// little memory.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::phf::{Phf, ARGON2ID};
+  /// use wardstone_core::standard::bsi::Bsi;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let argon2id = Phf::new(ARGON2ID.id, 19456, 2, 1);
+  /// let verdict = Bsi::validate_phf(ctx, argon2id);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ARGON2ID);
+  /// ```
+  fn validate_phf(ctx: Context, phf: Phf) -> Verdict<Phf> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, phf.security())
+        .cite(Citation::new(TR_02102_1, None))
+        .for_operation(ctx)
+    };
+    if phf.id != ARGON2ID.id {
+      return verdict(Status::Unrecognised, ARGON2ID);
+    }
+    if phf.memory < MIN_PHF_MEMORY || phf.security() < ARGON2ID.security() {
+      verdict(Status::Disallowed, ARGON2ID)
+    } else {
+      verdict(Status::Acceptable, ARGON2ID)
+    }
+  }
+
+  /// Validates a post-quantum signature primitive.
+  ///
+  /// The guide recommends the stateful hash-based schemes LMS and XMSS,
+  /// SLH-DSA, and ML-DSA with parameter sets that target at least NIST
+  /// security category 3. Those of categories 1 and 2 are disallowed.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::pqs::{ML_DSA_44, ML_DSA_65};
+  /// use wardstone_core::standard::bsi::Bsi;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Bsi::validate_pqs(ctx, ML_DSA_44);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ML_DSA_65);
+  /// ```
+  fn validate_pqs(ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(TR_02102_1, None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_PQ_SIGNATURES.contains(&key) {
+      let security = ctx.security().max(key.security());
+      match security {
+        ..=191 => verdict(Status::Disallowed, ML_DSA_65),
+        192 => verdict(Status::Acceptable, ML_DSA_65),
+        193.. => verdict(Status::Acceptable, ML_DSA_87),
+      }
+    } else {
+      verdict(Status::Unrecognised, ML_DSA_65)
     }
   }
 
   /// Validates a symmetric key primitive according to page 24 of the
   /// guide.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -324,22 +801,29 @@ impl Standard for Bsi {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::symmetric::{AES128, TDEA3};
   /// use wardstone_core::standard::bsi::Bsi;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Bsi::validate_symmetric(ctx, TDEA3), Err(AES128));
+  /// let verdict = Bsi::validate_symmetric(ctx, TDEA3);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), AES128);
   /// ```
-  fn validate_symmetric(ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric> {
+  fn validate_symmetric(ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(TR_02102_1, Some(24)))
+        .for_operation(ctx)
+    };
     if SPECIFIED_SYMMETRIC_KEYS.contains(&key) {
       let security = ctx.security().max(key.security());
       match security {
-        ..=119 => Err(AES128),
-        120..=128 => Ok(AES128),
-        129..=192 => Ok(AES192),
-        193.. => Ok(AES256),
+        ..=119 => verdict(Status::Disallowed, AES128),
+        120..=128 => verdict(Status::Acceptable, AES128),
+        129..=192 => verdict(Status::Acceptable, AES192),
+        193.. => verdict(Status::Acceptable, AES256),
       }
     } else {
-      Err(AES128)
+      verdict(Status::Unrecognised, AES128)
     }
   }
 }
@@ -347,7 +831,27 @@ impl Standard for Bsi {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{test_ecc, test_ffc, test_hash, test_hash_based, test_ifc, test_symmetric};
+  use crate::{
+    test_drbg, test_ecc, test_ffc, test_hash, test_hash_based, test_ifc, test_kdf, test_kem,
+    test_mac, test_mode, test_phf, test_pqs, test_symmetric,
+  };
+
+  test_drbg!(ctr_drbg_aes128, Bsi, CTR_DRBG_AES128, Ok(CTR_DRBG_AES128));
+  test_drbg!(
+    ctr_drbg_aes256_no_df,
+    Bsi,
+    CTR_DRBG_AES256_NO_DF,
+    Ok(CTR_DRBG_AES256)
+  );
+  test_drbg!(ctr_drbg_tdea, Bsi, CTR_DRBG_TDEA, Err(CTR_DRBG_AES128));
+  test_drbg!(
+    dual_ec_drbg_p384,
+    Bsi,
+    DUAL_EC_DRBG_P384,
+    Err(CTR_DRBG_AES128)
+  );
+  test_drbg!(hash_drbg_sha256, Bsi, HASH_DRBG_SHA256, Ok(CTR_DRBG_AES256));
+  test_drbg!(hmac_drbg_sha1, Bsi, HMAC_DRBG_SHA1, Err(CTR_DRBG_AES128));
 
   test_ecc!(p224, Bsi, P224, Err(BRAINPOOLP256R1));
   test_ecc!(p256, Bsi, P256, Ok(BRAINPOOLP256R1));
@@ -369,6 +873,8 @@ mod tests {
   test_ffc!(ffc_3072_256, Bsi, DSA_3072_256, Ok(DSA_3072_256));
   test_ffc!(ffc_7680_384, Bsi, DSA_7680_384, Ok(DSA_7680_384));
   test_ffc!(ffc_15360_512, Bsi, DSA_15360_512, Ok(DSA_15360_512));
+  test_ffc!(ffdhe2048, Bsi, FFDHE2048, Err(DSA_3072_256));
+  test_ffc!(modp1024, Bsi, MODP_1024, Err(DSA_3072_256));
 
   test_ifc!(ifc_1024, Bsi, RSA_PSS_1024, Err(RSA_PSS_2048));
   test_ifc!(ifc_2048, Bsi, RSA_PSS_2048, Ok(RSA_PSS_2048));
@@ -468,9 +974,108 @@ mod tests {
   test_hash_based!(shake128_pre_image_resistance, Bsi, SHAKE128, Err(SHA256));
   test_hash_based!(shake256_pre_image_resistance, Bsi, SHAKE256, Err(SHA256));
 
+  test_kdf!(hkdf_sha256, Bsi, HKDF_SHA256, Ok(HKDF_SHA256));
+  test_kdf!(hkdf_sha384, Bsi, HKDF_SHA384, Ok(HKDF_SHA384));
+  test_kdf!(kbkdf_cmac, Bsi, KBKDF_CMAC, Ok(HKDF_SHA256));
+  test_kdf!(sp800_56c_sha512, Bsi, SP800_56C_SHA512, Ok(HKDF_SHA512));
+  test_kdf!(
+    pbkdf2_hmac_sha256,
+    Bsi,
+    PBKDF2_HMAC_SHA256,
+    Err(HKDF_SHA256)
+  );
+  test_kdf!(tls12_prf_sha256, Bsi, TLS12_PRF_SHA256, Err(HKDF_SHA256));
+
+  test_kem!(ml_kem_768, Bsi, ML_KEM_768, Err(SECP256R1MLKEM768));
+  test_kem!(ml_kem_1024, Bsi, ML_KEM_1024, Err(SECP256R1MLKEM768));
+  test_kem!(x25519mlkem768, Bsi, X25519MLKEM768, Err(SECP256R1MLKEM768));
+  test_kem!(
+    secp256r1mlkem768,
+    Bsi,
+    SECP256R1MLKEM768,
+    Ok(SECP256R1MLKEM768)
+  );
+  test_kem!(
+    secp384r1mlkem1024,
+    Bsi,
+    SECP384R1MLKEM1024,
+    Ok(SECP384R1MLKEM1024)
+  );
+  test_kem!(frodokem_976, Bsi, FRODOKEM_976, Err(SECP256R1MLKEM768));
+
+  test_mac!(cmac, Bsi, CMAC, Ok(HMAC_SHA256));
+  test_mac!(gmac, Bsi, GMAC, Ok(HMAC_SHA256));
+  test_mac!(hmac_md5, Bsi, HMAC_MD5, Err(HMAC_SHA256));
+  test_mac!(hmac_sha1, Bsi, HMAC_SHA1, Err(HMAC_SHA256));
+  test_mac!(hmac_sha256, Bsi, HMAC_SHA256, Ok(HMAC_SHA256));
+  test_mac!(hmac_sha512, Bsi, HMAC_SHA512, Ok(HMAC_SHA512));
+  test_mac!(kmac256, Bsi, KMAC256, Err(HMAC_SHA256));
+  test_mac!(poly1305_mac, Bsi, POLY1305_MAC, Err(HMAC_SHA256));
+  test_mac!(umac, Bsi, UMAC, Err(HMAC_SHA256));
+  test_mac!(hmac_ripemd160, Bsi, HMAC_RIPEMD160, Err(HMAC_SHA256));
+
+  test_mode!(ecb, Bsi, ECB, Err(GCM));
+  test_mode!(cbc, Bsi, CBC, Ok(CBC));
+  test_mode!(ctr, Bsi, CTR, Ok(CTR));
+  test_mode!(xts, Bsi, XTS, Err(GCM));
+  test_mode!(ccm, Bsi, CCM, Ok(CCM));
+  test_mode!(gcm, Bsi, GCM, Ok(GCM));
+  test_mode!(gcm_siv, Bsi, GCM_SIV, Err(GCM));
+  test_mode!(ocb, Bsi, OCB, Err(GCM));
+  test_mode!(poly1305, Bsi, POLY1305, Err(GCM));
+
+  test_phf!(argon2d, Bsi, ARGON2D, Err(ARGON2ID));
+  test_phf!(argon2i, Bsi, ARGON2I, Err(ARGON2ID));
+  test_phf!(argon2id, Bsi, ARGON2ID, Ok(ARGON2ID));
+  test_phf!(
+    argon2id_first_option,
+    Bsi,
+    Phf::new(ARGON2ID.id, 2097152, 1, 4),
+    Ok(ARGON2ID)
+  );
+  test_phf!(
+    argon2id_19456_2_1,
+    Bsi,
+    Phf::new(ARGON2ID.id, 19456, 2, 1),
+    Err(ARGON2ID)
+  );
+  test_phf!(bcrypt, Bsi, BCRYPT, Err(ARGON2ID));
+  test_phf!(pbkdf2_sha512, Bsi, PBKDF2_SHA512, Err(ARGON2ID));
+  test_phf!(scrypt, Bsi, SCRYPT, Err(ARGON2ID));
+  test_phf!(des_crypt, Bsi, DES_CRYPT, Err(ARGON2ID));
+
+  test_pqs!(ml_dsa_44, Bsi, ML_DSA_44, Err(ML_DSA_65));
+  test_pqs!(ml_dsa_65, Bsi, ML_DSA_65, Ok(ML_DSA_65));
+  test_pqs!(ml_dsa_87, Bsi, ML_DSA_87, Ok(ML_DSA_87));
+  test_pqs!(slh_dsa_sha2_128s, Bsi, SLH_DSA_SHA2_128S, Err(ML_DSA_65));
+  test_pqs!(slh_dsa_shake_128f, Bsi, SLH_DSA_SHAKE_128F, Err(ML_DSA_65));
+  test_pqs!(slh_dsa_sha2_192s, Bsi, SLH_DSA_SHA2_192S, Ok(ML_DSA_65));
+  test_pqs!(slh_dsa_shake_256f, Bsi, SLH_DSA_SHAKE_256F, Ok(ML_DSA_87));
+  test_pqs!(lms_sha256_m24, Bsi, LMS_SHA256_M24, Ok(ML_DSA_65));
+  test_pqs!(xmss_sha2_256, Bsi, XMSS_SHA2_256, Ok(ML_DSA_87));
+
   test_symmetric!(two_key_tdea, Bsi, TDEA2, Err(AES128));
   test_symmetric!(three_key_tdea, Bsi, TDEA3, Err(AES128));
   test_symmetric!(aes128, Bsi, AES128, Ok(AES128));
   test_symmetric!(aes192, Bsi, AES192, Ok(AES192));
   test_symmetric!(aes256, Bsi, AES256, Ok(AES256));
+  test_symmetric!(chacha20, Bsi, CHACHA20, Err(AES128));
+  test_symmetric!(rc4, Bsi, RC4, Err(AES128));
+
+  #[test]
+  fn mac_with_short_tag_is_disallowed() {
+    let ctx = Context::default();
+    let cmac = Mac::new(CMAC.id, 128, 64);
+    let verdict = Bsi::validate_mac(ctx, cmac);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    assert_eq!(verdict.into_result(), Err(Mac::new(CMAC.id, 128, 96)));
+  }
+
+  #[test]
+  fn category_1_slh_dsa_is_disallowed() {
+    let ctx = Context::default();
+    let verdict = Bsi::validate_pqs(ctx, SLH_DSA_SHA2_128S);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    assert_eq!(verdict.into_result(), Err(ML_DSA_65));
+  }
 }
@@ -7,18 +7,40 @@ use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
 
-use super::Standard;
+use super::{Citation, Standard, Status, Verdict};
 use crate::context::Context;
+use crate::primitive::drbg::*;
 use crate::primitive::ecc::*;
 use crate::primitive::ffc::*;
 use crate::primitive::hash::*;
 use crate::primitive::ifc::*;
+use crate::primitive::kdf::*;
+use crate::primitive::kem::*;
+use crate::primitive::mac::*;
+use crate::primitive::mode::*;
+use crate::primitive::phf::*;
+use crate::primitive::pqs::*;
 use crate::primitive::symmetric::*;
 use crate::primitive::Primitive;
+use crate::standard::nist::Nist;
 
 // Exclusive use of CNSA 2.0 by then.
 const CUTOFF_YEAR: u16 = 2030;
 
+const CNSA_1_0: &str = "CNSA 1.0";
+const CNSA_2_0: &str = "CNSA 2.0";
+
+static SPECIFIED_DRBGS: Lazy<HashSet<Drbg>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CTR_DRBG_AES256);
+  s.insert(CTR_DRBG_AES256_NO_DF);
+  s.insert(HASH_DRBG_SHA384);
+  s.insert(HASH_DRBG_SHA512);
+  s.insert(HMAC_DRBG_SHA384);
+  s.insert(HMAC_DRBG_SHA512);
+  s
+});
+
 static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(SHA384);
@@ -26,6 +48,40 @@ static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   s
 });
 
+// Key and tag lengths are parameters of these primitives so the sets
+// below hold the identifiers of the constructions instead.
+static SPECIFIED_KDFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(HKDF_SHA384.id);
+  s.insert(HKDF_SHA512.id);
+  s.insert(KBKDF_HMAC_SHA384.id);
+  s.insert(KBKDF_HMAC_SHA512.id);
+  s.insert(SP800_56C_SHA384.id);
+  s.insert(SP800_56C_SHA512.id);
+  s.insert(TLS12_PRF_SHA384.id);
+  s
+});
+
+static SPECIFIED_MACS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(HMAC_SHA384.id);
+  s.insert(HMAC_SHA512.id);
+  s
+});
+
+static STATEFUL_HASH_BASED_SIGNATURES: Lazy<HashSet<Pqs>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(LMS_SHA256_M24);
+  s.insert(LMS_SHA256_M32);
+  s.insert(LMS_SHAKE_M24);
+  s.insert(LMS_SHAKE_M32);
+  s.insert(XMSS_SHA2_192);
+  s.insert(XMSS_SHA2_256);
+  s.insert(XMSS_SHAKE256_192);
+  s.insert(XMSS_SHAKE256_256);
+  s
+});
+
 /// [`Standard`] implementation of the Commercial National Security
 /// Algorithm Suites, [CNSA 1.0] and [CNSA 2.0].
 ///
@@ -34,15 +90,50 @@ static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
 pub struct Cnsa;
 
 impl Standard for Cnsa {
+  /// Validates a deterministic random bit generator.
+  ///
+  /// Both suites only allow AES-256, SHA-384 and SHA-512 so the only
+  /// compliant generators of NIST SP 800-90A are those built on these
+  /// primitives.
+  ///
+  /// These generators are acceptable while any other is disallowed. The
+  /// recommendation is always CTR_DRBG with AES-256.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant
+  /// generator.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::drbg::{CTR_DRBG_AES128, CTR_DRBG_AES256};
+  /// use wardstone_core::standard::cnsa::Cnsa;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Cnsa::validate_drbg(ctx, CTR_DRBG_AES128);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), CTR_DRBG_AES256);
+  /// ```
+  fn validate_drbg(_ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, drbg.security()).cite(Citation::new(CNSA_2_0, None))
+    };
+    if SPECIFIED_DRBGS.contains(&drbg) {
+      verdict(Status::Acceptable, CTR_DRBG_AES256)
+    } else {
+      verdict(Status::Disallowed, CTR_DRBG_AES256)
+    }
+  }
+
   /// Validate an elliptic curve cryptography primitive used for digital
   /// signatures and key establishment.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -52,20 +143,30 @@ impl Standard for Cnsa {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ecc::{P256, P384};
   /// use wardstone_core::standard::cnsa::Cnsa;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Cnsa::validate_ecc(ctx, P256), Err(P384));
+  /// let verdict = Cnsa::validate_ecc(ctx, P256);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), P384);
   /// ```
-  fn validate_ecc(ctx: Context, key: Ecc) -> Result<Ecc, Ecc> {
-    if ctx.year() > CUTOFF_YEAR {
-      return Err(ECC_NOT_ALLOWED);
+  fn validate_ecc(ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(CNSA_1_0, None))
+        .for_operation(ctx)
+    };
+    // Existing signatures may still be verified after the transition to
+    // CNSA 2.0.
+    if ctx.year() > CUTOFF_YEAR && !ctx.is_processing() {
+      return verdict(Status::Disallowed, ECC_NOT_ALLOWED).cite(Citation::new(CNSA_2_0, None));
     }
 
     if key == P384 {
-      Ok(P384)
+      let until = CUTOFF_YEAR;
+      verdict(Status::Deprecated { until }, P384)
     } else {
-      Err(P384)
+      verdict(Status::Disallowed, P384)
     }
   }
 
@@ -77,12 +178,11 @@ impl Standard for Cnsa {
   /// This primitive is not supported by either version of the CNSA
   /// guidance.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key sizes L and N that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key sizes L
-  /// and N with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key sizes L and N that one
+  /// should use instead. If it is but the context specifies a higher
+  /// security level, the recommendation is the key sizes L and N with
+  /// the desired security level.
   ///
   /// # Example
   ///
@@ -92,14 +192,17 @@ impl Standard for Cnsa {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ffc::{DSA_7680_384, FFC_NOT_SUPPORTED};
   /// use wardstone_core::standard::cnsa::Cnsa;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let dsa_7680 = DSA_7680_384;
-  /// assert_eq!(Cnsa::validate_ffc(ctx, dsa_7680), Err(FFC_NOT_SUPPORTED));
+  /// let verdict = Cnsa::validate_ffc(ctx, dsa_7680);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), FFC_NOT_SUPPORTED);
   /// ```
-  fn validate_ffc(_ctx: Context, _key: Ffc) -> Result<Ffc, Ffc> {
-    Err(FFC_NOT_SUPPORTED)
+  fn validate_ffc(_ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    Verdict::new(Status::Disallowed, FFC_NOT_SUPPORTED, key.security())
+      .cite(Citation::new(CNSA_1_0, None))
   }
 
   /// Validates a hash function.
@@ -110,12 +213,11 @@ impl Standard for Cnsa {
   /// function and hash based application are assessed by this single
   /// function.
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// # Example
   ///
@@ -126,33 +228,39 @@ impl Standard for Cnsa {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHA384};
   /// use wardstone_core::standard::cnsa::Cnsa;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Cnsa::validate_hash(ctx, SHA1), Err(SHA384));
+  /// let verdict = Cnsa::validate_hash(ctx, SHA1);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), SHA384);
   /// ```
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, hash.security())
+        .cite(Citation::new(CNSA_2_0, None))
+        .for_operation(ctx)
+    };
     if SPECIFIED_HASH_FUNCTIONS.contains(&hash) {
       let security = ctx.security().max(hash.security());
       match security {
-        ..=191 => Err(SHA384),
-        192..=255 => Ok(SHA384),
-        256.. => Ok(SHA512),
+        ..=191 => verdict(Status::Disallowed, SHA384),
+        192..=255 => verdict(Status::Acceptable, SHA384),
+        256.. => verdict(Status::Acceptable, SHA512),
       }
     } else {
-      Err(SHA384)
+      verdict(Status::Unrecognised, SHA384)
     }
   }
 
   /// Validates  an integer factorisation cryptography primitive the
   /// most common of which is the RSA signature algorithm.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key size that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key size
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key size that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the key size with the desired
+  /// security level.
   ///
   /// **Note:** Unlike other functions in this module, this will return
   /// a generic structure that specifies minimum private and public
@@ -166,33 +274,271 @@ impl Standard for Cnsa {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ifc::{RSA_PSS_2048, RSA_PSS_3072};
   /// use wardstone_core::standard::cnsa::Cnsa;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Cnsa::validate_ifc(ctx, RSA_PSS_2048), Err(RSA_PSS_3072));
+  /// let verdict = Cnsa::validate_ifc(ctx, RSA_PSS_2048);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), RSA_PSS_3072);
   /// ```
-  fn validate_ifc(ctx: Context, key: Ifc) -> Result<Ifc, Ifc> {
-    if ctx.year() > CUTOFF_YEAR {
-      return Err(IFC_NOT_ALLOWED);
+  fn validate_ifc(ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(CNSA_1_0, None))
+        .for_operation(ctx)
+    };
+    // Existing signatures may still be verified after the transition to
+    // CNSA 2.0.
+    if ctx.year() > CUTOFF_YEAR && !ctx.is_processing() {
+      return verdict(Status::Disallowed, IFC_NOT_ALLOWED).cite(Citation::new(CNSA_2_0, None));
     }
 
+    let until = CUTOFF_YEAR;
     let security = ctx.security().max(key.security());
     match security {
-      ..=127 => Err(RSA_PSS_3072),
-      128..=191 => Ok(RSA_PSS_3072),
-      192..=255 => Ok(RSA_PSS_7680),
-      256.. => Ok(RSA_PSS_15360),
+      ..=127 => verdict(Status::Disallowed, RSA_PSS_3072),
+      128..=191 => verdict(Status::Deprecated { until }, RSA_PSS_3072),
+      192..=255 => verdict(Status::Deprecated { until }, RSA_PSS_7680),
+      256.. => verdict(Status::Deprecated { until }, RSA_PSS_15360),
     }
   }
 
-  /// Validates a symmetric key primitive.
+  /// Validates a key derivation function.
+  ///
+  /// Both suites only allow SHA-384 and SHA-512 as hash functions so
+  /// the compliant functions are those built on either hash function
+  /// that derive a key of at least 256 bits.
+  ///
+  /// Any other function or a key shorter than 256 bits is therefore
+  /// disallowed. The recommendation is HKDF with SHA-384 or SHA-512
+  /// depending on the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant
+  /// function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kdf::{HKDF_SHA256, HKDF_SHA384};
+  /// use wardstone_core::standard::cnsa::Cnsa;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Cnsa::validate_kdf(ctx, HKDF_SHA256);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), HKDF_SHA384);
+  /// ```
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kdf.security())
+        .cite(Citation::new(CNSA_2_0, None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_KDFS.contains(&kdf.id) {
+      let security = ctx.security().max(kdf.security());
+      match security {
+        ..=255 => verdict(Status::Disallowed, HKDF_SHA384),
+        256..=384 => verdict(Status::Acceptable, HKDF_SHA384),
+        385.. => verdict(Status::Acceptable, HKDF_SHA512),
+      }
+    } else {
+      verdict(Status::Disallowed, HKDF_SHA384)
+    }
+  }
+
+  /// Validates a key encapsulation mechanism.
+  ///
+  /// CNSA 2.0 specifies ML-KEM-1024 for all classification levels. Its
+  /// hybrid combination with ECDH over P-384, the curve specified by
+  /// CNSA 1.0, is also accepted.
+  ///
+  /// The status of the verdict tells whether the key encapsulation
+  /// mechanism is compliant. If it is not, the recommendation is the
+  /// primitive that one should use instead. If it is but the context
+  /// specifies a higher security level, the recommendation is the
+  /// primitive with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key
+  /// encapsulation mechanism.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kem::{ML_KEM_1024, ML_KEM_768};
+  /// use wardstone_core::standard::cnsa::Cnsa;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Cnsa::validate_kem(ctx, ML_KEM_768);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ML_KEM_1024);
+  /// ```
+  fn validate_kem(_ctx: Context, kem: Kem) -> Verdict<Kem> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kem.security()).cite(Citation::new(CNSA_2_0, None))
+    };
+    if kem == ML_KEM_1024 || kem == SECP384R1MLKEM1024 {
+      verdict(Status::Acceptable, kem)
+    } else {
+      verdict(Status::Disallowed, ML_KEM_1024)
+    }
+  }
+
+  /// Validates a message authentication code.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
+  /// Both suites only allow SHA-384 and SHA-512 as hash functions and
+  /// AES-256 as a block cipher so the only compliant MACs are HMAC
+  /// based on either hash function with a key of at least 256 bits.
   ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// Any other MAC or a key shorter than 256 bits is therefore
+  /// disallowed. The recommendation is HMAC with SHA-384 or SHA-512
+  /// depending on the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant MAC.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mac::{HMAC_SHA256, HMAC_SHA384};
+  /// use wardstone_core::standard::cnsa::Cnsa;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Cnsa::validate_mac(ctx, HMAC_SHA256);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), HMAC_SHA384);
+  /// ```
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, mac.security())
+        .cite(Citation::new(CNSA_2_0, None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_MACS.contains(&mac.id) {
+      let security = ctx.security().max(mac.security());
+      match security {
+        ..=255 => verdict(Status::Disallowed, HMAC_SHA384),
+        256..=384 => verdict(Status::Acceptable, HMAC_SHA384),
+        385.. => verdict(Status::Acceptable, HMAC_SHA512),
+      }
+    } else {
+      verdict(Status::Disallowed, HMAC_SHA384)
+    }
+  }
+
+  /// Validates a mode of operation of a block cipher.
+  ///
+  /// Neither suite restricts the mode of operation that AES-256 is used
+  /// with so the mode is assessed against the NIST recommendations
+  /// instead (see
+  /// [`Nist::validate_mode`](crate::standard::nist::Nist::validate_mode)).
+  ///
+  /// The verdict is therefore the one of the NIST recommendations where
+  /// ECB is deprecated and modes outside of the SP 800-38 series are
+  /// unrecognised.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant mode.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mode::GCM;
+  /// use wardstone_core::standard::cnsa::Cnsa;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Cnsa::validate_mode(ctx, GCM);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), GCM);
+  /// ```
+  fn validate_mode(ctx: Context, mode: Mode) -> Verdict<Mode> {
+    Nist::validate_mode(ctx, mode)
+  }
+
+  /// Validates a password hashing function.
+  ///
+  /// Both suites only allow SHA-384 and SHA-512 as hash functions so
+  /// the only compliant function is PBKDF2 using HMAC-SHA512 which is
+  /// then assessed against NIST SP 800-63B.
+  ///
+  /// Any other function is therefore disallowed with PBKDF2 using
+  /// HMAC-SHA512 recommended instead.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant
+  /// function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::phf::{PBKDF2_SHA256, PBKDF2_SHA512};
+  /// use wardstone_core::standard::cnsa::Cnsa;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Cnsa::validate_phf(ctx, PBKDF2_SHA256);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), PBKDF2_SHA512);
+  /// ```
+  fn validate_phf(ctx: Context, phf: Phf) -> Verdict<Phf> {
+    if phf.id == PBKDF2_SHA512.id {
+      Nist::validate_phf(ctx, phf)
+    } else {
+      Verdict::new(Status::Disallowed, PBKDF2_SHA512, phf.security())
+        .cite(Citation::new(CNSA_2_0, None))
+        .for_operation(ctx)
+    }
+  }
+
+  /// Validates a post-quantum signature primitive.
+  ///
+  /// CNSA 2.0 specifies ML-DSA-87 for all classification levels and
+  /// approves all parameter sets of the stateful hash-based LMS and
+  /// XMSS schemes for software and firmware signing.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::pqs::{ML_DSA_65, ML_DSA_87};
+  /// use wardstone_core::standard::cnsa::Cnsa;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Cnsa::validate_pqs(ctx, ML_DSA_65);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ML_DSA_87);
+  /// ```
+  fn validate_pqs(_ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security()).cite(Citation::new(CNSA_2_0, None))
+    };
+    if key == ML_DSA_87 || STATEFUL_HASH_BASED_SIGNATURES.contains(&key) {
+      verdict(Status::Acceptable, key)
+    } else {
+      verdict(Status::Disallowed, ML_DSA_87)
+    }
+  }
+
+  /// Validates a symmetric key primitive.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -202,16 +548,21 @@ impl Standard for Cnsa {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::symmetric::{AES256, TDEA3};
   /// use wardstone_core::standard::cnsa::Cnsa;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Cnsa::validate_symmetric(ctx, TDEA3), Err(AES256));
+  /// let verdict = Cnsa::validate_symmetric(ctx, TDEA3);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), AES256);
   /// ```
-  fn validate_symmetric(_ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric> {
+  fn validate_symmetric(_ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security()).cite(Citation::new(CNSA_2_0, None))
+    };
     if key != AES256 {
-      Err(AES256)
+      verdict(Status::Disallowed, AES256)
     } else {
-      Ok(AES256)
+      verdict(Status::Acceptable, AES256)
     }
   }
 }
@@ -219,7 +570,32 @@ impl Standard for Cnsa {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{test_ecc, test_ffc, test_hash, test_ifc, test_symmetric};
+  use crate::context::Operation;
+  use crate::{
+    test_drbg, test_ecc, test_ffc, test_hash, test_ifc, test_kdf, test_kem, test_mac, test_mode,
+    test_phf, test_pqs, test_symmetric,
+  };
+
+  test_drbg!(ctr_drbg_aes128, Cnsa, CTR_DRBG_AES128, Err(CTR_DRBG_AES256));
+  test_drbg!(ctr_drbg_aes256, Cnsa, CTR_DRBG_AES256, Ok(CTR_DRBG_AES256));
+  test_drbg!(
+    dual_ec_drbg_p384,
+    Cnsa,
+    DUAL_EC_DRBG_P384,
+    Err(CTR_DRBG_AES256)
+  );
+  test_drbg!(
+    hash_drbg_sha512,
+    Cnsa,
+    HASH_DRBG_SHA512,
+    Ok(CTR_DRBG_AES256)
+  );
+  test_drbg!(
+    hmac_drbg_sha256,
+    Cnsa,
+    HMAC_DRBG_SHA256,
+    Err(CTR_DRBG_AES256)
+  );
 
   test_ecc!(p224, Cnsa, P224, Err(P384));
   test_ecc!(p256, Cnsa, P256, Err(P384));
@@ -262,6 +638,8 @@ mod tests {
   test_ffc!(ffc_3072_256, Cnsa, DSA_3072_256, Err(FFC_NOT_SUPPORTED));
   test_ffc!(ffc_7680_384, Cnsa, DSA_7680_384, Err(FFC_NOT_SUPPORTED));
   test_ffc!(ffc_15360_512, Cnsa, DSA_15360_512, Err(FFC_NOT_SUPPORTED));
+  test_ffc!(ffdhe2048, Cnsa, FFDHE2048, Err(FFC_NOT_SUPPORTED));
+  test_ffc!(modp1024, Cnsa, MODP_1024, Err(FFC_NOT_SUPPORTED));
 
   test_ifc!(ifc_1024, Cnsa, RSA_PSS_1024, Err(RSA_PSS_3072));
   test_ifc!(ifc_2048, Cnsa, RSA_PSS_2048, Err(RSA_PSS_3072));
@@ -269,9 +647,73 @@ mod tests {
   test_ifc!(ifc_7680, Cnsa, RSA_PSS_7680, Ok(RSA_PSS_7680));
   test_ifc!(ifc_15360, Cnsa, RSA_PSS_15360, Ok(RSA_PSS_15360));
 
+  test_kdf!(hkdf_sha256, Cnsa, HKDF_SHA256, Err(HKDF_SHA384));
+  test_kdf!(hkdf_sha384, Cnsa, HKDF_SHA384, Ok(HKDF_SHA384));
+  test_kdf!(hkdf_sha512, Cnsa, HKDF_SHA512, Ok(HKDF_SHA512));
+  test_kdf!(
+    pbkdf2_hmac_sha512,
+    Cnsa,
+    PBKDF2_HMAC_SHA512,
+    Err(HKDF_SHA384)
+  );
+  test_kdf!(tls12_prf_sha384, Cnsa, TLS12_PRF_SHA384, Ok(HKDF_SHA384));
+
+  test_kem!(ml_kem_512, Cnsa, ML_KEM_512, Err(ML_KEM_1024));
+  test_kem!(ml_kem_768, Cnsa, ML_KEM_768, Err(ML_KEM_1024));
+  test_kem!(ml_kem_1024, Cnsa, ML_KEM_1024, Ok(ML_KEM_1024));
+  test_kem!(x25519mlkem768, Cnsa, X25519MLKEM768, Err(ML_KEM_1024));
+  test_kem!(
+    secp384r1mlkem1024,
+    Cnsa,
+    SECP384R1MLKEM1024,
+    Ok(SECP384R1MLKEM1024)
+  );
+  test_kem!(frodokem_1344, Cnsa, FRODOKEM_1344, Err(ML_KEM_1024));
+
+  test_mac!(cmac, Cnsa, CMAC, Err(HMAC_SHA384));
+  test_mac!(hmac_sha256, Cnsa, HMAC_SHA256, Err(HMAC_SHA384));
+  test_mac!(hmac_sha384, Cnsa, HMAC_SHA384, Ok(HMAC_SHA384));
+  test_mac!(hmac_sha512, Cnsa, HMAC_SHA512, Ok(HMAC_SHA512));
+  test_mac!(kmac256, Cnsa, KMAC256, Err(HMAC_SHA384));
+  test_mac!(umac, Cnsa, UMAC, Err(HMAC_SHA384));
+  test_mac!(hmac_ripemd160, Cnsa, HMAC_RIPEMD160, Err(HMAC_SHA384));
+
+  test_mode!(cbc, Cnsa, CBC, Ok(CBC));
+  test_mode!(gcm, Cnsa, GCM, Ok(GCM));
+  test_mode!(ocb, Cnsa, OCB, Err(GCM));
+
+  test_phf!(argon2id, Cnsa, ARGON2ID, Err(PBKDF2_SHA512));
+  test_phf!(pbkdf2_sha256, Cnsa, PBKDF2_SHA256, Err(PBKDF2_SHA512));
+  test_phf!(pbkdf2_sha512, Cnsa, PBKDF2_SHA512, Ok(PBKDF2_SHA512));
+  test_phf!(
+    pbkdf2_sha512_1000,
+    Cnsa,
+    Phf::iterated(PBKDF2_SHA512.id, 1000),
+    Err(Phf::iterated(PBKDF2_SHA512.id, 10_000))
+  );
+  test_phf!(des_crypt, Cnsa, DES_CRYPT, Err(PBKDF2_SHA512));
+
+  test_pqs!(ml_dsa_44, Cnsa, ML_DSA_44, Err(ML_DSA_87));
+  test_pqs!(ml_dsa_65, Cnsa, ML_DSA_65, Err(ML_DSA_87));
+  test_pqs!(ml_dsa_87, Cnsa, ML_DSA_87, Ok(ML_DSA_87));
+  test_pqs!(slh_dsa_sha2_128s, Cnsa, SLH_DSA_SHA2_128S, Err(ML_DSA_87));
+  test_pqs!(slh_dsa_shake_256f, Cnsa, SLH_DSA_SHAKE_256F, Err(ML_DSA_87));
+  test_pqs!(lms_sha256_m24, Cnsa, LMS_SHA256_M24, Ok(LMS_SHA256_M24));
+  test_pqs!(xmss_sha2_256, Cnsa, XMSS_SHA2_256, Ok(XMSS_SHA2_256));
+
+  test_symmetric!(chacha20, Cnsa, CHACHA20, Err(AES256));
   test_symmetric!(two_key_tdea, Cnsa, TDEA2, Err(AES256));
   test_symmetric!(three_key_tdea, Cnsa, TDEA3, Err(AES256));
   test_symmetric!(aes128, Cnsa, AES128, Err(AES256));
   test_symmetric!(aes192, Cnsa, AES192, Err(AES256));
   test_symmetric!(aes256, Cnsa, AES256, Ok(AES256));
+
+  #[test]
+  fn p384_is_acceptable_for_processing_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1).with_operation(Operation::Process);
+    let verdict = Cnsa::validate_ecc(ctx, P384);
+    assert_eq!(verdict.status(), Status::Acceptable);
+    let verdict = Cnsa::validate_ecc(ctx.with_operation(Operation::Protect), P384);
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
 }
@@ -6,12 +6,19 @@ use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
 
-use super::Standard;
-use crate::context::Context;
+use super::{Citation, Standard, Status, Verdict};
+use crate::context::{Context, Usage};
+use crate::primitive::drbg::*;
 use crate::primitive::ecc::*;
 use crate::primitive::ffc::*;
 use crate::primitive::hash::*;
 use crate::primitive::ifc::*;
+use crate::primitive::kdf::*;
+use crate::primitive::kem::*;
+use crate::primitive::mac::*;
+use crate::primitive::mode::*;
+use crate::primitive::phf::*;
+use crate::primitive::pqs::*;
 use crate::primitive::symmetric::*;
 use crate::primitive::Primitive;
 
@@ -22,6 +29,38 @@ use crate::primitive::Primitive;
 // categories of legacy algorithms.
 const CUTOFF_YEAR: u16 = 2023;
 
+const D5_4: &str = "ECRYPT-CSA D5.4";
+
+// Generators built on SHA1 or TDEA are only fit for legacy use.
+static LEGACY_DRBGS: Lazy<HashSet<Drbg>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CTR_DRBG_TDEA);
+  s.insert(HASH_DRBG_SHA1);
+  s.insert(HMAC_DRBG_SHA1);
+  s
+});
+
+static SPECIFIED_DRBGS: Lazy<HashSet<Drbg>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CTR_DRBG_AES128);
+  s.insert(CTR_DRBG_AES128_NO_DF);
+  s.insert(CTR_DRBG_AES192);
+  s.insert(CTR_DRBG_AES192_NO_DF);
+  s.insert(CTR_DRBG_AES256);
+  s.insert(CTR_DRBG_AES256_NO_DF);
+  s.insert(HASH_DRBG_SHA224);
+  s.insert(HASH_DRBG_SHA256);
+  s.insert(HASH_DRBG_SHA384);
+  s.insert(HASH_DRBG_SHA512);
+  s.insert(HASH_DRBG_SHA512_256);
+  s.insert(HMAC_DRBG_SHA224);
+  s.insert(HMAC_DRBG_SHA256);
+  s.insert(HMAC_DRBG_SHA384);
+  s.insert(HMAC_DRBG_SHA512);
+  s.insert(HMAC_DRBG_SHA512_256);
+  s
+});
+
 static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(BLAKE2B_256);
@@ -49,6 +88,102 @@ static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   s
 });
 
+// Key and tag lengths are parameters of these primitives so the sets
+// below hold the identifiers of the constructions instead. The
+// pseudorandom function of TLS 1.0 and 1.1 is only fit for legacy use.
+static LEGACY_KDFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(TLS10_PRF.id);
+  s
+});
+
+static SPECIFIED_KDFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(HKDF_SHA256.id);
+  s.insert(HKDF_SHA384.id);
+  s.insert(HKDF_SHA512.id);
+  s.insert(KBKDF_CMAC.id);
+  s.insert(KBKDF_HMAC_SHA256.id);
+  s.insert(KBKDF_HMAC_SHA384.id);
+  s.insert(KBKDF_HMAC_SHA512.id);
+  s.insert(PBKDF2_HMAC_SHA1.id);
+  s.insert(PBKDF2_HMAC_SHA256.id);
+  s.insert(PBKDF2_HMAC_SHA512.id);
+  s.insert(SP800_56C_SHA256.id);
+  s.insert(SP800_56C_SHA384.id);
+  s.insert(SP800_56C_SHA512.id);
+  s.insert(TLS12_PRF_SHA256.id);
+  s.insert(TLS12_PRF_SHA384.id);
+  s
+});
+
+// HMAC based on MD5 or SHA1 is only fit for legacy use.
+static LEGACY_MACS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(HMAC_MD5.id);
+  s.insert(HMAC_SHA1.id);
+  s
+});
+
+// Cost parameters are part of these primitives so the sets below hold
+// the identifiers of the functions instead. PBKDF2 with SHA1 and bcrypt
+// are only fit for legacy use.
+static LEGACY_PHFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(BCRYPT.id);
+  s.insert(PBKDF2_SHA1.id);
+  s
+});
+
+static SPECIFIED_PHFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(ARGON2D.id);
+  s.insert(ARGON2I.id);
+  s.insert(ARGON2ID.id);
+  s.insert(PBKDF2_SHA256.id);
+  s.insert(PBKDF2_SHA512.id);
+  s.insert(SCRYPT.id);
+  s
+});
+
+static SPECIFIED_MACS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CMAC.id);
+  s.insert(GMAC.id);
+  s.insert(HMAC_SHA224.id);
+  s.insert(HMAC_SHA256.id);
+  s.insert(HMAC_SHA384.id);
+  s.insert(HMAC_SHA512.id);
+  s.insert(HMAC_SHA3_256.id);
+  s.insert(HMAC_SHA3_384.id);
+  s.insert(HMAC_SHA3_512.id);
+  s.insert(KMAC128.id);
+  s.insert(KMAC256.id);
+  s.insert(POLY1305_MAC.id);
+  s
+});
+
+// Modes that are only fit for legacy use. ECB is not recommended even
+// for legacy use.
+static LEGACY_MODES: Lazy<HashSet<Mode>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CFB);
+  s.insert(OFB);
+  s
+});
+
+static SPECIFIED_MODES: Lazy<HashSet<Mode>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CBC);
+  s.insert(CCM);
+  s.insert(CTR);
+  s.insert(GCM);
+  s.insert(OCB);
+  s.insert(POLY1305);
+  s.insert(XTS);
+  s
+});
+
 static SPECIFIED_SYMMETRIC_KEYS: Lazy<HashSet<Symmetric>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(AES128);
@@ -57,6 +192,8 @@ static SPECIFIED_SYMMETRIC_KEYS: Lazy<HashSet<Symmetric>> = Lazy::new(|| {
   s.insert(CAMELLIA128);
   s.insert(CAMELLIA192);
   s.insert(CAMELLIA256);
+  s.insert(CHACHA20);
+  s.insert(SALSA20);
   s.insert(SERPENT128);
   s.insert(SERPENT192);
   s.insert(SERPENT256);
@@ -72,16 +209,77 @@ static SPECIFIED_SYMMETRIC_KEYS: Lazy<HashSet<Symmetric>> = Lazy::new(|| {
 pub struct Ecrypt;
 
 impl Standard for Ecrypt {
+  /// Validates a deterministic random bit generator.
+  ///
+  /// The report recommends Hash_DRBG, HMAC_DRBG, and CTR_DRBG of NIST
+  /// SP 800-90A for future use when they are built on a primitive fit
+  /// for future use. Generators built on SHA1 or TDEA are only fit for
+  /// legacy use and Dual_EC_DRBG should not be used at all. The
+  /// security strength is assessed in the same way as a symmetric key.
+  ///
+  /// A generator that is not specified is unrecognised and one with
+  /// less than 80 bits of security is disallowed. A generator that is
+  /// only fit for legacy use, or has less than 128 bits of security, is
+  /// deprecated until 2023 and only fit for legacy use afterwards. Any
+  /// other generator is acceptable. The recommendation is CTR_DRBG with
+  /// the AES key size that matches the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a generator which is
+  /// only fit for legacy use.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::drbg::HASH_DRBG_SHA1;
+  /// use wardstone_core::standard::ecrypt::Ecrypt;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Ecrypt::validate_drbg(ctx, HASH_DRBG_SHA1);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// ```
+  fn validate_drbg(ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, drbg.security()).cite(Citation::new(D5_4, None))
+    };
+    let legacy = || {
+      if ctx.year() > CUTOFF_YEAR {
+        verdict(Status::Legacy, CTR_DRBG_AES128)
+      } else {
+        let until = CUTOFF_YEAR;
+        verdict(Status::Deprecated { until }, CTR_DRBG_AES128)
+      }
+    };
+    if [DUAL_EC_DRBG_P256, DUAL_EC_DRBG_P384, DUAL_EC_DRBG_P521].contains(&drbg) {
+      return verdict(Status::Disallowed, CTR_DRBG_AES128);
+    }
+    if !LEGACY_DRBGS.contains(&drbg) && !SPECIFIED_DRBGS.contains(&drbg) {
+      return verdict(Status::Unrecognised, CTR_DRBG_AES128);
+    }
+    let security = ctx.security().max(drbg.security());
+    match security {
+      ..=79 => verdict(Status::Disallowed, CTR_DRBG_AES128),
+      80..=127 => legacy(),
+      _ if LEGACY_DRBGS.contains(&drbg) => legacy(),
+      128 => verdict(Status::Acceptable, CTR_DRBG_AES128),
+      129..=192 => verdict(Status::Acceptable, CTR_DRBG_AES192),
+      193.. => verdict(Status::Acceptable, CTR_DRBG_AES256),
+    }
+  }
+
   /// Validate an elliptic curve cryptography primitive used for digital
   /// signatures and key establishment where f is the key size according
   /// to page 47 of the report.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// X25519 and X448 are only compliant for key agreement while Ed25519
+  /// and Ed448 are only compliant for digital signatures.
   ///
   /// **Note:** This will return a generic structure that specifies key
   /// sizes.
@@ -94,25 +292,38 @@ impl Standard for Ecrypt {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ecc::{ECC_256, P224};
   /// use wardstone_core::standard::ecrypt::Ecrypt;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Ecrypt::validate_ecc(ctx, P224), Ok(ECC_256));
+  /// let verdict = Ecrypt::validate_ecc(ctx, P224);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// assert_eq!(verdict.recommendation(), ECC_256);
   /// ```
-  fn validate_ecc(ctx: Context, key: Ecc) -> Result<Ecc, Ecc> {
+  fn validate_ecc(ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(D5_4, Some(47)))
+        .for_operation(ctx)
+    };
+    if (key.is_signature_only() && ctx.usage() == Usage::KeyEstablishment)
+      || (key.is_key_agreement_only() && ctx.usage() == Usage::DigitalSignature)
+    {
+      return verdict(Status::Disallowed, ECC_256);
+    }
     let security = ctx.security().max(key.security());
     match security {
-      ..=79 => Err(ECC_256),
+      ..=79 => verdict(Status::Disallowed, ECC_256),
       80..=127 => {
         if ctx.year() > CUTOFF_YEAR {
-          Err(ECC_256)
+          verdict(Status::Legacy, ECC_256)
         } else {
-          Ok(ECC_256)
+          let until = CUTOFF_YEAR;
+          verdict(Status::Deprecated { until }, ECC_256)
         }
       },
-      128 => Ok(ECC_256),
-      129..=192 => Ok(ECC_384),
-      193.. => Ok(ECC_512),
+      128 => verdict(Status::Acceptable, ECC_256),
+      129..=192 => verdict(Status::Acceptable, ECC_384),
+      193.. => verdict(Status::Acceptable, ECC_512),
     }
   }
 
@@ -123,12 +334,11 @@ impl Standard for Ecrypt {
   /// Diffie-Hellman and MQV which can also be implemented as such,
   /// according to page 47 of the report.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key sizes L and N that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key sizes L
-  /// and N with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key sizes L and N that one
+  /// should use instead. If it is but the context specifies a higher
+  /// security level, the recommendation is the key sizes L and N with
+  /// the desired security level.
   ///
   /// # Example
   ///
@@ -138,38 +348,45 @@ impl Standard for Ecrypt {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ffc::{DSA_2048_224, DSA_3072_256};
   /// use wardstone_core::standard::ecrypt::Ecrypt;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let dsa_2048 = DSA_2048_224;
   /// let dsa_3072 = DSA_3072_256;
-  /// assert_eq!(Ecrypt::validate_ffc(ctx, dsa_2048), Ok(dsa_3072));
+  /// let verdict = Ecrypt::validate_ffc(ctx, dsa_2048);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// assert_eq!(verdict.recommendation(), dsa_3072);
   /// ```
-  fn validate_ffc(ctx: Context, key: Ffc) -> Result<Ffc, Ffc> {
+  fn validate_ffc(ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(D5_4, Some(47)))
+        .for_operation(ctx)
+    };
     let security = ctx.security().max(key.security());
     match security {
-      ..=79 => Err(DSA_3072_256),
+      ..=79 => verdict(Status::Disallowed, DSA_3072_256),
       80..=127 => {
         if ctx.year() > CUTOFF_YEAR {
-          Err(DSA_3072_256)
+          verdict(Status::Legacy, DSA_3072_256)
         } else {
-          Ok(DSA_3072_256)
+          let until = CUTOFF_YEAR;
+          verdict(Status::Deprecated { until }, DSA_3072_256)
         }
       },
-      128 => Ok(DSA_3072_256),
-      129..=192 => Ok(DSA_7680_384),
-      193.. => Ok(DSA_15360_512),
+      128 => verdict(Status::Acceptable, DSA_3072_256),
+      129..=192 => verdict(Status::Acceptable, DSA_7680_384),
+      193.. => verdict(Status::Acceptable, DSA_15360_512),
     }
   }
 
   /// Validates a hash function according to pages 40-43 of the report.
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** An alternative might be suggested for a compliant hash
   /// function with a similar security level in which a switch to the
@@ -187,29 +404,43 @@ impl Standard for Ecrypt {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHA256};
   /// use wardstone_core::standard::ecrypt::Ecrypt;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Ecrypt::validate_hash(ctx, SHA1), Err(SHA256));
+  /// let verdict = Ecrypt::validate_hash(ctx, SHA1);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), SHA256);
   /// ```
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    // Applications such as message authentication codes and key
+    // derivation functions only rely on pre-image resistance.
+    let resistance = match ctx.usage() {
+      Usage::MessageAuthentication | Usage::KeyDerivation => hash.security() << 1,
+      _ => hash.security(),
+    };
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, resistance)
+        .cite(Citation::new(D5_4, Some(40)))
+        .for_operation(ctx)
+    };
     if SPECIFIED_HASH_FUNCTIONS.contains(&hash) {
-      let security = ctx.security().max(hash.security());
+      let security = ctx.security().max(resistance);
       match security {
-        ..=79 => Err(SHA256),
+        ..=79 => verdict(Status::Disallowed, SHA256),
         80..=127 => {
           if ctx.year() > CUTOFF_YEAR {
-            Err(SHA256)
+            verdict(Status::Legacy, SHA256)
           } else {
-            Ok(SHA256)
+            let until = CUTOFF_YEAR;
+            verdict(Status::Deprecated { until }, SHA256)
           }
         },
-        128 => Ok(SHA256),
-        129..=192 => Ok(SHA384),
-        193.. => Ok(SHA512),
+        128 => verdict(Status::Acceptable, SHA256),
+        129..=192 => verdict(Status::Acceptable, SHA384),
+        193.. => verdict(Status::Acceptable, SHA512),
       }
     } else {
-      Err(SHA256)
+      verdict(Status::Unrecognised, SHA256)
     }
   }
 
@@ -217,12 +448,11 @@ impl Standard for Ecrypt {
   /// most common of which is the RSA signature algorithm according to
   /// pages 47-48.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key size that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key size
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key size that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the key size with the desired
+  /// security level.
   ///
   /// **Note:** Unlike other functions in this module, this will return
   /// a generic structure that specifies minimum private and public
@@ -236,37 +466,330 @@ impl Standard for Ecrypt {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ifc::RSA_PSS_3072;
   /// use wardstone_core::standard::ecrypt::Ecrypt;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Ecrypt::validate_ifc(ctx, RSA_PSS_3072), Ok(RSA_PSS_3072));
+  /// let verdict = Ecrypt::validate_ifc(ctx, RSA_PSS_3072);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), RSA_PSS_3072);
   /// ```
-  fn validate_ifc(ctx: Context, key: Ifc) -> Result<Ifc, Ifc> {
+  fn validate_ifc(ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(D5_4, Some(47)))
+        .for_operation(ctx)
+    };
     let security = ctx.security().max(key.security());
     match security {
-      ..=79 => Err(RSA_PSS_3072),
+      ..=79 => verdict(Status::Disallowed, RSA_PSS_3072),
       80..=127 => {
         if ctx.year() > CUTOFF_YEAR {
-          Err(RSA_PSS_3072)
+          verdict(Status::Legacy, RSA_PSS_3072)
         } else {
-          Ok(RSA_PSS_3072)
+          let until = CUTOFF_YEAR;
+          verdict(Status::Deprecated { until }, RSA_PSS_3072)
         }
       },
-      128..=191 => Ok(RSA_PSS_3072),
-      192..=255 => Ok(RSA_PSS_7680),
-      256.. => Ok(RSA_PSS_15360),
+      128..=191 => verdict(Status::Acceptable, RSA_PSS_3072),
+      192..=255 => verdict(Status::Acceptable, RSA_PSS_7680),
+      256.. => verdict(Status::Acceptable, RSA_PSS_15360),
     }
   }
 
+  /// Validates a key derivation function.
+  ///
+  /// The report recommends the key-based KDFs of NIST SP 800-108, the
+  /// KDFs of NIST SP 800-56C including HKDF, PBKDF2, and the
+  /// pseudorandom function of TLS 1.2 for future use. The pseudorandom
+  /// function of TLS 1.0 and 1.1 is only fit for legacy use. The
+  /// derived key is assessed in the same way as a symmetric key.
+  ///
+  /// A function the report does not mention is unrecognised and a
+  /// derived key with less than 80 bits of security is disallowed. The
+  /// pseudorandom function of TLS 1.0 and 1.1 and a derived key with
+  /// less than 128 bits of security are deprecated until 2023 and only
+  /// fit for legacy use afterwards. Otherwise the function is
+  /// acceptable. The recommendation is HKDF with the desired security
+  /// level.
+  ///
+  /// **Note:** The report does not specify an iteration count for
+  /// PBKDF2 and so it is not assessed.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a function which is
+  /// only fit for legacy use.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kdf::TLS10_PRF;
+  /// use wardstone_core::standard::ecrypt::Ecrypt;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Ecrypt::validate_kdf(ctx, TLS10_PRF);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// ```
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kdf.security())
+        .cite(Citation::new(D5_4, None))
+        .for_operation(ctx)
+    };
+    let legacy = || {
+      if ctx.year() > CUTOFF_YEAR {
+        verdict(Status::Legacy, HKDF_SHA256)
+      } else {
+        let until = CUTOFF_YEAR;
+        verdict(Status::Deprecated { until }, HKDF_SHA256)
+      }
+    };
+    if !LEGACY_KDFS.contains(&kdf.id) && !SPECIFIED_KDFS.contains(&kdf.id) {
+      return verdict(Status::Unrecognised, HKDF_SHA256);
+    }
+    let security = ctx.security().max(kdf.security());
+    match security {
+      ..=79 => verdict(Status::Disallowed, HKDF_SHA256),
+      80..=127 => legacy(),
+      _ if LEGACY_KDFS.contains(&kdf.id) => legacy(),
+      128..=256 => verdict(Status::Acceptable, HKDF_SHA256),
+      257..=384 => verdict(Status::Acceptable, HKDF_SHA384),
+      385.. => verdict(Status::Acceptable, HKDF_SHA512),
+    }
+  }
+
+  /// Validates a key encapsulation mechanism.
+  ///
+  /// The report predates the standardisation of post-quantum key
+  /// encapsulation mechanisms and so none of them are supported.
+  ///
+  /// The status of the verdict tells whether the key encapsulation
+  /// mechanism is compliant. If it is not, the recommendation is the
+  /// primitive that one should use instead. If it is but the context
+  /// specifies a higher security level, the recommendation is the
+  /// primitive with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key
+  /// encapsulation mechanism.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kem::{KEM_NOT_SUPPORTED, ML_KEM_768};
+  /// use wardstone_core::standard::ecrypt::Ecrypt;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Ecrypt::validate_kem(ctx, ML_KEM_768);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), KEM_NOT_SUPPORTED);
+  /// ```
+  fn validate_kem(_ctx: Context, kem: Kem) -> Verdict<Kem> {
+    Verdict::new(Status::Unrecognised, KEM_NOT_SUPPORTED, kem.security())
+  }
+
+  /// Validates a message authentication code.
+  ///
+  /// The report recommends HMAC with a hash function fit for future
+  /// use, CMAC, GMAC, KMAC, and Poly1305 for future use. HMAC based on
+  /// MD5 or SHA1 is only fit for legacy use. The key is assessed in the
+  /// same way as that of a symmetric key primitive.
+  ///
+  /// A MAC the report does not mention is unrecognised and a key with
+  /// less than 80 bits of security is disallowed. HMAC based on MD5 or
+  /// SHA1 and a key with less than 128 bits of security are deprecated
+  /// until 2023 and only fit for legacy use afterwards. Otherwise the
+  /// MAC is acceptable. The recommendation is HMAC with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a MAC with a key
+  /// that is too short.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mac::{Mac, HMAC_SHA256};
+  /// use wardstone_core::standard::ecrypt::Ecrypt;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let hmac = Mac::new(HMAC_SHA256.id, 64, 256);
+  /// let verdict = Ecrypt::validate_mac(ctx, hmac);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), HMAC_SHA256);
+  /// ```
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, mac.security())
+        .cite(Citation::new(D5_4, None))
+        .for_operation(ctx)
+    };
+    let legacy = || {
+      if ctx.year() > CUTOFF_YEAR {
+        verdict(Status::Legacy, HMAC_SHA256)
+      } else {
+        let until = CUTOFF_YEAR;
+        verdict(Status::Deprecated { until }, HMAC_SHA256)
+      }
+    };
+    if !LEGACY_MACS.contains(&mac.id) && !SPECIFIED_MACS.contains(&mac.id) {
+      return verdict(Status::Unrecognised, HMAC_SHA256);
+    }
+    let security = ctx.security().max(mac.security());
+    match security {
+      ..=79 => verdict(Status::Disallowed, HMAC_SHA256),
+      80..=127 => legacy(),
+      _ if LEGACY_MACS.contains(&mac.id) => legacy(),
+      128..=256 => verdict(Status::Acceptable, HMAC_SHA256),
+      257..=384 => verdict(Status::Acceptable, HMAC_SHA384),
+      385.. => verdict(Status::Acceptable, HMAC_SHA512),
+    }
+  }
+
+  /// Validates a mode of operation of a block cipher or the
+  /// construction of an authenticated encryption scheme.
+  ///
+  /// The report recommends the CBC, CTR, and XTS modes, the CCM, GCM,
+  /// and OCB authenticated encryption modes, and ChaCha20 with Poly1305
+  /// for future use. The CFB and OFB modes are only fit for legacy use
+  /// whereas ECB is not recommended at all.
+  ///
+  /// ECB is therefore disallowed while CFB and OFB are deprecated until
+  /// 2023 and only fit for legacy use afterwards. The other recommended
+  /// modes are acceptable and recommended as they are, and any other
+  /// mode is unrecognised. GCM is recommended in place of modes that
+  /// are not acceptable.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant mode.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mode::{ECB, GCM};
+  /// use wardstone_core::standard::ecrypt::Ecrypt;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Ecrypt::validate_mode(ctx, ECB);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), GCM);
+  /// ```
+  fn validate_mode(ctx: Context, mode: Mode) -> Verdict<Mode> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, mode.security())
+        .cite(Citation::new(D5_4, None))
+        .for_operation(ctx)
+    };
+    if mode == ECB {
+      verdict(Status::Disallowed, GCM)
+    } else if LEGACY_MODES.contains(&mode) {
+      if ctx.year() > CUTOFF_YEAR {
+        verdict(Status::Legacy, GCM)
+      } else {
+        let until = CUTOFF_YEAR;
+        verdict(Status::Deprecated { until }, GCM)
+      }
+    } else if SPECIFIED_MODES.contains(&mode) {
+      verdict(Status::Acceptable, mode)
+    } else {
+      verdict(Status::Unrecognised, GCM)
+    }
+  }
+
+  /// Validates a password hashing function.
+  ///
+  /// The report recommends Argon2, scrypt, and PBKDF2 with a hash
+  /// function fit for future use. PBKDF2 with SHA1 and bcrypt are only
+  /// fit for legacy use.
+  ///
+  /// The recommended functions are therefore acceptable while PBKDF2
+  /// with SHA1 and bcrypt are deprecated until 2023 and only fit for
+  /// legacy use afterwards. Any other function is unrecognised. The
+  /// recommendation is always Argon2id.
+  ///
+  /// **Note:** The report does not specify cost parameters and so they
+  /// are not assessed.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a function which is
+  /// only fit for legacy use.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::phf::BCRYPT;
+  /// use wardstone_core::standard::ecrypt::Ecrypt;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Ecrypt::validate_phf(ctx, BCRYPT);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// ```
+  fn validate_phf(ctx: Context, phf: Phf) -> Verdict<Phf> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, phf.security())
+        .cite(Citation::new(D5_4, None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_PHFS.contains(&phf.id) {
+      verdict(Status::Acceptable, ARGON2ID)
+    } else if LEGACY_PHFS.contains(&phf.id) {
+      if ctx.year() > CUTOFF_YEAR {
+        verdict(Status::Legacy, ARGON2ID)
+      } else {
+        let until = CUTOFF_YEAR;
+        verdict(Status::Deprecated { until }, ARGON2ID)
+      }
+    } else {
+      verdict(Status::Unrecognised, ARGON2ID)
+    }
+  }
+
+  /// Validates a post-quantum signature primitive.
+  ///
+  /// The report predates the standardisation of post-quantum signature
+  /// schemes and so none of them are supported.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::pqs::{ML_DSA_87, PQS_NOT_SUPPORTED};
+  /// use wardstone_core::standard::ecrypt::Ecrypt;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Ecrypt::validate_pqs(ctx, ML_DSA_87);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), PQS_NOT_SUPPORTED);
+  /// ```
+  fn validate_pqs(_ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    Verdict::new(Status::Unrecognised, PQS_NOT_SUPPORTED, key.security())
+  }
+
   /// Validates a symmetric key primitive according to pages 37 to 40 of
   /// the report.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
+  /// Besides block ciphers, the report recommends the ChaCha and
+  /// Salsa20 stream ciphers. RC4 is not recommended even for legacy use
+  /// as a result of its many weaknesses regardless of the key size.
   ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -276,29 +799,39 @@ impl Standard for Ecrypt {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::symmetric::{AES128, TDEA3};
   /// use wardstone_core::standard::ecrypt::Ecrypt;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Ecrypt::validate_symmetric(ctx, TDEA3), Ok(AES128));
+  /// let verdict = Ecrypt::validate_symmetric(ctx, TDEA3);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// assert_eq!(verdict.recommendation(), AES128);
   /// ```
-  fn validate_symmetric(ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric> {
-    if SPECIFIED_SYMMETRIC_KEYS.contains(&key) {
+  fn validate_symmetric(ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(D5_4, Some(37)))
+        .for_operation(ctx)
+    };
+    if key == RC4 {
+      verdict(Status::Disallowed, AES128)
+    } else if SPECIFIED_SYMMETRIC_KEYS.contains(&key) {
       let security = ctx.security().max(key.security());
       match security {
-        ..=79 => Err(AES128),
+        ..=79 => verdict(Status::Disallowed, AES128),
         80..=127 => {
           if ctx.year() > CUTOFF_YEAR {
-            Err(AES128)
+            verdict(Status::Legacy, AES128)
           } else {
-            Ok(AES128)
+            let until = CUTOFF_YEAR;
+            verdict(Status::Deprecated { until }, AES128)
           }
         },
-        128 => Ok(AES128),
-        129..=192 => Ok(AES192),
-        193.. => Ok(AES256),
+        128 => verdict(Status::Acceptable, AES128),
+        129..=192 => verdict(Status::Acceptable, AES192),
+        193.. => verdict(Status::Acceptable, AES256),
       }
     } else {
-      Err(AES128)
+      verdict(Status::Unrecognised, AES128)
     }
   }
 }
@@ -306,7 +839,38 @@ impl Standard for Ecrypt {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{test_ecc, test_ffc, test_hash, test_ifc, test_symmetric};
+  use crate::context::Operation;
+  use crate::{
+    test_drbg, test_ecc, test_ffc, test_hash, test_ifc, test_kdf, test_kem, test_mac, test_mode,
+    test_phf, test_pqs, test_symmetric,
+  };
+
+  test_drbg!(
+    ctr_drbg_aes128,
+    Ecrypt,
+    CTR_DRBG_AES128,
+    Ok(CTR_DRBG_AES128)
+  );
+  test_drbg!(ctr_drbg_tdea, Ecrypt, CTR_DRBG_TDEA, Ok(CTR_DRBG_AES128));
+  test_drbg!(
+    dual_ec_drbg_p521,
+    Ecrypt,
+    DUAL_EC_DRBG_P521,
+    Err(CTR_DRBG_AES128)
+  );
+  test_drbg!(hash_drbg_sha1, Ecrypt, HASH_DRBG_SHA1, Ok(CTR_DRBG_AES128));
+  test_drbg!(
+    hash_drbg_sha224,
+    Ecrypt,
+    HASH_DRBG_SHA224,
+    Ok(CTR_DRBG_AES192)
+  );
+  test_drbg!(
+    hmac_drbg_sha384,
+    Ecrypt,
+    HMAC_DRBG_SHA384,
+    Ok(CTR_DRBG_AES256)
+  );
 
   test_ecc!(p224, Ecrypt, P224, Ok(ECC_256));
   test_ecc!(p256, Ecrypt, P256, Ok(ECC_256));
@@ -328,6 +892,8 @@ mod tests {
   test_ffc!(ffc_3072_256, Ecrypt, DSA_3072_256, Ok(DSA_3072_256));
   test_ffc!(ffc_7680_384, Ecrypt, DSA_7680_384, Ok(DSA_7680_384));
   test_ffc!(ffc_15360_512, Ecrypt, DSA_15360_512, Ok(DSA_15360_512));
+  test_ffc!(ffdhe2048, Ecrypt, FFDHE2048, Ok(DSA_3072_256));
+  test_ffc!(modp1024, Ecrypt, MODP_1024, Ok(DSA_3072_256));
 
   test_hash!(blake_224, Ecrypt, BLAKE_224, Ok(SHA256));
   test_hash!(blake_256, Ecrypt, BLAKE_256, Ok(SHA256));
@@ -361,15 +927,136 @@ mod tests {
   test_ifc!(ifc_7680, Ecrypt, RSA_PSS_7680, Ok(RSA_PSS_7680));
   test_ifc!(ifc_15360, Ecrypt, RSA_PSS_15360, Ok(RSA_PSS_15360));
 
+  test_kdf!(hkdf_sha256, Ecrypt, HKDF_SHA256, Ok(HKDF_SHA256));
+  test_kdf!(hkdf_sha512, Ecrypt, HKDF_SHA512, Ok(HKDF_SHA512));
+  test_kdf!(kbkdf_cmac, Ecrypt, KBKDF_CMAC, Ok(HKDF_SHA256));
+  test_kdf!(pbkdf2_hmac_sha1, Ecrypt, PBKDF2_HMAC_SHA1, Ok(HKDF_SHA256));
+  test_kdf!(tls10_prf, Ecrypt, TLS10_PRF, Ok(HKDF_SHA256));
+  test_kdf!(tls12_prf_sha384, Ecrypt, TLS12_PRF_SHA384, Ok(HKDF_SHA384));
+
+  test_kem!(ml_kem_768, Ecrypt, ML_KEM_768, Err(KEM_NOT_SUPPORTED));
+  test_kem!(
+    x25519mlkem768,
+    Ecrypt,
+    X25519MLKEM768,
+    Err(KEM_NOT_SUPPORTED)
+  );
+
+  test_mac!(cmac, Ecrypt, CMAC, Ok(HMAC_SHA256));
+  test_mac!(gmac, Ecrypt, GMAC, Ok(HMAC_SHA256));
+  test_mac!(hmac_md5, Ecrypt, HMAC_MD5, Ok(HMAC_SHA256));
+  test_mac!(hmac_sha1, Ecrypt, HMAC_SHA1, Ok(HMAC_SHA256));
+  test_mac!(hmac_sha256, Ecrypt, HMAC_SHA256, Ok(HMAC_SHA256));
+  test_mac!(hmac_sha512, Ecrypt, HMAC_SHA512, Ok(HMAC_SHA512));
+  test_mac!(kmac256, Ecrypt, KMAC256, Ok(HMAC_SHA256));
+  test_mac!(poly1305_mac, Ecrypt, POLY1305_MAC, Ok(HMAC_SHA256));
+  test_mac!(umac, Ecrypt, UMAC, Err(HMAC_SHA256));
+  test_mac!(hmac_ripemd160, Ecrypt, HMAC_RIPEMD160, Err(HMAC_SHA256));
+
+  test_mode!(ecb, Ecrypt, ECB, Err(GCM));
+  test_mode!(cbc, Ecrypt, CBC, Ok(CBC));
+  test_mode!(cfb, Ecrypt, CFB, Ok(GCM));
+  test_mode!(ofb, Ecrypt, OFB, Ok(GCM));
+  test_mode!(ctr, Ecrypt, CTR, Ok(CTR));
+  test_mode!(xts, Ecrypt, XTS, Ok(XTS));
+  test_mode!(ccm, Ecrypt, CCM, Ok(CCM));
+  test_mode!(gcm, Ecrypt, GCM, Ok(GCM));
+  test_mode!(gcm_siv, Ecrypt, GCM_SIV, Err(GCM));
+  test_mode!(ocb, Ecrypt, OCB, Ok(OCB));
+  test_mode!(poly1305, Ecrypt, POLY1305, Ok(POLY1305));
+
+  test_phf!(argon2id, Ecrypt, ARGON2ID, Ok(ARGON2ID));
+  test_phf!(bcrypt, Ecrypt, BCRYPT, Ok(ARGON2ID));
+  test_phf!(md5_crypt, Ecrypt, MD5_CRYPT, Err(ARGON2ID));
+  test_phf!(pbkdf2_sha1, Ecrypt, PBKDF2_SHA1, Ok(ARGON2ID));
+  test_phf!(pbkdf2_sha256, Ecrypt, PBKDF2_SHA256, Ok(ARGON2ID));
+  test_phf!(scrypt, Ecrypt, SCRYPT, Ok(ARGON2ID));
+  test_phf!(sha512_crypt, Ecrypt, SHA512_CRYPT, Err(ARGON2ID));
+  test_phf!(yescrypt, Ecrypt, YESCRYPT, Err(ARGON2ID));
+  test_phf!(des_crypt, Ecrypt, DES_CRYPT, Err(ARGON2ID));
+
+  test_pqs!(ml_dsa_44, Ecrypt, ML_DSA_44, Err(PQS_NOT_SUPPORTED));
+  test_pqs!(ml_dsa_87, Ecrypt, ML_DSA_87, Err(PQS_NOT_SUPPORTED));
+  test_pqs!(
+    slh_dsa_sha2_128s,
+    Ecrypt,
+    SLH_DSA_SHA2_128S,
+    Err(PQS_NOT_SUPPORTED)
+  );
+  test_pqs!(xmss_sha2_256, Ecrypt, XMSS_SHA2_256, Err(PQS_NOT_SUPPORTED));
+
   test_symmetric!(aes128, Ecrypt, AES128, Ok(AES128));
   test_symmetric!(aes192, Ecrypt, AES192, Ok(AES192));
   test_symmetric!(aes256, Ecrypt, AES256, Ok(AES256));
   test_symmetric!(camellia128, Ecrypt, CAMELLIA128, Ok(AES128));
   test_symmetric!(camellia192, Ecrypt, CAMELLIA192, Ok(AES192));
   test_symmetric!(camellia256, Ecrypt, CAMELLIA256, Ok(AES256));
+  test_symmetric!(chacha20, Ecrypt, CHACHA20, Ok(AES256));
+  test_symmetric!(salsa20, Ecrypt, SALSA20, Ok(AES256));
+  test_symmetric!(xchacha20, Ecrypt, XCHACHA20, Err(AES128));
+  test_symmetric!(rc4, Ecrypt, RC4, Err(AES128));
   test_symmetric!(serpent128, Ecrypt, SERPENT128, Ok(AES128));
   test_symmetric!(serpent192, Ecrypt, SERPENT192, Ok(AES192));
   test_symmetric!(serpent256, Ecrypt, SERPENT256, Ok(AES256));
   test_symmetric!(three_key_tdea, Ecrypt, TDEA3, Ok(AES128));
   test_symmetric!(two_key_tdea, Ecrypt, TDEA2, Ok(AES128));
+
+  #[test]
+  fn sha224_is_acceptable_for_key_derivation_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1).with_usage(Usage::KeyDerivation);
+    let verdict = Ecrypt::validate_hash(ctx, SHA224);
+    assert_eq!(verdict.status(), Status::Acceptable);
+  }
+
+  #[test]
+  fn rsa_2048_is_acceptable_for_processing_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1).with_operation(Operation::Process);
+    let verdict = Ecrypt::validate_ifc(ctx, RSA_PKCS1_2048);
+    assert_eq!(verdict.status(), Status::Acceptable);
+  }
+
+  #[test]
+  fn hmac_sha1_is_legacy_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1);
+    let verdict = Ecrypt::validate_mac(ctx, HMAC_SHA1);
+    assert_eq!(verdict.status(), Status::Legacy);
+    assert_eq!(verdict.into_result(), Err(HMAC_SHA256));
+  }
+
+  #[test]
+  fn bcrypt_is_legacy_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1);
+    let verdict = Ecrypt::validate_phf(ctx, BCRYPT);
+    assert_eq!(verdict.status(), Status::Legacy);
+    assert_eq!(verdict.into_result(), Err(ARGON2ID));
+  }
+
+  #[test]
+  fn hmac_drbg_sha1_is_legacy_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1);
+    let verdict = Ecrypt::validate_drbg(ctx, HMAC_DRBG_SHA1);
+    assert_eq!(verdict.status(), Status::Legacy);
+    assert_eq!(verdict.into_result(), Err(CTR_DRBG_AES128));
+  }
+
+  #[test]
+  fn x25519_is_acceptable_for_key_establishment() {
+    let ctx = Context::default().with_usage(Usage::KeyEstablishment);
+    let verdict = Ecrypt::validate_ecc(ctx, X25519);
+    assert_eq!(verdict.status(), Status::Acceptable);
+  }
+
+  #[test]
+  fn x25519_is_disallowed_for_digital_signatures() {
+    let ctx = Context::default().with_usage(Usage::DigitalSignature);
+    let verdict = Ecrypt::validate_ecc(ctx, X25519);
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+
+  #[test]
+  fn ed25519_is_disallowed_for_key_establishment() {
+    let ctx = Context::default().with_usage(Usage::KeyEstablishment);
+    let verdict = Ecrypt::validate_ecc(ctx, ED25519);
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
 }
@@ -5,14 +5,21 @@ use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
 
-use crate::context::Context;
+use crate::context::{Context, Usage};
+use crate::primitive::drbg::*;
 use crate::primitive::ecc::*;
 use crate::primitive::ffc::*;
 use crate::primitive::hash::*;
 use crate::primitive::ifc::*;
+use crate::primitive::kdf::*;
+use crate::primitive::kem::*;
+use crate::primitive::mac::*;
+use crate::primitive::mode::*;
+use crate::primitive::phf::*;
+use crate::primitive::pqs::*;
 use crate::primitive::symmetric::*;
 use crate::primitive::Primitive;
-use crate::standard::Standard;
+use crate::standard::{Citation, Standard, Status, Verdict};
 
 #[derive(PartialEq, Eq, Debug)]
 pub enum ValidationError {
@@ -22,6 +29,8 @@ pub enum ValidationError {
 const BASE_YEAR: u16 = 1982;
 const BASE_SECURITY: u16 = 56;
 
+const KEY_LENGTHS: &str = "Lenstra, Key Lengths";
+
 static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(RIPEMD160);
@@ -32,6 +41,21 @@ static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   s
 });
 
+static SPECIFIED_MODES: Lazy<HashSet<Mode>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CBC);
+  s.insert(CCM);
+  s.insert(CFB);
+  s.insert(CTR);
+  s.insert(GCM);
+  s.insert(GCM_SIV);
+  s.insert(OCB);
+  s.insert(OFB);
+  s.insert(POLY1305);
+  s.insert(XTS);
+  s
+});
+
 static SPECIFIED_SYMMETRIC_KEYS: Lazy<HashSet<Symmetric>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(AES128);
@@ -65,15 +89,68 @@ impl Lenstra {
 }
 
 impl Standard for Lenstra {
+  /// Validates a deterministic random bit generator.
+  ///
+  /// The paper is only concerned with key lengths so the generator is
+  /// deemed compliant if its security strength is at least the minimum
+  /// level of security for the year. Dual_EC_DRBG is disallowed
+  /// regardless as it was withdrawn from NIST SP 800-90A.
+  ///
+  /// A generator is therefore either disallowed or acceptable. The
+  /// recommendation is Hash_DRBG with the SHA hash function whose
+  /// security matches the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant
+  /// generator.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::drbg::HASH_DRBG_SHA256;
+  /// use wardstone_core::standard::lenstra::Lenstra;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Lenstra::validate_drbg(ctx, HASH_DRBG_SHA256);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), HASH_DRBG_SHA256);
+  /// ```
+  fn validate_drbg(ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, drbg.security()).cite(Citation::new(KEY_LENGTHS, None))
+    };
+    if [DUAL_EC_DRBG_P256, DUAL_EC_DRBG_P384, DUAL_EC_DRBG_P521].contains(&drbg) {
+      return verdict(Status::Disallowed, HASH_DRBG_SHA256);
+    }
+    let implied_security = ctx.security().max(drbg.security());
+    let min_security = match Lenstra::calculate_security(ctx.year()) {
+      Ok(security) => security,
+      Err(_) => return verdict(Status::Disallowed, HASH_DRBG_SHA256),
+    };
+    let recommendation = match implied_security.max(min_security) {
+      ..=128 => HASH_DRBG_SHA1,
+      129..=192 => HASH_DRBG_SHA224,
+      193.. => HASH_DRBG_SHA256,
+    };
+    if implied_security < min_security {
+      verdict(Status::Disallowed, recommendation)
+    } else {
+      verdict(Status::Acceptable, recommendation)
+    }
+  }
+
   /// Validate an elliptic curve cryptography primitive used for digital
   /// signatures and key establishment where f is the key size.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
+  /// X25519 and X448 are only compliant for key agreement while Ed25519
+  /// and Ed448 are only compliant for digital signatures.
   ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -83,16 +160,21 @@ impl Standard for Lenstra {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ecc::{BRAINPOOLP256R1, ECC_256};
   /// use wardstone_core::standard::lenstra::Lenstra;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Lenstra::validate_ecc(ctx, BRAINPOOLP256R1), Ok(ECC_256));
+  /// let verdict = Lenstra::validate_ecc(ctx, BRAINPOOLP256R1);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ECC_256);
   /// ```
-  fn validate_ecc(ctx: Context, key: Ecc) -> Result<Ecc, Ecc> {
+  fn validate_ecc(ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security()).cite(Citation::new(KEY_LENGTHS, Some(7)))
+    };
     let implied_security = ctx.security().max(key.security());
     let min_security = match Lenstra::calculate_security(ctx.year()) {
       Ok(security) => security,
-      Err(_) => return Err(ECC_NOT_ALLOWED),
+      Err(_) => return verdict(Status::Disallowed, ECC_NOT_ALLOWED),
     };
     let recommendation = match implied_security.max(min_security) {
       ..=111 => ECC_NOT_ALLOWED,
@@ -101,10 +183,15 @@ impl Standard for Lenstra {
       129..=192 => ECC_384,
       193.. => ECC_512,
     };
+    if (key.is_signature_only() && ctx.usage() == Usage::KeyEstablishment)
+      || (key.is_key_agreement_only() && ctx.usage() == Usage::DigitalSignature)
+    {
+      return verdict(Status::Disallowed, recommendation);
+    }
     if implied_security < min_security {
-      Err(recommendation)
+      verdict(Status::Disallowed, recommendation)
     } else {
-      Ok(recommendation)
+      verdict(Status::Acceptable, recommendation)
     }
   }
 
@@ -113,12 +200,11 @@ impl Standard for Lenstra {
   /// Examples include the DSA and key establishment algorithms such as
   /// Diffie-Hellman and MQV which can also be implemented as such.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key sizes L and N that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key sizes L
-  /// and N with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key sizes L and N that one
+  /// should use instead. If it is but the context specifies a higher
+  /// security level, the recommendation is the key sizes L and N with
+  /// the desired security level.
   ///
   /// # Example
   ///
@@ -128,17 +214,22 @@ impl Standard for Lenstra {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ffc::DSA_3072_256;
   /// use wardstone_core::standard::lenstra::Lenstra;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let dsa_3072 = DSA_3072_256;
-  /// assert_eq!(Lenstra::validate_ffc(ctx, dsa_3072), Ok(dsa_3072));
+  /// let verdict = Lenstra::validate_ffc(ctx, dsa_3072);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), dsa_3072);
   /// ```
-  fn validate_ffc(ctx: Context, key: Ffc) -> Result<Ffc, Ffc> {
+  fn validate_ffc(ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security()).cite(Citation::new(KEY_LENGTHS, Some(7)))
+    };
     let implied_security = ctx.security().max(key.security());
     let min_security = match Lenstra::calculate_security(ctx.year()) {
       Ok(security) => security,
-      Err(_) => return Err(FFC_NOT_SUPPORTED),
+      Err(_) => return verdict(Status::Disallowed, FFC_NOT_SUPPORTED),
     };
     let recommendation = match implied_security.max(min_security) {
       ..=79 => FFC_NOT_SUPPORTED,
@@ -149,9 +240,9 @@ impl Standard for Lenstra {
       193.. => DSA_15360_512,
     };
     if implied_security < min_security {
-      Err(recommendation)
+      verdict(Status::Disallowed, recommendation)
     } else {
-      Ok(recommendation)
+      verdict(Status::Acceptable, recommendation)
     }
   }
 
@@ -163,12 +254,11 @@ impl Standard for Lenstra {
   /// function and hash based application are assessed by this single
   /// function.
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** An alternative might be suggested for a compliant hash
   /// function with a similar security level in which a switch to the
@@ -186,17 +276,28 @@ impl Standard for Lenstra {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHA256};
   /// use wardstone_core::standard::lenstra::Lenstra;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Lenstra::validate_hash(ctx, SHA1), Err(SHA256));
+  /// let verdict = Lenstra::validate_hash(ctx, SHA1);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), SHA256);
   /// ```
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    // Applications such as message authentication codes and key
+    // derivation functions only rely on pre-image resistance.
+    let resistance = match ctx.usage() {
+      Usage::MessageAuthentication | Usage::KeyDerivation => hash.security() << 1,
+      _ => hash.security(),
+    };
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, resistance).cite(Citation::new(KEY_LENGTHS, Some(12)))
+    };
     if SPECIFIED_HASH_FUNCTIONS.contains(&hash) {
-      let implied_security = ctx.security().max(hash.security());
+      let implied_security = ctx.security().max(resistance);
       let min_security = match Lenstra::calculate_security(ctx.year()) {
         Ok(security) => security,
-        Err(_) => return Err(SHA256),
+        Err(_) => return verdict(Status::Disallowed, SHA256),
       };
       let recommendation = match implied_security.max(min_security) {
         // SHA1 and RIPEMD-160 offer less security than their digest
@@ -207,12 +308,12 @@ impl Standard for Lenstra {
         193.. => SHA512,
       };
       if implied_security < min_security {
-        Err(recommendation)
+        verdict(Status::Disallowed, recommendation)
       } else {
-        Ok(recommendation)
+        verdict(Status::Acceptable, recommendation)
       }
     } else {
-      Err(SHA256)
+      verdict(Status::Unrecognised, SHA256)
     }
   }
 
@@ -220,12 +321,11 @@ impl Standard for Lenstra {
   /// most common of which is the RSA signature algorithm based on
   /// pages 17-25.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key size that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key size
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key size that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the key size with the desired
+  /// security level.
   ///
   /// **Note:** Unlike other functions in this module, this will return
   /// a generic structure that specifies minimum private and public
@@ -239,12 +339,18 @@ impl Standard for Lenstra {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ifc::RSA_PSS_2048;
   /// use wardstone_core::standard::lenstra::Lenstra;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Lenstra::validate_ifc(ctx, RSA_PSS_2048), Ok(RSA_PSS_2048));
+  /// let verdict = Lenstra::validate_ifc(ctx, RSA_PSS_2048);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), RSA_PSS_2048);
   /// ```
-  fn validate_ifc(ctx: Context, key: Ifc) -> Result<Ifc, Ifc> {
+  fn validate_ifc(ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(KEY_LENGTHS, Some(25)))
+    };
     // Per Table 4 on page 25.
     let (implied_year, implied_security) = match key.k {
       ..=1023 => (u16::MIN, u16::MIN),
@@ -270,21 +376,277 @@ impl Standard for Lenstra {
 
     let security = ctx.security().max(implied_security);
     if !security_range.contains(&security) {
-      Err(recommendation)
+      verdict(Status::Disallowed, recommendation)
+    } else {
+      verdict(Status::Acceptable, recommendation)
+    }
+  }
+
+  /// Validates a key derivation function.
+  ///
+  /// The paper is only concerned with key lengths so the function is
+  /// deemed compliant if the derived key offers at least the minimum
+  /// level of security for the year. The iteration count of
+  /// password-based functions is not assessed.
+  ///
+  /// A function whose derived key falls short of that level of security
+  /// is disallowed and any other function is acceptable. The
+  /// recommendation is HKDF with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a function that
+  /// derives a key which is too short.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kdf::{Kdf, HKDF_SHA256};
+  /// use wardstone_core::standard::lenstra::Lenstra;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let hkdf = Kdf::new(HKDF_SHA256.id, 64, 0);
+  /// let verdict = Lenstra::validate_kdf(ctx, hkdf);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), HKDF_SHA256);
+  /// ```
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kdf.security()).cite(Citation::new(KEY_LENGTHS, None))
+    };
+    let implied_security = ctx.security().max(kdf.security());
+    let min_security = match Lenstra::calculate_security(ctx.year()) {
+      Ok(security) => security,
+      Err(_) => return verdict(Status::Disallowed, HKDF_SHA256),
+    };
+    let recommendation = match implied_security.max(min_security) {
+      ..=256 => HKDF_SHA256,
+      257..=384 => HKDF_SHA384,
+      385.. => HKDF_SHA512,
+    };
+    if implied_security < min_security {
+      verdict(Status::Disallowed, recommendation)
+    } else {
+      verdict(Status::Acceptable, recommendation)
+    }
+  }
+
+  /// Validates a key encapsulation mechanism.
+  ///
+  /// The paper predates these schemes so the security of a parameter
+  /// set is taken to be the one implied by its NIST security category.
+  ///
+  /// The status of the verdict tells whether the key encapsulation
+  /// mechanism is compliant. If it is not, the recommendation is the
+  /// primitive that one should use instead. If it is but the context
+  /// specifies a higher security level, the recommendation is the
+  /// primitive with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant key
+  /// encapsulation mechanism.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kem::ML_KEM_768;
+  /// use wardstone_core::standard::lenstra::Lenstra;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Lenstra::validate_kem(ctx, ML_KEM_768);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ML_KEM_768);
+  /// ```
+  fn validate_kem(ctx: Context, kem: Kem) -> Verdict<Kem> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kem.security()).cite(Citation::new(KEY_LENGTHS, Some(7)))
+    };
+    let implied_security = ctx.security().max(kem.security());
+    let min_security = match Lenstra::calculate_security(ctx.year()) {
+      Ok(security) => security,
+      Err(_) => return verdict(Status::Disallowed, KEM_NOT_SUPPORTED),
+    };
+    let recommendation = match implied_security.max(min_security) {
+      ..=128 => ML_KEM_512,
+      129..=192 => ML_KEM_768,
+      193.. => ML_KEM_1024,
+    };
+    if implied_security < min_security {
+      verdict(Status::Disallowed, recommendation)
     } else {
-      Ok(recommendation)
+      verdict(Status::Acceptable, recommendation)
+    }
+  }
+
+  /// Validates a message authentication code.
+  ///
+  /// The paper is only concerned with key lengths so the MAC is deemed
+  /// compliant if an exhaustive search for the key requires at least as
+  /// much effort as the minimum level of security for the year. This
+  /// means that a MAC based on a broken hash function such as MD5 might
+  /// still be deemed compliant.
+  ///
+  /// A MAC whose key falls short of that level of security is
+  /// disallowed and any other MAC is acceptable. The recommendation is
+  /// HMAC with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant MAC.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mac::{CMAC, HMAC_SHA256};
+  /// use wardstone_core::standard::lenstra::Lenstra;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Lenstra::validate_mac(ctx, CMAC);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), HMAC_SHA256);
+  /// ```
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, mac.security()).cite(Citation::new(KEY_LENGTHS, None))
+    };
+    let implied_security = ctx.security().max(mac.security());
+    let min_security = match Lenstra::calculate_security(ctx.year()) {
+      Ok(security) => security,
+      Err(_) => return verdict(Status::Disallowed, HMAC_SHA256),
+    };
+    let recommendation = match implied_security.max(min_security) {
+      ..=256 => HMAC_SHA256,
+      257..=384 => HMAC_SHA384,
+      385.. => HMAC_SHA512,
+    };
+    if implied_security < min_security {
+      verdict(Status::Disallowed, recommendation)
+    } else {
+      verdict(Status::Acceptable, recommendation)
+    }
+  }
+
+  /// Validates a mode of operation of a block cipher.
+  ///
+  /// The paper is only concerned with key lengths and assumes that the
+  /// security of a cipher is bounded by the effort of an exhaustive key
+  /// search. This does not hold for ECB which reveals patterns in the
+  /// plaintext no matter the key length and so it is disallowed with
+  /// GCM recommended instead. The other modes are acceptable and
+  /// recommended as they are. The paper says nothing about modes so the
+  /// verdict cites nothing.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant mode.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mode::CBC;
+  /// use wardstone_core::standard::lenstra::Lenstra;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Lenstra::validate_mode(ctx, CBC);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), CBC);
+  /// ```
+  fn validate_mode(_ctx: Context, mode: Mode) -> Verdict<Mode> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, mode.security());
+    if mode == ECB {
+      verdict(Status::Disallowed, GCM)
+    } else if SPECIFIED_MODES.contains(&mode) {
+      verdict(Status::Acceptable, mode)
+    } else {
+      verdict(Status::Unrecognised, GCM)
+    }
+  }
+
+  /// Validates a password hashing function.
+  ///
+  /// The paper is only concerned with key lengths and so none of these
+  /// functions are supported.
+  ///
+  /// The verdict is therefore always unrecognised with no function to
+  /// recommend instead.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a password hashing
+  /// function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::phf::{ARGON2ID, PHF_NOT_SUPPORTED};
+  /// use wardstone_core::standard::lenstra::Lenstra;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Lenstra::validate_phf(ctx, ARGON2ID);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), PHF_NOT_SUPPORTED);
+  /// ```
+  fn validate_phf(_ctx: Context, phf: Phf) -> Verdict<Phf> {
+    Verdict::new(Status::Unrecognised, PHF_NOT_SUPPORTED, phf.security())
+      .cite(Citation::new(KEY_LENGTHS, None))
+  }
+
+  /// Validates a post-quantum signature primitive.
+  ///
+  /// The paper predates these schemes so the security of a parameter
+  /// set is taken to be the one implied by its NIST security category.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant key.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::pqs::ML_DSA_44;
+  /// use wardstone_core::standard::lenstra::Lenstra;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Lenstra::validate_pqs(ctx, ML_DSA_44);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ML_DSA_44);
+  /// ```
+  fn validate_pqs(ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security()).cite(Citation::new(KEY_LENGTHS, Some(7)))
+    };
+    let implied_security = ctx.security().max(key.security());
+    let min_security = match Lenstra::calculate_security(ctx.year()) {
+      Ok(security) => security,
+      Err(_) => return verdict(Status::Disallowed, PQS_NOT_SUPPORTED),
+    };
+    let recommendation = match implied_security.max(min_security) {
+      ..=128 => ML_DSA_44,
+      129..=192 => ML_DSA_65,
+      193.. => ML_DSA_87,
+    };
+    if implied_security < min_security {
+      verdict(Status::Disallowed, recommendation)
+    } else {
+      verdict(Status::Acceptable, recommendation)
     }
   }
 
   /// Validates a symmetric key primitive according to pages 9-12 of the
   /// paper.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -294,17 +656,22 @@ impl Standard for Lenstra {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::symmetric::TDEA3;
   /// use wardstone_core::standard::lenstra::Lenstra;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Lenstra::validate_symmetric(ctx, TDEA3), Ok(TDEA3));
+  /// let verdict = Lenstra::validate_symmetric(ctx, TDEA3);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), TDEA3);
   /// ```
-  fn validate_symmetric(ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric> {
+  fn validate_symmetric(ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security()).cite(Citation::new(KEY_LENGTHS, Some(9)))
+    };
     if SPECIFIED_SYMMETRIC_KEYS.contains(&key) {
       let implied_security = ctx.security().max(key.security());
       let min_security = match Lenstra::calculate_security(ctx.year()) {
         Ok(security) => security,
-        Err(_) => return Err(AES128),
+        Err(_) => return verdict(Status::Disallowed, AES128),
       };
       let recommendation = match implied_security.max(min_security) {
         ..=95 => TDEA2,
@@ -315,12 +682,12 @@ impl Standard for Lenstra {
         193.. => AES256,
       };
       if implied_security < min_security {
-        Err(recommendation)
+        verdict(Status::Disallowed, recommendation)
       } else {
-        Ok(recommendation)
+        verdict(Status::Acceptable, recommendation)
       }
     } else {
-      Err(AES128)
+      verdict(Status::Unrecognised, AES128)
     }
   }
 }
@@ -328,7 +695,31 @@ impl Standard for Lenstra {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{test_ecc, test_ffc, test_hash, test_ifc, test_symmetric};
+  use crate::{
+    test_drbg, test_ecc, test_ffc, test_hash, test_ifc, test_kdf, test_kem, test_mac, test_mode,
+    test_phf, test_pqs, test_symmetric,
+  };
+
+  test_drbg!(ctr_drbg_tdea, Lenstra, CTR_DRBG_TDEA, Ok(HASH_DRBG_SHA1));
+  test_drbg!(
+    dual_ec_drbg_p384,
+    Lenstra,
+    DUAL_EC_DRBG_P384,
+    Err(HASH_DRBG_SHA256)
+  );
+  test_drbg!(hash_drbg_sha1, Lenstra, HASH_DRBG_SHA1, Ok(HASH_DRBG_SHA1));
+  test_drbg!(
+    hmac_drbg_sha512,
+    Lenstra,
+    HMAC_DRBG_SHA512,
+    Ok(HASH_DRBG_SHA256)
+  );
+  test_drbg!(
+    dual_ec_drbg_p521,
+    Lenstra,
+    DUAL_EC_DRBG_P521,
+    Err(HASH_DRBG_SHA256)
+  );
 
   test_ecc!(p224, Lenstra, P224, Ok(ECC_224));
   test_ecc!(p256, Lenstra, P256, Ok(ECC_256));
@@ -350,6 +741,8 @@ mod tests {
   test_ffc!(ffc_3072_256, Lenstra, DSA_3072_256, Ok(DSA_3072_256));
   test_ffc!(ffc_7680_384, Lenstra, DSA_7680_384, Ok(DSA_7680_384));
   test_ffc!(ffc_15360_512, Lenstra, DSA_15360_512, Ok(DSA_15360_512));
+  test_ffc!(ffdhe2048, Lenstra, FFDHE2048, Ok(DSA_2048_224));
+  test_ffc!(modp1024, Lenstra, MODP_1024, Err(DSA_2048_224));
 
   test_ifc!(ifc_1024, Lenstra, RSA_PSS_1024, Err(RSA_PSS_2048));
   test_ifc!(ifc_1280, Lenstra, RSA_PSS_1280, Err(RSA_PSS_2048));
@@ -387,6 +780,58 @@ mod tests {
   test_hash!(shake256, Lenstra, SHAKE256, Err(SHA256));
   test_hash!(whirlpool, Lenstra, WHIRLPOOL, Err(SHA256));
 
+  test_kdf!(hkdf_sha256, Lenstra, HKDF_SHA256, Ok(HKDF_SHA256));
+  test_kdf!(hkdf_sha512, Lenstra, HKDF_SHA512, Ok(HKDF_SHA512));
+  test_kdf!(pbkdf2_hmac_sha1, Lenstra, PBKDF2_HMAC_SHA1, Ok(HKDF_SHA256));
+  test_kdf!(tls10_prf, Lenstra, TLS10_PRF, Ok(HKDF_SHA256));
+
+  test_kem!(ml_kem_512, Lenstra, ML_KEM_512, Ok(ML_KEM_512));
+  test_kem!(ml_kem_768, Lenstra, ML_KEM_768, Ok(ML_KEM_768));
+  test_kem!(ml_kem_1024, Lenstra, ML_KEM_1024, Ok(ML_KEM_1024));
+  test_kem!(x25519mlkem768, Lenstra, X25519MLKEM768, Ok(ML_KEM_768));
+  test_kem!(mceliece348864, Lenstra, MCELIECE348864, Ok(ML_KEM_512));
+
+  test_mac!(cmac, Lenstra, CMAC, Ok(HMAC_SHA256));
+  test_mac!(hmac_md5, Lenstra, HMAC_MD5, Ok(HMAC_SHA256));
+  test_mac!(hmac_sha384, Lenstra, HMAC_SHA384, Ok(HMAC_SHA384));
+  test_mac!(kmac256, Lenstra, KMAC256, Ok(HMAC_SHA256));
+  test_mac!(
+    hmac_sha256_64,
+    Lenstra,
+    Mac::new(HMAC_SHA256.id, 64, 256),
+    Err(HMAC_SHA256)
+  );
+  test_mac!(umac, Lenstra, UMAC, Ok(HMAC_SHA256));
+  test_mac!(hmac_ripemd160, Lenstra, HMAC_RIPEMD160, Ok(HMAC_SHA256));
+
+  test_mode!(ecb, Lenstra, ECB, Err(GCM));
+  test_mode!(cbc, Lenstra, CBC, Ok(CBC));
+  test_mode!(ctr, Lenstra, CTR, Ok(CTR));
+  test_mode!(gcm, Lenstra, GCM, Ok(GCM));
+  test_mode!(poly1305, Lenstra, POLY1305, Ok(POLY1305));
+
+  #[test]
+  fn modes_are_not_cited() {
+    let ctx = Context::default();
+    assert_eq!(Lenstra::validate_mode(ctx, ECB).citation(), None);
+    assert_eq!(Lenstra::validate_mode(ctx, GCM).citation(), None);
+  }
+
+  test_phf!(argon2id, Lenstra, ARGON2ID, Err(PHF_NOT_SUPPORTED));
+  test_phf!(
+    pbkdf2_sha256,
+    Lenstra,
+    PBKDF2_SHA256,
+    Err(PHF_NOT_SUPPORTED)
+  );
+  test_phf!(des_crypt, Lenstra, DES_CRYPT, Err(PHF_NOT_SUPPORTED));
+
+  test_pqs!(ml_dsa_44, Lenstra, ML_DSA_44, Ok(ML_DSA_44));
+  test_pqs!(ml_dsa_65, Lenstra, ML_DSA_65, Ok(ML_DSA_65));
+  test_pqs!(ml_dsa_87, Lenstra, ML_DSA_87, Ok(ML_DSA_87));
+  test_pqs!(slh_dsa_sha2_128s, Lenstra, SLH_DSA_SHA2_128S, Ok(ML_DSA_44));
+  test_pqs!(lms_sha256_m24, Lenstra, LMS_SHA256_M24, Ok(ML_DSA_65));
+
   test_symmetric!(aes128, Lenstra, AES128, Ok(AES128));
   test_symmetric!(aes192, Lenstra, AES192, Ok(AES192));
   test_symmetric!(aes256, Lenstra, AES256, Ok(AES256));
@@ -396,6 +841,7 @@ mod tests {
   test_symmetric!(des, Lenstra, DES, Err(TDEA2));
   test_symmetric!(desx, Lenstra, DESX, Ok(DESX));
   test_symmetric!(idea, Lenstra, IDEA, Ok(AES128));
+  test_symmetric!(rc4, Lenstra, RC4, Err(AES128));
   test_symmetric!(serpent128, Lenstra, SERPENT128, Err(AES128));
   test_symmetric!(serpent192, Lenstra, SERPENT192, Err(AES128));
   test_symmetric!(serpent256, Lenstra, SERPENT256, Err(AES128));
@@ -6,19 +6,37 @@ use std::collections::HashSet;
 
 use once_cell::sync::Lazy;
 
-use super::Standard;
-use crate::context::Context;
+use super::{Citation, Standard, Status, Verdict};
+use crate::context::{Context, Usage};
+use crate::primitive::drbg::*;
 use crate::primitive::ecc::*;
 use crate::primitive::ffc::*;
 use crate::primitive::hash::*;
 use crate::primitive::ifc::*;
+use crate::primitive::kdf::*;
+use crate::primitive::kem::*;
+use crate::primitive::mac::*;
+use crate::primitive::mode::*;
+use crate::primitive::phf::*;
+use crate::primitive::pqs::*;
 use crate::primitive::symmetric::*;
-use crate::primitive::Primitive;
+use crate::primitive::{Primitive, Security};
 
 const CUTOFF_YEAR: u16 = 2031; // See p. 59.
 const CUTOFF_YEAR_3TDEA: u16 = 2023; // See footnote on p. 54.
 const CUTOFF_YEAR_DSA: u16 = 2023; // See FIPS-186-5 p. 16.
+const CUTOFF_YEAR_ECB: u16 = 2030; // See SP 800-131A Rev. 3 (Draft).
 
+const MIN_LEGACY_SECURITY: Security = 80; // See p. 59.
+const MIN_PBKDF2_ITERATIONS: u32 = 1000; // See SP 800-132 p. 7.
+const MIN_PHF_ITERATIONS: u32 = 10_000; // See SP 800-63B p. 14.
+const MIN_TAG_LENGTH: u16 = 32; // See SP 800-107 Rev. 1.
+
+const SP_800_57: &str = "NIST SP 800-57 Part 1 Rev. 5";
+
+// SP 800-186 also specifies Curve25519 and Curve448 but SP 800-56A Rev.
+// 3 does not approve X25519 and X448 for key agreement so they are left
+// out.
 static SPECIFIED_CURVES: Lazy<HashSet<Ecc>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(ED25519);
@@ -36,6 +54,30 @@ static SPECIFIED_CURVES: Lazy<HashSet<Ecc>> = Lazy::new(|| {
   s
 });
 
+static SPECIFIED_DRBGS: Lazy<HashSet<Drbg>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CTR_DRBG_AES128);
+  s.insert(CTR_DRBG_AES128_NO_DF);
+  s.insert(CTR_DRBG_AES192);
+  s.insert(CTR_DRBG_AES192_NO_DF);
+  s.insert(CTR_DRBG_AES256);
+  s.insert(CTR_DRBG_AES256_NO_DF);
+  s.insert(CTR_DRBG_TDEA);
+  s.insert(HASH_DRBG_SHA1);
+  s.insert(HASH_DRBG_SHA224);
+  s.insert(HASH_DRBG_SHA256);
+  s.insert(HASH_DRBG_SHA384);
+  s.insert(HASH_DRBG_SHA512);
+  s.insert(HASH_DRBG_SHA512_256);
+  s.insert(HMAC_DRBG_SHA1);
+  s.insert(HMAC_DRBG_SHA224);
+  s.insert(HMAC_DRBG_SHA256);
+  s.insert(HMAC_DRBG_SHA384);
+  s.insert(HMAC_DRBG_SHA512);
+  s.insert(HMAC_DRBG_SHA512_256);
+  s
+});
+
 static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(SHA1);
@@ -54,6 +96,106 @@ static SPECIFIED_HASH_FUNCTIONS: Lazy<HashSet<Hash>> = Lazy::new(|| {
   s
 });
 
+// Key and tag lengths are parameters of these primitives so the sets
+// below hold the identifiers of the constructions instead.
+static SPECIFIED_KDFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(HKDF_SHA256.id);
+  s.insert(HKDF_SHA384.id);
+  s.insert(HKDF_SHA512.id);
+  s.insert(KBKDF_CMAC.id);
+  s.insert(KBKDF_HMAC_SHA256.id);
+  s.insert(KBKDF_HMAC_SHA384.id);
+  s.insert(KBKDF_HMAC_SHA512.id);
+  s.insert(PBKDF2_HMAC_SHA1.id);
+  s.insert(PBKDF2_HMAC_SHA256.id);
+  s.insert(PBKDF2_HMAC_SHA512.id);
+  s.insert(SP800_56C_SHA256.id);
+  s.insert(SP800_56C_SHA384.id);
+  s.insert(SP800_56C_SHA512.id);
+  s.insert(TLS10_PRF.id);
+  s.insert(TLS12_PRF_SHA256.id);
+  s.insert(TLS12_PRF_SHA384.id);
+  s
+});
+
+static SPECIFIED_KEMS: Lazy<HashSet<Kem>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(ML_KEM_1024);
+  s.insert(ML_KEM_512);
+  s.insert(ML_KEM_768);
+  s.insert(SECP256R1MLKEM768);
+  s.insert(SECP384R1MLKEM1024);
+  s.insert(X25519MLKEM768);
+  s
+});
+
+static SPECIFIED_PHFS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(PBKDF2_SHA1.id);
+  s.insert(PBKDF2_SHA256.id);
+  s.insert(PBKDF2_SHA512.id);
+  s
+});
+
+static SPECIFIED_MACS: Lazy<HashSet<u16>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CMAC.id);
+  s.insert(GMAC.id);
+  s.insert(HMAC_SHA1.id);
+  s.insert(HMAC_SHA224.id);
+  s.insert(HMAC_SHA256.id);
+  s.insert(HMAC_SHA384.id);
+  s.insert(HMAC_SHA512.id);
+  s.insert(HMAC_SHA3_256.id);
+  s.insert(HMAC_SHA3_384.id);
+  s.insert(HMAC_SHA3_512.id);
+  s.insert(KMAC128.id);
+  s.insert(KMAC256.id);
+  s
+});
+
+static SPECIFIED_MODES: Lazy<HashSet<Mode>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(CBC);
+  s.insert(CCM);
+  s.insert(CFB);
+  s.insert(CTR);
+  s.insert(ECB);
+  s.insert(GCM);
+  s.insert(OFB);
+  s.insert(XTS);
+  s
+});
+
+static SPECIFIED_PQ_SIGNATURES: Lazy<HashSet<Pqs>> = Lazy::new(|| {
+  let mut s = HashSet::new();
+  s.insert(LMS_SHA256_M24);
+  s.insert(LMS_SHA256_M32);
+  s.insert(LMS_SHAKE_M24);
+  s.insert(LMS_SHAKE_M32);
+  s.insert(ML_DSA_44);
+  s.insert(ML_DSA_65);
+  s.insert(ML_DSA_87);
+  s.insert(SLH_DSA_SHA2_128F);
+  s.insert(SLH_DSA_SHA2_128S);
+  s.insert(SLH_DSA_SHA2_192F);
+  s.insert(SLH_DSA_SHA2_192S);
+  s.insert(SLH_DSA_SHA2_256F);
+  s.insert(SLH_DSA_SHA2_256S);
+  s.insert(SLH_DSA_SHAKE_128F);
+  s.insert(SLH_DSA_SHAKE_128S);
+  s.insert(SLH_DSA_SHAKE_192F);
+  s.insert(SLH_DSA_SHAKE_192S);
+  s.insert(SLH_DSA_SHAKE_256F);
+  s.insert(SLH_DSA_SHAKE_256S);
+  s.insert(XMSS_SHA2_192);
+  s.insert(XMSS_SHA2_256);
+  s.insert(XMSS_SHAKE256_192);
+  s.insert(XMSS_SHAKE256_256);
+  s
+});
+
 static SPECIFIED_SYMMETRIC_KEYS: Lazy<HashSet<Symmetric>> = Lazy::new(|| {
   let mut s = HashSet::new();
   s.insert(AES128);
@@ -64,6 +206,21 @@ static SPECIFIED_SYMMETRIC_KEYS: Lazy<HashSet<Symmetric>> = Lazy::new(|| {
   s
 });
 
+/// Primitives that no longer offer enough security to apply protection
+/// may still be used to process already protected data (see table 4 on
+/// p. 59) so they are acceptable when processing.
+///
+/// This only extends to primitives that once offered a security
+/// strength of at least 80 bits. Verdicts on flawed parameters such as
+/// a low iteration count or a short tag are not passed through here.
+fn for_legacy_use<T>(mut verdict: Verdict<T>, ctx: Context) -> Verdict<T> {
+  let legacy = verdict.security >= MIN_LEGACY_SECURITY;
+  if ctx.is_processing() && legacy && verdict.status == Status::Disallowed {
+    verdict.status = Status::Acceptable;
+  }
+  verdict
+}
+
 /// [`Standard`] implementation of the [NIST Special Publication 800-57
 /// Part 1 Revision 5 standard].
 ///
@@ -81,12 +238,11 @@ impl Nist {
   /// signatures use
   /// [`validate_hash`](crate::standard::nist::Nist::validate_hash).
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** that this means an alternative might be suggested for a
   /// compliant hash functions with a similar security level in which a
@@ -104,49 +260,124 @@ impl Nist {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHAKE128};
   /// use wardstone_core::standard::nist::Nist;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let hmac_sha1 = SHA1;
-  /// assert_eq!(Nist::validate_hash_based(ctx, hmac_sha1), Ok(hmac_sha1));
+  /// let verdict = Nist::validate_hash_based(ctx, hmac_sha1);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), hmac_sha1);
   /// ```
-  pub fn validate_hash_based(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  pub fn validate_hash_based(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    let pre_image_resistance = hash.security() << 1;
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, pre_image_resistance)
+        .cite(Citation::new(SP_800_57, Some(56)))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
     if SPECIFIED_HASH_FUNCTIONS.contains(&hash) {
-      let pre_image_resistance = hash.security() << 1;
       let security = ctx.security().max(pre_image_resistance);
       match security {
-        ..=111 => Err(SHAKE128),
+        ..=111 => verdict(Status::Disallowed, SHAKE128),
         112..=127 => {
           if ctx.year() > CUTOFF_YEAR {
-            Err(SHAKE128)
+            verdict(Status::Legacy, SHAKE128)
           } else {
-            Ok(SHAKE128)
+            let until = CUTOFF_YEAR;
+            verdict(Status::Deprecated { until }, SHAKE128)
           }
         },
-        128 => Ok(SHAKE128),
-        129..=160 => Ok(SHA1),
-        161..=224 => Ok(SHA224),
-        225..=256 => Ok(SHA256),
-        257..=394 => Ok(SHA384),
-        395.. => Ok(SHA512),
+        128 => verdict(Status::Acceptable, SHAKE128),
+        129..=160 => verdict(Status::Acceptable, SHA1),
+        161..=224 => verdict(Status::Acceptable, SHA224),
+        225..=256 => verdict(Status::Acceptable, SHA256),
+        257..=394 => verdict(Status::Acceptable, SHA384),
+        395.. => verdict(Status::Acceptable, SHA512),
       }
     } else {
-      Err(SHAKE128)
+      verdict(Status::Unrecognised, SHAKE128)
     }
   }
 }
 
 impl Standard for Nist {
+  /// Validates a deterministic random bit generator according to [SP
+  /// 800-90A Rev. 1] which specifies Hash_DRBG, HMAC_DRBG, and CTR_DRBG.
+  /// The security strength of the generator is assessed in the same
+  /// way as a symmetric key.
+  ///
+  /// Dual_EC_DRBG was withdrawn in Rev. 1 and is therefore disallowed
+  /// while any other generator that is not specified is unrecognised.
+  /// A generator with less than 112 bits of security is disallowed and
+  /// one with exactly 112 bits is deprecated until 2030, or 2023 for
+  /// CTR_DRBG with TDEA, and only fit for legacy use afterwards. Any
+  /// other generator is acceptable. The recommendation is CTR_DRBG with
+  /// the AES key size that matches the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a withdrawn
+  /// generator.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::drbg::{CTR_DRBG_AES128, DUAL_EC_DRBG_P256};
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Nist::validate_drbg(ctx, DUAL_EC_DRBG_P256);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), CTR_DRBG_AES128);
+  /// ```
+  ///
+  /// [SP 800-90A Rev. 1]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+  fn validate_drbg(ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, drbg.security())
+        .cite(Citation::new("NIST SP 800-90A Rev. 1", None))
+    };
+    if [DUAL_EC_DRBG_P256, DUAL_EC_DRBG_P384, DUAL_EC_DRBG_P521].contains(&drbg) {
+      return verdict(Status::Disallowed, CTR_DRBG_AES128);
+    }
+    if !SPECIFIED_DRBGS.contains(&drbg) {
+      return verdict(Status::Unrecognised, CTR_DRBG_AES128);
+    }
+    let security = ctx.security().max(drbg.security());
+    match security {
+      ..=111 => verdict(Status::Disallowed, CTR_DRBG_AES128),
+      112 => {
+        // See SP 800-131Ar2 p. 7.
+        let (until, citation) = if drbg == CTR_DRBG_TDEA {
+          (
+            CUTOFF_YEAR_3TDEA,
+            Citation::new("NIST SP 800-131A Rev. 2", Some(7)),
+          )
+        } else {
+          (CUTOFF_YEAR, Citation::new(SP_800_57, Some(54)))
+        };
+        if ctx.year() > until {
+          verdict(Status::Legacy, CTR_DRBG_AES128).cite(citation)
+        } else {
+          verdict(Status::Deprecated { until }, CTR_DRBG_AES128).cite(citation)
+        }
+      },
+      113..=128 => verdict(Status::Acceptable, CTR_DRBG_AES128),
+      129..=192 => verdict(Status::Acceptable, CTR_DRBG_AES192),
+      193.. => verdict(Status::Acceptable, CTR_DRBG_AES256),
+    }
+  }
+
   /// Validate an elliptic curve cryptography primitive used for digital
   /// signatures and key establishment where f is the key size according
   /// to page 54-55 of the standard.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -156,35 +387,49 @@ impl Standard for Nist {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ecc::P224;
   /// use wardstone_core::standard::nist::Nist;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Nist::validate_ecc(ctx, P224), Ok(P224));
+  /// let verdict = Nist::validate_ecc(ctx, P224);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2031 });
+  /// assert_eq!(verdict.recommendation(), P224);
   /// ```
-  fn validate_ecc(ctx: Context, key: Ecc) -> Result<Ecc, Ecc> {
+  fn validate_ecc(ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(SP_800_57, Some(54)))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
+    // EdDSA is only specified for digital signatures (see FIPS 186-5).
+    if key.is_signature_only() && ctx.usage() == Usage::KeyEstablishment {
+      return Verdict::new(Status::Disallowed, P256, key.security())
+        .cite(Citation::new("NIST FIPS 186-5", None));
+    }
     if SPECIFIED_CURVES.contains(&key) {
       let security = ctx.security().max(key.security());
       match security {
         ..=111 => {
           if ctx.year() > CUTOFF_YEAR {
-            Err(P256)
+            verdict(Status::Disallowed, P256)
           } else {
-            Err(P224)
+            verdict(Status::Disallowed, P224)
           }
         },
         112..=127 => {
           if ctx.year() > CUTOFF_YEAR {
-            Err(P256)
+            verdict(Status::Legacy, P256)
           } else {
-            Ok(P224)
+            let until = CUTOFF_YEAR;
+            verdict(Status::Deprecated { until }, P224)
           }
         },
-        128..=191 => Ok(P256),
-        192..=255 => Ok(P384),
-        256.. => Ok(P521),
+        128..=191 => verdict(Status::Acceptable, P256),
+        192..=255 => verdict(Status::Acceptable, P384),
+        256.. => verdict(Status::Acceptable, P521),
       }
     } else {
-      Err(P256)
+      verdict(Status::Unrecognised, P256)
     }
   }
 
@@ -197,12 +442,11 @@ impl Standard for Nist {
   /// A newer revision of FIPS-186, FIPS-186-5 no longer approves the
   /// DSA.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key sizes L and N that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key sizes L
-  /// and N with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key sizes L and N that one
+  /// should use instead. If it is but the context specifies a higher
+  /// security level, the recommendation is the key sizes L and N with
+  /// the desired security level.
   ///
   /// **Note:** The standard specifies the choices for the pair l and n
   /// and so primitives that do not strictly conform to this will be
@@ -218,37 +462,51 @@ impl Standard for Nist {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ffc::DSA_2048_224;
   /// use wardstone_core::standard::nist::Nist;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let dsa_2048 = DSA_2048_224;
-  /// assert_eq!(Nist::validate_ffc(ctx, dsa_2048), Ok(dsa_2048));
+  /// let verdict = Nist::validate_ffc(ctx, dsa_2048);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2031 });
+  /// assert_eq!(verdict.recommendation(), dsa_2048);
   /// ```
-  fn validate_ffc(ctx: Context, key: Ffc) -> Result<Ffc, Ffc> {
-    if ctx.year() > CUTOFF_YEAR_DSA {
-      return Err(FFC_NOT_SUPPORTED);
+  fn validate_ffc(ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    // FIPS-186-5 only allows the DSA to verify existing signatures but
+    // this does not extend to key establishment schemes such as
+    // Diffie-Hellman.
+    if ctx.year() > CUTOFF_YEAR_DSA && ctx.usage() != Usage::KeyEstablishment {
+      return Verdict::new(Status::Legacy, FFC_NOT_SUPPORTED, key.security())
+        .cite(Citation::new("NIST FIPS 186-5", Some(16)))
+        .for_operation(ctx);
     }
 
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(SP_800_57, Some(54)))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
     let security = ctx.security().max(key.security());
     match security {
       80 => {
         if ctx.year() > CUTOFF_YEAR {
-          Err(DSA_3072_256)
+          verdict(Status::Disallowed, DSA_3072_256)
         } else {
-          Err(DSA_2048_224)
+          verdict(Status::Disallowed, DSA_2048_224)
         }
       },
       112 => {
         if ctx.year() > CUTOFF_YEAR {
-          Err(DSA_3072_256)
+          verdict(Status::Legacy, DSA_3072_256)
         } else {
-          Ok(DSA_2048_224)
+          let until = CUTOFF_YEAR;
+          verdict(Status::Deprecated { until }, DSA_2048_224)
         }
       },
-      128 => Ok(DSA_3072_256),
-      192 => Ok(DSA_7680_384),
-      256 => Ok(DSA_15360_512),
-      _ => Err(FFC_NOT_SUPPORTED),
+      128 => verdict(Status::Acceptable, DSA_3072_256),
+      192 => verdict(Status::Acceptable, DSA_7680_384),
+      256 => verdict(Status::Acceptable, DSA_15360_512),
+      _ => verdict(Status::Unrecognised, FFC_NOT_SUPPORTED),
     }
   }
 
@@ -261,12 +519,11 @@ impl Standard for Nist {
   /// (KDFs), and random bit generation use
   /// [`validate_hash_based`](crate::standard::nist::Nist::validate_hash_based).
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** An alternative might be suggested for a compliant hash
   /// functions with a similar security level in which a switch to the
@@ -284,35 +541,51 @@ impl Standard for Nist {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHA224};
   /// use wardstone_core::standard::nist::Nist;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Nist::validate_hash(ctx, SHA1), Err(SHA224));
+  /// let verdict = Nist::validate_hash(ctx, SHA1);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), SHA224);
   /// ```
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    if matches!(
+      ctx.usage(),
+      Usage::MessageAuthentication | Usage::KeyDerivation
+    ) {
+      return Self::validate_hash_based(ctx, hash);
+    }
+
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, hash.security())
+        .cite(Citation::new(SP_800_57, Some(56)))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
     if SPECIFIED_HASH_FUNCTIONS.contains(&hash) {
       let security = ctx.security().max(hash.security());
       match security {
         ..=111 => {
           if ctx.year() > CUTOFF_YEAR {
-            Err(SHA256)
+            verdict(Status::Disallowed, SHA256)
           } else {
-            Err(SHA224)
+            verdict(Status::Disallowed, SHA224)
           }
         },
         112..=127 => {
           if ctx.year() > CUTOFF_YEAR {
-            Err(SHA256)
+            verdict(Status::Legacy, SHA256)
           } else {
-            Ok(SHA224)
+            let until = CUTOFF_YEAR;
+            verdict(Status::Deprecated { until }, SHA224)
           }
         },
-        128..=191 => Ok(SHA256),
-        192..=255 => Ok(SHA384),
-        256.. => Ok(SHA512),
+        128..=191 => verdict(Status::Acceptable, SHA256),
+        192..=255 => verdict(Status::Acceptable, SHA384),
+        256.. => verdict(Status::Acceptable, SHA512),
       }
     } else {
-      Err(SHA256)
+      verdict(Status::Unrecognised, SHA256)
     }
   }
 
@@ -320,12 +593,11 @@ impl Standard for Nist {
   /// most common of which is the RSA signature algorithm where k
   /// indicates the key size according to page 54-55 of the standard.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key size that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key size
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key size that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the key size with the desired
+  /// security level.
   ///
   /// **Note:** This will return a generic structure that specifies
   /// minimum private and public key sizes.
@@ -338,43 +610,406 @@ impl Standard for Nist {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ifc::RSA_PSS_2048;
   /// use wardstone_core::standard::nist::Nist;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Nist::validate_ifc(ctx, RSA_PSS_2048), Ok(RSA_PSS_2048));
+  /// let verdict = Nist::validate_ifc(ctx, RSA_PSS_2048);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2031 });
+  /// assert_eq!(verdict.recommendation(), RSA_PSS_2048);
   /// ```
-  fn validate_ifc(ctx: Context, key: Ifc) -> Result<Ifc, Ifc> {
+  fn validate_ifc(ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(SP_800_57, Some(54)))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
     let security = ctx.security().max(key.security());
     match security {
       ..=111 => {
         if ctx.year() > CUTOFF_YEAR {
-          Err(RSA_PSS_3072)
+          verdict(Status::Disallowed, RSA_PSS_3072)
         } else {
-          Err(RSA_PSS_2048)
+          verdict(Status::Disallowed, RSA_PSS_2048)
         }
       },
       112..=127 => {
         if ctx.year() > CUTOFF_YEAR {
-          Err(RSA_PSS_3072)
+          verdict(Status::Legacy, RSA_PSS_3072)
         } else {
-          Ok(RSA_PSS_2048)
+          let until = CUTOFF_YEAR;
+          verdict(Status::Deprecated { until }, RSA_PSS_2048)
         }
       },
-      128..=191 => Ok(RSA_PSS_3072),
-      192..=255 => Ok(RSA_PSS_7680),
-      256.. => Ok(RSA_PSS_15360),
+      128..=191 => verdict(Status::Acceptable, RSA_PSS_3072),
+      192..=255 => verdict(Status::Acceptable, RSA_PSS_7680),
+      256.. => verdict(Status::Acceptable, RSA_PSS_15360),
+    }
+  }
+
+  /// Validates a key derivation function according to [SP 800-108],
+  /// [SP 800-56C], [SP 800-132], and [SP 800-135] which specify the
+  /// key-based KDFs, the one-step and two-step KDFs such as HKDF,
+  /// PBKDF2, and the pseudorandom functions of TLS respectively.
+  ///
+  /// A function the recommendations do not specify is unrecognised.
+  /// PBKDF2 with fewer than 1000 iterations is disallowed, in which case
+  /// the recommendation keeps the same parameters but with the minimum
+  /// iteration count. Otherwise a derived key with less than 112 bits
+  /// of security is disallowed and one with less than 128 bits is
+  /// deprecated until 2031 and only fit for legacy use afterwards. The
+  /// recommendation is PBKDF2 for password-based functions and HKDF for
+  /// all others with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate PBKDF2 with too few
+  /// iterations.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kdf::{Kdf, PBKDF2_HMAC_SHA256};
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let pbkdf2 = Kdf::new(PBKDF2_HMAC_SHA256.id, 256, 100);
+  /// let verdict = Nist::validate_kdf(ctx, pbkdf2);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), PBKDF2_HMAC_SHA256);
+  /// ```
+  ///
+  /// [SP 800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+  /// [SP 800-56C]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+  /// [SP 800-132]: https://doi.org/10.6028/NIST.SP.800-132
+  /// [SP 800-135]: https://doi.org/10.6028/NIST.SP.800-135r1
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, kdf.security())
+        .cite(Citation::new(SP_800_57, Some(54)))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
+    if !SPECIFIED_KDFS.contains(&kdf.id) {
+      return verdict(Status::Unrecognised, HKDF_SHA256);
+    }
+    if kdf.is_password_based() && kdf.iterations < MIN_PBKDF2_ITERATIONS {
+      let recommendation = Kdf::new(kdf.id, kdf.key, MIN_PBKDF2_ITERATIONS);
+      return Verdict::new(Status::Disallowed, recommendation, kdf.security())
+        .cite(Citation::new("NIST SP 800-132", Some(7)));
+    }
+    let (low, medium, high) = if kdf.is_password_based() {
+      (PBKDF2_HMAC_SHA256, PBKDF2_HMAC_SHA512, PBKDF2_HMAC_SHA512)
+    } else {
+      (HKDF_SHA256, HKDF_SHA384, HKDF_SHA512)
+    };
+    let security = ctx.security().max(kdf.security());
+    match security {
+      ..=111 => verdict(Status::Disallowed, low),
+      112..=127 => {
+        if ctx.year() > CUTOFF_YEAR {
+          verdict(Status::Legacy, low)
+        } else {
+          let until = CUTOFF_YEAR;
+          verdict(Status::Deprecated { until }, low)
+        }
+      },
+      128..=256 => verdict(Status::Acceptable, low),
+      257..=384 => verdict(Status::Acceptable, medium),
+      385.. => verdict(Status::Acceptable, high),
+    }
+  }
+
+  /// Validates a key encapsulation mechanism according to [FIPS 203]
+  /// which specifies ML-KEM. Hybrid schemes are accepted when ML-KEM is
+  /// one of the components that are combined (see section 4.6 of [SP
+  /// 800-227]).
+  ///
+  /// The status of the verdict tells whether the key encapsulation
+  /// mechanism is compliant. If it is not, the recommendation is the
+  /// primitive that one should use instead. If it is but the context
+  /// specifies a higher security level, the recommendation is the
+  /// primitive with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant hybrid key
+  /// encapsulation mechanism.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kem::{ML_KEM_768, X25519MLKEM768};
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Nist::validate_kem(ctx, X25519MLKEM768);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ML_KEM_768);
+  /// ```
+  ///
+  /// [FIPS 203]: https://doi.org/10.6028/NIST.FIPS.203
+  /// [SP 800-227]: https://doi.org/10.6028/NIST.SP.800-227
+  fn validate_kem(ctx: Context, kem: Kem) -> Verdict<Kem> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, kem.security())
+        .cite(Citation::new("NIST FIPS 203", None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_KEMS.contains(&kem) {
+      let security = ctx.security().max(kem.security());
+      match security {
+        ..=128 => verdict(Status::Acceptable, ML_KEM_512),
+        129..=192 => verdict(Status::Acceptable, ML_KEM_768),
+        193.. => verdict(Status::Acceptable, ML_KEM_1024),
+      }
+    } else {
+      verdict(Status::Unrecognised, ML_KEM_768)
+    }
+  }
+
+  /// Validates a message authentication code according to [SP 800-107
+  /// Rev. 1] and [SP 800-131A Rev. 2] which approve HMAC with any
+  /// approved hash function, KMAC from [SP 800-185], CMAC from [SP
+  /// 800-38B], and GMAC from [SP 800-38D] provided that the key is at
+  /// least 112 bits long.
+  ///
+  /// A MAC the recommendations do not approve is unrecognised. A tag
+  /// shorter than 32 bits is disallowed regardless of the length of the
+  /// key, in which case the same MAC with a 32-bit tag is recommended.
+  /// Otherwise a key shorter than 112 bits is disallowed unless it
+  /// offers at least 80 bits of security and only processes already
+  /// protected data. The recommendation is HMAC with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate an HMAC key which is
+  /// too short.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mac::{Mac, HMAC_SHA256};
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let hmac = Mac::new(HMAC_SHA256.id, 80, 256);
+  /// let verdict = Nist::validate_mac(ctx, hmac);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), HMAC_SHA256);
+  /// ```
+  ///
+  /// [SP 800-107 Rev. 1]: https://doi.org/10.6028/NIST.SP.800-107r1
+  /// [SP 800-131A Rev. 2]: https://doi.org/10.6028/NIST.SP.800-131Ar2
+  /// [SP 800-185]: https://doi.org/10.6028/NIST.SP.800-185
+  /// [SP 800-38B]: https://doi.org/10.6028/NIST.SP.800-38B
+  /// [SP 800-38D]: https://doi.org/10.6028/NIST.SP.800-38D
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac> {
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, mac.security())
+        .cite(Citation::new("NIST SP 800-131A Rev. 2", None))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
+    if !SPECIFIED_MACS.contains(&mac.id) {
+      return verdict(Status::Unrecognised, HMAC_SHA256);
+    }
+    if mac.tag < MIN_TAG_LENGTH {
+      let recommendation = Mac::new(mac.id, mac.key, MIN_TAG_LENGTH);
+      return Verdict::new(Status::Disallowed, recommendation, mac.security())
+        .cite(Citation::new("NIST SP 800-107 Rev. 1", None));
+    }
+    let security = ctx.security().max(mac.security());
+    match security {
+      ..=111 => verdict(Status::Disallowed, HMAC_SHA256),
+      112..=256 => verdict(Status::Acceptable, HMAC_SHA256),
+      257..=384 => verdict(Status::Acceptable, HMAC_SHA384),
+      385.. => verdict(Status::Acceptable, HMAC_SHA512),
+    }
+  }
+
+  /// Validates a mode of operation of a block cipher according to the
+  /// SP 800-38 series of recommendations which specify the ECB, CBC,
+  /// CFB, OFB, and CTR modes in [SP 800-38A], CCM in [SP 800-38C], GCM
+  /// in [SP 800-38D], and XTS in [SP 800-38E].
+  ///
+  /// The modes of the series are acceptable and recommended as they
+  /// are, except for ECB which is deprecated and only fit for legacy
+  /// use afterwards. Any other mode, such as OCB, is unrecognised. GCM
+  /// is recommended in place of modes that are not acceptable.
+  ///
+  /// **Note:** The draft of SP 800-131A Rev. 3 proposes that ECB is no
+  /// longer used to encrypt data after 2030 and so it is deemed
+  /// deprecated until then. XTS is only approved to protect data on
+  /// storage devices which this function is unable to verify.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a mode which is not
+  /// approved.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mode::{GCM, OCB};
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Nist::validate_mode(ctx, OCB);
+  /// assert_eq!(verdict.status(), Status::Unrecognised);
+  /// assert_eq!(verdict.recommendation(), GCM);
+  /// ```
+  ///
+  /// [SP 800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+  /// [SP 800-38C]: https://doi.org/10.6028/NIST.SP.800-38C
+  /// [SP 800-38D]: https://doi.org/10.6028/NIST.SP.800-38D
+  /// [SP 800-38E]: https://doi.org/10.6028/NIST.SP.800-38E
+  fn validate_mode(ctx: Context, mode: Mode) -> Verdict<Mode> {
+    let document = if mode == CCM {
+      "NIST SP 800-38C"
+    } else if mode == GCM {
+      "NIST SP 800-38D"
+    } else if mode == XTS {
+      "NIST SP 800-38E"
+    } else {
+      "NIST SP 800-38A"
+    };
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, mode.security())
+        .cite(Citation::new(document, None))
+        .for_operation(ctx)
+    };
+    if !SPECIFIED_MODES.contains(&mode) {
+      return verdict(Status::Unrecognised, GCM);
+    }
+    if mode == ECB {
+      let citation = Citation::new("NIST SP 800-131A Rev. 3 (Draft)", None);
+      let until = CUTOFF_YEAR_ECB;
+      if ctx.year() > until {
+        verdict(Status::Legacy, GCM).cite(citation)
+      } else {
+        verdict(Status::Deprecated { until }, GCM).cite(citation)
+      }
+    } else {
+      verdict(Status::Acceptable, mode)
+    }
+  }
+
+  /// Validates a password hashing function according to [SP 800-63B]
+  /// which requires passwords to be hashed with an approved one-way key
+  /// derivation function such as PBKDF2 using an approved hash
+  /// function. The memory-hard functions and the schemes of crypt(3)
+  /// are not approved.
+  ///
+  /// A function that is not approved is unrecognised with PBKDF2 using
+  /// HMAC-SHA256 recommended instead. An iteration count below 10,000
+  /// is disallowed, in which case the recommendation keeps the same
+  /// function but with the minimum iteration count. Otherwise the
+  /// function is acceptable.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate PBKDF2 with too few
+  /// iterations.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::phf::{Phf, PBKDF2_SHA256};
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let pbkdf2 = Phf::iterated(PBKDF2_SHA256.id, 1000);
+  /// let want = Phf::iterated(PBKDF2_SHA256.id, 10_000);
+  /// let verdict = Nist::validate_phf(ctx, pbkdf2);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), want);
+  /// ```
+  ///
+  /// [SP 800-63B]: https://doi.org/10.6028/NIST.SP.800-63b
+  fn validate_phf(ctx: Context, phf: Phf) -> Verdict<Phf> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, phf.security())
+        .cite(Citation::new("NIST SP 800-63B", Some(14)))
+        .for_operation(ctx)
+    };
+    if !SPECIFIED_PHFS.contains(&phf.id) {
+      return verdict(Status::Unrecognised, PBKDF2_SHA256);
+    }
+    if phf.iterations < MIN_PHF_ITERATIONS {
+      let recommendation = Phf::iterated(phf.id, MIN_PHF_ITERATIONS);
+      return verdict(Status::Disallowed, recommendation);
+    }
+    if phf.id == PBKDF2_SHA512.id {
+      verdict(Status::Acceptable, PBKDF2_SHA512)
+    } else {
+      verdict(Status::Acceptable, PBKDF2_SHA256)
+    }
+  }
+
+  /// Validates a post-quantum signature primitive according to [FIPS
+  /// 204], [FIPS 205], and [SP 800-208] which specify ML-DSA, SLH-DSA,
+  /// and the stateful hash-based LMS and XMSS schemes respectively.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant key.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::pqs::ML_DSA_65;
+  /// use wardstone_core::standard::nist::Nist;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Nist::validate_pqs(ctx, ML_DSA_65);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ML_DSA_65);
+  /// ```
+  ///
+  /// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+  /// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+  /// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+  fn validate_pqs(ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    let document = if key.is_stateless_hash_based() {
+      "NIST FIPS 205"
+    } else if key.is_stateful_hash_based() {
+      "NIST SP 800-208"
+    } else {
+      "NIST FIPS 204"
+    };
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(document, None))
+        .for_operation(ctx)
+    };
+    if SPECIFIED_PQ_SIGNATURES.contains(&key) {
+      let security = ctx.security().max(key.security());
+      match security {
+        ..=128 => verdict(Status::Acceptable, ML_DSA_44),
+        129..=192 => verdict(Status::Acceptable, ML_DSA_65),
+        193.. => verdict(Status::Acceptable, ML_DSA_87),
+      }
+    } else {
+      verdict(Status::Unrecognised, ML_DSA_44)
     }
   }
 
   /// Validates a symmetric key primitive according to pages 54-55 of
   /// the standard.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -385,35 +1020,46 @@ impl Standard for Nist {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::symmetric::{AES128, TDEA3};
   /// use wardstone_core::standard::nist::Nist;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Nist::validate_symmetric(ctx, TDEA3), Ok(AES128));
+  /// let verdict = Nist::validate_symmetric(ctx, TDEA3);
+  /// assert_eq!(verdict.status(), Status::Deprecated { until: 2023 });
+  /// assert_eq!(verdict.recommendation(), AES128);
   /// ```
-  fn validate_symmetric(ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric> {
+  fn validate_symmetric(ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    let verdict = |status, recommendation| {
+      let verdict = Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(SP_800_57, Some(54)))
+        .for_operation(ctx);
+      for_legacy_use(verdict, ctx)
+    };
     if SPECIFIED_SYMMETRIC_KEYS.contains(&key) {
       let security = ctx.security().max(key.security());
       match security {
-        ..=111 => Err(AES128),
+        ..=111 => verdict(Status::Disallowed, AES128),
         112 => {
           // See SP 800-131Ar2 p. 7.
-          let cutoff = if key.id == TDEA3.id {
-            CUTOFF_YEAR_3TDEA
+          let (until, citation) = if key.id == TDEA3.id {
+            (
+              CUTOFF_YEAR_3TDEA,
+              Citation::new("NIST SP 800-131A Rev. 2", Some(7)),
+            )
           } else {
-            CUTOFF_YEAR
+            (CUTOFF_YEAR, Citation::new(SP_800_57, Some(54)))
           };
-          if ctx.year() > cutoff {
-            Err(AES128)
+          if ctx.year() > until {
+            verdict(Status::Legacy, AES128).cite(citation)
           } else {
-            Ok(AES128)
+            verdict(Status::Deprecated { until }, AES128).cite(citation)
           }
         },
-        113..=128 => Ok(AES128),
-        129..=192 => Ok(AES192),
-        193.. => Ok(AES256),
+        113..=128 => verdict(Status::Acceptable, AES128),
+        129..=192 => verdict(Status::Acceptable, AES192),
+        193.. => verdict(Status::Acceptable, AES256),
       }
     } else {
-      Err(AES128)
+      verdict(Status::Unrecognised, AES128)
     }
   }
 }
@@ -421,7 +1067,39 @@ impl Standard for Nist {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{test_ecc, test_ffc, test_hash, test_hash_based, test_ifc, test_symmetric};
+  use crate::context::Operation;
+  use crate::{
+    test_drbg, test_ecc, test_ffc, test_hash, test_hash_based, test_ifc, test_kdf, test_kem,
+    test_mac, test_mode, test_phf, test_pqs, test_symmetric,
+  };
+
+  test_drbg!(ctr_drbg_aes128, Nist, CTR_DRBG_AES128, Ok(CTR_DRBG_AES128));
+  test_drbg!(
+    ctr_drbg_aes256_no_df,
+    Nist,
+    CTR_DRBG_AES256_NO_DF,
+    Ok(CTR_DRBG_AES256)
+  );
+  test_drbg!(ctr_drbg_tdea, Nist, CTR_DRBG_TDEA, Ok(CTR_DRBG_AES128));
+  test_drbg!(
+    dual_ec_drbg_p256,
+    Nist,
+    DUAL_EC_DRBG_P256,
+    Err(CTR_DRBG_AES128)
+  );
+  test_drbg!(hash_drbg_sha1, Nist, HASH_DRBG_SHA1, Ok(CTR_DRBG_AES128));
+  test_drbg!(
+    hash_drbg_sha224,
+    Nist,
+    HASH_DRBG_SHA224,
+    Ok(CTR_DRBG_AES192)
+  );
+  test_drbg!(
+    hmac_drbg_sha512,
+    Nist,
+    HMAC_DRBG_SHA512,
+    Ok(CTR_DRBG_AES256)
+  );
 
   test_ecc!(p224, Nist, P224, Ok(P224));
   test_ecc!(p256, Nist, P256, Ok(P256));
@@ -443,6 +1121,8 @@ mod tests {
   test_ffc!(ffc_3072_256, Nist, DSA_3072_256, Ok(DSA_3072_256));
   test_ffc!(ffc_7680_384, Nist, DSA_7680_384, Ok(DSA_7680_384));
   test_ffc!(ffc_15360_512, Nist, DSA_15360_512, Ok(DSA_15360_512));
+  test_ffc!(ffdhe2048, Nist, FFDHE2048, Ok(DSA_2048_224));
+  test_ffc!(modp1024, Nist, MODP_1024, Err(DSA_2048_224));
 
   test_ifc!(ifc_1024, Nist, RSA_PSS_1024, Err(RSA_PSS_2048));
   test_ifc!(ifc_2048, Nist, RSA_PSS_2048, Ok(RSA_PSS_2048));
@@ -557,9 +1237,301 @@ mod tests {
   test_hash_based!(shake128_pre_image_resistance, Nist, SHAKE128, Ok(SHAKE128));
   test_hash_based!(shake256_pre_image_resistance, Nist, SHAKE256, Ok(SHA256));
 
+  test_kdf!(hkdf_sha256, Nist, HKDF_SHA256, Ok(HKDF_SHA256));
+  test_kdf!(hkdf_sha512, Nist, HKDF_SHA512, Ok(HKDF_SHA512));
+  test_kdf!(kbkdf_cmac, Nist, KBKDF_CMAC, Ok(HKDF_SHA256));
+  test_kdf!(kbkdf_hmac_sha384, Nist, KBKDF_HMAC_SHA384, Ok(HKDF_SHA384));
+  test_kdf!(sp800_56c_sha256, Nist, SP800_56C_SHA256, Ok(HKDF_SHA256));
+  test_kdf!(
+    pbkdf2_hmac_sha1,
+    Nist,
+    PBKDF2_HMAC_SHA1,
+    Ok(PBKDF2_HMAC_SHA256)
+  );
+  test_kdf!(
+    pbkdf2_hmac_sha256,
+    Nist,
+    PBKDF2_HMAC_SHA256,
+    Ok(PBKDF2_HMAC_SHA256)
+  );
+  test_kdf!(tls10_prf, Nist, TLS10_PRF, Ok(HKDF_SHA256));
+  test_kdf!(tls12_prf_sha384, Nist, TLS12_PRF_SHA384, Ok(HKDF_SHA384));
+
+  test_kem!(ml_kem_512, Nist, ML_KEM_512, Ok(ML_KEM_512));
+  test_kem!(ml_kem_768, Nist, ML_KEM_768, Ok(ML_KEM_768));
+  test_kem!(ml_kem_1024, Nist, ML_KEM_1024, Ok(ML_KEM_1024));
+  test_kem!(x25519mlkem768, Nist, X25519MLKEM768, Ok(ML_KEM_768));
+  test_kem!(secp256r1mlkem768, Nist, SECP256R1MLKEM768, Ok(ML_KEM_768));
+  test_kem!(
+    secp384r1mlkem1024,
+    Nist,
+    SECP384R1MLKEM1024,
+    Ok(ML_KEM_1024)
+  );
+  test_kem!(frodokem_976, Nist, FRODOKEM_976, Err(ML_KEM_768));
+  test_kem!(mceliece6960119, Nist, MCELIECE6960119, Err(ML_KEM_768));
+
+  test_mac!(cmac, Nist, CMAC, Ok(HMAC_SHA256));
+  test_mac!(gmac, Nist, GMAC, Ok(HMAC_SHA256));
+  test_mac!(hmac_md5, Nist, HMAC_MD5, Err(HMAC_SHA256));
+  test_mac!(hmac_sha1, Nist, HMAC_SHA1, Ok(HMAC_SHA256));
+  test_mac!(hmac_sha256, Nist, HMAC_SHA256, Ok(HMAC_SHA256));
+  test_mac!(hmac_sha384, Nist, HMAC_SHA384, Ok(HMAC_SHA384));
+  test_mac!(hmac_sha3_512, Nist, HMAC_SHA3_512, Ok(HMAC_SHA512));
+  test_mac!(kmac128, Nist, KMAC128, Ok(HMAC_SHA256));
+  test_mac!(kmac256, Nist, KMAC256, Ok(HMAC_SHA256));
+  test_mac!(poly1305_mac, Nist, POLY1305_MAC, Err(HMAC_SHA256));
+  test_mac!(umac, Nist, UMAC, Err(HMAC_SHA256));
+  test_mac!(hmac_ripemd160, Nist, HMAC_RIPEMD160, Err(HMAC_SHA256));
+
+  test_mode!(ecb, Nist, ECB, Ok(GCM));
+  test_mode!(cbc, Nist, CBC, Ok(CBC));
+  test_mode!(cfb, Nist, CFB, Ok(CFB));
+  test_mode!(ofb, Nist, OFB, Ok(OFB));
+  test_mode!(ctr, Nist, CTR, Ok(CTR));
+  test_mode!(xts, Nist, XTS, Ok(XTS));
+  test_mode!(ccm, Nist, CCM, Ok(CCM));
+  test_mode!(gcm, Nist, GCM, Ok(GCM));
+  test_mode!(gcm_siv, Nist, GCM_SIV, Err(GCM));
+  test_mode!(ocb, Nist, OCB, Err(GCM));
+  test_mode!(poly1305, Nist, POLY1305, Err(GCM));
+
+  test_phf!(argon2id, Nist, ARGON2ID, Err(PBKDF2_SHA256));
+  test_phf!(bcrypt, Nist, BCRYPT, Err(PBKDF2_SHA256));
+  test_phf!(md5_crypt, Nist, MD5_CRYPT, Err(PBKDF2_SHA256));
+  test_phf!(pbkdf2_sha1, Nist, PBKDF2_SHA1, Ok(PBKDF2_SHA256));
+  test_phf!(pbkdf2_sha256, Nist, PBKDF2_SHA256, Ok(PBKDF2_SHA256));
+  test_phf!(pbkdf2_sha512, Nist, PBKDF2_SHA512, Ok(PBKDF2_SHA512));
+  test_phf!(scrypt, Nist, SCRYPT, Err(PBKDF2_SHA256));
+  test_phf!(sha512_crypt, Nist, SHA512_CRYPT, Err(PBKDF2_SHA256));
+  test_phf!(des_crypt, Nist, DES_CRYPT, Err(PBKDF2_SHA256));
+
+  test_pqs!(ml_dsa_44, Nist, ML_DSA_44, Ok(ML_DSA_44));
+  test_pqs!(ml_dsa_65, Nist, ML_DSA_65, Ok(ML_DSA_65));
+  test_pqs!(ml_dsa_87, Nist, ML_DSA_87, Ok(ML_DSA_87));
+  test_pqs!(slh_dsa_sha2_128s, Nist, SLH_DSA_SHA2_128S, Ok(ML_DSA_44));
+  test_pqs!(slh_dsa_shake_256f, Nist, SLH_DSA_SHAKE_256F, Ok(ML_DSA_87));
+  test_pqs!(lms_sha256_m24, Nist, LMS_SHA256_M24, Ok(ML_DSA_65));
+  test_pqs!(xmss_sha2_256, Nist, XMSS_SHA2_256, Ok(ML_DSA_87));
+  test_pqs!(pqs_not_supported, Nist, PQS_NOT_SUPPORTED, Err(ML_DSA_44));
+
   test_symmetric!(two_key_tdea, Nist, TDEA2, Err(AES128));
   test_symmetric!(three_key_tdea, Nist, TDEA3, Ok(AES128));
   test_symmetric!(aes128, Nist, AES128, Ok(AES128));
   test_symmetric!(aes192, Nist, AES192, Ok(AES192));
   test_symmetric!(aes256, Nist, AES256, Ok(AES256));
+  test_symmetric!(chacha20, Nist, CHACHA20, Err(AES128));
+  test_symmetric!(rc4, Nist, RC4, Err(AES128));
+
+  #[test]
+  fn p224_is_deprecated() {
+    let ctx = Context::default();
+    let verdict = Nist::validate_ecc(ctx, P224);
+    assert_eq!(verdict.status(), Status::Deprecated { until: CUTOFF_YEAR });
+    assert_eq!(verdict.security(), 112);
+    assert_eq!(verdict.citation(), Some(Citation::new(SP_800_57, Some(54))));
+  }
+
+  #[test]
+  fn p224_is_legacy_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1);
+    let verdict = Nist::validate_ecc(ctx, P224);
+    assert_eq!(verdict.status(), Status::Legacy);
+    assert_eq!(verdict.into_result(), Err(P256));
+  }
+
+  #[test]
+  fn three_key_tdea_is_legacy_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR_3TDEA + 1);
+    let verdict = Nist::validate_symmetric(ctx, TDEA3);
+    assert_eq!(verdict.status(), Status::Legacy);
+    let citation = verdict.citation().unwrap();
+    assert_eq!(citation.to_string(), "NIST SP 800-131A Rev. 2, p. 7");
+  }
+
+  #[test]
+  fn ecb_is_legacy_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR_ECB + 1);
+    let verdict = Nist::validate_mode(ctx, ECB);
+    assert_eq!(verdict.status(), Status::Legacy);
+    assert_eq!(verdict.into_result(), Err(GCM));
+  }
+
+  #[test]
+  fn x25519_is_unrecognised() {
+    let ctx = Context::default();
+    let verdict = Nist::validate_ecc(ctx, X25519);
+    assert_eq!(verdict.status(), Status::Unrecognised);
+  }
+
+  #[test]
+  fn p224_is_acceptable_for_processing_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR + 1).with_operation(Operation::Process);
+    let verdict = Nist::validate_ecc(ctx, P224);
+    assert_eq!(verdict.status(), Status::Acceptable);
+  }
+
+  #[test]
+  fn rsa_1024_is_acceptable_for_processing() {
+    let ctx = Context::default().with_operation(Operation::Process);
+    let verdict = Nist::validate_ifc(ctx, RSA_PKCS1_1024);
+    assert_eq!(verdict.status(), Status::Acceptable);
+    assert_eq!(verdict.into_result(), Ok(RSA_PSS_2048));
+  }
+
+  #[test]
+  fn rsa_512_is_disallowed_for_processing() {
+    let ctx = Context::default().with_operation(Operation::Process);
+    let verdict = Nist::validate_ifc(ctx, Ifc::new(ID_RSA_PKCS1, 512));
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+
+  #[test]
+  fn few_pbkdf2_iterations_are_disallowed_for_processing() {
+    let ctx = Context::default().with_operation(Operation::Process);
+    let pbkdf2 = Kdf::new(PBKDF2_HMAC_SHA256.id, PBKDF2_HMAC_SHA256.key, 999);
+    let verdict = Nist::validate_kdf(ctx, pbkdf2);
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+
+  #[test]
+  fn short_tags_are_disallowed_for_processing() {
+    let ctx = Context::default().with_operation(Operation::Process);
+    let verdict = Nist::validate_mac(ctx, Mac::new(HMAC_SHA256.id, 256, 16));
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+
+  #[test]
+  fn rsa_1024_is_disallowed_for_protection() {
+    let ctx = Context::default().with_operation(Operation::Protect);
+    let verdict = Nist::validate_ifc(ctx, RSA_PKCS1_1024);
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+
+  #[test]
+  fn sha1_is_acceptable_for_processing() {
+    let ctx = Context::default().with_operation(Operation::Process);
+    let verdict = Nist::validate_hash(ctx, SHA1);
+    assert!(verdict.is_compliant());
+  }
+
+  #[test]
+  fn two_key_tdea_is_acceptable_for_processing() {
+    let ctx = Context::default().with_operation(Operation::Process);
+    let verdict = Nist::validate_symmetric(ctx, TDEA2);
+    assert_eq!(verdict.into_result(), Ok(AES128));
+  }
+
+  #[test]
+  fn dsa_1024_is_acceptable_for_processing() {
+    let ctx = Context::default().with_operation(Operation::Process);
+    let verdict = Nist::validate_ffc(ctx, DSA_1024_160);
+    assert!(verdict.is_compliant());
+  }
+
+  #[test]
+  fn ed25519_is_disallowed_for_key_establishment() {
+    let ctx = Context::default().with_usage(Usage::KeyEstablishment);
+    let verdict = Nist::validate_ecc(ctx, ED25519);
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+
+  #[test]
+  fn dh_is_acceptable_for_key_establishment_after_dsa_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR_DSA + 1).with_usage(Usage::KeyEstablishment);
+    let verdict = Nist::validate_ffc(ctx, DSA_3072_256);
+    assert_eq!(verdict.status(), Status::Acceptable);
+  }
+
+  #[test]
+  fn sha1_is_acceptable_for_message_authentication() {
+    let ctx = Context::default().with_usage(Usage::MessageAuthentication);
+    let verdict = Nist::validate_hash(ctx, SHA1);
+    assert_eq!(verdict.status(), Status::Acceptable);
+    assert_eq!(verdict.security(), 160);
+  }
+
+  #[test]
+  fn salt_length_up_to_hash_length_is_acceptable() {
+    let ctx = Context::default();
+    assert_eq!(Nist::validate_salt_length(ctx, SHA256, 0), Ok(32));
+    assert_eq!(Nist::validate_salt_length(ctx, SHA256, 32), Ok(32));
+    assert_eq!(Nist::validate_salt_length(ctx, SHA384, 49), Err(48));
+  }
+
+  #[test]
+  fn hmac_with_short_key_is_disallowed() {
+    let ctx = Context::default();
+    let hmac = Mac::new(HMAC_SHA256.id, 80, 256);
+    let verdict = Nist::validate_mac(ctx, hmac);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    assert_eq!(verdict.security(), 80);
+  }
+
+  #[test]
+  fn hmac_with_short_tag_is_disallowed() {
+    let ctx = Context::default();
+    let hmac = Mac::new(HMAC_SHA256.id, 256, 16);
+    let verdict = Nist::validate_mac(ctx, hmac);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    assert_eq!(
+      verdict.into_result(),
+      Err(Mac::new(HMAC_SHA256.id, 256, 32))
+    );
+  }
+
+  #[test]
+  fn pbkdf2_with_few_iterations_is_disallowed() {
+    let ctx = Context::default();
+    let pbkdf2 = Kdf::new(PBKDF2_HMAC_SHA512.id, 512, 999);
+    let verdict = Nist::validate_kdf(ctx, pbkdf2);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    let citation = verdict.citation().unwrap();
+    assert_eq!(citation.to_string(), "NIST SP 800-132, p. 7");
+    assert_eq!(verdict.into_result(), Err(PBKDF2_HMAC_SHA512));
+  }
+
+  #[test]
+  fn pbkdf2_with_many_iterations_is_acceptable() {
+    let ctx = Context::default();
+    let pbkdf2 = Kdf::new(PBKDF2_HMAC_SHA256.id, 256, 600_000);
+    let verdict = Nist::validate_kdf(ctx, pbkdf2);
+    assert_eq!(verdict.status(), Status::Acceptable);
+  }
+
+  #[test]
+  fn pbkdf2_password_hash_with_few_iterations_is_disallowed() {
+    let ctx = Context::default();
+    let pbkdf2 = Phf::iterated(PBKDF2_SHA1.id, 9999);
+    let verdict = Nist::validate_phf(ctx, pbkdf2);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    let citation = verdict.citation().unwrap();
+    assert_eq!(citation.to_string(), "NIST SP 800-63B, p. 14");
+    assert_eq!(
+      verdict.into_result(),
+      Err(Phf::iterated(PBKDF2_SHA1.id, 10_000))
+    );
+  }
+
+  #[test]
+  fn ctr_drbg_tdea_is_legacy_after_cutoff() {
+    let ctx = Context::new(0, CUTOFF_YEAR_3TDEA + 1);
+    let verdict = Nist::validate_drbg(ctx, CTR_DRBG_TDEA);
+    assert_eq!(verdict.status(), Status::Legacy);
+    assert_eq!(verdict.into_result(), Err(CTR_DRBG_AES128));
+  }
+
+  #[test]
+  fn pqs_cites_the_standard_of_its_family() {
+    let ctx = Context::default();
+    for (key, document) in [
+      (ML_DSA_65, "NIST FIPS 204"),
+      (SLH_DSA_SHA2_128S, "NIST FIPS 205"),
+      (LMS_SHA256_M24, "NIST SP 800-208"),
+      (XMSS_SHA2_256, "NIST SP 800-208"),
+    ] {
+      let verdict = Nist::validate_pqs(ctx, key);
+      assert_eq!(verdict.citation(), Some(Citation::new(document, None)));
+    }
+  }
 }
@@ -0,0 +1,427 @@
+//! Validate cryptographic primitives against a policy that is defined
+//! in a declarative format such as JSON or TOML instead of in code.
+//!
+//! A policy consists of a name and a set of rules for each kind of
+//! primitive. Each set of rules may restrict the primitives that are
+//! allowed and divides the security levels into bands starting at a
+//! given security level. Every band has a recommended primitive and
+//! optionally a year after which primitives in the band are only fit
+//! for legacy use.
+//!
+//! The following is an example of such a policy written in TOML.
+//!
+//! ```toml
+//! name = "Example Corp. Cryptographic Policy"
+//!
+//! [ecc]
+//! allowed = ["secp256r1", "secp384r1", "secp521r1", "ed25519"]
+//! bands = [
+//!   { security = 128, recommendation = "secp256r1" },
+//!   { security = 192, recommendation = "secp384r1" },
+//!   { security = 256, recommendation = "secp521r1" },
+//! ]
+//!
+//! [ifc]
+//! page = 12
+//! bands = [
+//!   { security = 112, recommendation = "rsa_pss_2048", until = 2030 },
+//!   { security = 128, recommendation = "rsa_pss_3072" },
+//! ]
+//! ```
+//!
+//! Primitives are referred to by the same names used when they are
+//! displayed. Primitives that the rules do not allow are disallowed
+//! while those for which the policy has no rules are deemed
+//! unrecognised.
+use std::collections::HashSet;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+
+use super::{salt_no_longer_than_hash, Citation, Status, Verdict};
+use crate::context::Context;
+use crate::primitive::asymmetric::Asymmetric;
+use crate::primitive::drbg::Drbg;
+use crate::primitive::ecc::Ecc;
+use crate::primitive::ffc::Ffc;
+use crate::primitive::hash::Hash;
+use crate::primitive::ifc::Ifc;
+use crate::primitive::kdf::Kdf;
+use crate::primitive::kem::Kem;
+use crate::primitive::mac::Mac;
+use crate::primitive::mode::Mode;
+use crate::primitive::phf::Phf;
+use crate::primitive::pqs::Pqs;
+use crate::primitive::symmetric::Symmetric;
+use crate::primitive::{Primitive, Security};
+
+/// A range of security levels that starts at `security` and ends where
+/// the next band starts.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Band<T> {
+  security: Security,
+  recommendation: T,
+  #[serde(default)]
+  until: Option<u16>,
+}
+
+/// The rules that apply to a single kind of primitive.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(
+  bound(deserialize = "T: Deserialize<'de> + Eq + std::hash::Hash"),
+  deny_unknown_fields
+)]
+struct Rules<T> {
+  #[serde(default)]
+  allowed: Option<HashSet<T>>,
+  #[serde(deserialize_with = "bands")]
+  bands: Vec<Band<T>>,
+  #[serde(default)]
+  fallback: Option<T>,
+  #[serde(default)]
+  page: Option<u16>,
+}
+
+impl<T: Copy + Eq + std::hash::Hash + Primitive> Rules<T> {
+  fn validate(&self, ctx: Context, key: T, document: &'static str) -> Verdict<T> {
+    let verdict = |status, recommendation| {
+      Verdict::new(status, recommendation, key.security())
+        .cite(Citation::new(document, self.page))
+        .for_operation(ctx)
+    };
+    let lowest = self.bands[0];
+    if let Some(allowed) = &self.allowed {
+      if !allowed.contains(&key) {
+        let recommendation = self.fallback.unwrap_or(lowest.recommendation);
+        return verdict(Status::Disallowed, recommendation);
+      }
+    }
+
+    let security = ctx.security().max(key.security());
+    let i = match self
+      .bands
+      .iter()
+      .rposition(|band| band.security <= security)
+    {
+      Some(i) => i,
+      None => return verdict(Status::Disallowed, lowest.recommendation),
+    };
+    let band = self.bands[i];
+    match band.until {
+      Some(until) if ctx.year() > until => {
+        let next = self.bands.get(i + 1).unwrap_or(&band);
+        verdict(Status::Legacy, next.recommendation)
+      },
+      Some(until) => verdict(Status::Deprecated { until }, band.recommendation),
+      None => verdict(Status::Acceptable, band.recommendation),
+    }
+  }
+}
+
+/// A standard defined by a policy file.
+///
+/// Unlike the other standards, a policy is only known at runtime and so
+/// it does not implement [`Standard`](crate::standard::Standard) but
+/// offers the same functions as methods instead.
+///
+/// # Example
+///
+/// The following illustrates a call to validate a hash function against
+/// a policy that only allows SHA-384.
+///
+/// ```
+/// use wardstone_core::context::Context;
+/// use wardstone_core::primitive::hash::{SHA256, SHA384};
+/// use wardstone_core::standard::policy::PolicyStandard;
+/// use wardstone_core::standard::Status;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let policy: PolicyStandard = serde_json::from_str(
+///   r#"{
+///     "name": "SHA-384 only",
+///     "hash": {
+///       "allowed": ["sha384"],
+///       "bands": [{ "security": 192, "recommendation": "sha384" }]
+///     }
+///   }"#,
+/// )?;
+/// let ctx = Context::default();
+/// let verdict = policy.validate_hash(ctx, SHA256);
+/// assert_eq!(verdict.status(), Status::Disallowed);
+/// assert_eq!(verdict.into_result(), Err(SHA384));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyStandard {
+  name: Name,
+  #[serde(default)]
+  drbg: Option<Rules<Drbg>>,
+  #[serde(default)]
+  ecc: Option<Rules<Ecc>>,
+  #[serde(default)]
+  ffc: Option<Rules<Ffc>>,
+  #[serde(default)]
+  hash: Option<Rules<Hash>>,
+  #[serde(default)]
+  ifc: Option<Rules<Ifc>>,
+  #[serde(default)]
+  kdf: Option<Rules<Kdf>>,
+  #[serde(default)]
+  kem: Option<Rules<Kem>>,
+  #[serde(default)]
+  mac: Option<Rules<Mac>>,
+  #[serde(default)]
+  mode: Option<Rules<Mode>>,
+  #[serde(default)]
+  phf: Option<Rules<Phf>>,
+  #[serde(default)]
+  pqs: Option<Rules<Pqs>>,
+  #[serde(default)]
+  symmetric: Option<Rules<Symmetric>>,
+}
+
+impl PolicyStandard {
+  pub fn name(&self) -> &'static str {
+    self.name.0
+  }
+
+  pub fn validate_asymmetric(&self, ctx: Context, key: Asymmetric) -> Verdict<Asymmetric> {
+    match key {
+      Asymmetric::Ecc(ecc) => self.validate_ecc(ctx, ecc).map(Into::into),
+      Asymmetric::Ifc(ifc) => self.validate_ifc(ctx, ifc).map(Into::into),
+      Asymmetric::Ffc(ffc) => self.validate_ffc(ctx, ffc).map(Into::into),
+      Asymmetric::Pqs(pqs) => self.validate_pqs(ctx, pqs).map(Into::into),
+    }
+  }
+
+  pub fn validate_drbg(&self, ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    Self::validate(&self.drbg, ctx, drbg, self.name.0)
+  }
+
+  pub fn validate_ecc(&self, ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    Self::validate(&self.ecc, ctx, key, self.name.0)
+  }
+
+  pub fn validate_ffc(&self, ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    Self::validate(&self.ffc, ctx, key, self.name.0)
+  }
+
+  pub fn validate_ifc(&self, ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    Self::validate(&self.ifc, ctx, key, self.name.0)
+  }
+
+  pub fn validate_kdf(&self, ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    Self::validate(&self.kdf, ctx, kdf, self.name.0)
+  }
+
+  pub fn validate_kem(&self, ctx: Context, kem: Kem) -> Verdict<Kem> {
+    Self::validate(&self.kem, ctx, kem, self.name.0)
+  }
+
+  pub fn validate_mac(&self, ctx: Context, mac: Mac) -> Verdict<Mac> {
+    Self::validate(&self.mac, ctx, mac, self.name.0)
+  }
+
+  pub fn validate_mode(&self, ctx: Context, mode: Mode) -> Verdict<Mode> {
+    Self::validate(&self.mode, ctx, mode, self.name.0)
+  }
+
+  pub fn validate_phf(&self, ctx: Context, phf: Phf) -> Verdict<Phf> {
+    Self::validate(&self.phf, ctx, phf, self.name.0)
+  }
+
+  pub fn validate_pqs(&self, ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    Self::validate(&self.pqs, ctx, key, self.name.0)
+  }
+
+  pub fn validate_hash(&self, ctx: Context, hash: Hash) -> Verdict<Hash> {
+    Self::validate(&self.hash, ctx, hash, self.name.0)
+  }
+
+  pub fn validate_symmetric(&self, ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    Self::validate(&self.symmetric, ctx, key, self.name.0)
+  }
+
+  /// Validates the salt length of an RSASSA-PSS signature.
+  ///
+  /// Policies do not restrict salt lengths so the requirement of FIPS
+  /// 186-5 that applies to the other standards applies here too.
+  pub fn validate_salt_length(&self, _ctx: Context, hash: Hash, salt_length: u32) -> Verdict<u32> {
+    salt_no_longer_than_hash(hash, salt_length)
+  }
+
+  fn validate<T>(rules: &Option<Rules<T>>, ctx: Context, key: T, name: &'static str) -> Verdict<T>
+  where
+    T: Copy + Eq + std::hash::Hash + Primitive,
+  {
+    match rules {
+      Some(rules) => rules.validate(ctx, key, name),
+      None => Verdict::new(Status::Unrecognised, key, key.security()),
+    }
+  }
+}
+
+/// The name of a policy which is cited in verdicts.
+#[derive(Clone, Copy, Debug)]
+struct Name(&'static str);
+
+// Citations refer to documents for the lifetime of the program. A
+// policy is usually loaded once on start up so the name is leaked
+// rather than threading a lifetime through every verdict.
+impl<'de> Deserialize<'de> for Name {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let name = String::deserialize(deserializer)?;
+    Ok(Self(Box::leak(name.into_boxed_str())))
+  }
+}
+
+fn bands<'de, D, T>(deserializer: D) -> Result<Vec<Band<T>>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: Deserialize<'de>,
+{
+  let mut bands = Vec::<Band<T>>::deserialize(deserializer)?;
+  if bands.is_empty() {
+    return Err(D::Error::custom("expected at least one band"));
+  }
+  bands.sort_by_key(|band| band.security);
+  Ok(bands)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::primitive::ecc::*;
+  use crate::primitive::hash::*;
+  use crate::primitive::ifc::*;
+  use crate::primitive::mac::*;
+  use crate::primitive::phf::*;
+  use crate::primitive::symmetric::*;
+
+  const POLICY: &str = r#"{
+    "name": "Example",
+    "ecc": {
+      "allowed": ["secp256r1", "secp384r1", "ed25519"],
+      "fallback": "secp384r1",
+      "bands": [
+        { "security": 192, "recommendation": "secp384r1" },
+        { "security": 128, "recommendation": "secp256r1" }
+      ]
+    },
+    "ifc": {
+      "page": 12,
+      "bands": [
+        { "security": 112, "recommendation": "rsa_pss_2048", "until": 2030 },
+        { "security": 128, "recommendation": "rsa_pss_3072" }
+      ]
+    }
+  }"#;
+
+  fn policy() -> PolicyStandard {
+    serde_json::from_str(POLICY).unwrap()
+  }
+
+  #[test]
+  fn acceptable() {
+    let verdict = policy().validate_ecc(Context::default(), ED25519);
+    assert_eq!(verdict.status(), Status::Acceptable);
+    assert_eq!(verdict, Ok(P256));
+    assert_eq!(verdict.citation(), Some(Citation::new("Example", None)));
+  }
+
+  #[test]
+  fn not_allowed_falls_back() {
+    let verdict = policy().validate_ecc(Context::default(), P224);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    assert_eq!(verdict, Err(P384));
+  }
+
+  #[test]
+  fn disallowed_below_lowest_band() {
+    let verdict = policy().validate_ifc(Context::default(), RSA_PKCS1_1024);
+    assert_eq!(verdict.status(), Status::Disallowed);
+    assert_eq!(verdict, Err(RSA_PSS_2048));
+  }
+
+  #[test]
+  fn deprecated_until_cutoff() {
+    let verdict = policy().validate_ifc(Context::new(0, 2030), RSA_PKCS1_2048);
+    assert_eq!(verdict.status(), Status::Deprecated { until: 2030 });
+    assert_eq!(verdict, Ok(RSA_PSS_2048));
+    assert_eq!(verdict.citation(), Some(Citation::new("Example", Some(12))));
+  }
+
+  #[test]
+  fn legacy_after_cutoff() {
+    let verdict = policy().validate_ifc(Context::new(0, 2031), RSA_PKCS1_2048);
+    assert_eq!(verdict.status(), Status::Legacy);
+    assert_eq!(verdict, Err(RSA_PSS_3072));
+  }
+
+  #[test]
+  fn missing_rules() {
+    let verdict = policy().validate_hash(Context::default(), SHA256);
+    assert_eq!(verdict.status(), Status::Unrecognised);
+    let verdict = policy().validate_symmetric(Context::default(), AES128);
+    assert_eq!(verdict, Err(AES128));
+  }
+
+  #[test]
+  fn empty_bands() {
+    let policy = r#"{ "name": "Empty", "hash": { "bands": [] } }"#;
+    assert!(serde_json::from_str::<PolicyStandard>(policy).is_err());
+  }
+
+  #[test]
+  fn unknown_primitive() {
+    let policy = r#"{
+      "name": "Unknown",
+      "hash": { "bands": [{ "security": 128, "recommendation": "sha257" }] }
+    }"#;
+    assert!(serde_json::from_str::<PolicyStandard>(policy).is_err());
+  }
+
+  #[test]
+  fn custom_key_length() {
+    let policy = r#"{
+      "name": "Custom",
+      "mac": {
+        "allowed": ["hmac_sha256", "hmac_sha256_128_128"],
+        "bands": [{ "security": 128, "recommendation": "hmac_sha256" }]
+      }
+    }"#;
+    let policy = serde_json::from_str::<PolicyStandard>(policy).unwrap();
+    let verdict = policy.validate_mac(Context::default(), Mac::new(HMAC_SHA256.id, 128, 128));
+    assert_eq!(verdict.status(), Status::Acceptable);
+    let verdict = policy.validate_mac(Context::default(), Mac::new(HMAC_SHA256.id, 96, 256));
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+
+  #[test]
+  fn custom_cost_parameters() {
+    let policy = r#"{
+      "name": "Custom",
+      "phf": {
+        "allowed": ["argon2id", "argon2id_m19456_t2_p1", "scrypt_n32768_r8_p1", "bcrypt_12"],
+        "bands": [{ "security": 0, "recommendation": "argon2id" }]
+      }
+    }"#;
+    let policy = serde_json::from_str::<PolicyStandard>(policy).unwrap();
+    let ctx = Context::default();
+    for phf in [
+      Phf::new(ARGON2ID.id, 19456, 2, 1),
+      Phf::scrypt(SCRYPT.id, 32768, 8, 1),
+      Phf::bcrypt(12),
+    ] {
+      assert_eq!(policy.validate_phf(ctx, phf).status(), Status::Acceptable);
+    }
+    let verdict = policy.validate_phf(ctx, Phf::bcrypt(10));
+    assert_eq!(verdict.status(), Status::Disallowed);
+  }
+}
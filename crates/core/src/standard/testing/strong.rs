@@ -7,13 +7,20 @@
 //! bumping the security parameter may not be enough for some signature
 //! schemes such as those that use elliptic curves.
 use crate::context::Context;
+use crate::primitive::drbg::*;
 use crate::primitive::ecc::*;
 use crate::primitive::ffc::*;
 use crate::primitive::hash::*;
 use crate::primitive::ifc::*;
+use crate::primitive::kdf::*;
+use crate::primitive::kem::*;
+use crate::primitive::mac::*;
+use crate::primitive::mode::*;
+use crate::primitive::phf::*;
+use crate::primitive::pqs::*;
 use crate::primitive::symmetric::*;
 use crate::primitive::Primitive;
-use crate::standard::Standard;
+use crate::standard::{Standard, Status, Verdict};
 
 /// [`Standard`] implementation of a mock standard that is intended to
 /// be relatively strong compared to all the other standards defined in
@@ -21,15 +28,50 @@ use crate::standard::Standard;
 pub struct Strong;
 
 impl Standard for Strong {
+  /// Validates a deterministic random bit generator.
+  ///
+  /// Dual_EC_DRBG is disallowed no matter its security strength.
+  ///
+  /// Any other generator with less than 256 bits of security is also
+  /// disallowed while the rest are acceptable. In either case CTR_DRBG
+  /// with AES-256 is recommended.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant
+  /// generator.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::drbg::{CTR_DRBG_AES128, CTR_DRBG_AES256};
+  /// use wardstone_core::standard::testing::strong::Strong;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Strong::validate_drbg(ctx, CTR_DRBG_AES128);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), CTR_DRBG_AES256);
+  /// ```
+  fn validate_drbg(ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, drbg.security());
+    if [DUAL_EC_DRBG_P256, DUAL_EC_DRBG_P384, DUAL_EC_DRBG_P521].contains(&drbg) {
+      return verdict(Status::Disallowed, CTR_DRBG_AES256);
+    }
+    let security = ctx.security().max(drbg.security());
+    match security {
+      ..=255 => verdict(Status::Disallowed, CTR_DRBG_AES256),
+      256.. => verdict(Status::Acceptable, CTR_DRBG_AES256),
+    }
+  }
+
   /// Validate an elliptic curve cryptography primitive used for digital
   /// signatures and key establishment.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -39,13 +81,15 @@ impl Standard for Strong {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ecc::{ECC_NOT_ALLOWED, ED25519};
   /// use wardstone_core::standard::testing::strong::Strong;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Strong::validate_ecc(ctx, ED25519), Err(ECC_NOT_ALLOWED));
+  /// let verdict = Strong::validate_ecc(ctx, ED25519);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ECC_NOT_ALLOWED);
   /// ```
-  fn validate_ecc(_ctx: Context, _key: Ecc) -> Result<Ecc, Ecc> {
-    Err(ECC_NOT_ALLOWED)
+  fn validate_ecc(_ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    Verdict::new(Status::Disallowed, ECC_NOT_ALLOWED, key.security())
   }
 
   /// Validates a finite field cryptography primitive.
@@ -53,12 +97,11 @@ impl Standard for Strong {
   /// Examples include the DSA and key establishment algorithms such as
   /// Diffie-Hellman.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key sizes L and N that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key sizes L
-  /// and N with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key sizes L and N that one
+  /// should use instead. If it is but the context specifies a higher
+  /// security level, the recommendation is the key sizes L and N with
+  /// the desired security level.
   ///
   /// # Example
   ///
@@ -68,24 +111,25 @@ impl Standard for Strong {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ffc::{DSA_2048_224, FFC_NOT_SUPPORTED};
   /// use wardstone_core::standard::testing::strong::Strong;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let dsa_2048 = DSA_2048_224;
-  /// assert_eq!(Strong::validate_ffc(ctx, dsa_2048), Err(FFC_NOT_SUPPORTED));
+  /// let verdict = Strong::validate_ffc(ctx, dsa_2048);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), FFC_NOT_SUPPORTED);
   /// ```
-  fn validate_ffc(_ctx: Context, _key: Ffc) -> Result<Ffc, Ffc> {
-    Err(FFC_NOT_SUPPORTED)
+  fn validate_ffc(_ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    Verdict::new(Status::Disallowed, FFC_NOT_SUPPORTED, key.security())
   }
 
   /// Validates a hash function.
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** An alternative might be suggested for a compliant hash
   /// function with a similar security level in which a switch to the
@@ -103,28 +147,30 @@ impl Standard for Strong {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA256, SHA512};
   /// use wardstone_core::standard::testing::strong::Strong;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Strong::validate_hash(ctx, SHA256), Err(SHA512));
+  /// let verdict = Strong::validate_hash(ctx, SHA256);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), SHA512);
   /// ```
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, hash.security());
     let security = ctx.security().max(hash.security());
     match security {
-      ..=255 => Err(SHA512),
-      256.. => Ok(SHA512),
+      ..=255 => verdict(Status::Disallowed, SHA512),
+      256.. => verdict(Status::Acceptable, SHA512),
     }
   }
 
   /// Validates  an integer factorisation cryptography primitive the
   /// most common of which is the RSA signature algorithm.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key size that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key size
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key size that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the key size with the desired
+  /// security level.
   ///
   /// **Note:** Unlike other functions in this module, this will return
   /// a generic structure that specifies minimum private and public
@@ -138,23 +184,210 @@ impl Standard for Strong {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ifc::{IFC_NOT_ALLOWED, RSA_PSS_2048};
   /// use wardstone_core::standard::testing::strong::Strong;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Strong::validate_ifc(ctx, RSA_PSS_2048);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), IFC_NOT_ALLOWED);
+  /// ```
+  fn validate_ifc(_ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    Verdict::new(Status::Disallowed, IFC_NOT_ALLOWED, key.security())
+  }
+
+  /// Validates a key derivation function.
+  ///
+  /// A function that derives a key with less than 256 bits of security
+  /// is disallowed and any other function is acceptable. In either case
+  /// HKDF with SHA512 is recommended.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant
+  /// function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kdf::{HKDF_SHA512, KBKDF_CMAC};
+  /// use wardstone_core::standard::testing::strong::Strong;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Strong::validate_kdf(ctx, KBKDF_CMAC);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), HKDF_SHA512);
+  /// ```
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, kdf.security());
+    let security = ctx.security().max(kdf.security());
+    match security {
+      ..=255 => verdict(Status::Disallowed, HKDF_SHA512),
+      256.. => verdict(Status::Acceptable, HKDF_SHA512),
+    }
+  }
+
+  /// Validates a key encapsulation mechanism.
+  ///
+  /// The status of the verdict tells whether the key encapsulation
+  /// mechanism is compliant. If it is not, the recommendation is the
+  /// primitive that one should use instead. If it is but the context
+  /// specifies a higher security level, the recommendation is the
+  /// primitive with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key
+  /// encapsulation mechanism.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kem::{ML_KEM_1024, X25519MLKEM768};
+  /// use wardstone_core::standard::testing::strong::Strong;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(
-  ///   Strong::validate_ifc(ctx, RSA_PSS_2048),
-  ///   Err(IFC_NOT_ALLOWED)
-  /// );
+  /// let verdict = Strong::validate_kem(ctx, X25519MLKEM768);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ML_KEM_1024);
   /// ```
-  fn validate_ifc(_ctx: Context, _key: Ifc) -> Result<Ifc, Ifc> {
-    Err(IFC_NOT_ALLOWED)
+  fn validate_kem(ctx: Context, kem: Kem) -> Verdict<Kem> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, kem.security());
+    let security = ctx.security().max(kem.security());
+    match security {
+      ..=255 => verdict(Status::Disallowed, ML_KEM_1024),
+      256.. => verdict(Status::Acceptable, ML_KEM_1024),
+    }
+  }
+
+  /// Validates a message authentication code.
+  ///
+  /// A MAC with less than 256 bits of security is disallowed and any
+  /// other MAC is acceptable. In either case HMAC with SHA512 is
+  /// recommended.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant MAC.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mac::{CMAC, HMAC_SHA512};
+  /// use wardstone_core::standard::testing::strong::Strong;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Strong::validate_mac(ctx, CMAC);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), HMAC_SHA512);
+  /// ```
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, mac.security());
+    let security = ctx.security().max(mac.security());
+    match security {
+      ..=255 => verdict(Status::Disallowed, HMAC_SHA512),
+      256.. => verdict(Status::Acceptable, HMAC_SHA512),
+    }
+  }
+
+  /// Validates a mode of operation of a block cipher.
+  ///
+  /// Modes that provide authenticated encryption are acceptable while
+  /// all others are disallowed. In either case GCM is recommended.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant mode.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mode::{CBC, GCM};
+  /// use wardstone_core::standard::testing::strong::Strong;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Strong::validate_mode(ctx, CBC);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), GCM);
+  /// ```
+  fn validate_mode(_ctx: Context, mode: Mode) -> Verdict<Mode> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, mode.security());
+    if mode.is_authenticated() {
+      verdict(Status::Acceptable, GCM)
+    } else {
+      verdict(Status::Disallowed, GCM)
+    }
+  }
+
+  /// Validates a password hashing function.
+  ///
+  /// Memory-hard functions that do at least as much work as Argon2id
+  /// with its default parameters are acceptable while all others are
+  /// disallowed. In either case Argon2id is recommended.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant
+  /// function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::phf::{ARGON2ID, PBKDF2_SHA512};
+  /// use wardstone_core::standard::testing::strong::Strong;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Strong::validate_phf(ctx, PBKDF2_SHA512);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ARGON2ID);
+  /// ```
+  fn validate_phf(_ctx: Context, phf: Phf) -> Verdict<Phf> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, phf.security());
+    if phf.is_memory_hard() && phf.security() >= ARGON2ID.security() {
+      verdict(Status::Acceptable, ARGON2ID)
+    } else {
+      verdict(Status::Disallowed, ARGON2ID)
+    }
+  }
+
+  /// Validates a post-quantum signature primitive.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a non-compliant key.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::pqs::{ML_DSA_65, ML_DSA_87};
+  /// use wardstone_core::standard::testing::strong::Strong;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Strong::validate_pqs(ctx, ML_DSA_65);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), ML_DSA_87);
+  /// ```
+  fn validate_pqs(ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, key.security());
+    let security = ctx.security().max(key.security());
+    match security {
+      ..=255 => verdict(Status::Disallowed, ML_DSA_87),
+      256.. => verdict(Status::Acceptable, ML_DSA_87),
+    }
   }
 
   /// Validates a symmetric key primitive.
   ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -165,16 +398,19 @@ impl Standard for Strong {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::symmetric::{AES256, TDEA3};
   /// use wardstone_core::standard::testing::strong::Strong;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Strong::validate_symmetric(ctx, TDEA3), Err(AES256));
+  /// let verdict = Strong::validate_symmetric(ctx, TDEA3);
+  /// assert_eq!(verdict.status(), Status::Disallowed);
+  /// assert_eq!(verdict.recommendation(), AES256);
   /// ```
-  fn validate_symmetric(ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric> {
+  fn validate_symmetric(ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, key.security());
     let security = ctx.security().max(key.security());
     match security {
-      ..=255 => Err(AES256),
-      256.. => Ok(AES256),
+      ..=255 => verdict(Status::Disallowed, AES256),
+      256.. => verdict(Status::Acceptable, AES256),
     }
   }
 }
@@ -182,7 +418,35 @@ impl Standard for Strong {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{test_ecc, test_ffc, test_hash, test_ifc, test_symmetric};
+  use crate::{
+    test_drbg, test_ecc, test_ffc, test_hash, test_ifc, test_kdf, test_kem, test_mac, test_mode,
+    test_phf, test_pqs, test_symmetric,
+  };
+
+  test_drbg!(
+    ctr_drbg_aes256,
+    Strong,
+    CTR_DRBG_AES256,
+    Ok(CTR_DRBG_AES256)
+  );
+  test_drbg!(
+    hash_drbg_sha224,
+    Strong,
+    HASH_DRBG_SHA224,
+    Err(CTR_DRBG_AES256)
+  );
+  test_drbg!(
+    hmac_drbg_sha512,
+    Strong,
+    HMAC_DRBG_SHA512,
+    Ok(CTR_DRBG_AES256)
+  );
+  test_drbg!(
+    dual_ec_drbg_p521,
+    Strong,
+    DUAL_EC_DRBG_P521,
+    Err(CTR_DRBG_AES256)
+  );
 
   test_ecc!(p224, Strong, P224, Err(ECC_NOT_ALLOWED));
   test_ecc!(p256, Strong, P256, Err(ECC_NOT_ALLOWED));
@@ -229,6 +493,8 @@ mod tests {
   test_ffc!(ffc_3072_256, Strong, DSA_3072_256, Err(FFC_NOT_SUPPORTED));
   test_ffc!(ffc_7680_384, Strong, DSA_7680_384, Err(FFC_NOT_SUPPORTED));
   test_ffc!(ffc_15360_512, Strong, DSA_15360_512, Err(FFC_NOT_SUPPORTED));
+  test_ffc!(ffdhe2048, Strong, FFDHE2048, Err(FFC_NOT_SUPPORTED));
+  test_ffc!(modp1024, Strong, MODP_1024, Err(FFC_NOT_SUPPORTED));
 
   test_ifc!(ifc_1024, Strong, RSA_PSS_1024, Err(IFC_NOT_ALLOWED));
   test_ifc!(ifc_1280, Strong, RSA_PSS_1280, Err(IFC_NOT_ALLOWED));
@@ -266,6 +532,57 @@ mod tests {
   test_hash!(shake256, Strong, SHAKE256, Err(SHA512));
   test_hash!(whirlpool, Strong, WHIRLPOOL, Ok(SHA512));
 
+  test_kdf!(hkdf_sha256, Strong, HKDF_SHA256, Ok(HKDF_SHA512));
+  test_kdf!(kbkdf_cmac, Strong, KBKDF_CMAC, Err(HKDF_SHA512));
+  test_kdf!(pbkdf2_hmac_sha1, Strong, PBKDF2_HMAC_SHA1, Err(HKDF_SHA512));
+  test_kdf!(tls12_prf_sha384, Strong, TLS12_PRF_SHA384, Ok(HKDF_SHA512));
+
+  test_kem!(ml_kem_512, Strong, ML_KEM_512, Err(ML_KEM_1024));
+  test_kem!(ml_kem_768, Strong, ML_KEM_768, Err(ML_KEM_1024));
+  test_kem!(ml_kem_1024, Strong, ML_KEM_1024, Ok(ML_KEM_1024));
+  test_kem!(x25519mlkem768, Strong, X25519MLKEM768, Err(ML_KEM_1024));
+  test_kem!(
+    secp384r1mlkem1024,
+    Strong,
+    SECP384R1MLKEM1024,
+    Ok(ML_KEM_1024)
+  );
+  test_kem!(frodokem_1344, Strong, FRODOKEM_1344, Ok(ML_KEM_1024));
+
+  test_mac!(cmac, Strong, CMAC, Err(HMAC_SHA512));
+  test_mac!(hmac_sha1, Strong, HMAC_SHA1, Err(HMAC_SHA512));
+  test_mac!(hmac_sha256, Strong, HMAC_SHA256, Ok(HMAC_SHA512));
+  test_mac!(kmac128, Strong, KMAC128, Err(HMAC_SHA512));
+  test_mac!(kmac256, Strong, KMAC256, Ok(HMAC_SHA512));
+  test_mac!(umac, Strong, UMAC, Err(HMAC_SHA512));
+  test_mac!(hmac_ripemd160, Strong, HMAC_RIPEMD160, Err(HMAC_SHA512));
+
+  test_mode!(ecb, Strong, ECB, Err(GCM));
+  test_mode!(cbc, Strong, CBC, Err(GCM));
+  test_mode!(gcm, Strong, GCM, Ok(GCM));
+  test_mode!(poly1305, Strong, POLY1305, Ok(GCM));
+
+  test_phf!(argon2id, Strong, ARGON2ID, Ok(ARGON2ID));
+  test_phf!(bcrypt, Strong, BCRYPT, Err(ARGON2ID));
+  test_phf!(pbkdf2_sha512, Strong, PBKDF2_SHA512, Err(ARGON2ID));
+  test_phf!(scrypt, Strong, SCRYPT, Ok(ARGON2ID));
+  test_phf!(yescrypt, Strong, YESCRYPT, Err(ARGON2ID));
+  test_phf!(des_crypt, Strong, DES_CRYPT, Err(ARGON2ID));
+
+  test_pqs!(ml_dsa_44, Strong, ML_DSA_44, Err(ML_DSA_87));
+  test_pqs!(ml_dsa_65, Strong, ML_DSA_65, Err(ML_DSA_87));
+  test_pqs!(ml_dsa_87, Strong, ML_DSA_87, Ok(ML_DSA_87));
+  test_pqs!(slh_dsa_sha2_128s, Strong, SLH_DSA_SHA2_128S, Err(ML_DSA_87));
+  test_pqs!(
+    slh_dsa_shake_256f,
+    Strong,
+    SLH_DSA_SHAKE_256F,
+    Ok(ML_DSA_87)
+  );
+  test_pqs!(lms_sha256_m24, Strong, LMS_SHA256_M24, Err(ML_DSA_87));
+  test_pqs!(xmss_sha2_256, Strong, XMSS_SHA2_256, Ok(ML_DSA_87));
+
+  test_symmetric!(chacha20, Strong, CHACHA20, Ok(AES256));
   test_symmetric!(aes128, Strong, AES128, Err(AES256));
   test_symmetric!(aes192, Strong, AES192, Err(AES256));
   test_symmetric!(aes256, Strong, AES256, Ok(AES256));
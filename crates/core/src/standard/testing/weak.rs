@@ -7,13 +7,20 @@
 //! in this crate.
 
 use crate::context::Context;
+use crate::primitive::drbg::*;
 use crate::primitive::ecc::*;
 use crate::primitive::ffc::*;
 use crate::primitive::hash::*;
 use crate::primitive::ifc::*;
+use crate::primitive::kdf::*;
+use crate::primitive::kem::*;
+use crate::primitive::mac::*;
+use crate::primitive::mode::*;
+use crate::primitive::phf::*;
+use crate::primitive::pqs::*;
 use crate::primitive::symmetric::*;
 use crate::primitive::Primitive;
-use crate::standard::Standard;
+use crate::standard::{Standard, Status, Verdict};
 
 /// [`Standard`] implementation of a mock standard that is intended to
 /// be relatively weak compared to all the other standards defined in
@@ -21,15 +28,53 @@ use crate::standard::Standard;
 pub struct Weak;
 
 impl Standard for Weak {
+  /// Validates a deterministic random bit generator.
+  ///
+  /// Dual_EC_DRBG is disallowed no matter its security strength.
+  ///
+  /// Any other generator with less than 64 bits of security is also
+  /// disallowed while the rest are acceptable. The recommendation is
+  /// Hash_DRBG with the SHA hash function whose security matches the
+  /// desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant
+  /// generator.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::drbg::{CTR_DRBG_TDEA, HASH_DRBG_SHA1};
+  /// use wardstone_core::standard::testing::weak::Weak;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Weak::validate_drbg(ctx, CTR_DRBG_TDEA);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), HASH_DRBG_SHA1);
+  /// ```
+  fn validate_drbg(ctx: Context, drbg: Drbg) -> Verdict<Drbg> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, drbg.security());
+    if [DUAL_EC_DRBG_P256, DUAL_EC_DRBG_P384, DUAL_EC_DRBG_P521].contains(&drbg) {
+      return verdict(Status::Disallowed, HASH_DRBG_SHA1);
+    }
+    let security = ctx.security().max(drbg.security());
+    match security {
+      ..=63 => verdict(Status::Disallowed, HASH_DRBG_SHA1),
+      64..=128 => verdict(Status::Acceptable, HASH_DRBG_SHA1),
+      129..=192 => verdict(Status::Acceptable, HASH_DRBG_SHA224),
+      193.. => verdict(Status::Acceptable, HASH_DRBG_SHA256),
+    }
+  }
+
   /// Validate an elliptic curve cryptography primitive used for digital
   /// signatures and key establishment.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -39,22 +84,25 @@ impl Standard for Weak {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ecc::ED25519;
   /// use wardstone_core::standard::testing::weak::Weak;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Weak::validate_ecc(ctx, ED25519), Ok(ED25519));
+  /// let verdict = Weak::validate_ecc(ctx, ED25519);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ED25519);
   /// ```
-  fn validate_ecc(ctx: Context, key: Ecc) -> Result<Ecc, Ecc> {
+  fn validate_ecc(ctx: Context, key: Ecc) -> Verdict<Ecc> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, key.security());
     let security = ctx.security().max(key.security());
     match security {
-      ..=63 => Err(P224),
-      64..=112 => Ok(P224),
-      113..=128 => Ok(ED25519),
-      129..=160 => Ok(BRAINPOOLP320R1),
-      161..=192 => Ok(P384),
-      193..=244 => Ok(ED448),
-      245..=256 => Ok(BRAINPOOLP512R1),
-      257.. => Ok(P521),
+      ..=63 => verdict(Status::Disallowed, P224),
+      64..=112 => verdict(Status::Acceptable, P224),
+      113..=128 => verdict(Status::Acceptable, ED25519),
+      129..=160 => verdict(Status::Acceptable, BRAINPOOLP320R1),
+      161..=192 => verdict(Status::Acceptable, P384),
+      193..=244 => verdict(Status::Acceptable, ED448),
+      245..=256 => verdict(Status::Acceptable, BRAINPOOLP512R1),
+      257.. => verdict(Status::Acceptable, P521),
     }
   }
 
@@ -63,12 +111,11 @@ impl Standard for Weak {
   /// Examples include the DSA and key establishment algorithms such as
   /// Diffie-Hellman.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key sizes L and N that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key sizes L
-  /// and N with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key sizes L and N that one
+  /// should use instead. If it is but the context specifies a higher
+  /// security level, the recommendation is the key sizes L and N with
+  /// the desired security level.
   ///
   /// # Example
   ///
@@ -78,32 +125,34 @@ impl Standard for Weak {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ffc::{DSA_2048_224, DSA_3072_256};
   /// use wardstone_core::standard::testing::weak::Weak;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
   /// let dsa_2048 = DSA_2048_224;
-  /// assert_eq!(Weak::validate_ffc(ctx, dsa_2048), Ok(dsa_2048));
+  /// let verdict = Weak::validate_ffc(ctx, dsa_2048);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), dsa_2048);
   /// ```
-  fn validate_ffc(ctx: Context, key: Ffc) -> Result<Ffc, Ffc> {
+  fn validate_ffc(ctx: Context, key: Ffc) -> Verdict<Ffc> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, key.security());
     let security = ctx.security().max(key.security());
     match security {
-      ..=63 => Err(DSA_1024_160),
-      64..=80 => Ok(DSA_1024_160),
-      81..=112 => Ok(DSA_2048_224),
-      113..=128 => Ok(DSA_3072_256),
-      129..=192 => Ok(DSA_7680_384),
-      193.. => Ok(DSA_15360_512),
+      ..=63 => verdict(Status::Disallowed, DSA_1024_160),
+      64..=80 => verdict(Status::Acceptable, DSA_1024_160),
+      81..=112 => verdict(Status::Acceptable, DSA_2048_224),
+      113..=128 => verdict(Status::Acceptable, DSA_3072_256),
+      129..=192 => verdict(Status::Acceptable, DSA_7680_384),
+      193.. => verdict(Status::Acceptable, DSA_15360_512),
     }
   }
 
   /// Validates a hash function.
   ///
-  /// If the hash function is not compliant then `Err` will contain the
-  /// recommended primitive that one should use instead.
-  ///
-  /// If the hash function is compliant but the context specifies a
-  /// higher security level, `Ok` will also hold the recommended
-  /// primitive with the desired security level.
+  /// The status of the verdict tells whether the hash function is
+  /// compliant. If it is not, the recommendation is the primitive that
+  /// one should use instead. If it is but the context specifies a
+  /// higher security level, the recommendation is the primitive with
+  /// the desired security level.
   ///
   /// **Note:** An alternative might be suggested for a compliant hash
   /// function with a similar security level in which a switch to the
@@ -121,33 +170,35 @@ impl Standard for Weak {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::hash::{SHA1, SHA256};
   /// use wardstone_core::standard::testing::weak::Weak;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Weak::validate_hash(ctx, SHA1), Ok(SHA1));
+  /// let verdict = Weak::validate_hash(ctx, SHA1);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), SHA1);
   /// ```
-  fn validate_hash(ctx: Context, hash: Hash) -> Result<Hash, Hash> {
+  fn validate_hash(ctx: Context, hash: Hash) -> Verdict<Hash> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, hash.security());
     let security = ctx.security().max(hash.security());
     match security {
-      ..=63 => Err(SHAKE128),
-      64 => Ok(SHAKE128),
-      65..=80 => Ok(SHA1),
-      81..=112 => Ok(SHA224),
-      113..=128 => Ok(BLAKE3),
-      129..=192 => Ok(BLAKE2B_384),
-      193.. => Ok(BLAKE2B_512),
+      ..=63 => verdict(Status::Disallowed, SHAKE128),
+      64 => verdict(Status::Acceptable, SHAKE128),
+      65..=80 => verdict(Status::Acceptable, SHA1),
+      81..=112 => verdict(Status::Acceptable, SHA224),
+      113..=128 => verdict(Status::Acceptable, BLAKE3),
+      129..=192 => verdict(Status::Acceptable, BLAKE2B_384),
+      193.. => verdict(Status::Acceptable, BLAKE2B_512),
     }
   }
 
   /// Validates  an integer factorisation cryptography primitive the
   /// most common of which is the RSA signature algorithm.
   ///
-  /// If the key is not compliant then `Err` will contain the
-  /// recommended key size that one should use instead.
-  ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended key size
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the key size that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the key size with the desired
+  /// security level.
   ///
   /// **Note:** Unlike other functions in this module, this will return
   /// a generic structure that specifies minimum private and public
@@ -161,28 +212,224 @@ impl Standard for Weak {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::ifc::RSA_PSS_2048;
   /// use wardstone_core::standard::testing::weak::Weak;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Weak::validate_ifc(ctx, RSA_PSS_2048);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), RSA_PSS_2048);
+  /// ```
+  fn validate_ifc(ctx: Context, key: Ifc) -> Verdict<Ifc> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, key.security());
+    let security = ctx.security().max(key.security());
+    match security {
+      ..=63 => verdict(Status::Disallowed, RSA_PSS_1024),
+      64..=80 => verdict(Status::Acceptable, RSA_PSS_1024),
+      81..=112 => verdict(Status::Acceptable, RSA_PSS_2048),
+      113..=128 => verdict(Status::Acceptable, RSA_PSS_3072),
+      129..=192 => verdict(Status::Acceptable, RSA_PSS_7680),
+      193.. => verdict(Status::Acceptable, RSA_PSS_15360),
+    }
+  }
+
+  /// Validates a key derivation function.
+  ///
+  /// A function that derives a key with less than 64 bits of security
+  /// is disallowed and any other function is acceptable. The
+  /// recommendation is the weakest function that offers the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kdf::TLS10_PRF;
+  /// use wardstone_core::standard::testing::weak::Weak;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Weak::validate_kdf(ctx, TLS10_PRF);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), TLS10_PRF);
+  /// ```
+  fn validate_kdf(ctx: Context, kdf: Kdf) -> Verdict<Kdf> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, kdf.security());
+    let security = ctx.security().max(kdf.security());
+    match security {
+      ..=63 => verdict(Status::Disallowed, TLS10_PRF),
+      64..=160 => verdict(Status::Acceptable, TLS10_PRF),
+      161..=256 => verdict(Status::Acceptable, HKDF_SHA256),
+      257.. => verdict(Status::Acceptable, HKDF_SHA512),
+    }
+  }
+
+  /// Validates a key encapsulation mechanism.
+  ///
+  /// The status of the verdict tells whether the key encapsulation
+  /// mechanism is compliant. If it is not, the recommendation is the
+  /// primitive that one should use instead. If it is but the context
+  /// specifies a higher security level, the recommendation is the
+  /// primitive with the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant key
+  /// encapsulation mechanism.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::kem::ML_KEM_512;
+  /// use wardstone_core::standard::testing::weak::Weak;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Weak::validate_kem(ctx, ML_KEM_512);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ML_KEM_512);
+  /// ```
+  fn validate_kem(ctx: Context, kem: Kem) -> Verdict<Kem> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, kem.security());
+    let security = ctx.security().max(kem.security());
+    match security {
+      ..=63 => verdict(Status::Disallowed, ML_KEM_512),
+      64..=128 => verdict(Status::Acceptable, ML_KEM_512),
+      129..=192 => verdict(Status::Acceptable, ML_KEM_768),
+      193.. => verdict(Status::Acceptable, ML_KEM_1024),
+    }
+  }
+
+  /// Validates a message authentication code.
+  ///
+  /// A MAC with less than 64 bits of security is disallowed and any
+  /// other MAC is acceptable. The recommendation is the weakest HMAC
+  /// that offers the desired security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant MAC.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mac::HMAC_MD5;
+  /// use wardstone_core::standard::testing::weak::Weak;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Weak::validate_mac(ctx, HMAC_MD5);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), HMAC_MD5);
+  /// ```
+  fn validate_mac(ctx: Context, mac: Mac) -> Verdict<Mac> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, mac.security());
+    let security = ctx.security().max(mac.security());
+    match security {
+      ..=63 => verdict(Status::Disallowed, HMAC_MD5),
+      64..=128 => verdict(Status::Acceptable, HMAC_MD5),
+      129..=160 => verdict(Status::Acceptable, HMAC_SHA1),
+      161..=256 => verdict(Status::Acceptable, HMAC_SHA256),
+      257.. => verdict(Status::Acceptable, HMAC_SHA512),
+    }
+  }
+
+  /// Validates a mode of operation of a block cipher.
+  ///
+  /// Every mode is compliant including those that do not hide patterns
+  /// in the plaintext such as ECB.
+  ///
+  /// The verdict is therefore always acceptable and recommends the mode
+  /// as it is.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant mode.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::mode::ECB;
+  /// use wardstone_core::standard::testing::weak::Weak;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Weak::validate_mode(ctx, ECB);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ECB);
+  /// ```
+  fn validate_mode(_ctx: Context, mode: Mode) -> Verdict<Mode> {
+    Verdict::new(Status::Acceptable, mode, mode.security())
+  }
+
+  /// Validates a password hashing function.
+  ///
+  /// A function with less than 9 bits of security is disallowed and any
+  /// other function is acceptable. In either case MD5-crypt is
+  /// recommended.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant function.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::phf::MD5_CRYPT;
+  /// use wardstone_core::standard::testing::weak::Weak;
+  /// use wardstone_core::standard::{Standard, Status};
+  ///
+  /// let ctx = Context::default();
+  /// let verdict = Weak::validate_phf(ctx, MD5_CRYPT);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), MD5_CRYPT);
+  /// ```
+  fn validate_phf(_ctx: Context, phf: Phf) -> Verdict<Phf> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, phf.security());
+    match phf.security() {
+      ..=8 => verdict(Status::Disallowed, MD5_CRYPT),
+      9.. => verdict(Status::Acceptable, MD5_CRYPT),
+    }
+  }
+
+  /// Validates a post-quantum signature primitive.
+  ///
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
+  ///
+  /// # Example
+  ///
+  /// The following illustrates a call to validate a compliant key.
+  ///
+  /// ```
+  /// use wardstone_core::context::Context;
+  /// use wardstone_core::primitive::pqs::ML_DSA_44;
+  /// use wardstone_core::standard::testing::weak::Weak;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Weak::validate_ifc(ctx, RSA_PSS_2048), Ok(RSA_PSS_2048));
+  /// let verdict = Weak::validate_pqs(ctx, ML_DSA_44);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), ML_DSA_44);
   /// ```
-  fn validate_ifc(ctx: Context, key: Ifc) -> Result<Ifc, Ifc> {
+  fn validate_pqs(ctx: Context, key: Pqs) -> Verdict<Pqs> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, key.security());
     let security = ctx.security().max(key.security());
     match security {
-      ..=63 => Err(RSA_PSS_1024),
-      64..=80 => Ok(RSA_PSS_1024),
-      81..=112 => Ok(RSA_PSS_2048),
-      113..=128 => Ok(RSA_PSS_3072),
-      129..=192 => Ok(RSA_PSS_7680),
-      193.. => Ok(RSA_PSS_15360),
+      ..=63 => verdict(Status::Disallowed, ML_DSA_44),
+      64..=128 => verdict(Status::Acceptable, ML_DSA_44),
+      129..=192 => verdict(Status::Acceptable, ML_DSA_65),
+      193.. => verdict(Status::Acceptable, ML_DSA_87),
     }
   }
 
   /// Validates a symmetric key primitive.
   ///
-  /// If the key is compliant but the context specifies a higher
-  /// security level, `Ok` will also hold the recommended primitive
-  /// with the desired security level.
+  /// The status of the verdict tells whether the key is compliant. If
+  /// it is not, the recommendation is the primitive that one should use
+  /// instead. If it is but the context specifies a higher security
+  /// level, the recommendation is the primitive with the desired
+  /// security level.
   ///
   /// # Example
   ///
@@ -193,22 +440,25 @@ impl Standard for Weak {
   /// use wardstone_core::context::Context;
   /// use wardstone_core::primitive::symmetric::TDEA3;
   /// use wardstone_core::standard::testing::weak::Weak;
-  /// use wardstone_core::standard::Standard;
+  /// use wardstone_core::standard::{Standard, Status};
   ///
   /// let ctx = Context::default();
-  /// assert_eq!(Weak::validate_symmetric(ctx, TDEA3), Ok(TDEA3));
+  /// let verdict = Weak::validate_symmetric(ctx, TDEA3);
+  /// assert_eq!(verdict.status(), Status::Acceptable);
+  /// assert_eq!(verdict.recommendation(), TDEA3);
   /// ```
-  fn validate_symmetric(ctx: Context, key: Symmetric) -> Result<Symmetric, Symmetric> {
+  fn validate_symmetric(ctx: Context, key: Symmetric) -> Verdict<Symmetric> {
+    let verdict = |status, recommendation| Verdict::new(status, recommendation, key.security());
     let security = ctx.security().max(key.security());
     match security {
-      ..=63 => Err(TDEA2),
-      64..=95 => Ok(TDEA2),
-      96..=112 => Ok(TDEA3),
-      113..=120 => Ok(DESX),
-      121..=126 => Ok(IDEA),
-      127..=128 => Ok(AES128),
-      129..=192 => Ok(AES192),
-      193.. => Ok(AES256),
+      ..=63 => verdict(Status::Disallowed, TDEA2),
+      64..=95 => verdict(Status::Acceptable, TDEA2),
+      96..=112 => verdict(Status::Acceptable, TDEA3),
+      113..=120 => verdict(Status::Acceptable, DESX),
+      121..=126 => verdict(Status::Acceptable, IDEA),
+      127..=128 => verdict(Status::Acceptable, AES128),
+      129..=192 => verdict(Status::Acceptable, AES192),
+      193.. => verdict(Status::Acceptable, AES256),
     }
   }
 }
@@ -216,7 +466,25 @@ impl Standard for Weak {
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::{test_ecc, test_ffc, test_hash, test_ifc, test_symmetric};
+  use crate::{
+    test_drbg, test_ecc, test_ffc, test_hash, test_ifc, test_kdf, test_kem, test_mac, test_mode,
+    test_phf, test_pqs, test_symmetric,
+  };
+
+  test_drbg!(ctr_drbg_aes256, Weak, CTR_DRBG_AES256, Ok(HASH_DRBG_SHA256));
+  test_drbg!(ctr_drbg_tdea, Weak, CTR_DRBG_TDEA, Ok(HASH_DRBG_SHA1));
+  test_drbg!(
+    hash_drbg_sha224,
+    Weak,
+    HASH_DRBG_SHA224,
+    Ok(HASH_DRBG_SHA224)
+  );
+  test_drbg!(
+    dual_ec_drbg_p256,
+    Weak,
+    DUAL_EC_DRBG_P256,
+    Err(HASH_DRBG_SHA1)
+  );
 
   test_ecc!(p224, Weak, P224, Ok(P224));
   test_ecc!(p256, Weak, P256, Ok(ED25519));
@@ -238,6 +506,8 @@ mod tests {
   test_ffc!(ffc_3072_256, Weak, DSA_3072_256, Ok(DSA_3072_256));
   test_ffc!(ffc_7680_384, Weak, DSA_7680_384, Ok(DSA_7680_384));
   test_ffc!(ffc_15360_512, Weak, DSA_15360_512, Ok(DSA_15360_512));
+  test_ffc!(ffdhe2048, Weak, FFDHE2048, Ok(DSA_2048_224));
+  test_ffc!(modp1024, Weak, MODP_1024, Ok(DSA_1024_160));
 
   test_ifc!(ifc_1024, Weak, RSA_PSS_1024, Ok(RSA_PSS_1024));
   test_ifc!(ifc_1280, Weak, RSA_PSS_1280, Ok(RSA_PSS_1024));
@@ -275,6 +545,53 @@ mod tests {
   test_hash!(shake256, Weak, SHAKE256, Ok(BLAKE3));
   test_hash!(whirlpool, Weak, WHIRLPOOL, Ok(BLAKE2B_512));
 
+  test_kdf!(hkdf_sha256, Weak, HKDF_SHA256, Ok(HKDF_SHA256));
+  test_kdf!(kbkdf_cmac, Weak, KBKDF_CMAC, Ok(TLS10_PRF));
+  test_kdf!(
+    pbkdf2_hmac_sha512,
+    Weak,
+    PBKDF2_HMAC_SHA512,
+    Ok(HKDF_SHA512)
+  );
+  test_kdf!(tls10_prf, Weak, TLS10_PRF, Ok(TLS10_PRF));
+
+  test_kem!(ml_kem_512, Weak, ML_KEM_512, Ok(ML_KEM_512));
+  test_kem!(ml_kem_768, Weak, ML_KEM_768, Ok(ML_KEM_768));
+  test_kem!(ml_kem_1024, Weak, ML_KEM_1024, Ok(ML_KEM_1024));
+  test_kem!(x25519mlkem768, Weak, X25519MLKEM768, Ok(ML_KEM_768));
+  test_kem!(mceliece348864, Weak, MCELIECE348864, Ok(ML_KEM_512));
+
+  test_mac!(cmac, Weak, CMAC, Ok(HMAC_MD5));
+  test_mac!(hmac_md5, Weak, HMAC_MD5, Ok(HMAC_MD5));
+  test_mac!(hmac_sha1, Weak, HMAC_SHA1, Ok(HMAC_SHA1));
+  test_mac!(hmac_sha512, Weak, HMAC_SHA512, Ok(HMAC_SHA512));
+  test_mac!(poly1305_mac, Weak, POLY1305_MAC, Ok(HMAC_MD5));
+  test_mac!(umac, Weak, UMAC, Ok(HMAC_MD5));
+  test_mac!(hmac_ripemd160, Weak, HMAC_RIPEMD160, Ok(HMAC_SHA1));
+
+  test_mode!(ecb, Weak, ECB, Ok(ECB));
+  test_mode!(cbc, Weak, CBC, Ok(CBC));
+  test_mode!(gcm, Weak, GCM, Ok(GCM));
+
+  test_phf!(argon2id, Weak, ARGON2ID, Ok(MD5_CRYPT));
+  test_phf!(md5_crypt, Weak, MD5_CRYPT, Ok(MD5_CRYPT));
+  test_phf!(
+    pbkdf2_sha1_1,
+    Weak,
+    Phf::iterated(PBKDF2_SHA1.id, 1),
+    Err(MD5_CRYPT)
+  );
+  test_phf!(sha512_crypt, Weak, SHA512_CRYPT, Ok(MD5_CRYPT));
+  test_phf!(des_crypt, Weak, DES_CRYPT, Err(MD5_CRYPT));
+
+  test_pqs!(ml_dsa_44, Weak, ML_DSA_44, Ok(ML_DSA_44));
+  test_pqs!(ml_dsa_65, Weak, ML_DSA_65, Ok(ML_DSA_65));
+  test_pqs!(ml_dsa_87, Weak, ML_DSA_87, Ok(ML_DSA_87));
+  test_pqs!(slh_dsa_sha2_128s, Weak, SLH_DSA_SHA2_128S, Ok(ML_DSA_44));
+  test_pqs!(lms_sha256_m24, Weak, LMS_SHA256_M24, Ok(ML_DSA_65));
+  test_pqs!(xmss_sha2_256, Weak, XMSS_SHA2_256, Ok(ML_DSA_87));
+
+  test_symmetric!(chacha20, Weak, CHACHA20, Ok(AES256));
   test_symmetric!(aes128, Weak, AES128, Ok(AES128));
   test_symmetric!(aes192, Weak, AES192, Ok(AES192));
   test_symmetric!(aes256, Weak, AES256, Ok(AES256));
@@ -1,5 +1,19 @@
 //! Testing utilities.
 
+/// Expands a unit test for a deterministic random bit generator
+/// primitive.
+#[macro_export]
+macro_rules! test_drbg {
+  ($name:ident, $standard:ident, $input:expr, $want:expr) => {
+    #[test]
+    fn $name() {
+      use $crate::context::Context;
+      let ctx = Context::default();
+      assert_eq!($standard::validate_drbg(ctx, $input), $want);
+    }
+  };
+}
+
 /// Expands a unit test for an elliptic curve primitive.
 #[macro_export]
 macro_rules! test_ecc {
@@ -39,6 +53,84 @@ macro_rules! test_ifc {
   };
 }
 
+/// Expands a unit test for a key derivation function primitive.
+#[macro_export]
+macro_rules! test_kdf {
+  ($name:ident, $standard:ident, $input:expr, $want:expr) => {
+    #[test]
+    fn $name() {
+      use $crate::context::Context;
+      let ctx = Context::default();
+      assert_eq!($standard::validate_kdf(ctx, $input), $want);
+    }
+  };
+}
+
+/// Expands a unit test for a key encapsulation mechanism primitive.
+#[macro_export]
+macro_rules! test_kem {
+  ($name:ident, $standard:ident, $input:expr, $want:expr) => {
+    #[test]
+    fn $name() {
+      use $crate::context::Context;
+      let ctx = Context::default();
+      assert_eq!($standard::validate_kem(ctx, $input), $want);
+    }
+  };
+}
+
+/// Expands a unit test for a message authentication code primitive.
+#[macro_export]
+macro_rules! test_mac {
+  ($name:ident, $standard:ident, $input:expr, $want:expr) => {
+    #[test]
+    fn $name() {
+      use $crate::context::Context;
+      let ctx = Context::default();
+      assert_eq!($standard::validate_mac(ctx, $input), $want);
+    }
+  };
+}
+
+/// Expands a unit test for a mode of operation primitive.
+#[macro_export]
+macro_rules! test_mode {
+  ($name:ident, $standard:ident, $input:expr, $want:expr) => {
+    #[test]
+    fn $name() {
+      use $crate::context::Context;
+      let ctx = Context::default();
+      assert_eq!($standard::validate_mode(ctx, $input), $want);
+    }
+  };
+}
+
+/// Expands a unit test for a password hashing function primitive.
+#[macro_export]
+macro_rules! test_phf {
+  ($name:ident, $standard:ident, $input:expr, $want:expr) => {
+    #[test]
+    fn $name() {
+      use $crate::context::Context;
+      let ctx = Context::default();
+      assert_eq!($standard::validate_phf(ctx, $input), $want);
+    }
+  };
+}
+
+/// Expands a unit test for a post-quantum signature primitive.
+#[macro_export]
+macro_rules! test_pqs {
+  ($name:ident, $standard:ident, $input:expr, $want:expr) => {
+    #[test]
+    fn $name() {
+      use $crate::context::Context;
+      let ctx = Context::default();
+      assert_eq!($standard::validate_pqs(ctx, $input), $want);
+    }
+  };
+}
+
 /// Expands a unit test for a hash function primitive.
 #[macro_export]
 macro_rules! test_hash {
@@ -7,14 +7,30 @@ fn main() {
   let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
   let target_dir = Path::new("../../target");
   let header = target_dir.join("wardstone.h");
+  let mut config = cbindgen::Config::default();
+  config.enumeration.prefix_with_name = true;
+  config.enumeration.rename_variants = cbindgen::RenameRule::ScreamingSnakeCase;
+  // The context holds these as plain integers so they are not reachable
+  // from any function.
+  config.export.include = vec!["Operation".to_string(), "Usage".to_string()];
   cbindgen::Builder::new()
+    .with_config(config)
     .rename_item("Context", "ws_context")
+    .rename_item("Drbg", "ws_drbg")
     .rename_item("Ecc", "ws_ecc")
     .rename_item("Ffc", "ws_ffc")
     .rename_item("Hash", "ws_hash")
     .rename_item("Ifc", "ws_ifc")
+    .rename_item("Kdf", "ws_kdf")
+    .rename_item("Kem", "ws_kem")
+    .rename_item("Mac", "ws_mac")
+    .rename_item("Mode", "ws_mode")
+    .rename_item("Operation", "ws_operation")
+    .rename_item("Phf", "ws_phf")
+    .rename_item("Pqs", "ws_pqs")
     .rename_item("Security", "ws_security")
     .rename_item("Symmetric", "ws_symmetric")
+    .rename_item("Usage", "ws_usage")
     .with_cpp_compat(true)
     .with_crate(crate_dir)
     .with_parse_deps(true)
@@ -1,10 +1,120 @@
 //! Specifies the context in which a cryptographic primitive will be
 //! assessed against.
-use wardstone_core::context::Context;
+use wardstone_core::context::{self, Operation, Usage};
+use wardstone_core::primitive::Security;
+
+/// Represents the context in which a cryptographic primitive will be
+/// assessed against such as the year and minimum security required by
+/// the user.
+///
+/// `usage` and `operation` hold values of the `ws_usage` and
+/// `ws_operation` enumerations respectively. They are kept as plain
+/// integers since the structure may be built by the caller and any
+/// other value is treated as an unspecified usage and as applying
+/// protection.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Context {
+  pub security: Security,
+  pub year: u16,
+  pub usage: u32,
+  pub operation: u32,
+}
+
+impl From<Context> for context::Context {
+  fn from(ctx: Context) -> Self {
+    context::Context::new(ctx.security, ctx.year)
+      .with_usage(usage_from_raw(ctx.usage))
+      .with_operation(operation_from_raw(ctx.operation))
+  }
+}
 
 /// Creates a context which will default to the year 2023 and will use
 /// the minimum security defined by the standard.
 #[no_mangle]
 pub extern "C" fn ws_context_default() -> Context {
-  Context::default()
+  let ctx = context::Context::default();
+  Context {
+    security: ctx.security(),
+    year: ctx.year(),
+    usage: ctx.usage() as u32,
+    operation: ctx.operation() as u32,
+  }
+}
+
+/// Creates a new context.
+///
+/// `security` denotes the minimum security required. If this is set
+/// to `0` then it will default to using the minimum security outlined
+/// in the standard. `year` is the year one expects the primitive to
+/// remain secure. `usage` is the purpose the primitive is put to and
+/// `operation` whether it applies protection or processes already
+/// protected data.
+///
+/// `usage` and `operation` take the values of the `ws_usage` and
+/// `ws_operation` enumerations respectively. Any other value is
+/// treated as an unspecified usage and as applying protection.
+#[no_mangle]
+pub extern "C" fn ws_context_new(
+  security: Security,
+  year: u16,
+  usage: u32,
+  operation: u32,
+) -> Context {
+  Context {
+    security,
+    year,
+    usage: usage_from_raw(usage) as u32,
+    operation: operation_from_raw(operation) as u32,
+  }
+}
+
+fn usage_from_raw(usage: u32) -> Usage {
+  [
+    Usage::Unspecified,
+    Usage::DigitalSignature,
+    Usage::KeyEstablishment,
+    Usage::Encryption,
+    Usage::MessageAuthentication,
+    Usage::KeyDerivation,
+  ]
+  .into_iter()
+  .find(|variant| *variant as u32 == usage)
+  .unwrap_or_default()
+}
+
+fn operation_from_raw(operation: u32) -> Operation {
+  [Operation::Protect, Operation::Process]
+    .into_iter()
+    .find(|variant| *variant as u32 == operation)
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unknown_usage_and_operation_are_defaults() {
+    let ctx = Context {
+      security: 0,
+      year: 2023,
+      usage: 42,
+      operation: u32::MAX,
+    };
+    let ctx = context::Context::from(ctx);
+    assert_eq!(ctx.usage(), Usage::Unspecified);
+    assert_eq!(ctx.operation(), Operation::Protect);
+  }
+
+  #[test]
+  fn new_context() {
+    let ctx = ws_context_new(128, 2030, Usage::KeyEstablishment as u32, 7);
+    assert_eq!(ctx.usage, Usage::KeyEstablishment as u32);
+    assert_eq!(ctx.operation, Operation::Protect as u32);
+    let ctx = context::Context::from(ctx);
+    assert_eq!(ctx.security(), 128);
+    assert_eq!(ctx.year(), 2030);
+    assert_eq!(ctx.usage(), Usage::KeyEstablishment);
+  }
 }
@@ -1,7 +1,14 @@
 //! Submodules that contain common cryptographic primitives and their
 //! instances.
+pub mod drbg;
 pub mod ecc;
 pub mod ffc;
 pub mod hash;
 pub mod ifc;
+pub mod kdf;
+pub mod kem;
+pub mod mac;
+pub mod mode;
+pub mod phf;
+pub mod pqs;
 pub mod symmetric;
@@ -0,0 +1,154 @@
+//! Specifies a deterministic random bit generator primitive and a set of
+//! commonly used instances.
+use wardstone_core::primitive::drbg::*;
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-128 with a derivation
+/// function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_CTR_DRBG_AES128: Drbg = CTR_DRBG_AES128;
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-192 with a derivation
+/// function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_CTR_DRBG_AES192: Drbg = CTR_DRBG_AES192;
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-256 with a derivation
+/// function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_CTR_DRBG_AES256: Drbg = CTR_DRBG_AES256;
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-128 without a
+/// derivation function which requires full entropy input.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_CTR_DRBG_AES128_NO_DF: Drbg = CTR_DRBG_AES128_NO_DF;
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-192 without a
+/// derivation function which requires full entropy input.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_CTR_DRBG_AES192_NO_DF: Drbg = CTR_DRBG_AES192_NO_DF;
+
+/// CTR_DRBG as defined in [SP800-90A] using AES-256 without a
+/// derivation function which requires full entropy input.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_CTR_DRBG_AES256_NO_DF: Drbg = CTR_DRBG_AES256_NO_DF;
+
+/// CTR_DRBG as defined in [SP800-90A] using three-key TDEA with a
+/// derivation function.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_CTR_DRBG_TDEA: Drbg = CTR_DRBG_TDEA;
+
+/// Dual_EC_DRBG as defined in the original [SP800-90A] using the P-256
+/// curve.
+///
+/// **Warning:** The generator was withdrawn in 2014 as its default
+/// points may hide a backdoor and it should not be used.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90A
+#[no_mangle]
+pub static WS_DUAL_EC_DRBG_P256: Drbg = DUAL_EC_DRBG_P256;
+
+/// Dual_EC_DRBG as defined in the original [SP800-90A] using the P-384
+/// curve.
+///
+/// **Warning:** The generator was withdrawn in 2014 as its default
+/// points may hide a backdoor and it should not be used.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90A
+#[no_mangle]
+pub static WS_DUAL_EC_DRBG_P384: Drbg = DUAL_EC_DRBG_P384;
+
+/// Dual_EC_DRBG as defined in the original [SP800-90A] using the P-521
+/// curve.
+///
+/// **Warning:** The generator was withdrawn in 2014 as its default
+/// points may hide a backdoor and it should not be used.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90A
+#[no_mangle]
+pub static WS_DUAL_EC_DRBG_P521: Drbg = DUAL_EC_DRBG_P521;
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA1.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HASH_DRBG_SHA1: Drbg = HASH_DRBG_SHA1;
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA224.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HASH_DRBG_SHA224: Drbg = HASH_DRBG_SHA224;
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HASH_DRBG_SHA256: Drbg = HASH_DRBG_SHA256;
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA384.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HASH_DRBG_SHA384: Drbg = HASH_DRBG_SHA384;
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA512.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HASH_DRBG_SHA512: Drbg = HASH_DRBG_SHA512;
+
+/// Hash_DRBG as defined in [SP800-90A] using SHA512/256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HASH_DRBG_SHA512_256: Drbg = HASH_DRBG_SHA512_256;
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA1.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HMAC_DRBG_SHA1: Drbg = HMAC_DRBG_SHA1;
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA224.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HMAC_DRBG_SHA224: Drbg = HMAC_DRBG_SHA224;
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HMAC_DRBG_SHA256: Drbg = HMAC_DRBG_SHA256;
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA384.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HMAC_DRBG_SHA384: Drbg = HMAC_DRBG_SHA384;
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA512.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HMAC_DRBG_SHA512: Drbg = HMAC_DRBG_SHA512;
+
+/// HMAC_DRBG as defined in [SP800-90A] using SHA512/256.
+///
+/// [SP800-90A]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+#[no_mangle]
+pub static WS_HMAC_DRBG_SHA512_256: Drbg = HMAC_DRBG_SHA512_256;
@@ -2,6 +2,10 @@
 //! instances.
 use wardstone_core::primitive::ffc::*;
 
+/// An identifier for custom Diffie-Hellman groups.
+#[no_mangle]
+pub static WS_ID_DH: u16 = ID_DH;
+
 /// Generic instance that represents a choice of L = 1024 and N = 160
 /// for a finite field cryptography primitive.
 #[no_mangle]
@@ -32,6 +36,97 @@ pub static WS_DSA_7680_384: Ffc = DSA_7680_384;
 #[no_mangle]
 pub static WS_DSA_15360_512: Ffc = DSA_15360_512;
 
+/// The 2048-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static WS_FFDHE2048: Ffc = FFDHE2048;
+
+/// The 3072-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static WS_FFDHE3072: Ffc = FFDHE3072;
+
+/// The 4096-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static WS_FFDHE4096: Ffc = FFDHE4096;
+
+/// The 6144-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static WS_FFDHE6144: Ffc = FFDHE6144;
+
+/// The 8192-bit finite field Diffie-Hellman group as defined in
+/// [RFC 7919].
+///
+/// [RFC 7919]: https://datatracker.ietf.org/doc/html/rfc7919
+#[no_mangle]
+pub static WS_FFDHE8192: Ffc = FFDHE8192;
+
+/// The 768-bit MODP group, also known as the First Oakley Group or
+/// group 1, as defined in [RFC 2409].
+///
+/// [RFC 2409]: https://datatracker.ietf.org/doc/html/rfc2409
+#[no_mangle]
+pub static WS_MODP_768: Ffc = MODP_768;
+
+/// The 1024-bit MODP group, also known as the Second Oakley Group or
+/// group 2, as defined in [RFC 2409].
+///
+/// [RFC 2409]: https://datatracker.ietf.org/doc/html/rfc2409
+#[no_mangle]
+pub static WS_MODP_1024: Ffc = MODP_1024;
+
+/// The 1536-bit MODP group, also known as group 5, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static WS_MODP_1536: Ffc = MODP_1536;
+
+/// The 2048-bit MODP group, also known as group 14, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static WS_MODP_2048: Ffc = MODP_2048;
+
+/// The 3072-bit MODP group, also known as group 15, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static WS_MODP_3072: Ffc = MODP_3072;
+
+/// The 4096-bit MODP group, also known as group 16, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static WS_MODP_4096: Ffc = MODP_4096;
+
+/// The 6144-bit MODP group, also known as group 17, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static WS_MODP_6144: Ffc = MODP_6144;
+
+/// The 8192-bit MODP group, also known as group 18, as defined in
+/// [RFC 3526].
+///
+/// [RFC 3526]: https://datatracker.ietf.org/doc/html/rfc3526
+#[no_mangle]
+pub static WS_MODP_8192: Ffc = MODP_8192;
+
 /// Placeholder for use in where this primitive is not supported.
 #[no_mangle]
 pub static WS_FFC_NOT_SUPPORTED: Ffc = FFC_NOT_SUPPORTED;
@@ -0,0 +1,109 @@
+//! Specifies a key derivation function primitive and a set of
+//! commonly used instances.
+use wardstone_core::primitive::kdf::*;
+
+/// HKDF as defined in [RFC 5869] using HMAC-SHA256.
+///
+/// [RFC 5869]: https://datatracker.ietf.org/doc/html/rfc5869
+#[no_mangle]
+pub static WS_HKDF_SHA256: Kdf = HKDF_SHA256;
+
+/// HKDF as defined in [RFC 5869] using HMAC-SHA384.
+///
+/// [RFC 5869]: https://datatracker.ietf.org/doc/html/rfc5869
+#[no_mangle]
+pub static WS_HKDF_SHA384: Kdf = HKDF_SHA384;
+
+/// HKDF as defined in [RFC 5869] using HMAC-SHA512.
+///
+/// [RFC 5869]: https://datatracker.ietf.org/doc/html/rfc5869
+#[no_mangle]
+pub static WS_HKDF_SHA512: Kdf = HKDF_SHA512;
+
+/// The key-based KDF as defined in [SP800-108] using CMAC with AES.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static WS_KBKDF_CMAC: Kdf = KBKDF_CMAC;
+
+/// The key-based KDF as defined in [SP800-108] using HMAC-SHA256.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static WS_KBKDF_HMAC_SHA256: Kdf = KBKDF_HMAC_SHA256;
+
+/// The key-based KDF as defined in [SP800-108] using HMAC-SHA384.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static WS_KBKDF_HMAC_SHA384: Kdf = KBKDF_HMAC_SHA384;
+
+/// The key-based KDF as defined in [SP800-108] using HMAC-SHA512.
+///
+/// [SP800-108]: https://doi.org/10.6028/NIST.SP.800-108r1
+#[no_mangle]
+pub static WS_KBKDF_HMAC_SHA512: Kdf = KBKDF_HMAC_SHA512;
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA1
+/// with the minimum iteration count of [SP800-132].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [SP800-132]: https://doi.org/10.6028/NIST.SP.800-132
+#[no_mangle]
+pub static WS_PBKDF2_HMAC_SHA1: Kdf = PBKDF2_HMAC_SHA1;
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA256
+/// with the minimum iteration count of [SP800-132].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [SP800-132]: https://doi.org/10.6028/NIST.SP.800-132
+#[no_mangle]
+pub static WS_PBKDF2_HMAC_SHA256: Kdf = PBKDF2_HMAC_SHA256;
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA512
+/// with the minimum iteration count of [SP800-132].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [SP800-132]: https://doi.org/10.6028/NIST.SP.800-132
+#[no_mangle]
+pub static WS_PBKDF2_HMAC_SHA512: Kdf = PBKDF2_HMAC_SHA512;
+
+/// The one-step KDF as defined in [SP800-56C] using SHA256.
+///
+/// [SP800-56C]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+#[no_mangle]
+pub static WS_SP800_56C_SHA256: Kdf = SP800_56C_SHA256;
+
+/// The one-step KDF as defined in [SP800-56C] using SHA384.
+///
+/// [SP800-56C]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+#[no_mangle]
+pub static WS_SP800_56C_SHA384: Kdf = SP800_56C_SHA384;
+
+/// The one-step KDF as defined in [SP800-56C] using SHA512.
+///
+/// [SP800-56C]: https://doi.org/10.6028/NIST.SP.800-56Cr2
+#[no_mangle]
+pub static WS_SP800_56C_SHA512: Kdf = SP800_56C_SHA512;
+
+/// The pseudorandom function of TLS 1.0 and 1.1 as defined in
+/// [RFC 2246] which combines HMAC-MD5 and HMAC-SHA1. The default key
+/// length is that of the master secret.
+///
+/// [RFC 2246]: https://datatracker.ietf.org/doc/html/rfc2246
+#[no_mangle]
+pub static WS_TLS10_PRF: Kdf = TLS10_PRF;
+
+/// The pseudorandom function of TLS 1.2 as defined in [RFC 5246] using
+/// HMAC-SHA256. The default key length is that of the master secret.
+///
+/// [RFC 5246]: https://datatracker.ietf.org/doc/html/rfc5246
+#[no_mangle]
+pub static WS_TLS12_PRF_SHA256: Kdf = TLS12_PRF_SHA256;
+
+/// The pseudorandom function of TLS 1.2 as defined in [RFC 5246] using
+/// HMAC-SHA384. The default key length is that of the master secret.
+///
+/// [RFC 5246]: https://datatracker.ietf.org/doc/html/rfc5246
+#[no_mangle]
+pub static WS_TLS12_PRF_SHA384: Kdf = TLS12_PRF_SHA384;
@@ -0,0 +1,108 @@
+//! Specifies a key encapsulation mechanism primitive and a set of
+//! commonly used instances.
+use wardstone_core::primitive::kem::*;
+
+/// The FrodoKEM-640 key encapsulation mechanism with either the AES or
+/// SHAKE pseudorandom generator as defined in the [FrodoKEM]
+/// specification.
+///
+/// [FrodoKEM]: https://frodokem.org/files/FrodoKEM-specification-20210604.pdf
+#[no_mangle]
+pub static WS_FRODOKEM_640: Kem = FRODOKEM_640;
+
+/// The FrodoKEM-976 key encapsulation mechanism with either the AES or
+/// SHAKE pseudorandom generator as defined in the [FrodoKEM]
+/// specification.
+///
+/// [FrodoKEM]: https://frodokem.org/files/FrodoKEM-specification-20210604.pdf
+#[no_mangle]
+pub static WS_FRODOKEM_976: Kem = FRODOKEM_976;
+
+/// The FrodoKEM-1344 key encapsulation mechanism with either the AES
+/// or SHAKE pseudorandom generator as defined in the [FrodoKEM]
+/// specification.
+///
+/// [FrodoKEM]: https://frodokem.org/files/FrodoKEM-specification-20210604.pdf
+#[no_mangle]
+pub static WS_FRODOKEM_1344: Kem = FRODOKEM_1344;
+
+/// The Classic McEliece parameter set mceliece348864 as defined in the
+/// [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static WS_MCELIECE348864: Kem = MCELIECE348864;
+
+/// The Classic McEliece parameter set mceliece460896 as defined in the
+/// [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static WS_MCELIECE460896: Kem = MCELIECE460896;
+
+/// The Classic McEliece parameter set mceliece6688128 as defined in
+/// the [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static WS_MCELIECE6688128: Kem = MCELIECE6688128;
+
+/// The Classic McEliece parameter set mceliece6960119 as defined in
+/// the [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static WS_MCELIECE6960119: Kem = MCELIECE6960119;
+
+/// The Classic McEliece parameter set mceliece8192128 as defined in
+/// the [Classic McEliece] specification.
+///
+/// [Classic McEliece]: https://classic.mceliece.org/mceliece-spec-20221023.pdf
+#[no_mangle]
+pub static WS_MCELIECE8192128: Kem = MCELIECE8192128;
+
+/// The Module-Lattice-Based Key-Encapsulation Mechanism parameter set
+/// ML-KEM-512 as defined in [FIPS 203].
+///
+/// [FIPS 203]: https://doi.org/10.6028/NIST.FIPS.203
+#[no_mangle]
+pub static WS_ML_KEM_512: Kem = ML_KEM_512;
+
+/// The Module-Lattice-Based Key-Encapsulation Mechanism parameter set
+/// ML-KEM-768 as defined in [FIPS 203].
+///
+/// [FIPS 203]: https://doi.org/10.6028/NIST.FIPS.203
+#[no_mangle]
+pub static WS_ML_KEM_768: Kem = ML_KEM_768;
+
+/// The Module-Lattice-Based Key-Encapsulation Mechanism parameter set
+/// ML-KEM-1024 as defined in [FIPS 203].
+///
+/// [FIPS 203]: https://doi.org/10.6028/NIST.FIPS.203
+#[no_mangle]
+pub static WS_ML_KEM_1024: Kem = ML_KEM_1024;
+
+/// The hybrid combination of ECDH over P-256 and ML-KEM-768 as defined
+/// in [draft-ietf-tls-ecdhe-mlkem].
+///
+/// [draft-ietf-tls-ecdhe-mlkem]: https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/
+#[no_mangle]
+pub static WS_SECP256R1MLKEM768: Kem = SECP256R1MLKEM768;
+
+/// The hybrid combination of ECDH over P-384 and ML-KEM-1024 as
+/// defined in [draft-ietf-tls-ecdhe-mlkem].
+///
+/// [draft-ietf-tls-ecdhe-mlkem]: https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/
+#[no_mangle]
+pub static WS_SECP384R1MLKEM1024: Kem = SECP384R1MLKEM1024;
+
+/// The hybrid combination of X25519 and ML-KEM-768 as defined in
+/// [draft-ietf-tls-ecdhe-mlkem].
+///
+/// [draft-ietf-tls-ecdhe-mlkem]: https://datatracker.ietf.org/doc/draft-ietf-tls-ecdhe-mlkem/
+#[no_mangle]
+pub static WS_X25519MLKEM768: Kem = X25519MLKEM768;
+
+/// Placeholder for use in where this primitive is not supported.
+#[no_mangle]
+pub static WS_KEM_NOT_SUPPORTED: Kem = KEM_NOT_SUPPORTED;
@@ -0,0 +1,106 @@
+//! Specifies a message authentication code primitive and a set of
+//! commonly used instances.
+use wardstone_core::primitive::mac::*;
+
+/// The cipher-based MAC as defined in [SP800-38B] using AES where `key`
+/// is the length of the AES key.
+///
+/// [SP800-38B]: https://doi.org/10.6028/NIST.SP.800-38B
+#[no_mangle]
+pub static WS_CMAC: Mac = CMAC;
+
+/// The Galois MAC as defined in [SP800-38D] using AES where `key` is
+/// the length of the AES key.
+///
+/// [SP800-38D]: https://doi.org/10.6028/NIST.SP.800-38D
+#[no_mangle]
+pub static WS_GMAC: Mac = GMAC;
+
+/// HMAC as defined in [RFC 2104] using MD5.
+///
+/// **Warning:** MD5 is broken and should not be used in new designs.
+///
+/// [RFC 2104]: https://datatracker.ietf.org/doc/html/rfc2104
+#[no_mangle]
+pub static WS_HMAC_MD5: Mac = HMAC_MD5;
+
+/// HMAC as defined in [FIPS 198-1] using SHA1.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA1: Mac = HMAC_SHA1;
+
+/// HMAC as defined in [FIPS 198-1] using SHA224.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA224: Mac = HMAC_SHA224;
+
+/// HMAC as defined in [FIPS 198-1] using SHA256.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA256: Mac = HMAC_SHA256;
+
+/// HMAC as defined in [FIPS 198-1] using SHA384.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA384: Mac = HMAC_SHA384;
+
+/// HMAC as defined in [FIPS 198-1] using SHA512.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA512: Mac = HMAC_SHA512;
+
+/// HMAC as defined in [FIPS 198-1] using SHA3-256.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA3_256: Mac = HMAC_SHA3_256;
+
+/// HMAC as defined in [FIPS 198-1] using SHA3-384.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA3_384: Mac = HMAC_SHA3_384;
+
+/// HMAC as defined in [FIPS 198-1] using SHA3-512.
+///
+/// [FIPS 198-1]: https://doi.org/10.6028/NIST.FIPS.198-1
+#[no_mangle]
+pub static WS_HMAC_SHA3_512: Mac = HMAC_SHA3_512;
+
+/// The Keccak MAC with a security of 128 bits as defined in
+/// [SP800-185].
+///
+/// [SP800-185]: https://doi.org/10.6028/NIST.SP.800-185
+#[no_mangle]
+pub static WS_KMAC128: Mac = KMAC128;
+
+/// The Keccak MAC with a security of 256 bits as defined in
+/// [SP800-185].
+///
+/// [SP800-185]: https://doi.org/10.6028/NIST.SP.800-185
+#[no_mangle]
+pub static WS_KMAC256: Mac = KMAC256;
+
+/// The Poly1305 one-time authenticator as defined in [RFC 8439].
+///
+/// [RFC 8439]: https://datatracker.ietf.org/doc/html/rfc8439
+#[no_mangle]
+pub static WS_POLY1305_MAC: Mac = POLY1305_MAC;
+
+/// UMAC as defined in [RFC 4418] using AES-128 where the tag is 32, 64,
+/// 96 or 128 bits long.
+///
+/// [RFC 4418]: https://datatracker.ietf.org/doc/html/rfc4418
+#[no_mangle]
+pub static WS_UMAC: Mac = UMAC;
+
+/// HMAC as defined in [RFC 2104] using RIPEMD-160.
+///
+/// [RFC 2104]: https://datatracker.ietf.org/doc/html/rfc2104
+#[no_mangle]
+pub static WS_HMAC_RIPEMD160: Mac = HMAC_RIPEMD160;
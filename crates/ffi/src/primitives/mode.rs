@@ -0,0 +1,80 @@
+//! Specifies a mode of operation primitive and a set of commonly used
+//! instances.
+use wardstone_core::primitive::mode::*;
+
+/// The Electronic Codebook mode as defined in [SP800-38A].
+///
+/// **Warning:** Identical blocks of plaintext are encrypted to
+/// identical blocks of ciphertext so this mode does not hide patterns
+/// in the data.
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static WS_ECB: Mode = ECB;
+
+/// The Cipher Block Chaining mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static WS_CBC: Mode = CBC;
+
+/// The Cipher Feedback mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static WS_CFB: Mode = CFB;
+
+/// The Output Feedback mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static WS_OFB: Mode = OFB;
+
+/// The Counter mode as defined in [SP800-38A].
+///
+/// [SP800-38A]: https://doi.org/10.6028/NIST.SP.800-38A
+#[no_mangle]
+pub static WS_CTR: Mode = CTR;
+
+/// The XEX-based tweaked-codebook mode with ciphertext stealing as
+/// defined in [SP800-38E] which is intended for storage devices.
+///
+/// [SP800-38E]: https://doi.org/10.6028/NIST.SP.800-38E
+#[no_mangle]
+pub static WS_XTS: Mode = XTS;
+
+/// The Counter with CBC-MAC authenticated encryption mode as defined
+/// in [SP800-38C].
+///
+/// [SP800-38C]: https://doi.org/10.6028/NIST.SP.800-38C
+#[no_mangle]
+pub static WS_CCM: Mode = CCM;
+
+/// The Galois/Counter authenticated encryption mode as defined in
+/// [SP800-38D].
+///
+/// [SP800-38D]: https://doi.org/10.6028/NIST.SP.800-38D
+#[no_mangle]
+pub static WS_GCM: Mode = GCM;
+
+/// The nonce misuse-resistant variant of the Galois/Counter mode as
+/// defined in [RFC 8452].
+///
+/// [RFC 8452]: https://datatracker.ietf.org/doc/html/rfc8452
+#[no_mangle]
+pub static WS_GCM_SIV: Mode = GCM_SIV;
+
+/// The Offset Codebook authenticated encryption mode as defined in
+/// [RFC 7253].
+///
+/// [RFC 7253]: https://datatracker.ietf.org/doc/html/rfc7253
+#[no_mangle]
+pub static WS_OCB: Mode = OCB;
+
+/// The authenticated encryption construction that combines a stream
+/// cipher such as ChaCha20 with the Poly1305 authenticator as defined
+/// in [RFC 8439].
+///
+/// [RFC 8439]: https://datatracker.ietf.org/doc/html/rfc8439
+#[no_mangle]
+pub static WS_POLY1305: Mode = POLY1305;
@@ -0,0 +1,112 @@
+//! Specifies a password hashing function primitive and a set of
+//! commonly used instances.
+use wardstone_core::primitive::phf::*;
+
+/// The data-dependent variant of Argon2 as defined in [RFC 9106] using
+/// the second recommended option of 64 MiB of memory, 3 passes and 4
+/// lanes.
+///
+/// **Warning:** The data-dependent memory access makes this variant
+/// susceptible to side-channel attacks.
+///
+/// [RFC 9106]: https://datatracker.ietf.org/doc/html/rfc9106
+#[no_mangle]
+pub static WS_ARGON2D: Phf = ARGON2D;
+
+/// The data-independent variant of Argon2 as defined in [RFC 9106]
+/// using the second recommended option of 64 MiB of memory, 3 passes
+/// and 4 lanes.
+///
+/// [RFC 9106]: https://datatracker.ietf.org/doc/html/rfc9106
+#[no_mangle]
+pub static WS_ARGON2I: Phf = ARGON2I;
+
+/// The hybrid variant of Argon2 as defined in [RFC 9106] using the
+/// second recommended option of 64 MiB of memory, 3 passes and 4 lanes.
+///
+/// [RFC 9106]: https://datatracker.ietf.org/doc/html/rfc9106
+#[no_mangle]
+pub static WS_ARGON2ID: Phf = ARGON2ID;
+
+/// The password hashing function of OpenBSD as defined in [bcrypt]
+/// with a cost factor of 10.
+///
+/// [bcrypt]: https://www.usenix.org/legacy/events/usenix99/provos/provos.pdf
+#[no_mangle]
+pub static WS_BCRYPT: Phf = BCRYPT;
+
+/// The traditional DES-based scheme of crypt(3), which has no
+/// identifier, uses a fixed iteration count of 25 and only the first
+/// eight characters of the password.
+///
+/// **Warning:** DES keys can be searched exhaustively and the
+/// iteration count is far too low to slow down guessing attacks.
+#[no_mangle]
+pub static WS_DES_CRYPT: Phf = DES_CRYPT;
+
+/// The MD5-based scheme of crypt(3), identified by `$1$`, which uses a
+/// fixed iteration count of 1000.
+///
+/// **Warning:** MD5 is broken and the iteration count is far too low to
+/// slow down guessing attacks.
+#[no_mangle]
+pub static WS_MD5_CRYPT: Phf = MD5_CRYPT;
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA1 with
+/// the iteration count recommended by [OWASP].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [OWASP]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+#[no_mangle]
+pub static WS_PBKDF2_SHA1: Phf = PBKDF2_SHA1;
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA256
+/// with the iteration count recommended by [OWASP].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [OWASP]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+#[no_mangle]
+pub static WS_PBKDF2_SHA256: Phf = PBKDF2_SHA256;
+
+/// The password-based KDF as defined in [RFC 8018] using HMAC-SHA512
+/// with the iteration count recommended by [OWASP].
+///
+/// [RFC 8018]: https://datatracker.ietf.org/doc/html/rfc8018
+/// [OWASP]: https://cheatsheetseries.owasp.org/cheatsheets/Password_Storage_Cheat_Sheet.html
+#[no_mangle]
+pub static WS_PBKDF2_SHA512: Phf = PBKDF2_SHA512;
+
+/// The memory-hard function as defined in [RFC 7914] with `N = 2^17`,
+/// `r = 8` and `p = 1` which uses 128 MiB of memory.
+///
+/// [RFC 7914]: https://datatracker.ietf.org/doc/html/rfc7914
+#[no_mangle]
+pub static WS_SCRYPT: Phf = SCRYPT;
+
+/// The SHA256-based scheme of crypt(3), identified by `$5$`, as defined
+/// in [Unix crypt using SHA-256 and SHA-512] with the default of 5000
+/// rounds.
+///
+/// [Unix crypt using SHA-256 and SHA-512]: https://www.akkadia.org/drepper/SHA-crypt.txt
+#[no_mangle]
+pub static WS_SHA256_CRYPT: Phf = SHA256_CRYPT;
+
+/// The SHA512-based scheme of crypt(3), identified by `$6$`, as defined
+/// in [Unix crypt using SHA-256 and SHA-512] with the default of 5000
+/// rounds.
+///
+/// [Unix crypt using SHA-256 and SHA-512]: https://www.akkadia.org/drepper/SHA-crypt.txt
+#[no_mangle]
+pub static WS_SHA512_CRYPT: Phf = SHA512_CRYPT;
+
+/// The scrypt-based scheme of crypt(3), identified by `$y$`, as used by
+/// [libxcrypt] with its default of `N = 2^12`, `r = 32` and `p = 1`
+/// which uses 16 MiB of memory.
+///
+/// [libxcrypt]: https://github.com/besser82/libxcrypt
+#[no_mangle]
+pub static WS_YESCRYPT: Phf = YESCRYPT;
+
+/// Placeholder for use in where this primitive is not supported.
+#[no_mangle]
+pub static WS_PHF_NOT_SUPPORTED: Phf = PHF_NOT_SUPPORTED;
@@ -0,0 +1,178 @@
+//! Specifies a post-quantum signature primitive and a set of commonly
+//! used instances.
+use wardstone_core::primitive::pqs::*;
+
+/// The Leighton-Micali signature scheme using SHA-256/192 as defined in
+/// [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_LMS_SHA256_M24: Pqs = LMS_SHA256_M24;
+
+/// The Leighton-Micali signature scheme using SHA-256 as defined in
+/// [RFC 8554] and [SP 800-208]. The tree height does not affect
+/// security and so a single instance stands in for all heights.
+///
+/// [RFC 8554]: https://www.rfc-editor.org/rfc/rfc8554.html
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_LMS_SHA256_M32: Pqs = LMS_SHA256_M32;
+
+/// The Leighton-Micali signature scheme using SHAKE256/192 as defined
+/// in [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_LMS_SHAKE_M24: Pqs = LMS_SHAKE_M24;
+
+/// The Leighton-Micali signature scheme using SHAKE256 as defined in
+/// [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_LMS_SHAKE_M32: Pqs = LMS_SHAKE_M32;
+
+/// The Module-Lattice-Based Digital Signature Algorithm parameter set
+/// ML-DSA-44 as defined in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+#[no_mangle]
+pub static WS_ML_DSA_44: Pqs = ML_DSA_44;
+
+/// The Module-Lattice-Based Digital Signature Algorithm parameter set
+/// ML-DSA-65 as defined in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+#[no_mangle]
+pub static WS_ML_DSA_65: Pqs = ML_DSA_65;
+
+/// The Module-Lattice-Based Digital Signature Algorithm parameter set
+/// ML-DSA-87 as defined in [FIPS 204].
+///
+/// [FIPS 204]: https://doi.org/10.6028/NIST.FIPS.204
+#[no_mangle]
+pub static WS_ML_DSA_87: Pqs = ML_DSA_87;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-128f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHA2_128F: Pqs = SLH_DSA_SHA2_128F;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-128s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHA2_128S: Pqs = SLH_DSA_SHA2_128S;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-192f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHA2_192F: Pqs = SLH_DSA_SHA2_192F;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-192s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHA2_192S: Pqs = SLH_DSA_SHA2_192S;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-256f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHA2_256F: Pqs = SLH_DSA_SHA2_256F;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHA2-256s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHA2_256S: Pqs = SLH_DSA_SHA2_256S;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-128f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHAKE_128F: Pqs = SLH_DSA_SHAKE_128F;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-128s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHAKE_128S: Pqs = SLH_DSA_SHAKE_128S;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-192f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHAKE_192F: Pqs = SLH_DSA_SHAKE_192F;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-192s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHAKE_192S: Pqs = SLH_DSA_SHAKE_192S;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-256f as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHAKE_256F: Pqs = SLH_DSA_SHAKE_256F;
+
+/// The Stateless Hash-Based Digital Signature Algorithm parameter set
+/// SLH-DSA-SHAKE-256s as defined in [FIPS 205].
+///
+/// [FIPS 205]: https://doi.org/10.6028/NIST.FIPS.205
+#[no_mangle]
+pub static WS_SLH_DSA_SHAKE_256S: Pqs = SLH_DSA_SHAKE_256S;
+
+/// The eXtended Merkle Signature Scheme using SHA-256/192 as defined
+/// in [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_XMSS_SHA2_192: Pqs = XMSS_SHA2_192;
+
+/// The eXtended Merkle Signature Scheme using SHA-256 as defined in
+/// [RFC 8391] and [SP 800-208]. The tree height does not affect
+/// security and so a single instance stands in for all heights.
+///
+/// [RFC 8391]: https://www.rfc-editor.org/rfc/rfc8391.html
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_XMSS_SHA2_256: Pqs = XMSS_SHA2_256;
+
+/// The eXtended Merkle Signature Scheme using SHAKE256/192 as defined
+/// in [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_XMSS_SHAKE256_192: Pqs = XMSS_SHAKE256_192;
+
+/// The eXtended Merkle Signature Scheme using SHAKE256 as defined in
+/// [SP 800-208]. The tree height does not affect security and so a
+/// single instance stands in for all heights.
+///
+/// [SP 800-208]: https://doi.org/10.6028/NIST.SP.800-208
+#[no_mangle]
+pub static WS_XMSS_SHAKE256_256: Pqs = XMSS_SHAKE256_256;
+
+/// Placeholder for use in where this primitive is not supported.
+#[no_mangle]
+pub static WS_PQS_NOT_SUPPORTED: Pqs = PQS_NOT_SUPPORTED;
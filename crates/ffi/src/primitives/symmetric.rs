@@ -38,6 +38,12 @@ pub static WS_CAMELLIA192: Symmetric = CAMELLIA192;
 #[no_mangle]
 pub static WS_CAMELLIA256: Symmetric = CAMELLIA256;
 
+/// The ChaCha20 stream cipher as defined in [RFC 8439].
+///
+/// [RFC 8439]: https://datatracker.ietf.org/doc/html/rfc8439
+#[no_mangle]
+pub static WS_CHACHA20: Symmetric = CHACHA20;
+
 /// The Data Encryption Standard algorithm.
 #[no_mangle]
 pub static WS_DES: Symmetric = DES;
@@ -50,6 +56,18 @@ pub static WS_DESX: Symmetric = DESX;
 #[no_mangle]
 pub static WS_IDEA: Symmetric = IDEA;
 
+/// The RC4 stream cipher also known as ARCFOUR.
+///
+/// **Warning:** This algorithm has been shown to be broken. It should
+/// only be used where compatibility with legacy systems, not security,
+/// is the goal.
+#[no_mangle]
+pub static WS_RC4: Symmetric = RC4;
+
+/// The Salsa20 stream cipher with 20 rounds.
+#[no_mangle]
+pub static WS_SALSA20: Symmetric = SALSA20;
+
 /// The Serpent encryption algorithm.
 #[no_mangle]
 pub static WS_SERPENT128: Symmetric = SERPENT128;
@@ -75,3 +93,8 @@ pub static WS_TDEA2: Symmetric = TDEA2;
 /// [SP800-67]: https://doi.org/10.6028/NIST.SP.800-67r2
 #[no_mangle]
 pub static WS_TDEA3: Symmetric = TDEA3;
+
+/// The XChaCha20 stream cipher which extends the nonce of ChaCha20 to
+/// 192 bits.
+#[no_mangle]
+pub static WS_XCHACHA20: Symmetric = XCHACHA20;
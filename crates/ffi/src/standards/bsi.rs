@@ -5,17 +5,51 @@
 //! [BSI TR-02102-1 Cryptographic Mechanisms: Recommendations and Key Lengths]: https://www.bsi.bund.de/SharedDocs/Downloads/EN/BSI/Publications/TechGuidelines/TG02102/BSI-TR-02102-1.html
 use std::ffi::c_int;
 
-use wardstone_core::context::Context;
+use wardstone_core::primitive::drbg::Drbg;
 use wardstone_core::primitive::ecc::Ecc;
 use wardstone_core::primitive::ffc::Ffc;
 use wardstone_core::primitive::hash::Hash;
 use wardstone_core::primitive::ifc::Ifc;
+use wardstone_core::primitive::kdf::Kdf;
+use wardstone_core::primitive::kem::Kem;
+use wardstone_core::primitive::mac::Mac;
+use wardstone_core::primitive::mode::Mode;
+use wardstone_core::primitive::phf::Phf;
+use wardstone_core::primitive::pqs::Pqs;
 use wardstone_core::primitive::symmetric::Symmetric;
 use wardstone_core::standard::bsi::Bsi;
 use wardstone_core::standard::Standard;
 
+use crate::context::Context;
 use crate::utilities;
 
+/// Validates a deterministic random bit generator. Hash_DRBG, HMAC_DRBG,
+/// and CTR_DRBG are compliant when built on a hash function or block
+/// cipher that the guide recommends.
+///
+/// If the generator is not compliant then `struct ws_drbg* alternative`
+/// will point to the recommended generator that one should use instead.
+///
+/// If the generator is compliant but the context specifies a higher
+/// security level, `struct ws_drbg*` will also point to the recommended
+/// generator with the desired security level.
+///
+/// The function returns `1` if the generator is compliant, `0` if it is
+/// not, and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_bsi_validate_drbg(
+  ctx: Context,
+  drbg: Drbg,
+  alternative: *mut Drbg,
+) -> c_int {
+  utilities::c_call(Bsi::validate_drbg, ctx, drbg, alternative)
+}
+
 /// Validate an elliptic curve cryptography primitive used for digital
 /// signatures and key establishment where f is the key size.
 ///
@@ -197,6 +231,179 @@ pub unsafe extern "C" fn ws_bsi_validate_ifc(
   utilities::c_call(Bsi::validate_ifc, ctx, key, alternative)
 }
 
+/// Validates a key derivation function.
+///
+/// The guide recommends the functions of NIST SP 800-56C and NIST SP
+/// 800-108. PBKDF2 and the pseudorandom functions of TLS are not
+/// recognised.
+///
+/// If the key derivation function is not compliant then
+/// `struct ws_kdf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the key derivation function is compliant but the context
+/// specifies a higher security level, `struct ws_kdf*` will also point
+/// to the recommended function with the desired security level.
+///
+/// The function returns `1` if the key derivation function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_bsi_validate_kdf(
+  ctx: Context,
+  kdf: Kdf,
+  alternative: *mut Kdf,
+) -> c_int {
+  utilities::c_call(Bsi::validate_kdf, ctx, kdf, alternative)
+}
+
+/// Validates a key encapsulation mechanism.
+///
+/// The guide recommends ML-KEM-768, ML-KEM-1024, FrodoKEM, and Classic
+/// McEliece only in combination with a traditional key agreement scheme
+/// over one of its recommended curves. Hybrid schemes that use X25519
+/// are therefore not compliant.
+///
+/// If the key encapsulation mechanism is not compliant then
+/// `struct ws_kem* alternative` will point to the recommended primitive
+/// that one should use instead.
+///
+/// If the key encapsulation mechanism is compliant but the context
+/// specifies a higher security level, `struct ws_kem*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the key encapsulation mechanism is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_bsi_validate_kem(
+  ctx: Context,
+  kem: Kem,
+  alternative: *mut Kem,
+) -> c_int {
+  utilities::c_call(Bsi::validate_kem, ctx, kem, alternative)
+}
+
+/// Validates a message authentication code.
+///
+/// The guide recommends HMAC, CMAC, and GMAC with a key of at least
+/// 128 bits and a tag of at least 96 bits.
+///
+/// If the message authentication code is not compliant then
+/// `struct ws_mac* alternative` will point to the recommended
+/// primitive that one should use instead.
+///
+/// If the message authentication code is compliant but the context
+/// specifies a higher security level, `struct ws_mac*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the message authentication code is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_bsi_validate_mac(
+  ctx: Context,
+  mac: Mac,
+  alternative: *mut Mac,
+) -> c_int {
+  utilities::c_call(Bsi::validate_mac, ctx, mac, alternative)
+}
+
+/// Validates a mode of operation of a block cipher.
+///
+/// The guide recommends the GCM and CCM authenticated encryption modes
+/// as well as the CBC and CTR modes. Other modes such as ECB and
+/// constructions based on stream ciphers are not recommended.
+///
+/// If the mode is not compliant then `struct ws_mode* alternative` will
+/// point to the recommended mode that one should use instead.
+///
+/// If the mode is compliant then `struct ws_mode*` will point to the
+/// mode itself.
+///
+/// The function returns `1` if the mode is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_bsi_validate_mode(
+  ctx: Context,
+  mode: Mode,
+  alternative: *mut Mode,
+) -> c_int {
+  utilities::c_call(Bsi::validate_mode, ctx, mode, alternative)
+}
+
+/// Validates a password hashing function. Only Argon2id with at least
+/// the parameters of the second option of RFC 9106 is compliant.
+///
+/// If the password hashing function is not compliant then
+/// `struct ws_phf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the password hashing function is compliant then
+/// `struct ws_phf*` will also point to the recommended function.
+///
+/// The function returns `1` if the password hashing function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_bsi_validate_phf(
+  ctx: Context,
+  phf: Phf,
+  alternative: *mut Phf,
+) -> c_int {
+  utilities::c_call(Bsi::validate_phf, ctx, phf, alternative)
+}
+
+/// Validates a post-quantum signature primitive.
+///
+/// The guide recommends the stateful hash-based schemes LMS and XMSS,
+/// SLH-DSA, and the ML-DSA parameter sets that target at least NIST
+/// security category 3.
+///
+/// If the key is not compliant then `struct ws_pqs* alternative` will
+/// point to the recommended primitive that one should use instead.
+///
+/// If the key is compliant but the context specifies a higher security
+/// level, `struct ws_pqs*` will also point to the recommended primitive
+/// with the desired security level.
+///
+/// The function returns `1` if the key is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_bsi_validate_pqs(
+  ctx: Context,
+  key: Pqs,
+  alternative: *mut Pqs,
+) -> c_int {
+  utilities::c_call(Bsi::validate_pqs, ctx, key, alternative)
+}
+
 /// Validates a symmetric key primitive according to pages 24 of the
 /// guide.
 ///
@@ -5,17 +5,50 @@
 //! [CNSA 2.0]: https://media.defense.gov/2022/Sep/07/2003071834/-1/-1/0/CSA_CNSA_2.0_ALGORITHMS_.PDF
 use std::ffi::c_int;
 
-use wardstone_core::context::Context;
+use wardstone_core::primitive::drbg::Drbg;
 use wardstone_core::primitive::ecc::Ecc;
 use wardstone_core::primitive::ffc::Ffc;
 use wardstone_core::primitive::hash::Hash;
 use wardstone_core::primitive::ifc::Ifc;
+use wardstone_core::primitive::kdf::Kdf;
+use wardstone_core::primitive::kem::Kem;
+use wardstone_core::primitive::mac::Mac;
+use wardstone_core::primitive::mode::Mode;
+use wardstone_core::primitive::phf::Phf;
+use wardstone_core::primitive::pqs::Pqs;
 use wardstone_core::primitive::symmetric::Symmetric;
 use wardstone_core::standard::cnsa::Cnsa;
 use wardstone_core::standard::Standard;
 
+use crate::context::Context;
 use crate::utilities;
 
+/// Validates a deterministic random bit generator. Only generators built
+/// on AES-256, SHA-384 or SHA-512 are compliant.
+///
+/// If the generator is not compliant then `struct ws_drbg* alternative`
+/// will point to the recommended generator that one should use instead.
+///
+/// If the generator is compliant but the context specifies a higher
+/// security level, `struct ws_drbg*` will also point to the recommended
+/// generator with the desired security level.
+///
+/// The function returns `1` if the generator is compliant, `0` if it is
+/// not, and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_cnsa_validate_drbg(
+  ctx: Context,
+  drbg: Drbg,
+  alternative: *mut Drbg,
+) -> c_int {
+  utilities::c_call(Cnsa::validate_drbg, ctx, drbg, alternative)
+}
+
 /// Validate an elliptic curve cryptography primitive used for digital
 /// signatures and key establishment.
 ///
@@ -134,6 +167,178 @@ pub unsafe extern "C" fn ws_cnsa_validate_ifc(
   utilities::c_call(Cnsa::validate_ifc, ctx, key, alternative)
 }
 
+/// Validates a key derivation function.
+///
+/// Only functions based on SHA-384 or SHA-512 that derive a key of at
+/// least 256 bits are compliant.
+///
+/// If the key derivation function is not compliant then
+/// `struct ws_kdf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the key derivation function is compliant but the context
+/// specifies a higher security level, `struct ws_kdf*` will also point
+/// to the recommended function with the desired security level.
+///
+/// The function returns `1` if the key derivation function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_cnsa_validate_kdf(
+  ctx: Context,
+  kdf: Kdf,
+  alternative: *mut Kdf,
+) -> c_int {
+  utilities::c_call(Cnsa::validate_kdf, ctx, kdf, alternative)
+}
+
+/// Validates a key encapsulation mechanism.
+///
+/// CNSA 2.0 specifies ML-KEM-1024 for all classification levels. Its
+/// hybrid combination with ECDH over P-384, the curve specified by CNSA
+/// 1.0, is also accepted.
+///
+/// If the key encapsulation mechanism is not compliant then
+/// `struct ws_kem* alternative` will point to the recommended primitive
+/// that one should use instead.
+///
+/// If the key encapsulation mechanism is compliant but the context
+/// specifies a higher security level, `struct ws_kem*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the key encapsulation mechanism is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_cnsa_validate_kem(
+  ctx: Context,
+  kem: Kem,
+  alternative: *mut Kem,
+) -> c_int {
+  utilities::c_call(Cnsa::validate_kem, ctx, kem, alternative)
+}
+
+/// Validates a message authentication code.
+///
+/// Only HMAC based on SHA-384 or SHA-512 with a key of at least 256
+/// bits is compliant.
+///
+/// If the message authentication code is not compliant then
+/// `struct ws_mac* alternative` will point to the recommended
+/// primitive that one should use instead.
+///
+/// If the message authentication code is compliant but the context
+/// specifies a higher security level, `struct ws_mac*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the message authentication code is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_cnsa_validate_mac(
+  ctx: Context,
+  mac: Mac,
+  alternative: *mut Mac,
+) -> c_int {
+  utilities::c_call(Cnsa::validate_mac, ctx, mac, alternative)
+}
+
+/// Validates a mode of operation of a block cipher.
+///
+/// Neither suite restricts the mode of operation that AES-256 is used
+/// with so the mode is assessed against the NIST recommendations
+/// instead.
+///
+/// If the mode is not compliant then `struct ws_mode* alternative` will
+/// point to the recommended mode that one should use instead.
+///
+/// If the mode is compliant then `struct ws_mode*` will point to the
+/// mode itself.
+///
+/// The function returns `1` if the mode is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_cnsa_validate_mode(
+  ctx: Context,
+  mode: Mode,
+  alternative: *mut Mode,
+) -> c_int {
+  utilities::c_call(Cnsa::validate_mode, ctx, mode, alternative)
+}
+
+/// Validates a password hashing function. Only PBKDF2 using
+/// HMAC-SHA512 is compliant provided that it also complies with NIST SP
+/// 800-63B.
+///
+/// If the password hashing function is not compliant then
+/// `struct ws_phf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the password hashing function is compliant then
+/// `struct ws_phf*` will also point to the recommended function.
+///
+/// The function returns `1` if the password hashing function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_cnsa_validate_phf(
+  ctx: Context,
+  phf: Phf,
+  alternative: *mut Phf,
+) -> c_int {
+  utilities::c_call(Cnsa::validate_phf, ctx, phf, alternative)
+}
+
+/// Validates a post-quantum signature primitive.
+///
+/// CNSA 2.0 specifies ML-DSA-87 for all classification levels and
+/// approves all parameter sets of the stateful hash-based LMS and XMSS
+/// schemes for software and firmware signing.
+///
+/// If the key is not compliant then `struct ws_pqs* alternative` will
+/// point to the recommended primitive that one should use instead.
+///
+/// If the key is compliant but the context specifies a higher security
+/// level, `struct ws_pqs*` will also point to the recommended primitive
+/// with the desired security level.
+///
+/// The function returns `1` if the key is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_cnsa_validate_pqs(
+  ctx: Context,
+  key: Pqs,
+  alternative: *mut Pqs,
+) -> c_int {
+  utilities::c_call(Cnsa::validate_pqs, ctx, key, alternative)
+}
+
 /// Validates a symmetric key primitive.
 ///
 /// If the key is not compliant then `struct ws_symmetric* alternative`
@@ -4,17 +4,51 @@
 //! [ECRYPT-CSA D5.4 Algorithms, Key Size and Protocols Report]: https://www.ecrypt.eu.org/csa/documents/D5.4-FinalAlgKeySizeProt.pdf
 use std::ffi::c_int;
 
-use wardstone_core::context::Context;
+use wardstone_core::primitive::drbg::Drbg;
 use wardstone_core::primitive::ecc::Ecc;
 use wardstone_core::primitive::ffc::Ffc;
 use wardstone_core::primitive::hash::Hash;
 use wardstone_core::primitive::ifc::Ifc;
+use wardstone_core::primitive::kdf::Kdf;
+use wardstone_core::primitive::kem::Kem;
+use wardstone_core::primitive::mac::Mac;
+use wardstone_core::primitive::mode::Mode;
+use wardstone_core::primitive::phf::Phf;
+use wardstone_core::primitive::pqs::Pqs;
 use wardstone_core::primitive::symmetric::Symmetric;
 use wardstone_core::standard::ecrypt::Ecrypt;
 use wardstone_core::standard::Standard;
 
+use crate::context::Context;
 use crate::utilities;
 
+/// Validates a deterministic random bit generator. Generators built on
+/// SHA1 or TDEA are only fit for legacy use and Dual_EC_DRBG is not
+/// compliant.
+///
+/// If the generator is not compliant then `struct ws_drbg* alternative`
+/// will point to the recommended generator that one should use instead.
+///
+/// If the generator is compliant but the context specifies a higher
+/// security level, `struct ws_drbg*` will also point to the recommended
+/// generator with the desired security level.
+///
+/// The function returns `1` if the generator is compliant, `0` if it is
+/// not, and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_ecrypt_validate_drbg(
+  ctx: Context,
+  drbg: Drbg,
+  alternative: *mut Drbg,
+) -> c_int {
+  utilities::c_call(Ecrypt::validate_drbg, ctx, drbg, alternative)
+}
+
 /// Validate an elliptic curve cryptography primitive used for digital
 /// signatures and key establishment where f is the key size according
 /// to page 47 of the report.
@@ -139,6 +173,177 @@ pub unsafe extern "C" fn ws_ecrypt_validate_ifc(
   utilities::c_call(Ecrypt::validate_ifc, ctx, key, alternative)
 }
 
+/// Validates a key derivation function.
+///
+/// The pseudorandom function of TLS 1.0 and 1.1 is only fit for legacy
+/// use.
+///
+/// If the key derivation function is not compliant then
+/// `struct ws_kdf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the key derivation function is compliant but the context
+/// specifies a higher security level, `struct ws_kdf*` will also point
+/// to the recommended function with the desired security level.
+///
+/// The function returns `1` if the key derivation function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_ecrypt_validate_kdf(
+  ctx: Context,
+  kdf: Kdf,
+  alternative: *mut Kdf,
+) -> c_int {
+  utilities::c_call(Ecrypt::validate_kdf, ctx, kdf, alternative)
+}
+
+/// Validates a key encapsulation mechanism.
+///
+/// The report predates the standardisation of post-quantum key
+/// encapsulation mechanisms and so none of them are supported.
+///
+/// If the key encapsulation mechanism is not compliant then
+/// `struct ws_kem* alternative` will point to the recommended primitive
+/// that one should use instead.
+///
+/// If the key encapsulation mechanism is compliant but the context
+/// specifies a higher security level, `struct ws_kem*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the key encapsulation mechanism is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_ecrypt_validate_kem(
+  ctx: Context,
+  kem: Kem,
+  alternative: *mut Kem,
+) -> c_int {
+  utilities::c_call(Ecrypt::validate_kem, ctx, kem, alternative)
+}
+
+/// Validates a message authentication code.
+///
+/// HMAC based on MD5 or SHA1 is only fit for legacy use.
+///
+/// If the message authentication code is not compliant then
+/// `struct ws_mac* alternative` will point to the recommended
+/// primitive that one should use instead.
+///
+/// If the message authentication code is compliant but the context
+/// specifies a higher security level, `struct ws_mac*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the message authentication code is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_ecrypt_validate_mac(
+  ctx: Context,
+  mac: Mac,
+  alternative: *mut Mac,
+) -> c_int {
+  utilities::c_call(Ecrypt::validate_mac, ctx, mac, alternative)
+}
+
+/// Validates a mode of operation of a block cipher or the construction
+/// of an authenticated encryption scheme.
+///
+/// The report recommends the CBC, CTR, and XTS modes, the CCM, GCM, and
+/// OCB authenticated encryption modes, and ChaCha20 with Poly1305 for
+/// future use. The CFB and OFB modes are only fit for legacy use
+/// whereas ECB is not recommended at all.
+///
+/// If the mode is not compliant then `struct ws_mode* alternative` will
+/// point to the recommended mode that one should use instead.
+///
+/// If the mode is compliant then `struct ws_mode*` will point to the
+/// mode itself.
+///
+/// The function returns `1` if the mode is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_ecrypt_validate_mode(
+  ctx: Context,
+  mode: Mode,
+  alternative: *mut Mode,
+) -> c_int {
+  utilities::c_call(Ecrypt::validate_mode, ctx, mode, alternative)
+}
+
+/// Validates a password hashing function. Argon2, scrypt, and PBKDF2
+/// with SHA256 or SHA512 are compliant while PBKDF2 with SHA1 and
+/// bcrypt are only fit for legacy use.
+///
+/// If the password hashing function is not compliant then
+/// `struct ws_phf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the password hashing function is compliant then
+/// `struct ws_phf*` will also point to the recommended function.
+///
+/// The function returns `1` if the password hashing function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_ecrypt_validate_phf(
+  ctx: Context,
+  phf: Phf,
+  alternative: *mut Phf,
+) -> c_int {
+  utilities::c_call(Ecrypt::validate_phf, ctx, phf, alternative)
+}
+
+/// Validates a post-quantum signature primitive.
+///
+/// The report predates the standardisation of post-quantum signature
+/// schemes and so none of them are supported.
+///
+/// If the key is not compliant then `struct ws_pqs* alternative` will
+/// point to the recommended primitive that one should use instead.
+///
+/// If the key is compliant but the context specifies a higher security
+/// level, `struct ws_pqs*` will also point to the recommended primitive
+/// with the desired security level.
+///
+/// The function returns `1` if the key is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_ecrypt_validate_pqs(
+  ctx: Context,
+  key: Pqs,
+  alternative: *mut Pqs,
+) -> c_int {
+  utilities::c_call(Ecrypt::validate_pqs, ctx, key, alternative)
+}
+
 /// Validates a symmetric key primitive according to pages 37 to 40 of
 /// the report.
 ///
@@ -3,17 +3,51 @@
 //! of Information Security, 06/2004.
 use std::ffi::c_int;
 
-use wardstone_core::context::Context;
+use wardstone_core::primitive::drbg::Drbg;
 use wardstone_core::primitive::ecc::Ecc;
 use wardstone_core::primitive::ffc::Ffc;
 use wardstone_core::primitive::hash::Hash;
 use wardstone_core::primitive::ifc::Ifc;
+use wardstone_core::primitive::kdf::Kdf;
+use wardstone_core::primitive::kem::Kem;
+use wardstone_core::primitive::mac::Mac;
+use wardstone_core::primitive::mode::Mode;
+use wardstone_core::primitive::phf::Phf;
+use wardstone_core::primitive::pqs::Pqs;
 use wardstone_core::primitive::symmetric::Symmetric;
 use wardstone_core::standard::lenstra::Lenstra;
 use wardstone_core::standard::Standard;
 
+use crate::context::Context;
 use crate::utilities;
 
+/// Validates a deterministic random bit generator. The paper is only
+/// concerned with key lengths so only the security strength is
+/// assessed.
+///
+/// If the generator is not compliant then `struct ws_drbg* alternative`
+/// will point to the recommended generator that one should use instead.
+///
+/// If the generator is compliant but the context specifies a higher
+/// security level, `struct ws_drbg*` will also point to the recommended
+/// generator with the desired security level.
+///
+/// The function returns `1` if the generator is compliant, `0` if it is
+/// not, and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_lenstra_validate_drbg(
+  ctx: Context,
+  drbg: Drbg,
+  alternative: *mut Drbg,
+) -> c_int {
+  utilities::c_call(Lenstra::validate_drbg, ctx, drbg, alternative)
+}
+
 /// Validate an elliptic curve cryptography primitive used for digital
 /// signatures and key establishment where f is the key size.
 ///
@@ -134,6 +168,171 @@ pub unsafe extern "C" fn ws_lenstra_validate_ifc(
   utilities::c_call(Lenstra::validate_ifc, ctx, key, alternative)
 }
 
+/// Validates a key derivation function based on the length of the
+/// derived key.
+///
+/// If the key derivation function is not compliant then
+/// `struct ws_kdf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the key derivation function is compliant but the context
+/// specifies a higher security level, `struct ws_kdf*` will also point
+/// to the recommended function with the desired security level.
+///
+/// The function returns `1` if the key derivation function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_lenstra_validate_kdf(
+  ctx: Context,
+  kdf: Kdf,
+  alternative: *mut Kdf,
+) -> c_int {
+  utilities::c_call(Lenstra::validate_kdf, ctx, kdf, alternative)
+}
+
+/// Validates a key encapsulation mechanism.
+///
+/// The paper predates these schemes so the security of a parameter set
+/// is taken to be the one implied by its NIST security category.
+///
+/// If the key encapsulation mechanism is not compliant then
+/// `struct ws_kem* alternative` will point to the recommended primitive
+/// that one should use instead.
+///
+/// If the key encapsulation mechanism is compliant but the context
+/// specifies a higher security level, `struct ws_kem*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the key encapsulation mechanism is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_lenstra_validate_kem(
+  ctx: Context,
+  kem: Kem,
+  alternative: *mut Kem,
+) -> c_int {
+  utilities::c_call(Lenstra::validate_kem, ctx, kem, alternative)
+}
+
+/// Validates a message authentication code based on the length of
+/// its key.
+///
+/// If the message authentication code is not compliant then
+/// `struct ws_mac* alternative` will point to the recommended
+/// primitive that one should use instead.
+///
+/// If the message authentication code is compliant but the context
+/// specifies a higher security level, `struct ws_mac*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the message authentication code is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_lenstra_validate_mac(
+  ctx: Context,
+  mac: Mac,
+  alternative: *mut Mac,
+) -> c_int {
+  utilities::c_call(Lenstra::validate_mac, ctx, mac, alternative)
+}
+
+/// Validates a mode of operation of a block cipher.
+///
+/// The paper is only concerned with key lengths so every mode is deemed
+/// compliant except for ECB which reveals patterns in the plaintext no
+/// matter the key length.
+///
+/// If the mode is not compliant then `struct ws_mode* alternative` will
+/// point to the recommended mode that one should use instead.
+///
+/// If the mode is compliant then `struct ws_mode*` will point to the
+/// mode itself.
+///
+/// The function returns `1` if the mode is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_lenstra_validate_mode(
+  ctx: Context,
+  mode: Mode,
+  alternative: *mut Mode,
+) -> c_int {
+  utilities::c_call(Lenstra::validate_mode, ctx, mode, alternative)
+}
+
+/// Validates a password hashing function. The paper is only concerned
+/// with key lengths and so none of these functions are supported.
+///
+/// If the password hashing function is not compliant then
+/// `struct ws_phf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the password hashing function is compliant then
+/// `struct ws_phf*` will also point to the recommended function.
+///
+/// The function returns `1` if the password hashing function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_lenstra_validate_phf(
+  ctx: Context,
+  phf: Phf,
+  alternative: *mut Phf,
+) -> c_int {
+  utilities::c_call(Lenstra::validate_phf, ctx, phf, alternative)
+}
+
+/// Validates a post-quantum signature primitive.
+///
+/// The paper predates these schemes so the security of a parameter set
+/// is taken to be the one implied by its NIST security category.
+///
+/// If the key is not compliant then `struct ws_pqs* alternative` will
+/// point to the recommended primitive that one should use instead.
+///
+/// If the key is compliant but the context specifies a higher security
+/// level, `struct ws_pqs*` will also point to the recommended primitive
+/// with the desired security level.
+///
+/// The function returns `1` if the key is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_lenstra_validate_pqs(
+  ctx: Context,
+  key: Pqs,
+  alternative: *mut Pqs,
+) -> c_int {
+  utilities::c_call(Lenstra::validate_pqs, ctx, key, alternative)
+}
+
 /// Validates a symmetric key primitive according to pages 9-12 of the
 /// paper.
 ///
@@ -4,17 +4,51 @@
 //! [NIST Special Publication 800-57 Part 1 Revision 5 standard]: https://doi.org/10.6028/NIST.SP.800-57pt1r5
 use std::ffi::c_int;
 
-use wardstone_core::context::Context;
+use wardstone_core::primitive::drbg::Drbg;
 use wardstone_core::primitive::ecc::Ecc;
 use wardstone_core::primitive::ffc::Ffc;
 use wardstone_core::primitive::hash::Hash;
 use wardstone_core::primitive::ifc::Ifc;
+use wardstone_core::primitive::kdf::Kdf;
+use wardstone_core::primitive::kem::Kem;
+use wardstone_core::primitive::mac::Mac;
+use wardstone_core::primitive::mode::Mode;
+use wardstone_core::primitive::phf::Phf;
+use wardstone_core::primitive::pqs::Pqs;
 use wardstone_core::primitive::symmetric::Symmetric;
 use wardstone_core::standard::nist::Nist;
 use wardstone_core::standard::Standard;
 
+use crate::context::Context;
 use crate::utilities;
 
+/// Validates a deterministic random bit generator according to SP
+/// 800-90A Rev. 1 which specifies Hash_DRBG, HMAC_DRBG, and CTR_DRBG.
+/// Dual_EC_DRBG is disallowed.
+///
+/// If the generator is not compliant then `struct ws_drbg* alternative`
+/// will point to the recommended generator that one should use instead.
+///
+/// If the generator is compliant but the context specifies a higher
+/// security level, `struct ws_drbg*` will also point to the recommended
+/// generator with the desired security level.
+///
+/// The function returns `1` if the generator is compliant, `0` if it is
+/// not, and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_nist_validate_drbg(
+  ctx: Context,
+  drbg: Drbg,
+  alternative: *mut Drbg,
+) -> c_int {
+  utilities::c_call(Nist::validate_drbg, ctx, drbg, alternative)
+}
+
 /// Validate an elliptic curve cryptography primitive used for digital
 /// signatures and key establishment where f is the key size according
 /// to page 54-55 of the standard.
@@ -104,6 +138,169 @@ pub unsafe extern "C" fn ws_nist_validate_ifc(
   utilities::c_call(Nist::validate_ifc, ctx, key, alternative)
 }
 
+/// Validates a key derivation function according to SP 800-108, SP
+/// 800-56C, SP 800-132, and SP 800-135 where PBKDF2 must use an
+/// iteration count of at least 1000.
+///
+/// If the key derivation function is not compliant then
+/// `struct ws_kdf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the key derivation function is compliant but the context
+/// specifies a higher security level, `struct ws_kdf*` will also point
+/// to the recommended function with the desired security level.
+///
+/// The function returns `1` if the key derivation function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_nist_validate_kdf(
+  ctx: Context,
+  kdf: Kdf,
+  alternative: *mut Kdf,
+) -> c_int {
+  utilities::c_call(Nist::validate_kdf, ctx, kdf, alternative)
+}
+
+/// Validates a key encapsulation mechanism according to FIPS 203 which
+/// specifies ML-KEM. Hybrid schemes are accepted when ML-KEM is one of
+/// the components that are combined (see section 4.6 of SP 800-227).
+///
+/// If the key encapsulation mechanism is not compliant then
+/// `struct ws_kem* alternative` will point to the recommended primitive
+/// that one should use instead.
+///
+/// If the key encapsulation mechanism is compliant but the context
+/// specifies a higher security level, `struct ws_kem*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the key encapsulation mechanism is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_nist_validate_kem(
+  ctx: Context,
+  kem: Kem,
+  alternative: *mut Kem,
+) -> c_int {
+  utilities::c_call(Nist::validate_kem, ctx, kem, alternative)
+}
+
+/// Validates a message authentication code according to SP 800-107
+/// Rev. 1 and SP 800-131A Rev. 2 which approve HMAC, KMAC, CMAC, and
+/// GMAC with a key of at least 112 bits and a tag of at least 32 bits.
+///
+/// If the message authentication code is not compliant then
+/// `struct ws_mac* alternative` will point to the recommended
+/// primitive that one should use instead.
+///
+/// If the message authentication code is compliant but the context
+/// specifies a higher security level, `struct ws_mac*` will also point
+/// to the recommended primitive with the desired security level.
+///
+/// The function returns `1` if the message authentication code is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_nist_validate_mac(
+  ctx: Context,
+  mac: Mac,
+  alternative: *mut Mac,
+) -> c_int {
+  utilities::c_call(Nist::validate_mac, ctx, mac, alternative)
+}
+
+/// Validates a mode of operation of a block cipher according to the
+/// SP 800-38 series of recommendations which specify the ECB, CBC, CFB,
+/// OFB, CTR, CCM, GCM, and XTS modes.
+///
+/// If the mode is not compliant then `struct ws_mode* alternative` will
+/// point to the recommended mode that one should use instead.
+///
+/// If the mode is compliant then `struct ws_mode*` will point to the
+/// mode itself.
+///
+/// The function returns `1` if the mode is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_nist_validate_mode(
+  ctx: Context,
+  mode: Mode,
+  alternative: *mut Mode,
+) -> c_int {
+  utilities::c_call(Nist::validate_mode, ctx, mode, alternative)
+}
+
+/// Validates a password hashing function according to SP 800-63B which
+/// approves PBKDF2 with an iteration count of at least 10,000.
+///
+/// If the password hashing function is not compliant then
+/// `struct ws_phf* alternative` will point to the recommended
+/// function that one should use instead.
+///
+/// If the password hashing function is compliant then
+/// `struct ws_phf*` will also point to the recommended function.
+///
+/// The function returns `1` if the password hashing function is
+/// compliant, `0` if it is not, and `-1` if an error occurs as a result
+/// of a missing or invalid argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_nist_validate_phf(
+  ctx: Context,
+  phf: Phf,
+  alternative: *mut Phf,
+) -> c_int {
+  utilities::c_call(Nist::validate_phf, ctx, phf, alternative)
+}
+
+/// Validates a post-quantum signature primitive according to FIPS 204,
+/// FIPS 205, and SP 800-208 which specify ML-DSA, SLH-DSA, and the
+/// stateful hash-based LMS and XMSS schemes respectively.
+///
+/// If the key is not compliant then `struct ws_pqs* alternative` will
+/// point to the recommended primitive that one should use instead.
+///
+/// If the key is compliant but the context specifies a higher security
+/// level, `struct ws_pqs*` will also point to the recommended primitive
+/// with the desired security level.
+///
+/// The function returns `1` if the key is compliant, `0` if it is not,
+/// and `-1` if an error occurs as a result of a missing or invalid
+/// argument.
+///
+/// # Safety
+///
+/// See crate documentation for comment on safety.
+#[no_mangle]
+pub unsafe extern "C" fn ws_nist_validate_pqs(
+  ctx: Context,
+  key: Pqs,
+  alternative: *mut Pqs,
+) -> c_int {
+  utilities::c_call(Nist::validate_pqs, ctx, key, alternative)
+}
+
 /// Validates a hash function according to page 56 of the standard. The
 /// reference is made with regards to applications that require
 /// collision resistance such as digital signatures.
@@ -1,16 +1,22 @@
 use std::ffi::c_int;
 
-use wardstone_core::context::Context;
+use wardstone_core::context;
+use wardstone_core::standard::Verdict;
+
+use crate::context::Context;
 
 /// A utility function that abstracts a call to a Rust function `f` and
 /// returns a result following C error handling conventions.
+///
+/// The context is validated here as C callers may build it without
+/// going through `ws_context_new`.
 pub(crate) unsafe fn c_call<T>(
-  f: fn(Context, T) -> Result<T, T>,
+  f: fn(context::Context, T) -> Verdict<T>,
   ctx: Context,
   primitive: T,
   alternative: *mut T,
 ) -> c_int {
-  let (recommendation, is_compliant) = match f(ctx, primitive) {
+  let (recommendation, is_compliant) = match f(ctx.into(), primitive).into_result() {
     Ok(recommendation) => (recommendation, true),
     Err(recommendation) => (recommendation, false),
   };
@@ -0,0 +1,112 @@
+#!/usr/bin/env sh
+# Generates the keys, certificates and OpenSSH files used by the unit
+# tests of the command-line application.
+#
+# Unlike the test certificates generated by generate_certificates.py,
+# these are kept out of Git Large File Storage so that the unit tests
+# can run without it.
+set -eu
+
+out="${1:-$(dirname "$0")/../crates/cmd/src/testing/fixtures}"
+mkdir -p "$out"
+cd "$out"
+tmp="$(mktemp -d)"
+trap 'rm -rf "$tmp"' EXIT
+
+days=36500
+ec() {
+	openssl genpkey -algorithm ec -pkeyopt ec_paramgen_curve:P-384 -out "$1"
+}
+
+# A chain from a leaf to a root where every certificate is signed with
+# ECDSA on P-384 and SHA-384.
+ec "$tmp/root.key"
+ec "$tmp/intermediate.key"
+ec "$tmp/leaf.key"
+openssl req -x509 -new -key "$tmp/root.key" -sha384 -days $days \
+	-subj "/CN=Wardstone Root" \
+	-addext "keyUsage=critical,keyCertSign,cRLSign" \
+	-addext "basicConstraints=critical,CA:true" -out "$tmp/root.pem"
+openssl req -new -key "$tmp/intermediate.key" -subj "/CN=Wardstone Intermediate" \
+	-out "$tmp/intermediate.csr"
+printf 'keyUsage=critical,keyCertSign,cRLSign\nbasicConstraints=critical,CA:true\n' \
+	>"$tmp/ca.ext"
+printf 'keyUsage=critical,digitalSignature\n' >"$tmp/leaf.ext"
+for hash in sha384 sha1; do
+	openssl x509 -req -in "$tmp/intermediate.csr" -CA "$tmp/root.pem" \
+		-CAkey "$tmp/root.key" -set_serial 2 -$hash -days $days \
+		-extfile "$tmp/ca.ext" -out "$tmp/intermediate_$hash.pem"
+done
+openssl req -new -key "$tmp/leaf.key" -subj "/CN=wardstone.test" -out "$tmp/leaf.csr"
+openssl x509 -req -in "$tmp/leaf.csr" -CA "$tmp/intermediate_sha384.pem" \
+	-CAkey "$tmp/intermediate.key" -set_serial 3 -sha384 -days $days \
+	-extfile "$tmp/leaf.ext" -out leaf.pem
+openssl x509 -in leaf.pem -outform der -out leaf.der
+cat leaf.pem "$tmp/intermediate_sha384.pem" "$tmp/root.pem" >chain.pem
+cat leaf.pem "$tmp/intermediate_sha1.pem" "$tmp/root.pem" >chain_sha1_intermediate.pem
+openssl crl2pkcs7 -nocrl -certfile chain.pem -outform der -out chain.p7b
+openssl pkcs8 -topk8 -nocrypt -in "$tmp/leaf.key" -out "$tmp/leaf_pkcs8.key"
+cat leaf.pem "$tmp/leaf_pkcs8.key" >leaf_with_key.pem
+
+# Self-signed RSASSA-PSS certificates where the parameters are either
+# all absent, and take their default values, or explicit.
+openssl genpkey -algorithm rsa -pkeyopt rsa_keygen_bits:2048 -out "$tmp/rsa.key"
+openssl req -x509 -new -key "$tmp/rsa.key" -days $days -subj "/CN=Wardstone PSS" \
+	-sha1 -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:20 \
+	-sigopt rsa_mgf1_md:sha1 -out pss_default.pem
+openssl req -x509 -new -key "$tmp/rsa.key" -days $days -subj "/CN=Wardstone PSS" \
+	-sha384 -sigopt rsa_padding_mode:pss -sigopt rsa_pss_saltlen:64 \
+	-sigopt rsa_mgf1_md:sha512 -out pss_explicit.pem
+
+# Private keys used for key agreement only and an encrypted PKCS#8 key.
+openssl genpkey -algorithm x25519 -out x25519.key
+openssl genpkey -algorithm x448 -out x448.key
+openssl pkcs8 -topk8 -in "$tmp/leaf.key" -v2 aes-256-cbc -v2prf hmacWithSHA256 \
+	-passout pass:wardstone -out encrypted_pkcs8.key
+
+# Encrypted PKCS#8 keys whose key derivation uses a single PBKDF2
+# iteration and scrypt respectively.
+ec "$tmp/protected.key"
+openssl pkcs8 -topk8 -in "$tmp/protected.key" -v2 aes-256-cbc -v2prf hmacWithSHA256 \
+	-iter 1 -passout pass:wardstone -out encrypted_pkcs8_one_iteration.key
+openssl pkcs8 -topk8 -in "$tmp/protected.key" -v2 aes-256-cbc -scrypt \
+	-scrypt_N 16384 -scrypt_r 8 -scrypt_p 1 -passout pass:wardstone \
+	-out encrypted_pkcs8_scrypt.key
+
+# OpenSSH certificates signed by an RSA certificate authority with the
+# ssh-rsa and rsa-sha2-512 signature algorithms and an expired one.
+ssh-keygen -q -t rsa -b 2048 -N "" -C "ca@wardstone" -f "$tmp/ssh_ca"
+ssh-keygen -q -t ed25519 -N "" -C "user@wardstone" -f "$tmp/ssh_user"
+for algorithm in ssh-rsa rsa-sha2-512; do
+	cp "$tmp/ssh_user.pub" "$tmp/ssh_user_$algorithm.pub"
+	ssh-keygen -q -s "$tmp/ssh_ca" -t "$algorithm" -I "wardstone-$algorithm" \
+		-n alice,bob -O force-command=/bin/true -V always:forever \
+		"$tmp/ssh_user_$algorithm.pub"
+	cp "$tmp/ssh_user_$algorithm-cert.pub" "ssh_$algorithm-cert.pub"
+done
+cp "$tmp/ssh_user.pub" "$tmp/ssh_user_expired.pub"
+ssh-keygen -q -s "$tmp/ssh_ca" -t rsa-sha2-256 -I "wardstone-expired" -n alice \
+	-V 20000101:20010101 "$tmp/ssh_user_expired.pub"
+cp "$tmp/ssh_user_expired-cert.pub" ssh_expired-cert.pub
+
+# An authorized_keys and a known_hosts file with options, markers and
+# hashed host names.
+ssh-keygen -q -t ecdsa -b 256 -N "" -C "ecdsa@wardstone" -f "$tmp/ssh_ecdsa"
+user="$(cut -d ' ' -f 1,2 "$tmp/ssh_user.pub")"
+ecdsa="$(cut -d ' ' -f 1,2 "$tmp/ssh_ecdsa.pub")"
+ca="$(cut -d ' ' -f 1,2 "$tmp/ssh_ca.pub")"
+cat >authorized_keys <<EOF
+# Comments and blank lines are ignored.
+
+$user user@wardstone
+command="echo \\"hello world\\"",no-pty,from="10.0.0.1,10.0.0.2" $ecdsa ecdsa@wardstone
+cert-authority,principals="alice" $ca
+EOF
+cat >known_hosts <<EOF
+wardstone.test,10.0.0.1 $ecdsa
+@cert-authority *.wardstone.test $ca
+@revoked revoked.wardstone.test $user
+EOF
+printf 'hashed.wardstone.test %s\n' "$user" >"$tmp/hashed"
+ssh-keygen -q -H -f "$tmp/hashed" >/dev/null 2>&1
+cat "$tmp/hashed" >>known_hosts